@@ -54,7 +54,7 @@ impl CredentialsCache {
             return None;
         };
 
-        if given_username && credentials.password().is_none() {
+        if given_username && !credentials.is_authenticated() {
             // If given a username, don't return password-less credentials
             trace!("No password in cache for realm {name}");
             return None;
@@ -74,7 +74,7 @@ impl CredentialsCache {
         let credentials = urls.get(url);
         if let Some(credentials) = credentials {
             if username.is_none() || username.as_deref() == credentials.username() {
-                if username.is_some() && credentials.password().is_none() {
+                if username.is_some() && !credentials.is_authenticated() {
                     // If given a username, don't return password-less credentials
                     trace!("No password in cache for URL {url}");
                     return None;
@@ -125,14 +125,13 @@ impl CredentialsCache {
         let mut realms = self.realms.write().unwrap();
 
         // Always replace existing entries if we have a password
-        if credentials.password().is_some() {
+        if credentials.is_authenticated() {
             return realms.insert(key, credentials.clone());
         }
 
         // If we only have a username, add a new entry or replace an existing entry if it doesn't have a password
         let existing = realms.get(&key);
-        if existing.is_none()
-            || existing.is_some_and(|credentials| credentials.password().is_none())
+        if existing.is_none() || existing.is_some_and(|credentials| !credentials.is_authenticated())
         {
             return realms.insert(key, credentials.clone());
         }
@@ -5,22 +5,45 @@ use base64::write::EncoderWriter;
 use netrc::Netrc;
 use reqwest::header::HeaderValue;
 use reqwest::Request;
+use std::fmt;
 use std::io::Read;
 use std::io::Write;
 use url::Url;
 
 use uv_static::EnvVars;
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct Credentials {
-    /// The name of the user for authentication.
-    username: Username,
-    /// The password to use for authentication.
-    password: Option<String>,
+#[derive(Clone, PartialEq)]
+pub enum Credentials {
+    /// HTTP Basic Authentication, with a username and (optionally) a password.
+    Basic {
+        /// The name of the user for authentication.
+        username: Username,
+        /// The password to use for authentication.
+        password: Option<String>,
+    },
+    /// HTTP Bearer Authentication, with a token.
+    Bearer {
+        /// The token to use for authentication.
+        token: String,
+    },
+}
+
+/// Redact the password and token, such that credentials can be safely logged.
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Basic { username, password } => f
+                .debug_struct("Basic")
+                .field("username", username)
+                .field("password", &password.as_ref().map(|_| "****"))
+                .finish(),
+            Self::Bearer { .. } => f.debug_struct("Bearer").field("token", &"****").finish(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Ord, PartialOrd, Hash, Default)]
-pub(crate) struct Username(Option<String>);
+pub struct Username(Option<String>);
 
 impl Username {
     /// Create a new username.
@@ -70,26 +93,60 @@ impl From<Option<String>> for Username {
 
 impl Credentials {
     pub(crate) fn new(username: Option<String>, password: Option<String>) -> Self {
-        Self {
+        Self::Basic {
             username: Username::new(username),
             password,
         }
     }
 
+    /// Create [`Credentials`] for HTTP Bearer Authentication.
+    pub(crate) fn bearer(token: String) -> Self {
+        Self::Bearer { token }
+    }
+
     pub fn username(&self) -> Option<&str> {
-        self.username.as_deref()
+        match self {
+            Self::Basic { username, .. } => username.as_deref(),
+            Self::Bearer { .. } => None,
+        }
     }
 
     pub(crate) fn to_username(&self) -> Username {
-        self.username.clone()
+        match self {
+            Self::Basic { username, .. } => username.clone(),
+            Self::Bearer { .. } => Username::none(),
+        }
     }
 
     pub fn password(&self) -> Option<&str> {
-        self.password.as_deref()
+        match self {
+            Self::Basic { password, .. } => password.as_deref(),
+            Self::Bearer { .. } => None,
+        }
+    }
+
+    /// Return the bearer token, if any.
+    pub fn token(&self) -> Option<&str> {
+        match self {
+            Self::Basic { .. } => None,
+            Self::Bearer { token } => Some(token),
+        }
+    }
+
+    /// Returns `true` if the credentials are complete, i.e., include a password or a token, such
+    /// that no further lookup is necessary.
+    pub(crate) fn is_authenticated(&self) -> bool {
+        match self {
+            Self::Basic { password, .. } => password.is_some(),
+            Self::Bearer { .. } => true,
+        }
     }
 
     pub(crate) fn is_empty(&self) -> bool {
-        self.password.is_none() && self.username.is_none()
+        match self {
+            Self::Basic { username, password } => password.is_none() && username.is_none(),
+            Self::Bearer { .. } => false,
+        }
     }
 
     /// Return [`Credentials`] for a [`Url`] from a [`Netrc`] file, if any.
@@ -107,7 +164,7 @@ impl Credentials {
             return None;
         };
 
-        Some(Credentials {
+        Some(Credentials::Basic {
             username: Username::new(Some(entry.login.clone())),
             password: Some(entry.password.clone()),
         })
@@ -120,7 +177,7 @@ impl Credentials {
         if url.username().is_empty() && url.password().is_none() {
             return None;
         }
-        Some(Self {
+        Some(Self::Basic {
             // Remove percent-encoding from URL credentials
             // See <https://github.com/pypa/pip/blob/06d21db4ff1ab69665c22a88718a4ea9757ca293/src/pip/_internal/utils/misc.py#L497-L499>
             username: if url.username().is_empty() {
@@ -145,9 +202,14 @@ impl Credentials {
 
     /// Extract the [`Credentials`] from the environment, given a named source.
     ///
-    /// For example, given a name of `"pytorch"`, search for `UV_INDEX_PYTORCH_USERNAME` and
-    /// `UV_INDEX_PYTORCH_PASSWORD`.
+    /// For example, given a name of `"pytorch"`, search for `UV_INDEX_PYTORCH_TOKEN`, then for
+    /// `UV_INDEX_PYTORCH_USERNAME` and `UV_INDEX_PYTORCH_PASSWORD`.
     pub fn from_env(name: impl AsRef<str>) -> Option<Self> {
+        if let Ok(token) = std::env::var(EnvVars::index_token(name.as_ref())) {
+            if !token.is_empty() {
+                return Some(Self::bearer(token));
+            }
+        }
         let username = std::env::var(EnvVars::index_username(name.as_ref())).ok();
         let password = std::env::var(EnvVars::index_password(name.as_ref())).ok();
         if username.is_none() && password.is_none() {
@@ -159,7 +221,7 @@ impl Credentials {
 
     /// Parse [`Credentials`] from an HTTP request, if any.
     ///
-    /// Only HTTP Basic Authentication is supported.
+    /// Only HTTP Basic and Bearer Authentication are supported.
     pub(crate) fn from_request(request: &Request) -> Option<Self> {
         // First, attempt to retrieve the credentials from the URL
         Self::from_url(request.url()).or(
@@ -173,13 +235,16 @@ impl Credentials {
 
     /// Parse [`Credentials`] from an authorization header, if any.
     ///
-    /// Only HTTP Basic Authentication is supported.
+    /// Only HTTP Basic and Bearer Authentication are supported.
     /// [`None`] will be returned if another authorization scheme is detected.
     ///
     /// Panics if the authentication is not conformant to the HTTP Basic Authentication scheme:
     /// - The contents must be base64 encoded
     /// - There must be a `:` separator
     pub(crate) fn from_header_value(header: &HeaderValue) -> Option<Self> {
+        if let Some(token) = header.as_bytes().strip_prefix(b"Bearer ") {
+            return Some(Self::bearer(String::from_utf8(token.to_vec()).ok()?));
+        }
         let mut value = header.as_bytes().strip_prefix(b"Basic ")?;
         let mut decoder = DecoderReader::new(&mut value, &BASE64_STANDARD);
         let mut buf = String::new();
@@ -202,10 +267,17 @@ impl Credentials {
         Some(Self::new(username, password))
     }
 
-    /// Create an HTTP Basic Authentication header for the credentials.
+    /// Create an HTTP Basic or Bearer Authentication header for the credentials.
     ///
     /// Panics if the username or password cannot be base64 encoded.
     pub(crate) fn to_header_value(&self) -> HeaderValue {
+        if let Self::Bearer { token } = self {
+            let mut header = HeaderValue::from_str(&format!("Bearer {token}"))
+                .expect("Bearer token should be a valid HeaderValue");
+            header.set_sensitive(true);
+            return header;
+        }
+
         // See: <https://github.com/seanmonstar/reqwest/blob/2c11ef000b151c2eebeed2c18a7b81042220c6b0/src/util.rs#L3>
         let mut buf = b"Basic ".to_vec();
         {
@@ -223,7 +295,8 @@ impl Credentials {
 
     /// Apply the credentials to the given URL.
     ///
-    /// Any existing credentials will be overridden.
+    /// Any existing credentials will be overridden. Bearer tokens cannot be represented in a URL,
+    /// and are not applied.
     #[must_use]
     pub fn apply(&self, mut url: Url) -> Url {
         if let Some(username) = self.username() {
@@ -355,4 +428,35 @@ mod tests {
         assert_debug_snapshot!(header, @r###""Basic dXNlcjpwYXNzd29yZD09""###);
         assert_eq!(Credentials::from_header_value(&header), Some(credentials));
     }
+
+    #[test]
+    fn authenticated_request_bearer() {
+        let url = Url::parse("https://example.com/simple/first/").unwrap();
+        let credentials = Credentials::bearer("token".to_string());
+
+        let mut request = Request::new(reqwest::Method::GET, url);
+        request = credentials.authenticate(request);
+
+        let mut header = request
+            .headers()
+            .get(reqwest::header::AUTHORIZATION)
+            .expect("Authorization header should be set")
+            .clone();
+        header.set_sensitive(false);
+
+        assert_debug_snapshot!(header, @r###""Bearer token""###);
+        assert_eq!(Credentials::from_header_value(&header), Some(credentials));
+    }
+
+    #[test]
+    fn debug_redacts_secrets() {
+        let credentials = Credentials::new(Some("user".to_string()), Some("password".to_string()));
+        assert_eq!(
+            format!("{credentials:?}"),
+            r#"Basic { username: Username(Some("user")), password: Some("****") }"#
+        );
+
+        let credentials = Credentials::bearer("token".to_string());
+        assert_eq!(format!("{credentials:?}"), r#"Bearer { token: "****" }"#);
+    }
 }
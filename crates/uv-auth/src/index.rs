@@ -0,0 +1,228 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use reqwest::header::{HeaderName, HeaderValue, InvalidHeaderName};
+use reqwest::Request;
+use url::Url;
+
+use crate::realm::is_url_prefix;
+use crate::Credentials;
+
+/// When to use authentication for requests to an index.
+#[derive(
+    Default, Debug, Copy, Clone, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum AuthPolicy {
+    /// Authenticate when necessary.
+    ///
+    /// If credentials are provided, they will be used. Otherwise, an unauthenticated request will
+    /// be attempted first. If the request fails, uv will search for credentials, and retry the
+    /// request with any credentials it finds.
+    #[default]
+    Auto,
+    /// Always authenticate.
+    ///
+    /// uv will search for credentials before sending any request, and error if no credentials are
+    /// found, rather than attempting an unauthenticated request.
+    Always,
+    /// Never authenticate.
+    ///
+    /// uv will error if credentials are provided for the index, and will never search for
+    /// credentials.
+    Never,
+}
+
+impl Display for AuthPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => f.write_str("auto"),
+            Self::Always => f.write_str("always"),
+            Self::Never => f.write_str("never"),
+        }
+    }
+}
+
+/// The scheme used to send credentials to an index.
+#[derive(
+    Default, Debug, Copy, Clone, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum AuthScheme {
+    /// HTTP Basic Authentication, with a username and password.
+    #[default]
+    Basic,
+    /// HTTP Bearer Authentication, with a token.
+    ///
+    /// Passwords discovered for the index (e.g., in the credential store) are sent as the token.
+    Bearer,
+}
+
+impl Display for AuthScheme {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Basic => f.write_str("basic"),
+            Self::Bearer => f.write_str("bearer"),
+        }
+    }
+}
+
+/// A custom HTTP header used to send a token to an index (e.g., `PRIVATE-TOKEN`).
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct AuthHeader(HeaderName);
+
+impl AuthHeader {
+    /// Return the [`HeaderName`] of the header.
+    pub fn as_header_name(&self) -> &HeaderName {
+        &self.0
+    }
+}
+
+impl FromStr for AuthHeader {
+    type Err = InvalidHeaderName;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        HeaderName::from_str(s).map(Self)
+    }
+}
+
+impl Display for AuthHeader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0.as_str())
+    }
+}
+
+impl serde::Serialize for AuthHeader {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.0.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for AuthHeader {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for AuthHeader {
+    fn schema_name() -> String {
+        "AuthHeader".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some(
+                    "The name of an HTTP header used to send a token to an index.".to_string(),
+                ),
+                ..schemars::schema::Metadata::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        }
+        .into()
+    }
+}
+
+/// An index, along with its [`AuthPolicy`].
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct Index {
+    /// The URL of the index.
+    pub url: Url,
+    /// The root endpoint of the index, to which the [`AuthPolicy`] applies.
+    ///
+    /// For indexes with a `/simple` endpoint, this excludes the `/simple` segment, such that the
+    /// policy also applies to other endpoints on the index (e.g., file downloads).
+    pub root_url: Url,
+    /// The authentication policy for the index.
+    pub auth_policy: AuthPolicy,
+    /// The scheme used to send credentials to the index.
+    pub auth_scheme: AuthScheme,
+    /// The header used to send a token to the index, in lieu of `Authorization`.
+    pub auth_header: Option<AuthHeader>,
+}
+
+impl Index {
+    /// Returns `true` if the given URL is served by this index.
+    fn is_prefix_for(&self, url: &Url) -> bool {
+        is_url_prefix(&self.root_url, url)
+    }
+
+    /// Returns `true` if the index expects a token, rather than a username and password.
+    fn uses_token(&self) -> bool {
+        self.auth_header.is_some() || self.auth_scheme == AuthScheme::Bearer
+    }
+
+    /// Attach the credentials to the given request, using the index's scheme.
+    ///
+    /// For indexes that expect a token, the token is taken from the credentials' token or, if
+    /// absent, their password (e.g., `__token__` credentials from the credential store).
+    fn authenticate(&self, credentials: &Credentials, mut request: Request) -> Request {
+        if !self.uses_token() {
+            return credentials.authenticate(request);
+        }
+
+        let Some(token) = credentials.token().or(credentials.password()) else {
+            return credentials.authenticate(request);
+        };
+
+        if let Some(header) = self.auth_header.as_ref() {
+            let Ok(mut value) = HeaderValue::from_str(token) else {
+                return credentials.authenticate(request);
+            };
+            value.set_sensitive(true);
+            let headers = request.headers_mut();
+            headers.remove(reqwest::header::AUTHORIZATION);
+            headers.insert(header.as_header_name().clone(), value);
+            request
+        } else {
+            Credentials::bearer(token.to_string()).authenticate(request)
+        }
+    }
+}
+
+/// The set of indexes with a known [`AuthPolicy`].
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Indexes(Vec<Index>);
+
+impl Indexes {
+    /// Create a new set of [`Indexes`].
+    pub fn from_indexes(indexes: impl IntoIterator<Item = Index>) -> Self {
+        Self(indexes.into_iter().collect())
+    }
+
+    /// Return the [`Index`] that serves the given URL, preferring the most specific match.
+    pub fn index_for(&self, url: &Url) -> Option<&Index> {
+        self.0
+            .iter()
+            .filter(|index| index.is_prefix_for(url))
+            .max_by_key(|index| index.root_url.path().trim_end_matches('/').len())
+    }
+
+    /// Return the [`AuthPolicy`] for the given URL.
+    ///
+    /// Defaults to [`AuthPolicy::Auto`] for URLs that aren't served by any known index.
+    pub fn auth_policy_for(&self, url: &Url) -> AuthPolicy {
+        self.index_for(url)
+            .map_or(AuthPolicy::Auto, |index| index.auth_policy)
+    }
+
+    /// Attach the credentials to the given request, using the scheme of the index that serves
+    /// the request, if any.
+    pub(crate) fn authenticate(&self, credentials: &Credentials, request: Request) -> Request {
+        match self.index_for(request.url()) {
+            Some(index) => index.authenticate(credentials, request),
+            None => credentials.authenticate(request),
+        }
+    }
+}
@@ -1,9 +1,22 @@
-use std::process::Stdio;
+use std::path::PathBuf;
+use std::process::{ExitStatus, Stdio};
+use tokio::io::AsyncWriteExt;
 use tokio::process::Command;
 use tracing::{instrument, trace, warn};
 use url::Url;
 
 use crate::credentials::Credentials;
+use crate::helper::CredentialHelper;
+
+#[derive(thiserror::Error, Debug)]
+pub enum KeyringError {
+    #[error("Failed to run the `keyring` command")]
+    Io(#[from] std::io::Error),
+    #[error("The `keyring` command failed ({0})")]
+    Status(ExitStatus),
+    #[error("Credential helpers do not support storing or removing credentials")]
+    Unsupported,
+}
 
 /// A backend for retrieving credentials from a keyring.
 ///
@@ -18,6 +31,8 @@ pub struct KeyringProvider {
 pub(crate) enum KeyringProviderBackend {
     /// Use the `keyring` command to fetch credentials.
     Subprocess,
+    /// Use a credential helper executable to fetch credentials.
+    Helper(CredentialHelper),
     #[cfg(test)]
     Dummy(std::collections::HashMap<(String, &'static str), &'static str>),
 }
@@ -30,6 +45,32 @@ impl KeyringProvider {
         }
     }
 
+    /// Create a new [`KeyringProvider::Helper`] for the given credential helper executable.
+    pub fn helper(command: impl Into<PathBuf>) -> Self {
+        Self {
+            backend: KeyringProviderBackend::Helper(CredentialHelper::new(command.into())),
+        }
+    }
+
+    /// Returns `true` if the provider requires a username to fetch credentials.
+    ///
+    /// Credential helpers determine the username themselves, while the `keyring` command can only
+    /// look up the password for a known username.
+    pub fn requires_username(&self) -> bool {
+        !matches!(self.backend, KeyringProviderBackend::Helper(_))
+    }
+
+    /// Fetch credentials for the given [`Url`] without a known username.
+    ///
+    /// Returns [`None`] if the provider requires a username (see
+    /// [`KeyringProvider::requires_username`]) or if no credentials were found.
+    pub async fn fetch_without_username(&self, url: &Url) -> Option<Credentials> {
+        match self.backend {
+            KeyringProviderBackend::Helper(ref helper) => helper.fetch(url, None).await,
+            _ => None,
+        }
+    }
+
     /// Fetch credentials for the given [`Url`] from the keyring.
     ///
     /// Returns [`None`] if no password was found for the username or if any errors
@@ -50,6 +91,12 @@ impl KeyringProvider {
             "Should only use keyring with a username"
         );
 
+        // Credential helpers receive the full URL and realm, and perform their own lookups.
+        if let KeyringProviderBackend::Helper(ref helper) = self.backend {
+            trace!("Checking credential helper for URL {url}");
+            return helper.fetch(url, Some(username)).await;
+        }
+
         // Check the full URL first
         // <https://github.com/pypa/pip/blob/ae5fff36b0aad6e5e0037884927eaa29163c0611/src/pip/_internal/network/auth.py#L376C1-L379C14>
         trace!("Checking keyring for URL {url}");
@@ -57,6 +104,9 @@ impl KeyringProvider {
             KeyringProviderBackend::Subprocess => {
                 self.fetch_subprocess(url.as_str(), username).await
             }
+            KeyringProviderBackend::Helper(_) => {
+                unreachable!("Credential helpers are handled above")
+            }
             #[cfg(test)]
             KeyringProviderBackend::Dummy(ref store) => {
                 Self::fetch_dummy(store, url.as_str(), username)
@@ -72,6 +122,9 @@ impl KeyringProvider {
             trace!("Checking keyring for host {host}");
             password = match self.backend {
                 KeyringProviderBackend::Subprocess => self.fetch_subprocess(&host, username).await,
+                KeyringProviderBackend::Helper(_) => {
+                    unreachable!("Credential helpers are handled above")
+                }
                 #[cfg(test)]
                 KeyringProviderBackend::Dummy(ref store) => {
                     Self::fetch_dummy(store, &host, username)
@@ -114,6 +167,71 @@ impl KeyringProvider {
         }
     }
 
+    /// Store a password for the given service and username in the keyring.
+    ///
+    /// The service is typically a URL (e.g., `https://example.com/simple`) or a host.
+    #[instrument(skip(self, password))]
+    pub async fn store(
+        &self,
+        service_name: &str,
+        username: &str,
+        password: &str,
+    ) -> Result<(), KeyringError> {
+        match self.backend {
+            KeyringProviderBackend::Subprocess => {
+                // The `keyring` CLI reads the password from stdin when it isn't a terminal.
+                let mut child = Command::new("keyring")
+                    .arg("set")
+                    .arg(service_name)
+                    .arg(username)
+                    .stdin(Stdio::piped())
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::inherit())
+                    .spawn()?;
+
+                let mut stdin = child.stdin.take().expect("Stdin should be piped");
+                stdin.write_all(password.as_bytes()).await?;
+                drop(stdin);
+
+                let status = child.wait().await?;
+                if status.success() {
+                    Ok(())
+                } else {
+                    Err(KeyringError::Status(status))
+                }
+            }
+            KeyringProviderBackend::Helper(_) => Err(KeyringError::Unsupported),
+            #[cfg(test)]
+            KeyringProviderBackend::Dummy(_) => unreachable!("The dummy keyring is read-only"),
+        }
+    }
+
+    /// Remove the password for the given service and username from the keyring.
+    #[instrument(skip(self))]
+    pub async fn remove(&self, service_name: &str, username: &str) -> Result<(), KeyringError> {
+        match self.backend {
+            KeyringProviderBackend::Subprocess => {
+                let status = Command::new("keyring")
+                    .arg("del")
+                    .arg(service_name)
+                    .arg(username)
+                    .stdin(Stdio::null())
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::inherit())
+                    .status()
+                    .await?;
+                if status.success() {
+                    Ok(())
+                } else {
+                    Err(KeyringError::Status(status))
+                }
+            }
+            KeyringProviderBackend::Helper(_) => Err(KeyringError::Unsupported),
+            #[cfg(test)]
+            KeyringProviderBackend::Dummy(_) => unreachable!("The dummy keyring is read-only"),
+        }
+    }
+
     #[cfg(test)]
     fn fetch_dummy(
         store: &std::collections::HashMap<(String, &'static str), &'static str>,
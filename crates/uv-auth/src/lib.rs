@@ -5,15 +5,20 @@ use url::Url;
 
 use cache::CredentialsCache;
 pub use credentials::Credentials;
-pub use keyring::KeyringProvider;
+pub use index::{AuthHeader, AuthPolicy, AuthScheme, Index, Indexes};
+pub use keyring::{KeyringError, KeyringProvider};
 pub use middleware::AuthMiddleware;
 use realm::Realm;
+pub use store::{CredentialStore, Service, StoreError, StoredCredentials};
 
 mod cache;
 mod credentials;
+mod helper;
+mod index;
 mod keyring;
 mod middleware;
 mod realm;
+mod store;
 
 // TODO(zanieb): Consider passing a cache explicitly throughout
 
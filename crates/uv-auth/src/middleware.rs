@@ -5,8 +5,9 @@ use url::Url;
 
 use crate::{
     credentials::{Credentials, Username},
+    index::{AuthPolicy, Indexes},
     realm::Realm,
-    CredentialsCache, KeyringProvider, CREDENTIALS_CACHE,
+    CredentialStore, CredentialsCache, KeyringProvider, CREDENTIALS_CACHE,
 };
 use anyhow::{anyhow, format_err};
 use netrc::Netrc;
@@ -48,26 +49,61 @@ impl NetrcMode {
     }
 }
 
+/// Strategy for loading the uv-managed credential store.
+enum CredentialStoreMode {
+    Automatic(LazyLock<Option<CredentialStore>>),
+    Enabled(CredentialStore),
+    Disabled,
+}
+
+impl Default for CredentialStoreMode {
+    fn default() -> Self {
+        CredentialStoreMode::Automatic(LazyLock::new(|| match CredentialStore::from_settings() {
+            Ok(store) => Some(store),
+            Err(err) => {
+                warn!("Error reading credential store: {err}");
+                None
+            }
+        }))
+    }
+}
+
+impl CredentialStoreMode {
+    /// Get the credential store if enabled.
+    fn get(&self) -> Option<&CredentialStore> {
+        match self {
+            CredentialStoreMode::Automatic(lock) => lock.as_ref(),
+            CredentialStoreMode::Enabled(store) => Some(store),
+            CredentialStoreMode::Disabled => None,
+        }
+    }
+}
+
 /// A middleware that adds basic authentication to requests.
 ///
 /// Uses a cache to propagate credentials from previously seen requests and
-/// fetches credentials from a netrc file and the keyring.
+/// fetches credentials from a netrc file, the credential store, and the keyring.
 pub struct AuthMiddleware {
     netrc: NetrcMode,
+    store: CredentialStoreMode,
     keyring: Option<KeyringProvider>,
     cache: Option<CredentialsCache>,
     /// We know that the endpoint needs authentication, so we don't try to send an unauthenticated
     /// request, avoiding cloning an uncloneable request.
     only_authenticated: bool,
+    /// The indexes with a known [`AuthPolicy`].
+    indexes: Indexes,
 }
 
 impl AuthMiddleware {
     pub fn new() -> Self {
         Self {
             netrc: NetrcMode::default(),
+            store: CredentialStoreMode::default(),
             keyring: None,
             cache: None,
             only_authenticated: false,
+            indexes: Indexes::default(),
         }
     }
 
@@ -84,6 +120,19 @@ impl AuthMiddleware {
         self
     }
 
+    /// Configure the [`CredentialStore`] to use.
+    ///
+    /// `None` disables authentication via the credential store.
+    #[must_use]
+    pub fn with_credential_store(mut self, store: Option<CredentialStore>) -> Self {
+        self.store = if let Some(store) = store {
+            CredentialStoreMode::Enabled(store)
+        } else {
+            CredentialStoreMode::Disabled
+        };
+        self
+    }
+
     /// Configure the [`KeyringProvider`] to use.
     #[must_use]
     pub fn with_keyring(mut self, keyring: Option<KeyringProvider>) -> Self {
@@ -106,6 +155,13 @@ impl AuthMiddleware {
         self
     }
 
+    /// Configure the [`Indexes`] to use, to determine the [`AuthPolicy`] for each request.
+    #[must_use]
+    pub fn with_indexes(mut self, indexes: Indexes) -> Self {
+        self.indexes = indexes;
+        self
+    }
+
     /// Get the configured authentication store.
     ///
     /// If not set, the global store is used.
@@ -139,6 +195,7 @@ impl Middleware for AuthMiddleware {
     ///
     /// - Check the cache (realm key) for a password
     /// - Check the netrc for a password
+    /// - Check the credential store for a password
     /// - Check the keyring for a password
     /// - Perform the request
     /// - Add the username and password to the cache if successful
@@ -156,6 +213,8 @@ impl Middleware for AuthMiddleware {
     /// - On 401, 403, or 404 check for authentication if there was a cache miss
     ///     - Check the cache (realm key) for the username and password
     ///     - Check the netrc for a username and password
+    ///     - Check the credential store for a username and password
+    ///     - Check the credential helper, if configured, for a username and password (or a token)
     ///     - Perform the request again if found
     ///     - Add the username and password to the cache if successful
     async fn handle(
@@ -172,12 +231,26 @@ impl Middleware for AuthMiddleware {
         let url = tracing_url(&request, credentials.as_ref());
         trace!("Handling request for {url}");
 
+        // If the index disables authentication, send the request as-is, without looking up any
+        // credentials.
+        let auth_policy = self.indexes.auth_policy_for(request.url());
+        if auth_policy == AuthPolicy::Never {
+            if credentials.is_some() {
+                return Err(Error::Middleware(format_err!(
+                    "Credentials were provided for {url}, but the index is configured with `authenticate = \"never\"`"
+                )));
+            }
+            trace!("Authentication is disabled for {url}, sending unauthenticated request");
+            return next.run(request, extensions).await;
+        }
+
         if let Some(credentials) = credentials {
             let credentials = Arc::new(credentials);
 
-            // If there's a password, send the request and cache
-            if credentials.password().is_some() {
+            // If there's a password (or a token), send the request and cache
+            if credentials.is_authenticated() {
                 trace!("Request for {url} is already fully authenticated");
+                request = self.indexes.authenticate(&credentials, request);
                 return self
                     .complete_request(Some(credentials), request, extensions, next)
                     .await;
@@ -189,21 +262,21 @@ impl Middleware for AuthMiddleware {
                 .cache()
                 .get_realm(Realm::from(request.url()), credentials.to_username())
             {
-                request = credentials.authenticate(request);
+                request = self.indexes.authenticate(&credentials, request);
                 // Do not insert already-cached credentials
                 None
             } else if let Some(credentials) = self
                 .cache()
                 .get_url(request.url(), &credentials.to_username())
             {
-                request = credentials.authenticate(request);
+                request = self.indexes.authenticate(&credentials, request);
                 // Do not insert already-cached credentials
                 None
             } else if let Some(credentials) = self
                 .fetch_credentials(Some(&credentials), request.url())
                 .await
             {
-                request = credentials.authenticate(request);
+                request = self.indexes.authenticate(&credentials, request);
                 Some(credentials)
             } else {
                 // If we don't find a password, we'll still attempt the request with the existing credentials
@@ -221,10 +294,10 @@ impl Middleware for AuthMiddleware {
         // Check the cache for a URL match first, this can save us from making a failing request
         let credentials = self.cache().get_url(request.url(), &Username::none());
         if let Some(credentials) = credentials.as_ref() {
-            request = credentials.authenticate(request);
+            request = self.indexes.authenticate(&credentials, request);
 
             // If it's fully authenticated, finish the request
-            if credentials.password().is_some() {
+            if credentials.is_authenticated() {
                 trace!("Request for {url} is fully authenticated");
                 return self.complete_request(None, request, extensions, next).await;
             }
@@ -237,46 +310,47 @@ impl Middleware for AuthMiddleware {
             .as_ref()
             .is_some_and(|credentials| credentials.username().is_some());
 
-        let (mut retry_request, response) = if self.only_authenticated {
-            // For endpoints where we require the user to provide credentials, we don't try the
-            // unauthenticated request first.
-            trace!("Checking for credentials for {url}");
-            (request, None)
-        } else {
-            let url = tracing_url(&request, credentials.as_deref());
-            if credentials.is_none() {
-                trace!("Attempting unauthenticated request for {url}");
+        let (mut retry_request, response) =
+            if self.only_authenticated || auth_policy == AuthPolicy::Always {
+                // For endpoints where we require the user to provide credentials, we don't try the
+                // unauthenticated request first.
+                trace!("Checking for credentials for {url}");
+                (request, None)
             } else {
-                trace!("Attempting partially authenticated request for {url}");
-            }
+                let url = tracing_url(&request, credentials.as_deref());
+                if credentials.is_none() {
+                    trace!("Attempting unauthenticated request for {url}");
+                } else {
+                    trace!("Attempting partially authenticated request for {url}");
+                }
 
-            // <https://github.com/TrueLayer/reqwest-middleware/blob/abdf1844c37092d323683c2396b7eefda1418d3c/reqwest-retry/src/middleware.rs#L141-L149>
-            // Clone the request so we can retry it on authentication failure
-            let retry_request = request.try_clone().ok_or_else(|| {
-                Error::Middleware(anyhow!(
-                    "Request object is not cloneable. Are you passing a streaming body?"
-                        .to_string()
-                ))
-            })?;
-
-            let response = next.clone().run(request, extensions).await?;
-
-            // If we don't fail with authorization related codes, return the response
-            if !matches!(
-                response.status(),
-                StatusCode::FORBIDDEN | StatusCode::NOT_FOUND | StatusCode::UNAUTHORIZED
-            ) {
-                return Ok(response);
-            }
+                // <https://github.com/TrueLayer/reqwest-middleware/blob/abdf1844c37092d323683c2396b7eefda1418d3c/reqwest-retry/src/middleware.rs#L141-L149>
+                // Clone the request so we can retry it on authentication failure
+                let retry_request = request.try_clone().ok_or_else(|| {
+                    Error::Middleware(anyhow!(
+                        "Request object is not cloneable. Are you passing a streaming body?"
+                            .to_string()
+                    ))
+                })?;
+
+                let response = next.clone().run(request, extensions).await?;
+
+                // If we don't fail with authorization related codes, return the response
+                if !matches!(
+                    response.status(),
+                    StatusCode::FORBIDDEN | StatusCode::NOT_FOUND | StatusCode::UNAUTHORIZED
+                ) {
+                    return Ok(response);
+                }
 
-            // Otherwise, search for credentials
-            trace!(
-                "Request for {url} failed with {}, checking for credentials",
-                response.status()
-            );
+                // Otherwise, search for credentials
+                trace!(
+                    "Request for {url} failed with {}, checking for credentials",
+                    response.status()
+                );
 
-            (retry_request, Some(response))
-        };
+                (retry_request, Some(response))
+            };
 
         // Check if there are credentials in the realm-level cache
         let credentials = self
@@ -290,9 +364,9 @@ impl Middleware for AuthMiddleware {
             )
             .or(credentials);
         if let Some(credentials) = credentials.as_ref() {
-            if credentials.password().is_some() {
+            if credentials.is_authenticated() {
                 trace!("Retrying request for {url} with credentials from cache {credentials:?}");
-                retry_request = credentials.authenticate(retry_request);
+                retry_request = self.indexes.authenticate(&credentials, retry_request);
                 return self
                     .complete_request(None, retry_request, extensions, next)
                     .await;
@@ -305,7 +379,7 @@ impl Middleware for AuthMiddleware {
             .fetch_credentials(credentials.as_deref(), retry_request.url())
             .await
         {
-            retry_request = credentials.authenticate(retry_request);
+            retry_request = self.indexes.authenticate(&credentials, retry_request);
             trace!("Retrying request for {url} with {credentials:?}");
             return self
                 .complete_request(Some(credentials), retry_request, extensions, next)
@@ -315,7 +389,7 @@ impl Middleware for AuthMiddleware {
         if let Some(credentials) = credentials.as_ref() {
             if !attempt_has_username {
                 trace!("Retrying request for {url} with username from cache {credentials:?}");
-                retry_request = credentials.authenticate(retry_request);
+                retry_request = self.indexes.authenticate(&credentials, retry_request);
                 return self
                     .complete_request(None, retry_request, extensions, next)
                     .await;
@@ -324,6 +398,10 @@ impl Middleware for AuthMiddleware {
 
         if let Some(response) = response {
             Ok(response)
+        } else if auth_policy == AuthPolicy::Always {
+            Err(Error::Middleware(format_err!(
+                "Missing credentials for {url}, but the index is configured with `authenticate = \"always\"`"
+            )))
         } else {
             Err(Error::Middleware(format_err!(
                 "Missing credentials for {url}"
@@ -365,7 +443,7 @@ impl AuthMiddleware {
 
     /// Fetch credentials for a URL.
     ///
-    /// Supports netrc file and keyring lookups.
+    /// Supports netrc file, credential store, and keyring lookups.
     async fn fetch_credentials(
         &self,
         credentials: Option<&Credentials>,
@@ -411,6 +489,17 @@ impl AuthMiddleware {
         }) {
             debug!("Found credentials in netrc file for {url}");
             Some(credentials)
+        } else if let Some(credentials) = self
+            .fetch_from_store(
+                url,
+                credentials
+                    .as_ref()
+                    .and_then(|credentials| credentials.username()),
+            )
+            .await
+        {
+            debug!("Found credentials in credential store for {url}");
+            Some(credentials)
         // N.B. The keyring provider performs lookups for the exact URL then
         //      falls back to the host, but we cache the result per realm so if a keyring
         //      implementation returns different credentials for different URLs in the
@@ -420,6 +509,9 @@ impl AuthMiddleware {
                 if let Some(username) = credentials.and_then(|credentials| credentials.username()) {
                     debug!("Checking keyring for credentials for {username}@{url}");
                     keyring.fetch(url, username).await
+                } else if !keyring.requires_username() {
+                    debug!("Checking credential helper for credentials for {url}");
+                    keyring.fetch_without_username(url).await
                 } else {
                     debug!("Skipping keyring lookup for {url} with no username");
                     None
@@ -439,6 +531,13 @@ impl AuthMiddleware {
 
         credentials
     }
+
+    /// Fetch credentials for a URL from the uv-managed credential store.
+    async fn fetch_from_store(&self, url: &Url, username: Option<&str>) -> Option<Credentials> {
+        let store = self.store.get()?;
+        debug!("Checking credential store for credentials for {url}");
+        store.get(url, username)?.resolve().await
+    }
 }
 
 fn tracing_url(request: &Request, credentials: Option<&Credentials>) -> String {
@@ -472,13 +571,19 @@ mod tests {
     use test_log::test;
 
     use url::Url;
-    use wiremock::matchers::{basic_auth, method, path_regex};
+    use wiremock::matchers::{basic_auth, header, method, path_regex};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use super::*;
+    use crate::{AuthScheme, Index, Service, StoredCredentials};
 
     type Error = Box<dyn std::error::Error>;
 
+    /// Create an [`AuthMiddleware`] that ignores the user's credential store.
+    fn auth_middleware() -> AuthMiddleware {
+        AuthMiddleware::new().with_credential_store(None)
+    }
+
     async fn start_test_server(username: &'static str, password: &'static str) -> MockServer {
         let server = MockServer::start().await;
 
@@ -508,7 +613,7 @@ mod tests {
     async fn test_no_credentials() -> Result<(), Error> {
         let server = start_test_server("user", "password").await;
         let client = test_client_builder()
-            .with(AuthMiddleware::new().with_cache(CredentialsCache::new()))
+            .with(auth_middleware().with_cache(CredentialsCache::new()))
             .build();
 
         assert_eq!(
@@ -540,7 +645,7 @@ mod tests {
 
         let server = start_test_server(username, password).await;
         let client = test_client_builder()
-            .with(AuthMiddleware::new().with_cache(CredentialsCache::new()))
+            .with(auth_middleware().with_cache(CredentialsCache::new()))
             .build();
 
         let base_url = Url::parse(&server.uri())?;
@@ -596,7 +701,7 @@ mod tests {
         );
 
         let client = test_client_builder()
-            .with(AuthMiddleware::new().with_cache(cache))
+            .with(auth_middleware().with_cache(cache))
             .build();
 
         let mut url = base_url.clone();
@@ -647,7 +752,7 @@ mod tests {
         );
 
         let client = test_client_builder()
-            .with(AuthMiddleware::new().with_cache(cache))
+            .with(auth_middleware().with_cache(cache))
             .build();
 
         let mut url = base_url.clone();
@@ -701,7 +806,7 @@ mod tests {
         let server = start_test_server(username, password).await;
         let client = test_client_builder()
             .with(
-                AuthMiddleware::new()
+                auth_middleware()
                     .with_cache(CredentialsCache::new())
                     .with_netrc(Netrc::from_file(netrc_file.path()).ok()),
             )
@@ -731,6 +836,89 @@ mod tests {
         Ok(())
     }
 
+    #[test(tokio::test)]
+    async fn test_credential_store() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+        let server = start_test_server(username, password).await;
+
+        let mut store = CredentialStore::default();
+        store.insert(StoredCredentials {
+            service: Service::from(Url::parse(&server.uri())?),
+            username: username.to_string(),
+            password: Some(password.to_string()),
+            keyring: false,
+        });
+
+        let client = test_client_builder()
+            .with(
+                auth_middleware()
+                    .with_cache(CredentialsCache::new())
+                    .with_netrc(None)
+                    .with_credential_store(Some(store)),
+            )
+            .build();
+
+        assert_eq!(
+            client.get(server.uri()).send().await?.status(),
+            200,
+            "Credentials should be pulled from the credential store"
+        );
+
+        let mut url = Url::parse(&server.uri())?;
+        url.set_username("other").unwrap();
+        assert_eq!(
+            client.get(url).send().await?.status(),
+            401,
+            "Credentials for a different username should not be used"
+        );
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test(tokio::test)]
+    async fn test_credential_helper_bearer() -> Result<(), Error> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header("authorization", "Bearer token"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::tempdir()?;
+        let helper = dir.path().join("helper");
+        fs_err::write(
+            &helper,
+            "#!/bin/sh\ncat > /dev/null\necho '{\"token\": \"token\"}'\n",
+        )?;
+        fs_err::set_permissions(&helper, std::fs::Permissions::from_mode(0o755))?;
+
+        let client = test_client_builder()
+            .with(
+                auth_middleware()
+                    .with_cache(CredentialsCache::new())
+                    .with_netrc(None)
+                    .with_credential_store(None)
+                    .with_keyring(Some(KeyringProvider::helper(helper))),
+            )
+            .build();
+
+        assert_eq!(
+            client.get(server.uri()).send().await?.status(),
+            200,
+            "A token should be pulled from the credential helper without a username"
+        );
+
+        Ok(())
+    }
+
     #[test(tokio::test)]
     async fn test_netrc_file_matching_host() -> Result<(), Error> {
         let username = "user";
@@ -747,7 +935,7 @@ mod tests {
 
         let client = test_client_builder()
             .with(
-                AuthMiddleware::new()
+                auth_middleware()
                     .with_cache(CredentialsCache::new())
                     .with_netrc(Some(
                         Netrc::from_file(netrc_file.path()).expect("Test has valid netrc file"),
@@ -793,7 +981,7 @@ mod tests {
 
         let client = test_client_builder()
             .with(
-                AuthMiddleware::new()
+                auth_middleware()
                     .with_cache(CredentialsCache::new())
                     .with_netrc(Some(
                         Netrc::from_file(netrc_file.path()).expect("Test has valid netrc file"),
@@ -835,7 +1023,7 @@ mod tests {
 
         let client = test_client_builder()
             .with(
-                AuthMiddleware::new()
+                auth_middleware()
                     .with_cache(CredentialsCache::new())
                     .with_netrc(Some(
                         Netrc::from_file(netrc_file.path()).expect("Test has valid netrc file"),
@@ -871,7 +1059,7 @@ mod tests {
 
         let client = test_client_builder()
             .with(
-                AuthMiddleware::new()
+                auth_middleware()
                     .with_cache(CredentialsCache::new())
                     .with_keyring(Some(KeyringProvider::dummy([(
                         (
@@ -942,7 +1130,7 @@ mod tests {
 
         let client = test_client_builder()
             .with(
-                AuthMiddleware::new()
+                auth_middleware()
                     .with_cache(CredentialsCache::new())
                     .with_keyring(Some(KeyringProvider::dummy([(
                         // Omit the port from the keyring entry
@@ -978,21 +1166,22 @@ mod tests {
             &base_url,
             Arc::new(Credentials::new(Some(username.to_string()), None)),
         );
-        let client = test_client_builder()
-            .with(AuthMiddleware::new().with_cache(cache).with_keyring(Some(
-                KeyringProvider::dummy([(
-                    (
-                        format!(
-                            "{}:{}",
-                            base_url.host_str().unwrap(),
-                            base_url.port().unwrap()
+        let client =
+            test_client_builder()
+                .with(auth_middleware().with_cache(cache).with_keyring(Some(
+                    KeyringProvider::dummy([(
+                        (
+                            format!(
+                                "{}:{}",
+                                base_url.host_str().unwrap(),
+                                base_url.port().unwrap()
+                            ),
+                            username,
                         ),
-                        username,
-                    ),
-                    password,
-                )]),
-            )))
-            .build();
+                        password,
+                    )]),
+                )))
+                .build();
 
         assert_eq!(
             client.get(server.uri()).send().await?.status(),
@@ -1041,7 +1230,7 @@ mod tests {
         );
 
         let client = test_client_builder()
-            .with(AuthMiddleware::new().with_cache(cache))
+            .with(auth_middleware().with_cache(cache))
             .build();
 
         // Both servers should work
@@ -1092,7 +1281,7 @@ mod tests {
 
         let client = test_client_builder()
             .with(
-                AuthMiddleware::new()
+                auth_middleware()
                     .with_cache(CredentialsCache::new())
                     .with_keyring(Some(KeyringProvider::dummy([
                         (
@@ -1240,7 +1429,7 @@ mod tests {
         );
 
         let client = test_client_builder()
-            .with(AuthMiddleware::new().with_cache(cache))
+            .with(auth_middleware().with_cache(cache))
             .build();
 
         // Both servers should work
@@ -1346,7 +1535,7 @@ mod tests {
 
         let client = test_client_builder()
             .with(
-                AuthMiddleware::new()
+                auth_middleware()
                     .with_cache(CredentialsCache::new())
                     .with_keyring(Some(KeyringProvider::dummy([
                         (
@@ -1481,7 +1670,7 @@ mod tests {
 
         let client = test_client_builder()
             .with(
-                AuthMiddleware::new()
+                auth_middleware()
                     .with_cache(CredentialsCache::new())
                     .with_keyring(Some(KeyringProvider::dummy([
                         ((base_url_1.clone(), username), password_1),
@@ -1543,4 +1732,229 @@ mod tests {
 
         Ok(())
     }
+
+    /// With `authenticate = "always"`, credentials should be attached to the first request, and
+    /// requests without credentials should fail without being sent.
+    #[test(tokio::test)]
+    async fn test_auth_policy_always() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(basic_auth(username, password))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(401))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let base_url = Url::parse(&server.uri())?;
+        let indexes = Indexes::from_indexes([Index {
+            url: base_url.join("simple")?,
+            root_url: base_url.clone(),
+            auth_policy: AuthPolicy::Always,
+            auth_scheme: AuthScheme::Basic,
+            auth_header: None,
+        }]);
+
+        let client = test_client_builder()
+            .with(
+                auth_middleware()
+                    .with_cache(CredentialsCache::new())
+                    .with_netrc(None)
+                    .with_indexes(indexes.clone()),
+            )
+            .build();
+        let err = client
+            .get(format!("{}/simple/foo/", server.uri()))
+            .send()
+            .await
+            .expect_err("Requests without credentials should fail");
+        assert!(
+            err.to_string().contains("authenticate = \"always\""),
+            "The error should reference the auth policy: {err}"
+        );
+
+        let mut netrc_file = NamedTempFile::new()?;
+        writeln!(
+            netrc_file,
+            r"machine {} login {username} password {password}",
+            base_url.host_str().unwrap()
+        )?;
+        let client = test_client_builder()
+            .with(
+                auth_middleware()
+                    .with_cache(CredentialsCache::new())
+                    .with_netrc(Some(
+                        Netrc::from_file(netrc_file.path()).expect("Test has valid netrc file"),
+                    ))
+                    .with_indexes(indexes),
+            )
+            .build();
+        assert_eq!(
+            client
+                .get(format!("{}/simple/foo/", server.uri()))
+                .send()
+                .await?
+                .status(),
+            200,
+            "Credentials should be attached to the first request"
+        );
+
+        Ok(())
+    }
+
+    /// With `authenticate = "never"`, credentials should never be attached, and requests with
+    /// credentials should fail.
+    #[test(tokio::test)]
+    async fn test_auth_policy_never() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(basic_auth(username, password))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        let base_url = Url::parse(&server.uri())?;
+        let indexes = Indexes::from_indexes([Index {
+            url: base_url.join("simple")?,
+            root_url: base_url.clone(),
+            auth_policy: AuthPolicy::Never,
+            auth_scheme: AuthScheme::Basic,
+            auth_header: None,
+        }]);
+
+        let mut netrc_file = NamedTempFile::new()?;
+        writeln!(
+            netrc_file,
+            r"machine {} login {username} password {password}",
+            base_url.host_str().unwrap()
+        )?;
+        let client = test_client_builder()
+            .with(
+                auth_middleware()
+                    .with_cache(CredentialsCache::new())
+                    .with_netrc(Some(
+                        Netrc::from_file(netrc_file.path()).expect("Test has valid netrc file"),
+                    ))
+                    .with_indexes(indexes),
+            )
+            .build();
+
+        assert_eq!(
+            client
+                .get(format!("{}/simple/foo/", server.uri()))
+                .send()
+                .await?
+                .status(),
+            401,
+            "Credentials should not be pulled from the netrc file"
+        );
+
+        let mut url = base_url.join("simple/foo/")?;
+        url.set_username(username).unwrap();
+        url.set_password(Some(password)).unwrap();
+        let err = client
+            .get(url)
+            .send()
+            .await
+            .expect_err("Requests with credentials should fail");
+        assert!(
+            err.to_string().contains("authenticate = \"never\""),
+            "The error should reference the auth policy: {err}"
+        );
+
+        Ok(())
+    }
+
+    /// Tokens from the credential store should be sent via the index's scheme.
+    #[test(tokio::test)]
+    async fn test_auth_scheme_token() -> Result<(), Error> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path_regex("/bearer/.*"))
+            .and(header("Authorization", "Bearer secret"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path_regex("/header/.*"))
+            .and(header("Job-Token", "secret"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        let base_url = Url::parse(&server.uri())?;
+        let mut store = CredentialStore::default();
+        store.insert(StoredCredentials {
+            service: Service::from(base_url.clone()),
+            username: "__token__".to_string(),
+            password: Some("secret".to_string()),
+            keyring: false,
+        });
+
+        let indexes = Indexes::from_indexes([
+            Index {
+                url: base_url.join("bearer/simple")?,
+                root_url: base_url.join("bearer")?,
+                auth_policy: AuthPolicy::Auto,
+                auth_scheme: AuthScheme::Bearer,
+                auth_header: None,
+            },
+            Index {
+                url: base_url.join("header/simple")?,
+                root_url: base_url.join("header")?,
+                auth_policy: AuthPolicy::Auto,
+                auth_scheme: AuthScheme::Basic,
+                auth_header: Some("Job-Token".parse()?),
+            },
+        ]);
+
+        let client = test_client_builder()
+            .with(
+                auth_middleware()
+                    .with_cache(CredentialsCache::new())
+                    .with_netrc(None)
+                    .with_credential_store(Some(store))
+                    .with_indexes(indexes),
+            )
+            .build();
+
+        assert_eq!(
+            client
+                .get(format!("{}/bearer/simple/foo/", server.uri()))
+                .send()
+                .await?
+                .status(),
+            200,
+            "The stored token should be sent as a bearer token"
+        );
+        assert_eq!(
+            client
+                .get(format!("{}/header/simple/foo/", server.uri()))
+                .send()
+                .await?
+                .status(),
+            200,
+            "The stored token should be sent in the custom header"
+        );
+
+        Ok(())
+    }
 }
@@ -58,12 +58,56 @@ impl Display for Realm {
     }
 }
 
+/// Returns `true` if the URL is in the realm of the prefix and its path is within the prefix's
+/// path, e.g., `https://example.com/simple/anyio` for `https://example.com/simple/`.
+pub(crate) fn is_url_prefix(prefix: &Url, url: &Url) -> bool {
+    if Realm::from(prefix) != Realm::from(url) {
+        return false;
+    }
+
+    let root = prefix.path().trim_end_matches('/');
+    url.path()
+        .strip_prefix(root)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
 #[cfg(test)]
 mod tests {
     use url::{ParseError, Url};
 
+    use crate::realm::is_url_prefix;
     use crate::Realm;
 
+    #[test]
+    fn test_is_url_prefix() -> Result<(), ParseError> {
+        let prefix = Url::parse("https://example.com/simple/")?;
+        assert!(is_url_prefix(
+            &prefix,
+            &Url::parse("https://example.com/simple")?
+        ));
+        assert!(is_url_prefix(
+            &prefix,
+            &Url::parse("https://example.com:443/simple/anyio/")?
+        ));
+        assert!(!is_url_prefix(
+            &prefix,
+            &Url::parse("https://example.com/simple-other/")?
+        ));
+        assert!(!is_url_prefix(
+            &prefix,
+            &Url::parse("http://example.com/simple/")?
+        ));
+        assert!(!is_url_prefix(
+            &prefix,
+            &Url::parse("https://example.com:8443/simple/")?
+        ));
+        assert!(is_url_prefix(
+            &Url::parse("https://example.com")?,
+            &Url::parse("https://example.com/files/anyio.whl")?
+        ));
+        Ok(())
+    }
+
     #[test]
     fn test_should_retain_auth() -> Result<(), ParseError> {
         // Exact match (https)
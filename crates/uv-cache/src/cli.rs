@@ -2,7 +2,7 @@ use std::io;
 use std::path::{Path, PathBuf};
 use uv_static::EnvVars;
 
-use crate::Cache;
+use crate::{Cache, RemoteCache};
 use clap::Parser;
 use tracing::{debug, warn};
 
@@ -29,6 +29,33 @@ pub struct CacheArgs {
     /// To view the location of the cache directory, run `uv cache dir`.
     #[arg(global = true, long, env = EnvVars::UV_CACHE_DIR)]
     pub cache_dir: Option<PathBuf>,
+
+    /// Read-only cache directories to consult for any entries that are missing from the cache
+    /// directory, e.g., a warm cache that was baked into a CI image.
+    ///
+    /// Entries are hard-linked (or copied) into the cache directory when used; the read-only
+    /// directories are never modified.
+    #[arg(
+        global = true,
+        long = "cache-readonly-dir",
+        hide = true,
+        env = EnvVars::UV_CACHE_READONLY_DIRS,
+        value_delimiter = if cfg!(windows) { ';' } else { ':' },
+    )]
+    pub cache_readonly_dirs: Option<Vec<PathBuf>>,
+
+    /// The location of a remote cache for wheels built from source distributions, shared across
+    /// machines.
+    ///
+    /// Either an `https://` URL to a server that supports `GET` and `PUT` requests, or a path (or
+    /// `file://` URL) to a shared directory.
+    #[arg(
+        global = true,
+        long,
+        hide = true,
+        env = EnvVars::UV_REMOTE_BUILD_CACHE,
+    )]
+    pub remote_build_cache: Option<RemoteCache>,
 }
 
 impl Cache {
@@ -39,11 +66,34 @@ impl Cache {
     /// 3. The system-appropriate cache directory.
     /// 4. A `.uv_cache` directory in the current working directory.
     ///
+    /// Unless the user requested `--no-cache`, the given read-only cache layers and remote cache
+    /// are added to the cache.
+    ///
     /// Returns an absolute cache dir.
-    pub fn from_settings(no_cache: bool, cache_dir: Option<PathBuf>) -> Result<Self, io::Error> {
+    pub fn from_settings(
+        no_cache: bool,
+        cache_dir: Option<PathBuf>,
+        readonly_dirs: Vec<PathBuf>,
+        remote: Option<RemoteCache>,
+    ) -> Result<Self, io::Error> {
         if no_cache {
-            Self::temp()
-        } else if let Some(cache_dir) = cache_dir {
+            return Self::temp();
+        }
+
+        let readonly = readonly_dirs
+            .into_iter()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .collect::<Vec<_>>();
+
+        Ok(Self::from_directory(cache_dir)?
+            .with_readonly_layers(readonly)
+            .with_remote(remote))
+    }
+
+    /// Determine the cache directory, preferring the specific cache directory specified by the
+    /// user, if any.
+    fn from_directory(cache_dir: Option<PathBuf>) -> Result<Self, io::Error> {
+        if let Some(cache_dir) = cache_dir {
             Ok(Self::from_path(cache_dir))
         } else if let Some(cache_dir) = uv_dirs::legacy_user_cache_dir().filter(|dir| dir.exists())
         {
@@ -82,7 +132,12 @@ impl TryFrom<CacheArgs> for Cache {
     type Error = io::Error;
 
     fn try_from(value: CacheArgs) -> Result<Self, Self::Error> {
-        Cache::from_settings(value.no_cache, value.cache_dir)
+        Cache::from_settings(
+            value.no_cache,
+            value.cache_dir,
+            value.cache_readonly_dirs.unwrap_or_default(),
+            value.remote_build_cache,
+        )
     }
 }
 
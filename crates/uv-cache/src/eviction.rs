@@ -0,0 +1,594 @@
+//! Size- and age-based eviction of cache entries.
+//!
+//! Eviction covers the archive bucket (i.e., unzipped wheels, whether downloaded or built from
+//! source), the wheel bucket (pointers to those archives, and wheel metadata), and the source
+//! distribution bucket (source trees and built wheels, per revision), which together account for
+//! the vast majority of the cache's size. The last access time of an archive is tracked via the
+//! modification time of its directory, which is refreshed whenever the archive is installed into
+//! an environment (see [`touch`]).
+
+use std::collections::hash_map::Entry;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use tracing::debug;
+
+use crate::{rm_rf, Cache, CacheBucket, Removal};
+
+/// A size in bytes, e.g., `20GiB` or `500MB`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteSize(u64);
+
+impl ByteSize {
+    /// Returns the size in bytes.
+    pub fn as_u64(self) -> u64 {
+        self.0
+    }
+}
+
+impl From<u64> for ByteSize {
+    fn from(bytes: u64) -> Self {
+        Self(bytes)
+    }
+}
+
+impl FromStr for ByteSize {
+    type Err = String;
+
+    /// Parse a [`ByteSize`] from a string.
+    ///
+    /// Accepts a number followed by an optional unit, e.g., `1024`, `500MB`, or `20GiB`. Decimal
+    /// units (`KB`, `MB`, ...) are powers of 1000, while binary units (`KiB`, `MiB`, ...) and
+    /// single-letter units (`K`, `M`, ...) are powers of 1024.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let trimmed = input.trim();
+        let split = trimmed
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(trimmed.len());
+        let (number, unit) = trimmed.split_at(split);
+        let number = number
+            .parse::<f64>()
+            .map_err(|_| format!("`{input}` is not a valid size (expected, e.g., `20GiB`)"))?;
+        let multiplier: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+            "" | "b" => 1,
+            "kb" => 1000,
+            "mb" => 1000u64.pow(2),
+            "gb" => 1000u64.pow(3),
+            "tb" => 1000u64.pow(4),
+            "k" | "kib" => 1024,
+            "m" | "mib" => 1024u64.pow(2),
+            "g" | "gib" => 1024u64.pow(3),
+            "t" | "tib" => 1024u64.pow(4),
+            _ => {
+                return Err(format!(
+                    "`{input}` has an unknown unit (expected one of: `B`, `KB`, `KiB`, `MB`, `MiB`, `GB`, `GiB`, `TB`, `TiB`)"
+                ))
+            }
+        };
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(Self((number * multiplier as f64) as u64))
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ByteSize {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        ByteSize::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ByteSize {
+    fn schema_name() -> String {
+        "ByteSize".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            string: Some(Box::new(schemars::schema::StringValidation {
+                pattern: Some(r"^\s*\d+(\.\d+)?\s*([KkMmGgTt]([Ii]?[Bb])?|[Bb])?\s*$".to_string()),
+                ..schemars::schema::StringValidation::default()
+            })),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some(
+                    "A size in bytes, with an optional unit (e.g., `500MB` or `20GiB`)."
+                        .to_string(),
+                ),
+                ..schemars::schema::Metadata::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        }
+        .into()
+    }
+}
+
+/// A duration, e.g., `30d` or `12h`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Age(Duration);
+
+impl Age {
+    /// Returns the age as a [`Duration`].
+    pub fn as_duration(self) -> Duration {
+        self.0
+    }
+}
+
+impl FromStr for Age {
+    type Err = String;
+
+    /// Parse an [`Age`] from a string.
+    ///
+    /// Accepts a number followed by a unit, one of `s`, `m`, `h`, `d`, or `w` (e.g., `30d`).
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let trimmed = input.trim();
+        let split = trimmed
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(trimmed.len());
+        let (number, unit) = trimmed.split_at(split);
+        let number = number
+            .parse::<u64>()
+            .map_err(|_| format!("`{input}` is not a valid age (expected, e.g., `30d`)"))?;
+        let seconds = match unit.trim().to_ascii_lowercase().as_str() {
+            "s" | "sec" | "secs" | "second" | "seconds" => 1,
+            "m" | "min" | "mins" | "minute" | "minutes" => 60,
+            "h" | "hr" | "hrs" | "hour" | "hours" => 60 * 60,
+            "d" | "day" | "days" => 60 * 60 * 24,
+            "w" | "week" | "weeks" => 60 * 60 * 24 * 7,
+            _ => {
+                return Err(format!(
+                    "`{input}` has an unknown unit (expected one of: `s`, `m`, `h`, `d`, `w`)"
+                ))
+            }
+        };
+        Ok(Self(Duration::from_secs(number.saturating_mul(seconds))))
+    }
+}
+
+/// Record an access to a cache entry, for the purpose of least-recently-used eviction.
+///
+/// Updates the modification time of the entry. Failures are ignored, as access tracking is
+/// best-effort.
+pub fn touch(path: impl AsRef<Path>) {
+    let path = path.as_ref();
+    match open_for_touch(path) {
+        Ok(file) => {
+            if let Err(err) = file.set_modified(SystemTime::now()) {
+                debug!("Failed to update mtime for {}: {err}", path.display());
+            }
+        }
+        Err(err) => debug!("Failed to open {} to update mtime: {err}", path.display()),
+    }
+}
+
+/// Open a file or directory such that its modification time can be updated.
+// `File.set_modified` is not available in `fs_err` yet
+#[allow(clippy::disallowed_types)]
+fn open_for_touch(path: &Path) -> io::Result<std::fs::File> {
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::OpenOptionsExt;
+
+        // Directories can only be opened with `FILE_FLAG_BACKUP_SEMANTICS`, and updating the
+        // timestamps requires `FILE_WRITE_ATTRIBUTES`.
+        const FILE_WRITE_ATTRIBUTES: u32 = 0x0100;
+        const FILE_FLAG_BACKUP_SEMANTICS: u32 = 0x0200_0000;
+        std::fs::OpenOptions::new()
+            .access_mode(FILE_WRITE_ATTRIBUTES)
+            .custom_flags(FILE_FLAG_BACKUP_SEMANTICS)
+            .open(path)
+    }
+    #[cfg(not(windows))]
+    {
+        std::fs::File::open(path)
+    }
+}
+
+/// Mark an archive as symlinked into an environment, such that it's never evicted.
+///
+/// Unlike hard links and copies, environments that were installed with symlinks break when the
+/// underlying archive is removed.
+pub fn pin(archive: impl AsRef<Path>) {
+    let marker = pin_marker(archive.as_ref());
+    if marker.is_file() {
+        return;
+    }
+    if let Err(err) = fs_err::write(&marker, "") {
+        debug!("Failed to pin archive {}: {err}", marker.display());
+    }
+}
+
+/// Return the path to the marker file that pins the given archive.
+pub(crate) fn pin_marker(archive: &Path) -> PathBuf {
+    let mut name = archive.file_name().unwrap_or_default().to_os_string();
+    name.push(".pinned");
+    archive.with_file_name(name)
+}
+
+/// Returns `true` if the given path is a pin marker (see [`pin`]).
+pub(crate) fn is_pin_marker(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "pinned")
+}
+
+/// A set of cache entries that are evicted together.
+///
+/// Archives are grouped with the wheel entries (pointers, metadata, and links) and source
+/// distribution revisions that refer to them, such that eviction never leaves any of them
+/// dangling.
+#[derive(Debug)]
+struct Unit {
+    /// The files and directories that make up the unit.
+    paths: Vec<PathBuf>,
+    /// The archives the unit links to.
+    links: Vec<PathBuf>,
+    /// The most recent access time of any entry in the unit.
+    last_access: SystemTime,
+    /// The number of bytes that would be freed by removing the unit.
+    reclaimable: u64,
+    /// Whether the unit includes an archive that's symlinked into an environment.
+    pinned: bool,
+}
+
+impl Unit {
+    fn new(paths: Vec<PathBuf>, last_access: SystemTime) -> Self {
+        Self {
+            paths,
+            links: Vec::new(),
+            last_access,
+            reclaimable: 0,
+            pinned: false,
+        }
+    }
+
+    /// Merge another unit into this one.
+    fn merge(&mut self, other: Self) {
+        self.paths.extend(other.paths);
+        self.links.extend(other.links);
+        self.last_access = self.last_access.max(other.last_access);
+        self.reclaimable += other.reclaimable;
+        self.pinned |= other.pinned;
+    }
+}
+
+/// The evictable entries in the cache, collected in a single pass.
+#[derive(Debug, Default)]
+struct Inventory {
+    units: Vec<Unit>,
+    /// The unit index for each archive, wheel entry, and source distribution revision.
+    index: FxHashMap<PathBuf, usize>,
+    /// The pinned archives.
+    pinned: FxHashSet<PathBuf>,
+    /// All symlinks in the cache, to clean up any that dangle after eviction.
+    symlinks: Vec<PathBuf>,
+    /// The number of bytes that would be freed by removing the entire cache.
+    size: u64,
+}
+
+impl Inventory {
+    /// Walk the cache, collecting the evictable units.
+    fn collect(cache: &Cache) -> io::Result<Self> {
+        let mut inventory = Self::default();
+        let archives = cache.bucket(CacheBucket::Archive);
+        let wheels = cache.bucket(CacheBucket::Wheels);
+        let sdists = cache.bucket(CacheBucket::SourceDistributions);
+
+        // The archive or source distribution revision that's currently being walked. As the walk is
+        // depth-first, all entries within a unit are visited consecutively.
+        let mut current: Option<(PathBuf, usize)> = None;
+
+        for entry in walkdir::WalkDir::new(&cache.root) {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(err)
+                    if err
+                        .io_error()
+                        .is_some_and(|err| err.kind() == io::ErrorKind::NotFound) =>
+                {
+                    continue
+                }
+                Err(err) => return Err(err.into()),
+            };
+            let path = entry.path();
+            let file_type = entry.file_type();
+
+            if current
+                .as_ref()
+                .is_some_and(|(root, _)| !path.starts_with(root))
+            {
+                current = None;
+            }
+
+            if current.is_none() && file_type.is_dir() {
+                let is_unit = if path.parent() == Some(archives.as_path()) {
+                    true
+                } else {
+                    path.starts_with(&sdists) && path.join("metadata.msgpack").is_file()
+                };
+                if is_unit {
+                    let last_access = entry.metadata()?.modified()?;
+                    let unit = inventory.insert(path.to_path_buf(), last_access);
+                    current = Some((path.to_path_buf(), unit));
+                }
+                continue;
+            }
+
+            if file_type.is_dir() {
+                continue;
+            }
+
+            // Pin markers are stored alongside the archives.
+            if path.parent() == Some(archives.as_path()) && is_pin_marker(path) {
+                inventory.pinned.insert(path.with_extension(""));
+                continue;
+            }
+
+            // Entries in the wheel bucket are grouped by wheel, e.g., the `.http` pointer,
+            // `.msgpack` metadata, and link to the unzipped archive for a given wheel filename.
+            let unit = if let Some((_, unit)) = current {
+                Some(unit)
+            } else if path.starts_with(&wheels) {
+                let last_access = entry.metadata()?.modified()?;
+                let unit = inventory.wheel(wheel_key(path), last_access);
+                inventory.units[unit].paths.push(path.to_path_buf());
+                Some(unit)
+            } else {
+                None
+            };
+
+            if file_type.is_symlink() {
+                if let Some(unit) = unit {
+                    if let Some(archive) = link_target(path, &archives) {
+                        inventory.units[unit].links.push(archive);
+                    }
+                }
+                inventory.symlinks.push(path.to_path_buf());
+                continue;
+            }
+
+            let metadata = entry.metadata()?;
+            if is_linked(&metadata) {
+                continue;
+            }
+            inventory.size += metadata.len();
+            if let Some(unit) = unit {
+                inventory.units[unit].reclaimable += metadata.len();
+            }
+        }
+
+        Ok(inventory)
+    }
+
+    /// Add a unit rooted at the given path.
+    fn insert(&mut self, path: PathBuf, last_access: SystemTime) -> usize {
+        let unit = self.units.len();
+        self.index.insert(path.clone(), unit);
+        self.units.push(Unit::new(vec![path], last_access));
+        unit
+    }
+
+    /// Return the unit for the wheel with the given key, recording an access at the given time.
+    fn wheel(&mut self, key: PathBuf, last_access: SystemTime) -> usize {
+        if let Some(&unit) = self.index.get(&key) {
+            let unit_ref = &mut self.units[unit];
+            unit_ref.last_access = unit_ref.last_access.max(last_access);
+            return unit;
+        }
+        let unit = self.units.len();
+        self.index.insert(key, unit);
+        self.units.push(Unit::new(Vec::new(), last_access));
+        unit
+    }
+
+    /// Group the units that link to the same archives, returning them from least- to
+    /// most-recently used.
+    fn into_groups(self) -> Vec<Unit> {
+        let Self {
+            units,
+            index,
+            pinned,
+            ..
+        } = self;
+
+        // Union the units with the archives they link to.
+        let mut parents = (0..units.len()).collect::<Vec<_>>();
+        for (unit, entry) in units.iter().enumerate() {
+            for link in &entry.links {
+                if let Some(&archive) = index.get(link) {
+                    let (a, b) = (find(&mut parents, unit), find(&mut parents, archive));
+                    parents[a] = b;
+                }
+            }
+        }
+
+        let mut groups: FxHashMap<usize, Unit> = FxHashMap::default();
+        for (unit, mut entry) in units.into_iter().enumerate() {
+            entry.pinned = entry
+                .paths
+                .first()
+                .is_some_and(|path| pinned.contains(path));
+            match groups.entry(find(&mut parents, unit)) {
+                Entry::Occupied(mut group) => group.get_mut().merge(entry),
+                Entry::Vacant(group) => {
+                    group.insert(entry);
+                }
+            }
+        }
+
+        let mut groups = groups.into_values().collect::<Vec<_>>();
+        groups.sort_by_key(|group| group.last_access);
+        groups
+    }
+}
+
+/// Find the representative of a unit in a union-find forest, compressing the path.
+fn find(parents: &mut [usize], unit: usize) -> usize {
+    let mut root = unit;
+    while parents[root] != root {
+        root = parents[root];
+    }
+    let mut unit = unit;
+    while parents[unit] != root {
+        let next = parents[unit];
+        parents[unit] = root;
+        unit = next;
+    }
+    root
+}
+
+/// Return the key that groups the entries for a single wheel in the wheel bucket, i.e., the path
+/// without the `.http`, `.rev`, `.msgpack`, or `.whl` extension.
+fn wheel_key(path: &Path) -> PathBuf {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("http" | "rev" | "msgpack" | "whl") => path.with_extension(""),
+        _ => path.to_path_buf(),
+    }
+}
+
+/// Return the archive that the given symlink points to, if any.
+fn link_target(link: &Path, archives: &Path) -> Option<PathBuf> {
+    let target = fs_err::read_link(link).ok()?;
+    let id = target.file_name()?;
+    let bucket = target.parent()?.file_name()?;
+    (bucket == archives.file_name()?).then(|| archives.join(id))
+}
+
+/// Returns `true` if the file is hard-linked elsewhere (e.g., into an environment), such that
+/// removing it from the cache wouldn't free any space.
+fn is_linked(metadata: &std::fs::Metadata) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        metadata.nlink() > 1
+    }
+    // The number of hard links isn't available on other platforms (on Windows, it's unstable), so
+    // every file is treated as reclaimable. Removing a hard-linked file from the cache doesn't
+    // affect the environments it's linked into; it just frees less space than expected.
+    #[cfg(not(unix))]
+    {
+        let _ = metadata;
+        false
+    }
+}
+
+impl Cache {
+    /// Evict the least-recently-used entries from the archive, wheel, and source distribution
+    /// buckets, until the cache is no larger than `max_size`. Any entries that haven't been
+    /// accessed within `older_than` are evicted regardless of the cache size.
+    ///
+    /// Entries that refer to one another (e.g., a wheel pointer and its unzipped archive) are
+    /// evicted together. Files that are hard-linked into an environment don't count towards the
+    /// size of the cache, as removing them wouldn't free any space; entries that consist solely of
+    /// such files are retained, as are archives that are symlinked into an environment.
+    pub fn evict(
+        &self,
+        max_size: Option<ByteSize>,
+        older_than: Option<Age>,
+    ) -> io::Result<Removal> {
+        let mut summary = Removal::default();
+
+        if max_size.is_none() && older_than.is_none() {
+            return Ok(summary);
+        }
+        if !self.root.is_dir() {
+            return Ok(summary);
+        }
+
+        let mut inventory = Inventory::collect(self)?;
+        let mut size = inventory.size;
+        let symlinks = std::mem::take(&mut inventory.symlinks);
+        debug!("Cache size: {size} bytes");
+
+        let cutoff = older_than.and_then(|age| SystemTime::now().checked_sub(age.as_duration()));
+
+        let mut evicted = false;
+        for group in inventory.into_groups() {
+            if group.reclaimable == 0 || group.pinned {
+                continue;
+            }
+            let expired = cutoff.is_some_and(|cutoff| group.last_access < cutoff);
+            let oversized = max_size.is_some_and(|max_size| size > max_size.as_u64());
+            if !expired && !oversized {
+                // Groups are sorted by access time, so the remaining groups are within the age
+                // limit too.
+                break;
+            }
+            debug!(
+                "Evicting cache entries: {} ({} bytes)",
+                group
+                    .paths
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                group.reclaimable
+            );
+            for path in &group.paths {
+                summary += rm_rf(path)?;
+            }
+            size = size.saturating_sub(group.reclaimable);
+            evicted = true;
+        }
+
+        // Remove any other links to the evicted entries (e.g., from cached environments), which
+        // are now dangling.
+        if evicted {
+            for symlink in symlinks {
+                if symlink.symlink_metadata().is_ok() && !symlink.exists() {
+                    debug!("Removing dangling cache link: {}", symlink.display());
+                    summary += rm_rf(&symlink)?;
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use super::{Age, ByteSize};
+
+    #[test]
+    fn parse_byte_size() {
+        assert_eq!(ByteSize::from_str("1024").unwrap().as_u64(), 1024);
+        assert_eq!(ByteSize::from_str("1K").unwrap().as_u64(), 1024);
+        assert_eq!(ByteSize::from_str("500MB").unwrap().as_u64(), 500_000_000);
+        assert_eq!(
+            ByteSize::from_str("20GiB").unwrap().as_u64(),
+            20 * 1024 * 1024 * 1024
+        );
+        assert_eq!(
+            ByteSize::from_str("1.5 GiB").unwrap().as_u64(),
+            3 * 512 * 1024 * 1024
+        );
+        assert!(ByteSize::from_str("GiB").is_err());
+        assert!(ByteSize::from_str("20XB").is_err());
+    }
+
+    #[test]
+    fn parse_age() {
+        assert_eq!(
+            Age::from_str("30d").unwrap().as_duration(),
+            Duration::from_secs(30 * 24 * 60 * 60)
+        );
+        assert_eq!(
+            Age::from_str("12h").unwrap().as_duration(),
+            Duration::from_secs(12 * 60 * 60)
+        );
+        assert_eq!(
+            Age::from_str("2 weeks").unwrap().as_duration(),
+            Duration::from_secs(14 * 24 * 60 * 60)
+        );
+        assert!(Age::from_str("30").is_err());
+        assert!(Age::from_str("d").is_err());
+    }
+}
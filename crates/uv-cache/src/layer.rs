@@ -0,0 +1,337 @@
+//! Read-only cache layers.
+//!
+//! A [`Cache`] may be backed by one or more read-only lower layers (e.g., a warm cache that was
+//! baked into a CI image). Lower layers are never modified. Instead, whenever an entry is requested
+//! from the cache and it's missing from the primary (writable) cache, it's imported from the first
+//! lower layer that contains it, by hard-linking its files into the primary cache (falling back to
+//! copying, e.g., across filesystems).
+//!
+//! Links into a lower layer's archive bucket are re-pointed at the primary cache, importing the
+//! linked archive along the way, such that the primary cache remains self-contained. This includes
+//! links into the layer's original location, if the layer was relocated after it was populated.
+
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+use tracing::{debug, warn};
+
+use crate::{Cache, CacheBucket};
+
+impl Cache {
+    /// Add read-only lower layers to the cache, which are consulted (in order) for any entries
+    /// that are missing from the primary cache.
+    #[must_use]
+    pub fn with_readonly_layers(self, layers: Vec<PathBuf>) -> Self {
+        Self {
+            readonly: layers,
+            ..self
+        }
+    }
+
+    /// Return the read-only lower layers of the cache.
+    pub fn readonly_layers(&self) -> &[PathBuf] {
+        &self.readonly
+    }
+
+    /// Import the path (relative to the cache root) from the read-only lower layers, if it's
+    /// missing from the primary cache.
+    ///
+    /// Directories are merged, such that any entries that are missing from the primary cache are
+    /// imported, while existing entries are retained. Failures are logged, but otherwise ignored,
+    /// in which case the entry is treated as a cache miss.
+    pub(crate) fn import(&self, relative: &Path) {
+        if self.readonly.is_empty() {
+            return;
+        }
+
+        // Directories are merged recursively, so if the path (or any of its parents) was already
+        // imported, there's nothing left to do.
+        {
+            let mut imported = self.imported.lock().unwrap();
+            if relative
+                .ancestors()
+                .any(|ancestor| imported.contains(ancestor))
+            {
+                return;
+            }
+            imported.insert(relative.to_path_buf());
+        }
+
+        let target = self.root.join(relative);
+        for layer in &self.readonly {
+            let source = layer.join(relative);
+            let Ok(metadata) = fs_err::symlink_metadata(&source) else {
+                continue;
+            };
+
+            // Files and links are imported from the first layer that contains them; directories
+            // are merged across all layers.
+            let is_dir = metadata.is_dir();
+            if !is_dir && fs_err::symlink_metadata(&target).is_ok() {
+                return;
+            }
+
+            if let Err(err) = self.import_path(layer, &source, &target, Provenance::Trusted) {
+                warn!(
+                    "Failed to import cache entry from read-only layer `{}`: {err}",
+                    source.display()
+                );
+            }
+
+            if !is_dir {
+                return;
+            }
+        }
+    }
+
+    /// Merge a directory that's structured like a cache (e.g., an unpacked cache bundle) into the
+    /// primary cache.
+    ///
+    /// As with read-only layers, any entries that are missing from the primary cache are imported,
+    /// while existing entries are retained. Unlike read-only layers, failures are returned.
+    ///
+    /// The directory may have been created elsewhere, so its links are untrusted: any links with
+    /// absolute targets, or with targets outside the directory, are rejected.
+    pub fn import_layer(&self, layer: &Path) -> io::Result<()> {
+        let layer = std::path::absolute(layer)?;
+        for entry in fs_err::read_dir(&layer)? {
+            let entry = entry?;
+            self.import_path(
+                &layer,
+                &entry.path(),
+                &self.root.join(entry.file_name()),
+                Provenance::Untrusted,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Import a path from the given layer into the primary cache.
+    fn import_path(
+        &self,
+        layer: &Path,
+        source: &Path,
+        target: &Path,
+        provenance: Provenance,
+    ) -> io::Result<()> {
+        let metadata = fs_err::symlink_metadata(source)?;
+
+        if metadata.is_symlink() {
+            if fs_err::symlink_metadata(target).is_ok() {
+                return Ok(());
+            }
+
+            let link = fs_err::read_link(source)?;
+            debug!("Importing cache link from layer: {}", source.display());
+            fs_err::create_dir_all(target.parent().expect("Cache entry to have parent"))?;
+
+            match provenance {
+                Provenance::Trusted => {
+                    // If the link points into the layer (e.g., into its archive bucket), import
+                    // the target, and point the link at the primary cache instead.
+                    let link = match self.layer_relative(layer, &link) {
+                        Some(relative) => {
+                            self.import(&relative);
+                            self.root.join(relative)
+                        }
+                        None => link,
+                    };
+                    uv_fs::replace_symlink(link, target)?;
+                }
+                Provenance::Untrusted => {
+                    // Links must point to an entry within the layer, which is imported alongside
+                    // the link. The link is created first, such that a directory that contains a
+                    // link to itself is only imported once.
+                    let relative = resolve_untrusted_link(layer, source, &link)?;
+                    let linked = layer.join(&relative);
+                    if fs_err::symlink_metadata(&linked)?.is_symlink() {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!(
+                                "Link `{}` points to another link: `{}`",
+                                source.display(),
+                                link.display()
+                            ),
+                        ));
+                    }
+                    uv_fs::replace_symlink(self.root.join(&relative), target)?;
+                    self.import_path(layer, &linked, &self.root.join(&relative), provenance)?;
+                }
+            }
+        } else if metadata.is_dir() {
+            // Archives are immutable, so import them atomically, to avoid exposing a partially
+            // imported archive to concurrent readers.
+            let archive = self.bucket(CacheBucket::Archive);
+            if target.parent() == Some(archive.as_path()) {
+                if target.exists() {
+                    return Ok(());
+                }
+                debug!("Importing cache archive from layer: {}", source.display());
+                fs_err::create_dir_all(&archive)?;
+                let temp_dir = tempfile::tempdir_in(&archive)?;
+                link_or_copy_dir(source, temp_dir.path(), provenance)?;
+                match fs_err::rename(temp_dir.path(), target) {
+                    Ok(()) => {}
+                    // Another process may have imported the archive concurrently.
+                    Err(_) if target.exists() => {}
+                    Err(err) => return Err(err),
+                }
+                return Ok(());
+            }
+
+            fs_err::create_dir_all(target)?;
+            for entry in fs_err::read_dir(source)? {
+                let entry = entry?;
+                self.import_path(
+                    layer,
+                    &entry.path(),
+                    &target.join(entry.file_name()),
+                    provenance,
+                )?;
+            }
+        } else {
+            if fs_err::symlink_metadata(target).is_ok() {
+                return Ok(());
+            }
+            debug!("Importing cache entry from layer: {}", source.display());
+            fs_err::create_dir_all(target.parent().expect("Cache entry to have parent"))?;
+            link_or_copy_file(source, target)?;
+        }
+
+        Ok(())
+    }
+
+    /// Resolve the target of a link within a read-only layer to a path relative to the cache root.
+    ///
+    /// Links into the layer itself are resolved directly. Since the cache stores absolute links,
+    /// a layer that was relocated after it was populated (e.g., a cache that was built at one path
+    /// and then copied into a CI image at another) will contain links into its original location,
+    /// so links into _any_ archive bucket are rebased onto the layer, if the layer contains the
+    /// linked archive.
+    fn layer_relative(&self, layer: &Path, link: &Path) -> Option<PathBuf> {
+        if let Ok(relative) = link.strip_prefix(layer) {
+            return Some(relative.to_path_buf());
+        }
+
+        let id = link.file_name()?;
+        let bucket = link.parent()?.file_name()?;
+        if bucket != CacheBucket::Archive.to_str() {
+            return None;
+        }
+
+        // The link may also point into the primary cache, which doesn't require an import.
+        if link.starts_with(&self.root) {
+            return None;
+        }
+
+        let relative = Path::new(bucket).join(id);
+        if layer.join(&relative).is_dir() {
+            Some(relative)
+        } else {
+            None
+        }
+    }
+}
+
+/// The provenance of a layer that's imported into the primary cache.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Provenance {
+    /// A read-only layer that was configured by the user (e.g., a cache baked into a CI image).
+    Trusted,
+    /// A directory that may have been created elsewhere (e.g., an unpacked cache bundle).
+    Untrusted,
+}
+
+/// Resolve the target of a link within an untrusted layer to a path relative to the layer.
+///
+/// Returns an error if the target is absolute, or if it points outside the layer.
+fn resolve_untrusted_link(layer: &Path, path: &Path, link: &Path) -> io::Result<PathBuf> {
+    let is_relative = link.components().all(|component| {
+        matches!(
+            component,
+            Component::Normal(_) | Component::CurDir | Component::ParentDir
+        )
+    });
+    if !is_relative {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "Link `{}` has an absolute target: `{}`",
+                path.display(),
+                link.display()
+            ),
+        ));
+    }
+
+    let parent = path.parent().expect("Link to have parent");
+    uv_fs::normalize_absolute_path(&parent.join(link))
+        .ok()
+        .and_then(|resolved| {
+            resolved
+                .strip_prefix(layer)
+                .ok()
+                .filter(|relative| !relative.as_os_str().is_empty())
+                .map(Path::to_path_buf)
+        })
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "Link `{}` points outside of `{}`: `{}`",
+                    path.display(),
+                    layer.display(),
+                    link.display()
+                ),
+            )
+        })
+}
+
+/// Recursively hard-link (or copy) the contents of a directory into another directory.
+///
+/// Links within the directory are copied as-is. If the directory is untrusted, links must be
+/// relative, and must point within the directory.
+fn link_or_copy_dir(source: &Path, target: &Path, provenance: Provenance) -> io::Result<()> {
+    for entry in walkdir::WalkDir::new(source) {
+        let entry = entry?;
+        let relative = entry
+            .path()
+            .strip_prefix(source)
+            .expect("walkdir starts with root");
+        let path = target.join(relative);
+        if entry.file_type().is_dir() {
+            fs_err::create_dir_all(&path)?;
+        } else if entry.file_type().is_symlink() {
+            let link = fs_err::read_link(entry.path())?;
+            if provenance == Provenance::Untrusted {
+                resolve_untrusted_link(source, entry.path(), &link)?;
+            }
+            uv_fs::replace_symlink(link, &path)?;
+        } else {
+            link_or_copy_file(entry.path(), &path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Hard-link a file, falling back to an atomic copy if hard-linking fails (e.g., across
+/// filesystems).
+fn link_or_copy_file(source: &Path, target: &Path) -> io::Result<()> {
+    match fs_err::hard_link(source, target) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == io::ErrorKind::AlreadyExists => Ok(()),
+        Err(err) => {
+            debug!(
+                "Failed to hard-link `{}`, falling back to copy: {err}",
+                source.display()
+            );
+            let parent = target.parent().expect("Cache entry to have parent");
+            let temp_file = tempfile::NamedTempFile::new_in(parent)?;
+            fs_err::copy(source, temp_file.path())?;
+            match temp_file.persist_noclobber(target) {
+                Ok(_) => Ok(()),
+                Err(err) if err.error.kind() == io::ErrorKind::AlreadyExists => Ok(()),
+                Err(err) => Err(err.error),
+            }
+        }
+    }
+}
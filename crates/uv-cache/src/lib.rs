@@ -4,7 +4,7 @@ use std::io;
 use std::io::Write;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use rustc_hash::FxHashSet;
 use tracing::debug;
@@ -19,8 +19,11 @@ use uv_pypi_types::ResolutionMetadata;
 pub use crate::by_timestamp::CachedByTimestamp;
 #[cfg(feature = "clap")]
 pub use crate::cli::CacheArgs;
+pub use crate::eviction::{pin, touch, Age, ByteSize};
+pub use crate::remote::RemoteCache;
 use crate::removal::Remover;
 pub use crate::removal::{rm_rf, Removal};
+pub use crate::size::dir_size;
 pub use crate::wheel::WheelCache;
 use crate::wheel::WheelCacheKind;
 
@@ -28,7 +31,11 @@ mod archive;
 mod by_timestamp;
 #[cfg(feature = "clap")]
 mod cli;
+mod eviction;
+mod layer;
+mod remote;
 mod removal;
+mod size;
 mod wheel;
 
 /// A [`CacheEntry`] which may or may not exist yet.
@@ -142,6 +149,14 @@ pub struct Cache {
     /// Included to ensure that the temporary directory exists for the length of the operation, but
     /// is dropped at the end as appropriate.
     temp_dir: Option<Arc<tempfile::TempDir>>,
+    /// Read-only lower layers, consulted (in order) for any entries that are missing from the
+    /// cache directory.
+    readonly: Vec<PathBuf>,
+    /// The paths (relative to the cache root) that were already imported from the read-only
+    /// layers, such that each entry is only imported once per invocation.
+    imported: Arc<Mutex<FxHashSet<PathBuf>>>,
+    /// A remote cache, shared across machines, for wheels built from source distributions.
+    remote: Option<RemoteCache>,
 }
 
 impl Cache {
@@ -151,6 +166,9 @@ impl Cache {
             root: root.into(),
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: None,
+            readonly: Vec::new(),
+            imported: Arc::default(),
+            remote: None,
         }
     }
 
@@ -161,6 +179,9 @@ impl Cache {
             root: temp_dir.path().to_path_buf(),
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: Some(Arc::new(temp_dir)),
+            readonly: Vec::new(),
+            imported: Arc::default(),
+            remote: None,
         })
     }
 
@@ -175,6 +196,15 @@ impl Cache {
         &self.root
     }
 
+    /// Acquire an exclusive lock on the cache, to guard against concurrent maintenance operations
+    /// (like `uv cache prune` and `uv cache verify --repair`).
+    ///
+    /// Blocks until the lock is available, so should not be called from an async context.
+    pub fn lock_blocking(&self) -> Result<LockedFile, io::Error> {
+        fs_err::create_dir_all(&self.root)?;
+        LockedFile::acquire_blocking(self.root.join(".lock"), self.root.display())
+    }
+
     /// Return the [`Refresh`] policy for the cache.
     pub fn refresh(&self) -> &Refresh {
         &self.refresh
@@ -186,22 +216,38 @@ impl Cache {
     }
 
     /// Compute an entry in the cache.
+    ///
+    /// If the cache has read-only layers, any of the shard's contents that are missing from the
+    /// cache directory are imported from the layers.
     pub fn shard(&self, cache_bucket: CacheBucket, dir: impl AsRef<Path>) -> CacheShard {
+        self.import(&Path::new(cache_bucket.to_str()).join(dir.as_ref()));
         CacheShard(self.bucket(cache_bucket).join(dir.as_ref()))
     }
 
     /// Compute an entry in the cache.
+    ///
+    /// If the cache has read-only layers, and the entry is missing from the cache directory, it's
+    /// imported from the layers.
     pub fn entry(
         &self,
         cache_bucket: CacheBucket,
         dir: impl AsRef<Path>,
         file: impl AsRef<Path>,
     ) -> CacheEntry {
+        self.import(
+            &Path::new(cache_bucket.to_str())
+                .join(dir.as_ref())
+                .join(file.as_ref()),
+        );
         CacheEntry::new(self.bucket(cache_bucket).join(dir), file)
     }
 
     /// Return the path to an archive in the cache.
+    ///
+    /// If the cache has read-only layers, and the archive is missing from the cache directory, it's
+    /// imported from the layers.
     pub fn archive(&self, id: &ArchiveId) -> PathBuf {
+        self.import(&Path::new(CacheBucket::Archive.to_str()).join(id));
         self.bucket(CacheBucket::Archive).join(id)
     }
 
@@ -339,6 +385,11 @@ impl Cache {
 
         Ok(Self {
             root: std::path::absolute(root)?,
+            readonly: self
+                .readonly
+                .iter()
+                .map(std::path::absolute)
+                .collect::<Result<_, _>>()?,
             ..self
         })
     }
@@ -424,6 +475,7 @@ impl Cache {
             if entry.file_name() == "CACHEDIR.TAG"
                 || entry.file_name() == ".gitignore"
                 || entry.file_name() == ".git"
+                || entry.file_name() == ".lock"
             {
                 continue;
             }
@@ -524,14 +576,29 @@ impl Cache {
 
         match fs_err::read_dir(self.bucket(CacheBucket::Archive)) {
             Ok(entries) => {
+                let mut markers = Vec::new();
                 for entry in entries {
                     let entry = entry?;
                     let path = fs_err::canonicalize(entry.path())?;
-                    if !references.contains(&path) {
+                    if eviction::is_pin_marker(&path) {
+                        markers.push(path);
+                        continue;
+                    }
+                    // Archives that are symlinked into an environment are retained, even if
+                    // they're no longer referenced by the cache.
+                    if !references.contains(&path) && !eviction::pin_marker(&path).is_file() {
                         debug!("Removing dangling cache archive: {}", path.display());
                         summary += rm_rf(path)?;
                     }
                 }
+
+                // Remove any pin markers for archives that no longer exist.
+                for marker in markers {
+                    if !marker.with_extension("").exists() {
+                        debug!("Removing dangling pin marker: {}", marker.display());
+                        summary += rm_rf(marker)?;
+                    }
+                }
             }
             Err(err) if err.kind() == io::ErrorKind::NotFound => (),
             Err(err) => return Err(err),
@@ -801,7 +868,7 @@ impl CacheBucket {
             Self::SourceDistributions => "sdists-v7",
             Self::FlatIndex => "flat-index-v2",
             Self::Git => "git-v0",
-            Self::Interpreter => "interpreter-v4",
+            Self::Interpreter => "interpreter-v5",
             // Note that when bumping this, you'll also need to bump it
             // in crates/uv/tests/cache_clean.rs.
             Self::Simple => "simple-v15",
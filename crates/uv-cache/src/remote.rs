@@ -0,0 +1,119 @@
+//! Remote caches for built wheels.
+//!
+//! A [`Cache`] may be backed by a remote cache, shared across machines (e.g., between developers
+//! and CI), from which wheels built from source distributions are fetched before building, and to
+//! which any locally built wheels are uploaded.
+
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use url::{Host, Url};
+
+use crate::Cache;
+
+/// The location of a remote cache for built wheels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteCache {
+    /// An HTTPS server (or an HTTP server on a loopback address) that supports `GET` and `PUT`
+    /// requests.
+    Url(Url),
+    /// A directory on a shared filesystem (e.g., a network mount).
+    Path(PathBuf),
+}
+
+impl FromStr for RemoteCache {
+    type Err = String;
+
+    /// Parse a [`RemoteCache`] from an `https://` URL, a `file://` URL, or a path.
+    ///
+    /// As wheels fetched from the remote cache are installed as-is, plain `http://` URLs are only
+    /// accepted for loopback addresses (e.g., a local proxy).
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match Url::parse(input) {
+            Ok(url) if url.scheme() == "https" => Ok(Self::Url(url)),
+            Ok(url) if url.scheme() == "http" => {
+                if is_loopback(&url) {
+                    Ok(Self::Url(url))
+                } else {
+                    Err(format!(
+                        "`{input}` must use `https` (`http` is only supported for loopback addresses)"
+                    ))
+                }
+            }
+            Ok(url) if url.scheme() == "file" => url
+                .to_file_path()
+                .map(Self::Path)
+                .map_err(|()| format!("`{input}` is not a valid file URL")),
+            // On Windows, `C:\...` parses as a URL with the scheme `c`.
+            Ok(url) if url.scheme().len() > 1 => Err(format!(
+                "`{input}` has an unsupported scheme (expected `http`, `https`, or `file`)"
+            )),
+            _ => Ok(Self::Path(PathBuf::from(input))),
+        }
+    }
+}
+
+/// Returns `true` if the URL refers to a loopback address.
+fn is_loopback(url: &Url) -> bool {
+    match url.host() {
+        Some(Host::Domain(domain)) => domain == "localhost",
+        Some(Host::Ipv4(ip)) => ip.is_loopback(),
+        Some(Host::Ipv6(ip)) => ip.is_loopback(),
+        None => false,
+    }
+}
+
+impl Display for RemoteCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Url(url) => write!(f, "{url}"),
+            Self::Path(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for RemoteCache {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        RemoteCache::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for RemoteCache {
+    fn schema_name() -> String {
+        "RemoteCache".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some(
+                    "An `https://` URL, a `file://` URL, or a path to a remote build cache."
+                        .to_string(),
+                ),
+                ..schemars::schema::Metadata::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        }
+        .into()
+    }
+}
+
+impl Cache {
+    /// Set the [`RemoteCache`] to use for wheels built from source distributions.
+    #[must_use]
+    pub fn with_remote(self, remote: Option<RemoteCache>) -> Self {
+        Self { remote, ..self }
+    }
+
+    /// Return the [`RemoteCache`] to use for wheels built from source distributions, if any.
+    pub fn remote(&self) -> Option<&RemoteCache> {
+        self.remote.as_ref()
+    }
+}
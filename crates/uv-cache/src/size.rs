@@ -0,0 +1,39 @@
+use std::io;
+use std::path::Path;
+
+use crate::{Cache, CacheBucket};
+
+/// Compute the total size of the files within a directory (or of a single file), in bytes.
+///
+/// Symbolic links are not followed, such that entries that are linked into multiple buckets (e.g.,
+/// unzipped wheels in the archive bucket) are only counted once. Returns `0` if the path does not
+/// exist.
+pub fn dir_size(path: impl AsRef<Path>) -> io::Result<u64> {
+    let mut size = 0;
+    for entry in walkdir::WalkDir::new(path.as_ref()) {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(err)
+                if err
+                    .io_error()
+                    .is_some_and(|err| err.kind() == io::ErrorKind::NotFound) =>
+            {
+                continue
+            }
+            Err(err) => return Err(err.into()),
+        };
+        if entry.file_type().is_file() {
+            size += entry.metadata()?.len();
+        }
+    }
+    Ok(size)
+}
+
+impl Cache {
+    /// Compute the size of each cache bucket, in bytes.
+    pub fn bucket_sizes(&self) -> io::Result<Vec<(CacheBucket, u64)>> {
+        CacheBucket::iter()
+            .map(|bucket| Ok((bucket, dir_size(self.bucket(bucket))?)))
+            .collect()
+    }
+}
@@ -9,7 +9,7 @@ use clap::builder::Styles;
 use clap::{Args, Parser, Subcommand};
 
 use url::Url;
-use uv_cache::CacheArgs;
+use uv_cache::{Age, ByteSize, CacheArgs};
 use uv_configuration::{
     ConfigSettingEntry, ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier,
     ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing, VersionControlSystem,
@@ -36,7 +36,7 @@ pub enum VersionFormat {
 }
 
 #[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
-pub enum PythonListFormat {
+pub enum OutputFormat {
     /// Plain text (for humans).
     #[default]
     Text,
@@ -159,6 +159,12 @@ pub struct GlobalArgs {
     #[arg(global = true, long, help_heading = "Python options")]
     pub no_python_downloads: bool,
 
+    /// Ignore Python version files owned by other tools.
+    ///
+    /// By default, `.tool-versions`, `mise.toml`, and `runtime.txt` files are also respected.
+    #[arg(global = true, long, env = EnvVars::UV_NO_EXTERNAL_VERSION_FILES, value_parser = clap::builder::BoolishValueParser::new(), help_heading = "Python options")]
+    pub no_external_version_files: bool,
+
     /// Deprecated version of [`Self::python_downloads`].
     #[arg(global = true, long, hide = true)]
     pub python_fetch: Option<PythonDownloads>,
@@ -236,6 +242,14 @@ pub struct GlobalArgs {
     )]
     pub allow_insecure_host: Option<Vec<Maybe<TrustedHost>>>,
 
+    /// The path to a credential helper executable to use with `--keyring-provider helper`.
+    ///
+    /// The helper is invoked as `<helper> get` and receives a JSON request with the `url`, `realm`,
+    /// and (if known) `username` on stdin. It should print a JSON object with a `username` and
+    /// `password`, or a bearer `token`, to stdout.
+    #[arg(global = true, long, env = EnvVars::UV_CREDENTIAL_HELPER, value_name = "PATH")]
+    pub credential_helper: Option<PathBuf>,
+
     /// Whether to enable experimental, preview features.
     ///
     /// Preview features may change without warning.
@@ -453,6 +467,12 @@ pub enum Commands {
         #[command(subcommand)]
         command: BuildBackendCommand,
     },
+    /// Manage credentials for package indexes and other hosts.
+    #[command(
+        after_help = "Use `uv help auth` for more details.",
+        after_long_help = ""
+    )]
+    Auth(AuthNamespace),
     /// Manage uv's cache.
     #[command(
         after_help = "Use `uv help cache` for more details.",
@@ -523,6 +543,95 @@ pub struct SelfUpdateArgs {
     pub token: Option<String>,
 }
 
+#[derive(Args)]
+pub struct AuthNamespace {
+    #[command(subcommand)]
+    pub command: AuthCommand,
+}
+
+#[derive(Subcommand)]
+pub enum AuthCommand {
+    /// Store credentials for an index or host.
+    ///
+    /// Credentials are stored in a file in the uv state directory that is only readable by the
+    /// current user, and are used for any request to the index or host (e.g., when resolving
+    /// dependencies, downloading distributions, or publishing).
+    ///
+    /// With `--keyring-provider subprocess`, the password is stored in the system keyring (via the
+    /// `keyring` CLI) instead, and only the username is written to the file.
+    Login(AuthLoginArgs),
+    /// Remove stored credentials for an index or host.
+    Logout(AuthLogoutArgs),
+    /// Show the stored token (or password) for an index or host.
+    Token(AuthTokenArgs),
+    /// List the indexes and hosts with stored credentials.
+    List,
+}
+
+#[derive(Args, Debug)]
+pub struct AuthLoginArgs {
+    /// The index or host to store credentials for.
+    ///
+    /// Accepts the name of an index in the configuration (e.g., `internal`), a URL (e.g.,
+    /// `https://example.com/simple`), or a host (e.g., `example.com`).
+    ///
+    /// When provided with the name of an index, the credentials apply to the root of the index
+    /// (e.g., without the `/simple` suffix), such that they're also used for file downloads.
+    pub service: String,
+
+    /// The username to store.
+    ///
+    /// If omitted, uv will prompt for the username.
+    #[arg(short, long, conflicts_with = "token")]
+    pub username: Option<String>,
+
+    /// The password to store.
+    ///
+    /// If omitted, uv will prompt for the password.
+    #[arg(short, long, conflicts_with = "token")]
+    pub password: Option<String>,
+
+    /// The token to store.
+    ///
+    /// Using a token is equivalent to passing `__token__` as `--username` and the token as
+    /// `--password`.
+    #[arg(short, long)]
+    pub token: Option<String>,
+
+    /// Store the password in the system keyring, rather than in uv's credentials file.
+    ///
+    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
+    /// the `keyring` CLI to store the password.
+    ///
+    /// Defaults to `disabled`.
+    #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
+    pub keyring_provider: Option<KeyringProviderType>,
+}
+
+#[derive(Args, Debug)]
+pub struct AuthLogoutArgs {
+    /// The index or host to remove credentials for.
+    ///
+    /// Accepts the name of an index in the configuration, a URL, or a host.
+    pub service: String,
+
+    /// Only remove the credentials for the given username.
+    #[arg(short, long)]
+    pub username: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct AuthTokenArgs {
+    /// The index or host to show the token for.
+    ///
+    /// Accepts the name of an index in the configuration, a URL, or a host.
+    pub service: String,
+
+    /// Show the token for the given username.
+    #[arg(short, long)]
+    pub username: Option<String>,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct CacheNamespace {
@@ -536,6 +645,34 @@ pub enum CacheCommand {
     Clean(CleanArgs),
     /// Prune all unreachable objects from the cache.
     Prune(PruneArgs),
+    /// Show the size of the cache, in total and per cache bucket.
+    Size(CacheSizeArgs),
+    /// List the entries in the cache, like cached wheels, source distributions, and Git
+    /// checkouts.
+    List(CacheListArgs),
+    /// Verify the integrity of the cached wheels.
+    ///
+    /// Checks that every unzipped wheel in the cache matches its `RECORD` file, that every
+    /// reference to an unzipped wheel is valid, and that no entries were left behind by
+    /// interrupted writes.
+    ///
+    /// Exits with a non-zero status if any issues are found, unless `--repair` is provided.
+    /// Unzipped wheels that aren't referenced by any cache entry are reported, but don't affect
+    /// the exit status; use `uv cache prune` to remove them.
+    Verify(CacheVerifyArgs),
+    /// Export the cache entries required to sync a lockfile to a portable bundle.
+    ///
+    /// The bundle contains the wheels and source distributions for every package in the lockfile,
+    /// such that it can be imported into the cache on another machine (via `uv cache import`) and
+    /// synced without network access (via `uv sync --offline`).
+    ///
+    /// By default, the bundle includes the wheels that are compatible with the current platform.
+    /// Use `--python-platform` to include wheels for additional platforms.
+    Export(CacheExportArgs),
+    /// Import a bundle created by `uv cache export` into the cache.
+    ///
+    /// Existing cache entries are retained.
+    Import(CacheImportArgs),
     /// Show the cache directory.
     ///
     ///
@@ -578,6 +715,95 @@ pub struct PruneArgs {
     /// that were built from source.
     #[arg(long)]
     pub ci: bool,
+
+    /// Evict the least-recently-used wheels and source distributions from the cache until it's no
+    /// larger than the given size, e.g., `20GiB` or `500MB`.
+    ///
+    /// Wheels that are hard-linked into an environment don't count towards the size of the cache,
+    /// as removing them wouldn't free any space, and are retained. Wheels that are symlinked into
+    /// an environment (i.e., with `--link-mode symlink`) are never evicted.
+    ///
+    /// Defaults to the `cache-max-size` setting, if any.
+    #[arg(long, env = EnvVars::UV_CACHE_MAX_SIZE, value_name = "SIZE")]
+    pub max_size: Option<ByteSize>,
+
+    /// Evict any wheels and source distributions that haven't been used within the given duration,
+    /// e.g., `30d` or `12h`.
+    ///
+    /// Accepts a number followed by a unit, one of `s` (seconds), `m` (minutes), `h` (hours), `d`
+    /// (days), or `w` (weeks).
+    #[arg(long, value_name = "AGE")]
+    pub older_than: Option<Age>,
+}
+
+#[derive(Args, Debug)]
+pub struct CacheSizeArgs {
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = OutputFormat::default())]
+    pub output_format: OutputFormat,
+}
+
+#[derive(Args, Debug)]
+pub struct CacheListArgs {
+    /// Only show cache entries for the given package.
+    ///
+    /// Entries that don't correspond to a package, like Git checkouts, interpreter query
+    /// results, and cached environments, are omitted.
+    #[arg(long, value_name = "NAME")]
+    pub package: Option<PackageName>,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = OutputFormat::default())]
+    pub output_format: OutputFormat,
+}
+
+#[derive(Args, Debug)]
+pub struct CacheVerifyArgs {
+    /// Remove any corrupt, dangling, or incomplete entries from the cache, such that they're
+    /// re-fetched (or rebuilt) when next needed.
+    #[arg(long)]
+    pub repair: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct CacheExportArgs {
+    /// The path to the lockfile to export.
+    #[arg(long, value_name = "PATH", default_value = "uv.lock")]
+    pub lock: PathBuf,
+
+    /// The path to which the bundle should be written, as a `.tar.zst` archive.
+    #[arg(long, short, value_name = "PATH")]
+    pub output: PathBuf,
+
+    /// An additional platform to include wheels for, beyond the current platform.
+    ///
+    /// May be provided multiple times.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    #[arg(long)]
+    pub python_platform: Vec<TargetTriple>,
+
+    /// The Python interpreter to use to select compatible wheels, and to build any source
+    /// distributions.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args, Debug)]
+pub struct CacheImportArgs {
+    /// The path to the bundle to import, as created by `uv cache export`.
+    pub bundle: PathBuf,
 }
 
 #[derive(Args)]
@@ -1818,9 +2044,6 @@ pub struct PipUninstallArgs {
 
     /// Attempt to use `keyring` for authentication for remote requirements files.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
-    ///
     /// Defaults to `disabled`.
     #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
     pub keyring_provider: Option<KeyringProviderType>,
@@ -2366,6 +2589,20 @@ pub struct VenvArgs {
     #[clap(long)]
     pub allow_existing: bool,
 
+    /// Upgrade the Python interpreter of an existing virtual environment in place.
+    ///
+    /// By default, `uv venv` will remove an existing virtual environment at the given path. The
+    /// `--upgrade-python` option will instead update the environment to use the requested
+    /// interpreter, retaining any installed packages that remain compatible with it, including
+    /// across minor versions (e.g., from Python 3.11 to 3.12). Pure-Python packages and editable
+    /// installations are kept; packages with wheels that aren't compatible with the new interpreter
+    /// are reinstalled from the registry.
+    ///
+    /// If the existing environment uses a different Python implementation, or wasn't created by
+    /// uv, it is recreated instead.
+    #[arg(long, conflicts_with = "allow_existing")]
+    pub upgrade_python: bool,
+
     /// The path to the virtual environment to create.
     ///
     /// Default to `.venv` in the working directory.
@@ -2424,9 +2661,6 @@ pub struct VenvArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
-    ///
     /// Defaults to `disabled`.
     #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
     pub keyring_provider: Option<KeyringProviderType>,
@@ -4081,6 +4315,9 @@ pub struct ToolListArgs {
 
     #[arg(long, hide = true)]
     pub no_python_downloads: bool,
+
+    #[arg(long, hide = true)]
+    pub no_external_version_files: bool,
 }
 
 #[derive(Args)]
@@ -4193,9 +4430,6 @@ pub struct ToolUpgradeArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
-    ///
     /// Defaults to `disabled`.
     #[arg(
         long,
@@ -4419,6 +4653,18 @@ pub enum PythonCommand {
 
     /// Uninstall Python versions.
     Uninstall(PythonUninstallArgs),
+
+    /// Verify the integrity of managed Python installations.
+    ///
+    /// Each installation is checked against the manifest of its files recorded at install time,
+    /// its `sysconfig` data is checked to ensure it was patched to match the installation path, and
+    /// its interpreter is queried to ensure it can be executed.
+    ///
+    /// Installations without a manifest, e.g., those installed by older versions of uv, skip the
+    /// file check.
+    ///
+    /// Use `--repair` to reinstall any installations that fail verification.
+    Verify(PythonVerifyArgs),
 }
 
 #[derive(Args)]
@@ -4461,8 +4707,8 @@ pub struct PythonListArgs {
     pub show_urls: bool,
 
     /// Select the output format.
-    #[arg(long, value_enum, default_value_t = PythonListFormat::default())]
-    pub output_format: PythonListFormat,
+    #[arg(long, value_enum, default_value_t = OutputFormat::default())]
+    pub output_format: OutputFormat,
 }
 
 #[derive(Args)]
@@ -4524,6 +4770,12 @@ pub struct PythonInstallArgs {
     #[arg(long, env = EnvVars::UV_PYPY_INSTALL_MIRROR)]
     pub pypy_mirror: Option<String>,
 
+    /// Attempt to use `keyring` for authentication for Python download mirrors.
+    ///
+    /// Defaults to `disabled`.
+    #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
+    pub keyring_provider: Option<KeyringProviderType>,
+
     /// Reinstall the requested Python version, if it's already installed.
     ///
     /// By default, uv will exit successfully if the version is already
@@ -4572,6 +4824,42 @@ pub struct PythonUninstallArgs {
     pub all: bool,
 }
 
+#[derive(Args)]
+pub struct PythonVerifyArgs {
+    /// The directory where the Python was installed.
+    #[arg(long, short, env = EnvVars::UV_PYTHON_INSTALL_DIR)]
+    pub install_dir: Option<PathBuf>,
+
+    /// The Python version(s) to verify.
+    ///
+    /// If not provided, all managed Python installations will be verified.
+    ///
+    /// See `uv help python` to view supported request formats.
+    pub targets: Vec<String>,
+
+    /// Reinstall any Python installations that fail verification.
+    #[arg(long)]
+    pub repair: bool,
+
+    /// Set the URL to use as the source for downloading Python installations during repair.
+    ///
+    /// See `uv python install --mirror` for details.
+    #[arg(long, env = EnvVars::UV_PYTHON_INSTALL_MIRROR)]
+    pub mirror: Option<String>,
+
+    /// Set the URL to use as the source for downloading PyPy installations during repair.
+    ///
+    /// See `uv python install --pypy-mirror` for details.
+    #[arg(long, env = EnvVars::UV_PYPY_INSTALL_MIRROR)]
+    pub pypy_mirror: Option<String>,
+
+    /// Attempt to use `keyring` for authentication for Python download mirrors during repair.
+    ///
+    /// Defaults to `disabled`.
+    #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
+    pub keyring_provider: Option<KeyringProviderType>,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PythonFindArgs {
@@ -4658,6 +4946,8 @@ pub struct GenerateShellCompletionArgs {
     pub python_preference: Option<PythonPreference>,
     #[arg(long, hide = true)]
     pub no_python_downloads: bool,
+    #[arg(long, hide = true)]
+    pub no_external_version_files: bool,
 
     #[arg(long, short, conflicts_with = "verbose", hide = true)]
     pub quiet: bool,
@@ -4873,9 +5163,6 @@ pub struct InstallerArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
-    ///
     /// Defaults to `disabled`.
     #[arg(
         long,
@@ -5015,9 +5302,6 @@ pub struct ResolverArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
-    ///
     /// Defaults to `disabled`.
     #[arg(
         long,
@@ -5205,9 +5489,6 @@ pub struct ResolverInstallerArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
-    ///
     /// Defaults to `disabled`.
     #[arg(
         long,
@@ -5376,9 +5657,6 @@ pub struct FetchArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
-    ///
     /// Defaults to `disabled`.
     #[arg(
         long,
@@ -5484,19 +5762,17 @@ pub struct PublishArgs {
     )]
     pub token: Option<String>,
 
-    /// Configure using trusted publishing through GitHub Actions.
+    /// Configure using trusted publishing through GitHub Actions, GitLab CI, Google Cloud, or an
+    /// OIDC token provided via `UV_PUBLISH_OIDC_TOKEN` or `UV_PUBLISH_OIDC_TOKEN_FILE`.
     ///
-    /// By default, uv checks for trusted publishing when running in GitHub Actions, but ignores it
-    /// if it isn't configured or the workflow doesn't have enough permissions (e.g., a pull request
-    /// from a fork).
+    /// By default, uv checks for trusted publishing when running in a supported CI provider, but
+    /// ignores it if it isn't configured or the workflow doesn't have enough permissions (e.g., a
+    /// pull request from a fork).
     #[arg(long)]
     pub trusted_publishing: Option<TrustedPublishing>,
 
     /// Attempt to use `keyring` for authentication for remote requirements files.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
-    ///
     /// Defaults to `disabled`.
     #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
     pub keyring_provider: Option<KeyringProviderType>,
@@ -5506,6 +5782,9 @@ pub struct PublishArgs {
     /// Note that there are typically different URLs for index access (e.g., `https:://.../simple`)
     /// and index upload.
     ///
+    /// Use a `file://` URL to copy the files into a local directory laid out as a simple index
+    /// (PEP 503) instead, e.g., to serve it with a static file server.
+    ///
     /// Defaults to PyPI's publish URL (<https://upload.pypi.org/legacy/>).
     #[arg(long, env = EnvVars::UV_PUBLISH_URL)]
     pub publish_url: Option<Url>,
@@ -5526,6 +5805,29 @@ pub struct PublishArgs {
     #[arg(long, env = EnvVars::UV_PUBLISH_CHECK_URL)]
     pub check_url: Option<IndexUrl>,
 
+    /// The directory containing the PEP 740 attestations of the distributions.
+    ///
+    /// For each distribution, an attestation named `<filename>.publish.attestation` is included in
+    /// the upload, if present. The subject of each attestation must match the name and SHA-256
+    /// digest of its distribution.
+    ///
+    /// Defaults to looking for attestations next to each distribution.
+    #[arg(long, value_name = "DIR")]
+    pub attestations: Option<PathBuf>,
+
+    /// Validate the files locally before uploading them.
+    ///
+    /// Checks that the filename matches the package name and version in the metadata, that the
+    /// metadata version and description content type are supported, that the `RECORD` of each
+    /// wheel lists every file in the wheel with a matching hash, and that no version is included
+    /// twice.
+    ///
+    /// If any file fails validation, no files are uploaded. Common reStructuredText mistakes that
+    /// prevent the index from rendering the description, such as short title underlines or
+    /// Sphinx-only directives and roles, are reported as warnings.
+    #[arg(long)]
+    pub check: bool,
+
     #[arg(long, hide = true)]
     pub skip_existing: bool,
 }
@@ -7,14 +7,15 @@ use reqwest_retry::{
 };
 use std::error::Error;
 use std::fmt::Debug;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 use std::{env, iter};
 use tracing::{debug, trace};
 use url::Url;
-use uv_auth::AuthMiddleware;
-use uv_configuration::{KeyringProviderType, TrustedHost};
+use uv_auth::{AuthMiddleware, Indexes};
+use uv_configuration::{HostTls, KeyringProviderType, ProxySettings, TrustedHost};
+use uv_distribution_types::{IndexHttpPolicy, ProxyUrl};
 use uv_fs::Simplified;
 use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Platform;
@@ -22,9 +23,13 @@ use uv_static::EnvVars;
 use uv_version::version;
 use uv_warnings::warn_user_once;
 
+use crate::http_policy::{HttpPolicy, HttpPolicyRetryableStrategy};
 use crate::linehaul::LineHaul;
-use crate::middleware::OfflineMiddleware;
-use crate::tls::read_identity;
+use crate::middleware::{
+    MissingCredentialHelperMiddleware, OfflineMiddleware, ProxyErrorMiddleware,
+};
+use crate::proxy::{ClientProxy, ProxyError};
+use crate::tls::{read_identity, HostTlsMaterial};
 use crate::Connectivity;
 
 pub const DEFAULT_RETRIES: u32 = 3;
@@ -46,14 +51,22 @@ pub enum AuthIntegration {
 #[derive(Debug, Clone)]
 pub struct BaseClientBuilder<'a> {
     keyring: KeyringProviderType,
+    credential_helper: Option<PathBuf>,
     allow_insecure_host: Vec<TrustedHost>,
+    host_tls: Vec<HostTlsMaterial>,
     native_tls: bool,
-    retries: u32,
+    retries: Option<u32>,
+    retry_middleware: bool,
+    http_policy: IndexHttpPolicy,
+    http_policies: Vec<(Url, IndexHttpPolicy)>,
+    proxy: ProxySettings,
+    index_proxies: Vec<(Url, Option<ProxyUrl>)>,
     pub connectivity: Connectivity,
     client: Option<Client>,
     markers: Option<&'a MarkerEnvironment>,
     platform: Option<&'a Platform>,
     auth_integration: AuthIntegration,
+    indexes: Indexes,
     default_timeout: Duration,
     extra_middleware: Option<ExtraMiddleware>,
 }
@@ -80,14 +93,22 @@ impl BaseClientBuilder<'_> {
     pub fn new() -> Self {
         Self {
             keyring: KeyringProviderType::default(),
+            credential_helper: None,
             allow_insecure_host: vec![],
+            host_tls: vec![],
             native_tls: false,
             connectivity: Connectivity::Online,
-            retries: DEFAULT_RETRIES,
+            retries: None,
+            retry_middleware: true,
+            http_policy: IndexHttpPolicy::default(),
+            http_policies: vec![],
+            proxy: ProxySettings::default(),
+            index_proxies: vec![],
             client: None,
             markers: None,
             platform: None,
             auth_integration: AuthIntegration::default(),
+            indexes: Indexes::default(),
             default_timeout: Duration::from_secs(30),
             extra_middleware: None,
         }
@@ -101,21 +122,77 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Set the credential helper to use with [`KeyringProviderType::Helper`].
+    #[must_use]
+    pub fn credential_helper(mut self, credential_helper: Option<PathBuf>) -> Self {
+        self.credential_helper = credential_helper;
+        self
+    }
+
     #[must_use]
     pub fn allow_insecure_host(mut self, allow_insecure_host: Vec<TrustedHost>) -> Self {
         self.allow_insecure_host = allow_insecure_host;
         self
     }
 
+    /// Set the custom TLS settings for specific hosts.
+    #[must_use]
+    pub fn host_tls(mut self, host_tls: Vec<HostTlsMaterial>) -> Self {
+        self.host_tls = host_tls;
+        self
+    }
+
     #[must_use]
     pub fn connectivity(mut self, connectivity: Connectivity) -> Self {
         self.connectivity = connectivity;
         self
     }
 
+    /// Set the number of retries, taking precedence over the global and per-index settings.
     #[must_use]
     pub fn retries(mut self, retries: u32) -> Self {
-        self.retries = retries;
+        self.retries = Some(retries);
+        self
+    }
+
+    /// Whether to retry transient failures in the client's middleware.
+    ///
+    /// Requests with a streaming body can't be cloned for a retry, so callers that send them
+    /// disable the middleware and retry with [`BaseClient::retry_policy_for`] themselves.
+    #[must_use]
+    pub fn retry_middleware(mut self, retry_middleware: bool) -> Self {
+        self.retry_middleware = retry_middleware;
+        self
+    }
+
+    /// Set the retry and timeout settings for all hosts (e.g., from `uv.toml`).
+    ///
+    /// Settings from the environment (e.g., `UV_HTTP_RETRIES`) take precedence over these
+    /// settings.
+    #[must_use]
+    pub fn http_policy(mut self, http_policy: IndexHttpPolicy) -> Self {
+        self.http_policy = http_policy;
+        self
+    }
+
+    /// Set the retry and timeout settings for the hosts of the given indexes.
+    #[must_use]
+    pub fn http_policies(mut self, http_policies: Vec<(Url, IndexHttpPolicy)>) -> Self {
+        self.http_policies = http_policies;
+        self
+    }
+
+    /// Set the proxy settings for all requests.
+    #[must_use]
+    pub fn proxy(mut self, proxy: ProxySettings) -> Self {
+        self.proxy = proxy;
+        self
+    }
+
+    /// Set the proxies for the hosts of the given indexes, where `None` bypasses any proxy.
+    #[must_use]
+    pub fn index_proxies(mut self, index_proxies: Vec<(Url, Option<ProxyUrl>)>) -> Self {
+        self.index_proxies = index_proxies;
         self
     }
 
@@ -149,6 +226,12 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn indexes(mut self, indexes: Indexes) -> Self {
+        self.indexes = indexes;
+        self
+    }
+
     #[must_use]
     pub fn default_timeout(mut self, default_timeout: Duration) -> Self {
         self.default_timeout = default_timeout;
@@ -165,9 +248,22 @@ impl<'a> BaseClientBuilder<'a> {
         matches!(self.connectivity, Connectivity::Offline)
     }
 
-    /// Create a [`RetryPolicy`] for the client.
-    fn retry_policy(&self) -> ExponentialBackoff {
-        ExponentialBackoff::builder().build_with_max_retries(self.retries)
+    /// Resolve the global [`HttpPolicy`] from the environment and the configured settings.
+    fn global_http_policy(&self) -> HttpPolicy {
+        let mut policy = HttpPolicy::from_env(&self.http_policy, self.default_timeout);
+        if let Some(retries) = self.retries {
+            policy.retries = retries;
+        }
+        policy
+    }
+
+    /// Resolve the [`HttpPolicy`] for the host of an index.
+    fn index_http_policy(&self, global: &HttpPolicy, overrides: &IndexHttpPolicy) -> HttpPolicy {
+        let mut policy = global.with_overrides(overrides);
+        if let Some(retries) = self.retries {
+            policy.retries = retries;
+        }
+        policy
     }
 
     pub fn build(&self) -> BaseClient {
@@ -194,69 +290,218 @@ impl<'a> BaseClientBuilder<'a> {
             path_exists
         });
 
-        // Timeout options, matching https://doc.rust-lang.org/nightly/cargo/reference/config.html#httptimeout
-        // `UV_REQUEST_TIMEOUT` is provided for backwards compatibility with v0.1.6
-        let timeout = env::var(EnvVars::UV_HTTP_TIMEOUT)
-            .or_else(|_| env::var(EnvVars::UV_REQUEST_TIMEOUT))
-            .or_else(|_| env::var(EnvVars::HTTP_TIMEOUT))
-            .and_then(|value| {
-                value.parse::<u64>()
-                    .map(Duration::from_secs)
-                    .or_else(|_| {
-                        // On parse error, warn and use the default timeout
-                        warn_user_once!("Ignoring invalid value from environment for `UV_HTTP_TIMEOUT`. Expected an integer number of seconds, got \"{value}\".");
-                        Ok(self.default_timeout)
-                    })
-            })
-            .unwrap_or(self.default_timeout);
+        let policy = self.global_http_policy();
+        let timeout = policy.read_timeout;
         debug!("Using request timeout of {}s", timeout.as_secs());
 
+        let (proxy, proxy_error) = resolve_proxy(Some(&self.proxy));
+
         // Create a secure client that validates certificates.
         let raw_client = self.create_client(
             &user_agent_string,
-            timeout,
+            &policy,
             ssl_cert_file_exists,
             Security::Secure,
+            None,
+            &proxy,
         );
 
         // Create an insecure client that accepts invalid certificates.
         let raw_dangerous_client = self.create_client(
             &user_agent_string,
-            timeout,
+            &policy,
             ssl_cert_file_exists,
             Security::Insecure,
+            None,
+            &proxy,
         );
 
+        // Create a secure client for each host with custom TLS settings.
+        let host_clients = self
+            .host_tls
+            .iter()
+            .map(|material| {
+                debug!("Using custom TLS settings for: {}", material.host_tls.host);
+                let raw_client = self.create_client(
+                    &user_agent_string,
+                    &policy,
+                    ssl_cert_file_exists,
+                    Security::Secure,
+                    Some(material),
+                    &proxy,
+                );
+                HostClient {
+                    host_tls: material.host_tls.clone(),
+                    client: self.apply_middleware(
+                        raw_client.clone(),
+                        &policy,
+                        proxy_error.as_ref(),
+                    ),
+                    raw_client,
+                }
+            })
+            .collect();
+
+        // Create a client for the host of each index with custom retry, timeout, or proxy
+        // settings, respecting the TLS settings of the host.
+        let mut index_clients: Vec<IndexClient> = Vec::new();
+        let index_urls = self
+            .http_policies
+            .iter()
+            .map(|(url, _)| url)
+            .chain(self.index_proxies.iter().map(|(url, _)| url));
+        for url in index_urls {
+            if index_clients
+                .iter()
+                .any(|index_client| index_client.matches(url))
+            {
+                continue;
+            }
+
+            // If multiple indexes share a host, the first index with settings takes precedence.
+            let overrides = self
+                .http_policies
+                .iter()
+                .find(|(index, _)| index.origin() == url.origin())
+                .map(|(_, overrides)| {
+                    debug!("Using custom retry and timeout settings for: {url}");
+                    overrides.clone()
+                })
+                .unwrap_or_default();
+            let index_proxy = match self
+                .index_proxies
+                .iter()
+                .find(|(index, _)| index.origin() == url.origin())
+            {
+                Some((_, Some(proxy))) => {
+                    debug!("Using proxy `{proxy}` for: {url}");
+                    Some(ProxySettings {
+                        proxy: Some(proxy.clone()),
+                        no_proxy: vec![],
+                    })
+                }
+                Some((_, None)) => {
+                    debug!("Bypassing proxy for: {url}");
+                    None
+                }
+                None => Some(self.proxy.clone()),
+            };
+            let (index_proxy, index_proxy_error) = resolve_proxy(index_proxy.as_ref());
+
+            let index_policy = self.index_http_policy(&policy, &overrides);
+            let security = if self
+                .allow_insecure_host
+                .iter()
+                .any(|allow_insecure_host| allow_insecure_host.matches(url))
+            {
+                Security::Insecure
+            } else {
+                Security::Secure
+            };
+            let material = self
+                .host_tls
+                .iter()
+                .find(|material| material.host_tls.matches(url));
+            let raw_client = self.create_client(
+                &user_agent_string,
+                &index_policy,
+                ssl_cert_file_exists,
+                security,
+                material,
+                &index_proxy,
+            );
+            index_clients.push(IndexClient {
+                url: url.clone(),
+                overrides,
+                client: self.apply_middleware(
+                    raw_client.clone(),
+                    &index_policy,
+                    index_proxy_error.as_ref(),
+                ),
+                policy: index_policy,
+                proxy_error: index_proxy_error,
+                raw_client,
+            });
+        }
+
         // Wrap in any relevant middleware and handle connectivity.
-        let client = self.apply_middleware(raw_client.clone());
-        let dangerous_client = self.apply_middleware(raw_dangerous_client.clone());
+        let client = self.apply_middleware(raw_client.clone(), &policy, proxy_error.as_ref());
+        let dangerous_client =
+            self.apply_middleware(raw_dangerous_client.clone(), &policy, proxy_error.as_ref());
 
         BaseClient {
             connectivity: self.connectivity,
             allow_insecure_host: self.allow_insecure_host.clone(),
-            retries: self.retries,
+            policy,
+            keyring: self.keyring,
+            credential_helper: self.credential_helper.clone(),
             client,
             raw_client,
             dangerous_client,
             raw_dangerous_client,
+            host_clients,
+            index_clients,
+            proxy_error,
             timeout,
         }
     }
 
     /// Share the underlying client between two different middleware configurations.
     pub fn wrap_existing(&self, existing: &BaseClient) -> BaseClient {
-        // Wrap in any relevant middleware and handle connectivity.
-        let client = self.apply_middleware(existing.raw_client.clone());
-        let dangerous_client = self.apply_middleware(existing.raw_dangerous_client.clone());
+        // The timeouts are part of the existing clients, so only the retry settings are updated.
+        let policy = HttpPolicy {
+            read_timeout: existing.timeout,
+            ..self.global_http_policy()
+        };
+
+        // Wrap in any relevant middleware and handle connectivity. The proxies are part of the
+        // existing clients, so any proxy errors are retained.
+        let proxy_error = existing.proxy_error.as_ref();
+        let client = self.apply_middleware(existing.raw_client.clone(), &policy, proxy_error);
+        let dangerous_client =
+            self.apply_middleware(existing.raw_dangerous_client.clone(), &policy, proxy_error);
+        let host_clients = existing
+            .host_clients
+            .iter()
+            .map(|host_client| HostClient {
+                host_tls: host_client.host_tls.clone(),
+                client: self.apply_middleware(host_client.raw_client.clone(), &policy, proxy_error),
+                raw_client: host_client.raw_client.clone(),
+            })
+            .collect();
+        let index_clients = existing
+            .index_clients
+            .iter()
+            .map(|index_client| {
+                let index_policy = self.index_http_policy(&policy, &index_client.overrides);
+                IndexClient {
+                    url: index_client.url.clone(),
+                    overrides: index_client.overrides.clone(),
+                    client: self.apply_middleware(
+                        index_client.raw_client.clone(),
+                        &index_policy,
+                        index_client.proxy_error.as_ref(),
+                    ),
+                    policy: index_policy,
+                    proxy_error: index_client.proxy_error.clone(),
+                    raw_client: index_client.raw_client.clone(),
+                }
+            })
+            .collect();
 
         BaseClient {
             connectivity: self.connectivity,
             allow_insecure_host: self.allow_insecure_host.clone(),
-            retries: self.retries,
+            policy,
+            keyring: self.keyring,
+            credential_helper: self.credential_helper.clone(),
             client,
             dangerous_client,
             raw_client: existing.raw_client.clone(),
             raw_dangerous_client: existing.raw_dangerous_client.clone(),
+            host_clients,
+            index_clients,
+            proxy_error: existing.proxy_error.clone(),
             timeout: existing.timeout,
         }
     }
@@ -264,32 +509,48 @@ impl<'a> BaseClientBuilder<'a> {
     fn create_client(
         &self,
         user_agent: &str,
-        timeout: Duration,
+        policy: &HttpPolicy,
         ssl_cert_file_exists: bool,
         security: Security,
+        host_tls: Option<&HostTlsMaterial>,
+        proxy: &ClientProxy,
     ) -> Client {
         // Configure the builder.
         let client_builder = ClientBuilder::new()
             .http1_title_case_headers()
             .user_agent(user_agent)
             .pool_max_idle_per_host(20)
-            .read_timeout(timeout)
+            .read_timeout(policy.read_timeout)
             .tls_built_in_root_certs(false);
 
+        let client_builder = if let Some(connect_timeout) = policy.connect_timeout {
+            client_builder.connect_timeout(connect_timeout)
+        } else {
+            client_builder
+        };
+
         // If necessary, accept invalid certificates.
         let client_builder = match security {
             Security::Secure => client_builder,
             Security::Insecure => client_builder.danger_accept_invalid_certs(true),
         };
 
-        let client_builder = if self.native_tls || ssl_cert_file_exists {
+        let client_builder = if let Some(roots) = host_tls.and_then(|tls| tls.roots.as_ref()) {
+            // Trust only the certificate authorities configured for the host.
+            roots
+                .iter()
+                .cloned()
+                .fold(client_builder, ClientBuilder::add_root_certificate)
+        } else if self.native_tls || ssl_cert_file_exists {
             client_builder.tls_built_in_native_certs(true)
         } else {
             client_builder.tls_built_in_webpki_certs(true)
         };
 
         // Configure mTLS.
-        let client_builder = if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
+        let client_builder = if let Some(identity) = host_tls.and_then(|tls| tls.identity.clone()) {
+            client_builder.identity(identity)
+        } else if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
             match read_identity(&ssl_client_cert) {
                 Ok(identity) => client_builder.identity(identity),
                 Err(err) => {
@@ -301,36 +562,61 @@ impl<'a> BaseClientBuilder<'a> {
             client_builder
         };
 
+        // Configure the proxy.
+        let client_builder = proxy.apply(client_builder);
+
         client_builder
             .build()
             .expect("Failed to build HTTP client.")
     }
 
-    fn apply_middleware(&self, client: Client) -> ClientWithMiddleware {
+    fn apply_middleware(
+        &self,
+        client: Client,
+        policy: &HttpPolicy,
+        proxy_error: Option<&ProxyError>,
+    ) -> ClientWithMiddleware {
         match self.connectivity {
             Connectivity::Online => {
+                // Reject all requests if the proxy is invalid, rather than connecting directly.
+                if let Some(err) = proxy_error {
+                    return reqwest_middleware::ClientBuilder::new(client)
+                        .with(ProxyErrorMiddleware::new(err.clone()))
+                        .build();
+                }
+
                 let mut client = reqwest_middleware::ClientBuilder::new(client);
 
                 // Avoid uncloneable errors with a streaming body during publish.
-                if self.retries > 0 {
+                if self.retry_middleware && policy.retries > 0 {
                     // Initialize the retry strategy.
                     let retry_strategy = RetryTransientMiddleware::new_with_policy_and_strategy(
-                        self.retry_policy(),
-                        UvRetryableStrategy,
+                        policy.retry_policy(),
+                        policy.retry_strategy(),
                     );
                     client = client.with(retry_strategy);
                 }
 
+                // Reject all requests if the keyring provider is misconfigured.
+                let Ok(keyring) = self.keyring.to_provider(self.credential_helper.as_deref())
+                else {
+                    return client.with(MissingCredentialHelperMiddleware).build();
+                };
+
                 // Initialize the authentication middleware to set headers.
                 match self.auth_integration {
                     AuthIntegration::Default => {
-                        client = client
-                            .with(AuthMiddleware::new().with_keyring(self.keyring.to_provider()));
+                        client = client.with(
+                            AuthMiddleware::new()
+                                .with_keyring(keyring)
+                                .with_indexes(self.indexes.clone()),
+                        );
                     }
                     AuthIntegration::OnlyAuthenticated => {
                         client = client.with(
                             AuthMiddleware::new()
-                                .with_keyring(self.keyring.to_provider())
+                                .with_keyring(keyring)
+                                .with_indexes(self.indexes.clone())
                                 .with_only_authenticated(true),
                         );
                     }
@@ -366,14 +652,72 @@ pub struct BaseClient {
     raw_client: Client,
     /// The HTTP client that accepts invalid certificates without middleware.
     raw_dangerous_client: Client,
+    /// The HTTP clients for hosts with custom TLS settings.
+    host_clients: Vec<HostClient>,
+    /// The HTTP clients for the hosts of indexes with custom retry, timeout, or proxy settings.
+    index_clients: Vec<IndexClient>,
+    /// The error for the proxy of the clients, if it's invalid, in which case all requests are
+    /// rejected.
+    proxy_error: Option<ProxyError>,
     /// The connectivity mode to use.
     connectivity: Connectivity,
     /// Configured client timeout, in seconds.
     timeout: Duration,
     /// Hosts that are trusted to use the insecure client.
     allow_insecure_host: Vec<TrustedHost>,
-    /// The number of retries to attempt on transient errors.
-    retries: u32,
+    /// The retry and timeout settings for hosts without custom settings.
+    policy: HttpPolicy,
+    /// The keyring provider used for credential lookup.
+    keyring: KeyringProviderType,
+    /// The credential helper used with [`KeyringProviderType::Helper`].
+    credential_helper: Option<PathBuf>,
+}
+
+/// An HTTP client for a host with custom TLS settings.
+#[derive(Debug, Clone)]
+struct HostClient {
+    /// The TLS settings for the host.
+    host_tls: HostTls,
+    /// The underlying HTTP client, with the host's TLS settings applied.
+    client: ClientWithMiddleware,
+    /// The HTTP client without middleware.
+    raw_client: Client,
+}
+
+/// An HTTP client for the host of an index with custom retry, timeout, or proxy settings.
+#[derive(Debug, Clone)]
+struct IndexClient {
+    /// The URL of the index.
+    url: Url,
+    /// The retry and timeout settings of the index.
+    overrides: IndexHttpPolicy,
+    /// The resolved retry and timeout settings for the host.
+    policy: HttpPolicy,
+    /// The error for the proxy of the host, if it's invalid.
+    proxy_error: Option<ProxyError>,
+    /// The underlying HTTP client, with the host's settings applied.
+    client: ClientWithMiddleware,
+    /// The HTTP client without middleware.
+    raw_client: Client,
+}
+
+impl IndexClient {
+    /// Returns `true` if the URL is on the same host as the index.
+    fn matches(&self, url: &Url) -> bool {
+        self.url.origin() == url.origin()
+    }
+}
+
+/// Resolve the proxies for a client.
+///
+/// An invalid proxy is an error, rather than a reason to connect directly. Building a client is
+/// infallible, so the client instead bypasses any proxy and the error is returned alongside it, to
+/// reject all of the client's requests.
+fn resolve_proxy(settings: Option<&ProxySettings>) -> (ClientProxy, Option<ProxyError>) {
+    match ClientProxy::new(settings) {
+        Ok(proxy) => (proxy, None),
+        Err(err) => (ClientProxy::Direct, Some(err)),
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -387,13 +731,38 @@ enum Security {
 impl BaseClient {
     /// Selects the appropriate client based on the host's trustworthiness.
     pub fn for_host(&self, url: &Url) -> &ClientWithMiddleware {
-        if self.disable_ssl(url) {
+        if let Some(index_client) = self.index_client(url) {
+            &index_client.client
+        } else if self.disable_ssl(url) {
             &self.dangerous_client
+        } else if let Some(host_client) = self.host_client(url) {
+            &host_client.client
         } else {
             &self.client
         }
     }
 
+    /// Returns the custom TLS settings for the host, if any.
+    pub fn host_tls(&self, url: &Url) -> Option<&HostTls> {
+        self.host_client(url)
+            .map(|host_client| &host_client.host_tls)
+    }
+
+    /// Returns the [`HostClient`] for the host, if the host has custom TLS settings.
+    fn host_client(&self, url: &Url) -> Option<&HostClient> {
+        self.host_clients
+            .iter()
+            .find(|host_client| host_client.host_tls.matches(url))
+    }
+
+    /// Returns the [`IndexClient`] for the host, if the host has custom retry, timeout, or proxy
+    /// settings.
+    fn index_client(&self, url: &Url) -> Option<&IndexClient> {
+        self.index_clients
+            .iter()
+            .find(|index_client| index_client.matches(url))
+    }
+
     /// Returns `true` if the host is trusted to use the insecure client.
     pub fn disable_ssl(&self, url: &Url) -> bool {
         self.allow_insecure_host
@@ -411,9 +780,34 @@ impl BaseClient {
         self.connectivity
     }
 
+    /// The configured keyring provider.
+    pub fn keyring(&self) -> KeyringProviderType {
+        self.keyring
+    }
+
+    /// The configured credential helper.
+    pub fn credential_helper(&self) -> Option<&Path> {
+        self.credential_helper.as_deref()
+    }
+
     /// The [`RetryPolicy`] for the client.
     pub fn retry_policy(&self) -> ExponentialBackoff {
-        ExponentialBackoff::builder().build_with_max_retries(self.retries)
+        self.policy.retry_policy()
+    }
+
+    /// The [`RetryPolicy`] for requests to the given URL, respecting the settings of its index.
+    pub fn retry_policy_for(&self, url: &Url) -> ExponentialBackoff {
+        self.index_client(url)
+            .map_or(&self.policy, |index_client| &index_client.policy)
+            .retry_policy()
+    }
+
+    /// The [`RetryableStrategy`] for requests to the given URL, respecting the retryable status
+    /// codes of its index.
+    pub fn retry_strategy_for(&self, url: &Url) -> HttpPolicyRetryableStrategy {
+        self.index_client(url)
+            .map_or(&self.policy, |index_client| &index_client.policy)
+            .retry_strategy()
     }
 }
 
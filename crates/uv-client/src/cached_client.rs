@@ -11,9 +11,9 @@ use serde::{Deserialize, Serialize};
 use tracing::{debug, info_span, instrument, trace, warn, Instrument};
 
 use uv_cache::{CacheEntry, Freshness};
+use uv_distribution_types::CacheControlHeader;
 use uv_fs::write_atomic;
 
-use crate::base_client::is_extended_transient_error;
 use crate::BaseClient;
 use crate::{
     httpcache::{AfterResponse, BeforeRequest, CachePolicy, CachePolicyBuilder},
@@ -157,7 +157,7 @@ impl<E: Into<Self> + std::error::Error + 'static> From<CachedClientError<E>> for
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub enum CacheControl {
     /// Respect the `cache-control` header from the response.
     None,
@@ -165,6 +165,23 @@ pub enum CacheControl {
     MustRevalidate,
     /// Allow the client to return stale responses.
     AllowStale,
+    /// Override the `cache-control` header from the response with a custom value (e.g., as
+    /// configured for the index).
+    Override(CacheControlHeader),
+}
+
+impl CacheControl {
+    /// Determine the [`CacheControl`] for a given [`Freshness`], applying the `cache-control`
+    /// header override, if any.
+    ///
+    /// A user-requested refresh takes precedence over the override.
+    pub fn from_freshness(freshness: Freshness, header: Option<&CacheControlHeader>) -> Self {
+        match (freshness, header) {
+            (Freshness::Stale, _) => Self::MustRevalidate,
+            (Freshness::Fresh | Freshness::Missing, Some(header)) => Self::Override(header.clone()),
+            (Freshness::Fresh | Freshness::Missing, None) => Self::None,
+        }
+    }
 }
 
 impl From<Freshness> for CacheControl {
@@ -269,12 +286,12 @@ impl CachedClient {
     {
         let fresh_req = req.try_clone().expect("HTTP request must be cloneable");
         let cached_response = if let Some(cached) = Self::read_cache(cache_entry).await {
-            self.send_cached(req, cache_control, cached)
+            self.send_cached(req, &cache_control, cached)
                 .boxed_local()
                 .await?
         } else {
             debug!("No cache entry for: {}", req.url());
-            let (response, cache_policy) = self.fresh_request(req).await?;
+            let (response, cache_policy) = self.fresh_request(req, &cache_control).await?;
             CachedResponse::ModifiedOrNew {
                 response,
                 cache_policy,
@@ -288,8 +305,13 @@ impl CachedClient {
                         "Broken fresh cache entry (for payload) at {}, removing: {err}",
                         cache_entry.path().display()
                     );
-                    self.resend_and_heal_cache(fresh_req, cache_entry, response_callback)
-                        .await
+                    self.resend_and_heal_cache(
+                        fresh_req,
+                        cache_entry,
+                        cache_control,
+                        response_callback,
+                    )
+                    .await
                 }
             },
             CachedResponse::NotModified { cached, new_policy } => {
@@ -309,8 +331,13 @@ impl CachedClient {
                                  (for payload) at {}, removing: {err}",
                                 cache_entry.path().display()
                             );
-                            self.resend_and_heal_cache(fresh_req, cache_entry, response_callback)
-                                .await
+                            self.resend_and_heal_cache(
+                                fresh_req,
+                                cache_entry,
+                                cache_control,
+                                response_callback,
+                            )
+                            .await
                         }
                     }
                 }
@@ -325,8 +352,13 @@ impl CachedClient {
                 // ETag didn't match). We need to make a fresh request.
                 if response.status() == http::StatusCode::NOT_MODIFIED {
                     warn!("Server returned unusable 304 for: {}", fresh_req.url());
-                    self.resend_and_heal_cache(fresh_req, cache_entry, response_callback)
-                        .await
+                    self.resend_and_heal_cache(
+                        fresh_req,
+                        cache_entry,
+                        cache_control,
+                        response_callback,
+                    )
+                    .await
                 } else {
                     self.run_response_callback(
                         cache_entry,
@@ -356,7 +388,7 @@ impl CachedClient {
         Callback: FnOnce(Response) -> CallbackReturn,
         CallbackReturn: Future<Output = Result<Payload, CallBackError>>,
     {
-        let (response, cache_policy) = self.fresh_request(req).await?;
+        let (response, cache_policy) = self.fresh_request(req, CacheControl::None).await?;
 
         let payload = self
             .run_response_callback(cache_entry, cache_policy, response, move |resp| async {
@@ -377,6 +409,7 @@ impl CachedClient {
         &self,
         req: Request,
         cache_entry: &CacheEntry,
+        cache_control: CacheControl,
         response_callback: Callback,
     ) -> Result<Payload::Target, CachedClientError<CallBackError>>
     where
@@ -384,7 +417,7 @@ impl CachedClient {
         CallbackReturn: Future<Output = Result<Payload, CallBackError>>,
     {
         let _ = fs_err::tokio::remove_file(&cache_entry.path()).await;
-        let (response, cache_policy) = self.fresh_request(req).await?;
+        let (response, cache_policy) = self.fresh_request(req, &cache_control).await?;
         self.run_response_callback(cache_entry, cache_policy, response, response_callback)
             .await
     }
@@ -456,12 +489,12 @@ impl CachedClient {
     async fn send_cached(
         &self,
         mut req: Request,
-        cache_control: CacheControl,
+        cache_control: &CacheControl,
         cached: DataWithCachePolicy,
     ) -> Result<CachedResponse, Error> {
         // Apply the cache control header, if necessary.
         match cache_control {
-            CacheControl::None | CacheControl::AllowStale => {}
+            CacheControl::None | CacheControl::AllowStale | CacheControl::Override(..) => {}
             CacheControl::MustRevalidate => {
                 req.headers_mut().insert(
                     http::header::CACHE_CONTROL,
@@ -475,10 +508,15 @@ impl CachedClient {
                 CachedResponse::FreshCache(cached)
             }
             BeforeRequest::Stale(new_cache_policy_builder) => match cache_control {
-                CacheControl::None | CacheControl::MustRevalidate => {
+                CacheControl::None | CacheControl::MustRevalidate | CacheControl::Override(..) => {
                     debug!("Found stale response for: {}", req.url());
-                    self.send_cached_handle_stale(req, cached, new_cache_policy_builder)
-                        .await?
+                    self.send_cached_handle_stale(
+                        req,
+                        cache_control,
+                        cached,
+                        new_cache_policy_builder,
+                    )
+                    .await?
                 }
                 CacheControl::AllowStale => {
                     debug!("Found stale (but allowed) response for: {}", req.url());
@@ -491,7 +529,7 @@ impl CachedClient {
                     "Cached request doesn't match current request for: {}",
                     req.url()
                 );
-                let (response, cache_policy) = self.fresh_request(req).await?;
+                let (response, cache_policy) = self.fresh_request(req, cache_control).await?;
                 CachedResponse::ModifiedOrNew {
                     response,
                     cache_policy,
@@ -503,12 +541,13 @@ impl CachedClient {
     async fn send_cached_handle_stale(
         &self,
         req: Request,
+        cache_control: &CacheControl,
         cached: DataWithCachePolicy,
         new_cache_policy_builder: CachePolicyBuilder,
     ) -> Result<CachedResponse, Error> {
         let url = req.url().clone();
         debug!("Sending revalidation request for: {url}");
-        let response = self
+        let mut response = self
             .0
             .for_host(req.url())
             .execute(req)
@@ -517,6 +556,7 @@ impl CachedClient {
             .map_err(|err| ErrorKind::from_reqwest_middleware(url.clone(), err))?
             .error_for_status()
             .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+        override_cache_control(&mut response, cache_control);
         match cached
             .cache_policy
             .after_response(new_cache_policy_builder, &response)
@@ -545,11 +585,12 @@ impl CachedClient {
     async fn fresh_request(
         &self,
         req: Request,
+        cache_control: &CacheControl,
     ) -> Result<(Response, Option<Box<CachePolicy>>), Error> {
         let url = req.url().clone();
         trace!("Sending fresh {} request for {}", req.method(), url);
         let cache_policy_builder = CachePolicyBuilder::new(&req);
-        let response = self
+        let mut response = self
             .0
             .for_host(&url)
             .execute(req)
@@ -557,6 +598,7 @@ impl CachedClient {
             .map_err(|err| ErrorKind::from_reqwest_middleware(url.clone(), err))?
             .error_for_status()
             .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+        override_cache_control(&mut response, cache_control);
         let cache_policy = cache_policy_builder.build(&response);
         let cache_policy = if cache_policy.to_archived().is_storable() {
             Some(Box::new(cache_policy))
@@ -615,16 +657,22 @@ impl CachedClient {
     {
         let mut n_past_retries = 0;
         let start_time = SystemTime::now();
-        let retry_policy = self.uncached().retry_policy();
+        let retry_policy = self.uncached().retry_policy_for(req.url());
+        let retry_strategy = self.uncached().retry_strategy_for(req.url());
         loop {
             let fresh_req = req.try_clone().expect("HTTP request must be cloneable");
             let result = self
-                .get_cacheable(fresh_req, cache_entry, cache_control, &response_callback)
+                .get_cacheable(
+                    fresh_req,
+                    cache_entry,
+                    cache_control.clone(),
+                    &response_callback,
+                )
                 .await;
             if result
                 .as_ref()
                 .err()
-                .is_some_and(|err| is_extended_transient_error(err))
+                .is_some_and(|err| retry_strategy.is_transient_error(err))
             {
                 let retry_decision = retry_policy.should_retry(start_time, n_past_retries);
                 if let reqwest_retry::RetryDecision::Retry { execute_after } = retry_decision {
@@ -664,7 +712,8 @@ impl CachedClient {
     {
         let mut n_past_retries = 0;
         let start_time = SystemTime::now();
-        let retry_policy = self.uncached().retry_policy();
+        let retry_policy = self.uncached().retry_policy_for(req.url());
+        let retry_strategy = self.uncached().retry_strategy_for(req.url());
         loop {
             let fresh_req = req.try_clone().expect("HTTP request must be cloneable");
             let result = self
@@ -673,7 +722,7 @@ impl CachedClient {
             if result
                 .as_ref()
                 .err()
-                .is_some_and(|err| is_extended_transient_error(err))
+                .is_some_and(|err| retry_strategy.is_transient_error(err))
             {
                 let retry_decision = retry_policy.should_retry(start_time, n_past_retries);
                 if let reqwest_retry::RetryDecision::Retry { execute_after } = retry_decision {
@@ -694,6 +743,23 @@ impl CachedClient {
     }
 }
 
+/// If the [`CacheControl`] overrides the `cache-control` header, replace the header on the
+/// response, such that the cache policy is derived from the override rather than the server.
+fn override_cache_control(response: &mut Response, cache_control: &CacheControl) {
+    let CacheControl::Override(header) = cache_control else {
+        return;
+    };
+    let value = http::HeaderValue::from_str(header.as_str())
+        .expect("`cache-control` overrides are validated on parse");
+    trace!(
+        "Overriding `cache-control` header for {}: {header}",
+        response.url()
+    );
+    response
+        .headers_mut()
+        .insert(http::header::CACHE_CONTROL, value);
+}
+
 #[derive(Debug)]
 enum CachedResponse {
     /// The cached response is fresh without an HTTP request (e.g. age < max-age).
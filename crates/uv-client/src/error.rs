@@ -149,6 +149,9 @@ pub enum ErrorKind {
     #[error(transparent)]
     JoinRelativeUrl(#[from] uv_pypi_types::JoinRelativeError),
 
+    #[error(transparent)]
+    Flat(#[from] crate::flat_index::FlatIndexError),
+
     #[error("Expected a file URL, but received: {0}")]
     NonFileUrl(Url),
 
@@ -106,32 +106,7 @@ impl<'a> FlatIndexClient<'a> {
         indexes: impl Iterator<Item = &IndexUrl>,
     ) -> Result<FlatIndexEntries, FlatIndexError> {
         let mut fetches = futures::stream::iter(indexes)
-            .map(|index| async move {
-                let entries = match index {
-                    IndexUrl::Path(url) => {
-                        let path = url
-                            .to_file_path()
-                            .map_err(|()| FlatIndexError::NonFileUrl(url.to_url()))?;
-                        Self::read_from_directory(&path, index)
-                            .map_err(|err| FlatIndexError::FindLinksDirectory(path.clone(), err))?
-                    }
-                    IndexUrl::Pypi(url) | IndexUrl::Url(url) => self
-                        .read_from_url(url, index)
-                        .await
-                        .map_err(|err| FlatIndexError::FindLinksUrl(url.to_url(), err))?,
-                };
-                if entries.is_empty() {
-                    warn!("No packages found in `--find-links` entry: {}", index);
-                } else {
-                    debug!(
-                        "Found {} package{} in `--find-links` entry: {}",
-                        entries.len(),
-                        if entries.len() == 1 { "" } else { "s" },
-                        index
-                    );
-                }
-                Ok::<FlatIndexEntries, FlatIndexError>(entries)
-            })
+            .map(|index| self.fetch_index(index))
             .buffered(16);
 
         let mut results = FlatIndexEntries::default();
@@ -144,6 +119,35 @@ impl<'a> FlatIndexClient<'a> {
         Ok(results)
     }
 
+    /// Read a single flat index, either a local directory or a remote HTML index.
+    #[allow(clippy::result_large_err)]
+    pub async fn fetch_index(&self, index: &IndexUrl) -> Result<FlatIndexEntries, FlatIndexError> {
+        let entries = match index {
+            IndexUrl::Path(url) => {
+                let path = url
+                    .to_file_path()
+                    .map_err(|()| FlatIndexError::NonFileUrl(url.to_url()))?;
+                Self::read_from_directory(&path, index)
+                    .map_err(|err| FlatIndexError::FindLinksDirectory(path.clone(), err))?
+            }
+            IndexUrl::Pypi(url) | IndexUrl::Url(url) => self
+                .read_from_url(url, index)
+                .await
+                .map_err(|err| FlatIndexError::FindLinksUrl(url.to_url(), err))?,
+        };
+        if entries.is_empty() {
+            warn!("No packages found in flat index: {}", index);
+        } else {
+            debug!(
+                "Found {} package{} in flat index: {}",
+                entries.len(),
+                if entries.len() == 1 { "" } else { "s" },
+                index
+            );
+        }
+        Ok(entries)
+    }
+
     /// Read a flat remote index from a `--find-links` URL.
     async fn read_from_url(
         &self,
@@ -156,10 +160,13 @@ impl<'a> FlatIndexClient<'a> {
             format!("{}.msgpack", cache_digest(&url.to_string())),
         );
         let cache_control = match self.client.connectivity() {
-            Connectivity::Online => CacheControl::from(
+            Connectivity::Online => CacheControl::from_freshness(
                 self.cache
                     .freshness(&cache_entry, None)
                     .map_err(ErrorKind::Io)?,
+                self.client
+                    .index_urls()
+                    .simple_api_cache_control_for(flat_index),
             ),
             Connectivity::Offline => CacheControl::AllowStale,
         };
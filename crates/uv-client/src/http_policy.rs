@@ -0,0 +1,287 @@
+//! Retry and timeout settings for HTTP requests.
+
+use std::time::Duration;
+use std::{env, iter};
+
+use reqwest::{Response, StatusCode};
+use reqwest_retry::policies::ExponentialBackoff;
+use reqwest_retry::{Retryable, RetryableStrategy};
+use tracing::debug;
+
+use uv_distribution_types::IndexHttpPolicy;
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
+
+use crate::base_client::{is_extended_transient_error, UvRetryableStrategy, DEFAULT_RETRIES};
+
+/// The default minimum delay between retries, matching [`ExponentialBackoff`].
+const DEFAULT_RETRY_BACKOFF_MIN: Duration = Duration::from_secs(1);
+
+/// The default maximum delay between retries, matching [`ExponentialBackoff`].
+const DEFAULT_RETRY_BACKOFF_MAX: Duration = Duration::from_secs(30 * 60);
+
+/// The retry and timeout settings for HTTP requests, either for all hosts or for the host of an
+/// index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct HttpPolicy {
+    /// The number of times to retry a failed request.
+    pub(crate) retries: u32,
+    /// The minimum delay between retries.
+    pub(crate) retry_backoff_min: Option<Duration>,
+    /// The maximum delay between retries.
+    pub(crate) retry_backoff_max: Option<Duration>,
+    /// The timeout for establishing a connection, if any.
+    pub(crate) connect_timeout: Option<Duration>,
+    /// The timeout for reading from a connection.
+    pub(crate) read_timeout: Duration,
+    /// The status codes to retry, replacing the default set of retryable status codes.
+    pub(crate) retry_status_codes: Option<Vec<StatusCode>>,
+}
+
+impl HttpPolicy {
+    /// Resolve the global settings from the environment, falling back to the given settings
+    /// (e.g., from `uv.toml`) for any values that aren't set in the environment.
+    pub(crate) fn from_env(settings: &IndexHttpPolicy, default_timeout: Duration) -> Self {
+        // Timeout options, matching https://doc.rust-lang.org/nightly/cargo/reference/config.html#httptimeout
+        // `UV_REQUEST_TIMEOUT` is provided for backwards compatibility with v0.1.6
+        let read_timeout = env::var(EnvVars::UV_HTTP_TIMEOUT)
+            .or_else(|_| env::var(EnvVars::UV_REQUEST_TIMEOUT))
+            .or_else(|_| env::var(EnvVars::HTTP_TIMEOUT))
+            .ok()
+            .and_then(|value| parse_seconds(EnvVars::UV_HTTP_TIMEOUT, &value))
+            .or(settings.read_timeout.map(Duration::from_secs))
+            .unwrap_or(default_timeout);
+
+        let retries = env::var(EnvVars::UV_HTTP_RETRIES)
+            .ok()
+            .and_then(|value| match value.parse::<u32>() {
+                Ok(retries) => Some(retries),
+                Err(_) => {
+                    warn_user_once!("Ignoring invalid value from environment for `UV_HTTP_RETRIES`. Expected an integer, got \"{value}\".");
+                    None
+                }
+            })
+            .or(settings.retries)
+            .unwrap_or(DEFAULT_RETRIES);
+
+        let retry_backoff_min = env::var(EnvVars::UV_HTTP_RETRY_BACKOFF_MIN)
+            .ok()
+            .and_then(|value| parse_seconds(EnvVars::UV_HTTP_RETRY_BACKOFF_MIN, &value))
+            .or(settings.retry_backoff_min.map(Duration::from_secs));
+        let retry_backoff_max = env::var(EnvVars::UV_HTTP_RETRY_BACKOFF_MAX)
+            .ok()
+            .and_then(|value| parse_seconds(EnvVars::UV_HTTP_RETRY_BACKOFF_MAX, &value))
+            .or(settings.retry_backoff_max.map(Duration::from_secs));
+        let connect_timeout = env::var(EnvVars::UV_HTTP_CONNECT_TIMEOUT)
+            .ok()
+            .and_then(|value| parse_seconds(EnvVars::UV_HTTP_CONNECT_TIMEOUT, &value))
+            .or(settings.connect_timeout.map(Duration::from_secs));
+
+        let retry_status_codes = env::var(EnvVars::UV_HTTP_RETRY_STATUS_CODES)
+            .ok()
+            .and_then(|value| {
+                value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|code| !code.is_empty())
+                    .map(|code| {
+                        code.parse::<u16>()
+                            .ok()
+                            .and_then(|code| StatusCode::from_u16(code).ok())
+                    })
+                    .collect::<Option<Vec<_>>>()
+                    .or_else(|| {
+                        warn_user_once!("Ignoring invalid value from environment for `UV_HTTP_RETRY_STATUS_CODES`. Expected a comma-separated list of HTTP status codes, got \"{value}\".");
+                        None
+                    })
+            })
+            .or_else(|| {
+                settings
+                    .retry_status_codes
+                    .as_deref()
+                    .map(|codes| parse_status_codes("http-retry-status-codes", codes))
+            });
+
+        Self {
+            retries,
+            retry_backoff_min,
+            retry_backoff_max,
+            connect_timeout,
+            read_timeout,
+            retry_status_codes,
+        }
+    }
+
+    /// Apply the settings of an index on top of these settings.
+    pub(crate) fn with_overrides(&self, overrides: &IndexHttpPolicy) -> Self {
+        let retry_status_codes = match &overrides.retry_status_codes {
+            Some(codes) => Some(parse_status_codes("retry-status-codes", codes)),
+            None => self.retry_status_codes.clone(),
+        };
+        Self {
+            retries: overrides.retries.unwrap_or(self.retries),
+            retry_backoff_min: overrides
+                .retry_backoff_min
+                .map(Duration::from_secs)
+                .or(self.retry_backoff_min),
+            retry_backoff_max: overrides
+                .retry_backoff_max
+                .map(Duration::from_secs)
+                .or(self.retry_backoff_max),
+            connect_timeout: overrides
+                .connect_timeout
+                .map(Duration::from_secs)
+                .or(self.connect_timeout),
+            read_timeout: overrides
+                .read_timeout
+                .map_or(self.read_timeout, Duration::from_secs),
+            retry_status_codes,
+        }
+    }
+
+    /// Create a [`RetryPolicy`] with the configured number of retries and backoff bounds.
+    pub(crate) fn retry_policy(&self) -> ExponentialBackoff {
+        let builder = ExponentialBackoff::builder();
+        let builder = if self.retry_backoff_min.is_some() || self.retry_backoff_max.is_some() {
+            let min = self.retry_backoff_min.unwrap_or(DEFAULT_RETRY_BACKOFF_MIN);
+            // `retry_bounds` requires the minimum to be less than or equal to the maximum.
+            let max = self
+                .retry_backoff_max
+                .unwrap_or(DEFAULT_RETRY_BACKOFF_MAX)
+                .max(min);
+            builder.retry_bounds(min, max)
+        } else {
+            builder
+        };
+        builder.build_with_max_retries(self.retries)
+    }
+
+    /// Create a [`RetryableStrategy`] that retries the configured status codes.
+    pub(crate) fn retry_strategy(&self) -> HttpPolicyRetryableStrategy {
+        HttpPolicyRetryableStrategy {
+            retry_status_codes: self.retry_status_codes.clone(),
+        }
+    }
+}
+
+/// Parse the HTTP status codes of a setting, warning on (and skipping) invalid codes.
+fn parse_status_codes(name: &str, codes: &[u16]) -> Vec<StatusCode> {
+    codes
+        .iter()
+        .filter_map(|&code| match StatusCode::from_u16(code) {
+            Ok(code) => Some(code),
+            Err(_) => {
+                warn_user_once!("Ignoring invalid HTTP status code in `{name}`: {code}");
+                None
+            }
+        })
+        .collect()
+}
+
+/// Parse a number of seconds from an environment variable, warning on invalid values.
+fn parse_seconds(name: &str, value: &str) -> Option<Duration> {
+    match value
+        .parse::<f64>()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+    {
+        Some(duration) => Some(duration),
+        None => {
+            warn_user_once!("Ignoring invalid value from environment for `{name}`. Expected a number of seconds, got \"{value}\".");
+            None
+        }
+    }
+}
+
+/// Extends [`UvRetryableStrategy`] to retry responses with a user-provided set of status codes,
+/// in lieu of the default set of retryable status codes.
+#[derive(Debug, Clone)]
+pub struct HttpPolicyRetryableStrategy {
+    retry_status_codes: Option<Vec<StatusCode>>,
+}
+
+impl HttpPolicyRetryableStrategy {
+    /// Return `true` if an error that surfaced after the retry middleware, e.g., while streaming
+    /// or deserializing a response body, is transient.
+    ///
+    /// Responses with a retryable status code are already retried by the middleware with this
+    /// strategy, so errors for a status code are never considered transient here, to avoid
+    /// retrying them twice.
+    pub fn is_transient_error(&self, err: &(dyn std::error::Error + 'static)) -> bool {
+        let has_status = iter::successors(Some(err), |&err| err.source()).any(|err| {
+            err.downcast_ref::<reqwest::Error>()
+                .is_some_and(|err| err.status().is_some())
+        });
+        !has_status && is_extended_transient_error(err)
+    }
+}
+
+impl RetryableStrategy for HttpPolicyRetryableStrategy {
+    fn handle(&self, res: &Result<Response, reqwest_middleware::Error>) -> Option<Retryable> {
+        match (res, &self.retry_status_codes) {
+            (Ok(response), Some(retry_status_codes)) => {
+                let status = response.status();
+                if retry_status_codes.contains(&status) {
+                    debug!(
+                        "Transient request failure for {} ({status}), retrying",
+                        response.url()
+                    );
+                    Some(Retryable::Transient)
+                } else if status.is_success() {
+                    None
+                } else {
+                    Some(Retryable::Fatal)
+                }
+            }
+            _ => UvRetryableStrategy.handle(res),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use reqwest::StatusCode;
+
+    use uv_distribution_types::IndexHttpPolicy;
+
+    use super::HttpPolicy;
+
+    #[test]
+    fn index_overrides() {
+        let global = HttpPolicy {
+            retries: 3,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: Duration::from_secs(30),
+            retry_status_codes: Some(vec![StatusCode::SERVICE_UNAVAILABLE]),
+        };
+
+        // Without overrides, the global settings apply.
+        assert_eq!(global.with_overrides(&IndexHttpPolicy::default()), global);
+
+        let policy = global.with_overrides(&IndexHttpPolicy {
+            retries: Some(10),
+            retry_backoff_min: Some(2),
+            connect_timeout: Some(5),
+            retry_status_codes: Some(vec![429, 502]),
+            ..IndexHttpPolicy::default()
+        });
+        assert_eq!(
+            policy,
+            HttpPolicy {
+                retries: 10,
+                retry_backoff_min: Some(Duration::from_secs(2)),
+                retry_backoff_max: None,
+                connect_timeout: Some(Duration::from_secs(5)),
+                read_timeout: Duration::from_secs(30),
+                retry_status_codes: Some(vec![
+                    StatusCode::TOO_MANY_REQUESTS,
+                    StatusCode::BAD_GATEWAY
+                ]),
+            }
+        );
+    }
+}
@@ -4,22 +4,27 @@ pub use base_client::{
 };
 pub use cached_client::{CacheControl, CachedClient, CachedClientError, DataWithCachePolicy};
 pub use error::{Error, ErrorKind, WrappedReqwestError};
-pub use flat_index::{FlatIndexClient, FlatIndexEntries, FlatIndexError};
+pub use flat_index::{FlatIndexClient, FlatIndexEntries, FlatIndexEntry, FlatIndexError};
+pub use http_policy::HttpPolicyRetryableStrategy;
 pub use linehaul::LineHaul;
+pub use proxy::ProxyError;
 pub use registry_client::{
-    Connectivity, RegistryClient, RegistryClientBuilder, SimpleMetadata, SimpleMetadatum,
-    VersionFiles,
+    Connectivity, MetadataFormat, RegistryClient, RegistryClientBuilder, SimpleMetadata,
+    SimpleMetadatum, VersionFiles,
 };
 pub use rkyvutil::{Deserializer, OwnedArchive, Serializer, Validator};
+pub use tls::{HostTlsError, HostTlsMaterial};
 
 mod base_client;
 mod cached_client;
 mod error;
 mod flat_index;
 mod html;
+mod http_policy;
 mod httpcache;
 mod linehaul;
 mod middleware;
+mod proxy;
 mod registry_client;
 mod remote_metadata;
 mod rkyvutil;
@@ -5,6 +5,10 @@ use reqwest::{Request, Response};
 use reqwest_middleware::{Middleware, Next};
 use url::Url;
 
+use uv_configuration::MissingCredentialHelper;
+
+use crate::proxy::ProxyError;
+
 /// A custom error type for the offline middleware.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct OfflineError {
@@ -45,3 +49,42 @@ impl Middleware for OfflineMiddleware {
         ))
     }
 }
+
+/// A middleware that always returns an error indicating that `--keyring-provider helper` was
+/// selected without a credential helper.
+pub(crate) struct MissingCredentialHelperMiddleware;
+
+#[async_trait::async_trait]
+impl Middleware for MissingCredentialHelperMiddleware {
+    async fn handle(
+        &self,
+        _req: Request,
+        _extensions: &mut Extensions,
+        _next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        Err(reqwest_middleware::Error::Middleware(
+            MissingCredentialHelper.into(),
+        ))
+    }
+}
+
+/// A middleware that always returns an error indicating that the proxy for the client is invalid.
+pub(crate) struct ProxyErrorMiddleware(ProxyError);
+
+impl ProxyErrorMiddleware {
+    pub(crate) fn new(err: ProxyError) -> Self {
+        Self(err)
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for ProxyErrorMiddleware {
+    async fn handle(
+        &self,
+        _req: Request,
+        _extensions: &mut Extensions,
+        _next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        Err(reqwest_middleware::Error::Middleware(self.0.clone().into()))
+    }
+}
@@ -0,0 +1,193 @@
+//! Proxy settings for HTTP requests.
+
+use std::env;
+use std::sync::Arc;
+
+use reqwest::{ClientBuilder, NoProxy, Proxy};
+
+use uv_configuration::ProxySettings;
+use uv_distribution_types::ProxyUrl;
+use uv_static::EnvVars;
+
+/// An error for a proxy that can't be used, e.g., because its host can't be resolved.
+#[derive(thiserror::Error, Debug, Clone)]
+pub enum ProxyError {
+    #[error("Failed to configure proxy `{0}`")]
+    Url(ProxyUrl, #[source] Arc<reqwest::Error>),
+    #[error("Failed to configure proxy from `{0}`")]
+    Env(&'static str, #[source] Arc<reqwest::Error>),
+}
+
+/// The proxies to configure on a client, resolved from the [`ProxySettings`].
+#[derive(Debug, Clone)]
+pub(crate) enum ClientProxy {
+    /// Respect the proxy environment variables, as `reqwest` does by default.
+    System,
+    /// Send requests through the given proxies, the first matching proxy taking precedence.
+    Proxies(Vec<Proxy>),
+    /// Connect directly, bypassing any proxy.
+    Direct,
+}
+
+impl ClientProxy {
+    /// Resolve the proxies for the given settings, where `None` bypasses any proxy.
+    pub(crate) fn new(settings: Option<&ProxySettings>) -> Result<Self, ProxyError> {
+        Self::from_settings(settings, |name| env::var(name).ok())
+    }
+
+    /// Resolve the proxies for the given settings, reading the proxy environment variables with
+    /// `read_var`.
+    fn from_settings(
+        settings: Option<&ProxySettings>,
+        read_var: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, ProxyError> {
+        let Some(ProxySettings { proxy, no_proxy }) = settings else {
+            return Ok(Self::Direct);
+        };
+
+        if let Some(proxy) = proxy {
+            let reqwest_proxy = Proxy::all(proxy.url().clone())
+                .map_err(|err| ProxyError::Url(proxy.clone(), Arc::new(err)))?;
+            return Ok(Self::Proxies(vec![
+                reqwest_proxy.no_proxy(NoProxy::from_string(&no_proxy.join(",")))
+            ]));
+        }
+
+        // Without any hosts to exclude, defer to `reqwest`.
+        if no_proxy.is_empty() {
+            return Ok(Self::System);
+        }
+
+        // Otherwise, configuring any proxy on the client disables the proxy environment variables
+        // in `reqwest`, so read them here, excluding the hosts from both `NO_PROXY` and the
+        // settings. Like `reqwest`, accept the lowercase variants, and prefer the scheme-specific
+        // proxies over `ALL_PROXY`.
+        let read_var = |name: &str| {
+            read_var(name)
+                .or_else(|| read_var(&name.to_ascii_lowercase()))
+                .filter(|value| !value.is_empty())
+        };
+        let no_proxy = read_var(EnvVars::NO_PROXY)
+            .into_iter()
+            .chain(no_proxy.iter().cloned())
+            .collect::<Vec<_>>()
+            .join(",");
+        let constructors: [(&'static str, fn(String) -> reqwest::Result<Proxy>); 3] = [
+            (EnvVars::HTTPS_PROXY, Proxy::https),
+            (EnvVars::HTTP_PROXY, Proxy::http),
+            (EnvVars::ALL_PROXY, Proxy::all),
+        ];
+        let mut proxies = Vec::new();
+        for (name, constructor) in constructors {
+            let Some(value) = read_var(name) else {
+                continue;
+            };
+            let proxy = constructor(value).map_err(|err| ProxyError::Env(name, Arc::new(err)))?;
+            proxies.push(proxy.no_proxy(NoProxy::from_string(&no_proxy)));
+        }
+        if proxies.is_empty() {
+            return Ok(Self::System);
+        }
+        Ok(Self::Proxies(proxies))
+    }
+
+    /// Configure the proxies on a client.
+    pub(crate) fn apply(&self, client_builder: ClientBuilder) -> ClientBuilder {
+        match self {
+            Self::System => client_builder,
+            Self::Proxies(proxies) => proxies
+                .iter()
+                .cloned()
+                .fold(client_builder, |builder, proxy| builder.proxy(proxy)),
+            Self::Direct => client_builder.no_proxy(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use futures::future;
+    use http_body_util::Full;
+    use hyper::body::Bytes;
+    use hyper::server::conn::http1;
+    use hyper::service::service_fn;
+    use hyper::{Request, Response};
+    use hyper_util::rt::TokioIo;
+    use reqwest::Client;
+    use tokio::net::TcpListener;
+
+    use uv_configuration::ProxySettings;
+    use uv_static::EnvVars;
+
+    use super::ClientProxy;
+
+    /// Start a server that responds to every request with the given body, returning its address.
+    async fn start_server(body: &'static str) -> Result<String> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        tokio::spawn(async move {
+            loop {
+                let (socket, _) = listener.accept().await.unwrap();
+                let svc = service_fn(move |_req: Request<hyper::body::Incoming>| {
+                    future::ok::<_, hyper::Error>(Response::new(Full::new(Bytes::from(body))))
+                });
+                tokio::task::spawn(async move {
+                    http1::Builder::new()
+                        .serve_connection(TokioIo::new(socket), svc)
+                        .await
+                        .expect("Server Started");
+                });
+            }
+        });
+        Ok(addr.to_string())
+    }
+
+    /// With `no-proxy` but without `proxy`, the proxies from the environment should be used for
+    /// all other hosts.
+    #[tokio::test]
+    async fn no_proxy_with_env_proxy() -> Result<()> {
+        let proxy_addr = start_server("proxied").await?;
+        let direct_addr = start_server("direct").await?;
+
+        let settings = ProxySettings {
+            proxy: None,
+            no_proxy: vec!["127.0.0.1".to_string()],
+        };
+        let proxy = ClientProxy::from_settings(Some(&settings), |name| {
+            (name == EnvVars::HTTP_PROXY).then(|| format!("http://{proxy_addr}"))
+        })?;
+        let client = proxy.apply(Client::builder()).build()?;
+
+        let response = client
+            .get(format!("http://{direct_addr}/simple/"))
+            .send()
+            .await?;
+        assert_eq!(response.text().await?, "direct");
+
+        let response = client
+            .get("http://files.internal.invalid/file.whl")
+            .send()
+            .await?;
+        assert_eq!(response.text().await?, "proxied");
+
+        Ok(())
+    }
+
+    /// An invalid proxy in the environment should be an error.
+    #[test]
+    fn invalid_env_proxy() {
+        let settings = ProxySettings {
+            proxy: None,
+            no_proxy: vec!["localhost".to_string()],
+        };
+        let err = ClientProxy::from_settings(Some(&settings), |name| {
+            (name == "https_proxy").then(|| "ftp://proxy.example.com".to_string())
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to configure proxy from `HTTPS_PROXY`"
+        );
+    }
+}
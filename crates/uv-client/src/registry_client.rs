@@ -1,7 +1,8 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Debug;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use async_http_range_reader::AsyncHttpRangeReader;
@@ -10,16 +11,17 @@ use http::HeaderMap;
 use itertools::Either;
 use reqwest::{Client, Response, StatusCode};
 use reqwest_middleware::ClientWithMiddleware;
-use tokio::sync::Semaphore;
+use tokio::sync::{OnceCell, Semaphore};
 use tracing::{info_span, instrument, trace, warn, Instrument};
 use url::Url;
 
 use uv_cache::{Cache, CacheBucket, CacheEntry, WheelCache};
 use uv_configuration::KeyringProviderType;
-use uv_configuration::{IndexStrategy, TrustedHost};
+use uv_configuration::{HostTls, IndexStrategy, ProxySettings, TrustedHost};
 use uv_distribution_filename::{DistFilename, SourceDistFilename, WheelFilename};
 use uv_distribution_types::{
-    BuiltDist, File, FileLocation, Index, IndexCapabilities, IndexUrl, IndexUrls, Name,
+    BuiltDist, File, FileLocation, IndexCapabilities, IndexFormat, IndexHttpPolicy, IndexUrl,
+    IndexUrls, Name,
 };
 use uv_metadata::{read_metadata_async_seek, read_metadata_async_stream};
 use uv_normalize::PackageName;
@@ -30,10 +32,11 @@ use uv_pypi_types::{ResolutionMetadata, SimpleJson};
 
 use crate::base_client::{BaseClientBuilder, ExtraMiddleware};
 use crate::cached_client::CacheControl;
+use crate::flat_index::{FlatIndexClient, FlatIndexEntries, FlatIndexEntry};
 use crate::html::SimpleHtml;
 use crate::remote_metadata::wheel_metadata_from_remote_zip;
 use crate::rkyvutil::OwnedArchive;
-use crate::{BaseClient, CachedClient, CachedClientError, Error, ErrorKind};
+use crate::{BaseClient, CachedClient, CachedClientError, Error, ErrorKind, HostTlsMaterial};
 
 /// A builder for an [`RegistryClient`].
 #[derive(Debug, Clone)]
@@ -58,6 +61,21 @@ impl RegistryClientBuilder<'_> {
 impl<'a> RegistryClientBuilder<'a> {
     #[must_use]
     pub fn index_urls(mut self, index_urls: IndexUrls) -> Self {
+        self.base_client_builder = self
+            .base_client_builder
+            .indexes(uv_auth::Indexes::from(&index_urls))
+            .http_policies(
+                index_urls
+                    .http_policies()
+                    .map(|(url, policy)| (url.clone(), policy.clone()))
+                    .collect(),
+            )
+            .index_proxies(
+                index_urls
+                    .proxies()
+                    .map(|(url, proxy)| (url.clone(), proxy.cloned()))
+                    .collect(),
+            );
         self.index_urls = index_urls;
         self
     }
@@ -74,6 +92,14 @@ impl<'a> RegistryClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn credential_helper(mut self, credential_helper: Option<PathBuf>) -> Self {
+        self.base_client_builder = self
+            .base_client_builder
+            .credential_helper(credential_helper);
+        self
+    }
+
     #[must_use]
     pub fn allow_insecure_host(mut self, allow_insecure_host: Vec<TrustedHost>) -> Self {
         self.base_client_builder = self
@@ -82,6 +108,24 @@ impl<'a> RegistryClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn host_tls(mut self, host_tls: Vec<HostTlsMaterial>) -> Self {
+        self.base_client_builder = self.base_client_builder.host_tls(host_tls);
+        self
+    }
+
+    #[must_use]
+    pub fn http_policy(mut self, http_policy: IndexHttpPolicy) -> Self {
+        self.base_client_builder = self.base_client_builder.http_policy(http_policy);
+        self
+    }
+
+    #[must_use]
+    pub fn proxy(mut self, proxy: ProxySettings) -> Self {
+        self.base_client_builder = self.base_client_builder.proxy(proxy);
+        self
+    }
+
     #[must_use]
     pub fn connectivity(mut self, connectivity: Connectivity) -> Self {
         self.base_client_builder = self.base_client_builder.connectivity(connectivity);
@@ -149,6 +193,7 @@ impl<'a> RegistryClientBuilder<'a> {
             connectivity,
             client,
             timeout,
+            flat_indexes: FlatIndexCache::default(),
         }
     }
 
@@ -170,6 +215,7 @@ impl<'a> RegistryClientBuilder<'a> {
             connectivity,
             client,
             timeout,
+            flat_indexes: FlatIndexCache::default(),
         }
     }
 }
@@ -202,9 +248,16 @@ pub struct RegistryClient {
     connectivity: Connectivity,
     /// Configured client timeout, in seconds.
     timeout: Duration,
+    /// The flat indexes fetched by this client.
+    flat_indexes: FlatIndexCache,
 }
 
 impl RegistryClient {
+    /// Return the [`IndexUrls`] used by this client.
+    pub fn index_urls(&self) -> &IndexUrls {
+        &self.index_urls
+    }
+
     /// Return the [`CachedClient`] used by this client.
     pub fn cached_client(&self) -> &CachedClient {
         &self.client
@@ -220,6 +273,21 @@ impl RegistryClient {
         self.client.uncached().disable_ssl(url)
     }
 
+    /// Returns the custom TLS settings for the given URL, if any.
+    pub fn host_tls(&self, url: &Url) -> Option<&HostTls> {
+        self.client.uncached().host_tls(url)
+    }
+
+    /// Return the [`KeyringProviderType`] used by this client.
+    pub fn keyring(&self) -> KeyringProviderType {
+        self.client.uncached().keyring()
+    }
+
+    /// Return the credential helper used by this client, if any.
+    pub fn credential_helper(&self) -> Option<&Path> {
+        self.client.uncached().credential_helper()
+    }
+
     /// Return the [`Connectivity`] mode used by this client.
     pub fn connectivity(&self) -> Connectivity {
         self.connectivity
@@ -230,23 +298,30 @@ impl RegistryClient {
         self.timeout
     }
 
-    /// Fetch a package from the `PyPI` simple API.
+    /// Fetch a package from the `PyPI` simple API, or from a flat index.
     ///
     /// "simple" here refers to [PEP 503 – Simple Repository API](https://peps.python.org/pep-0503/)
     /// and [PEP 691 – JSON-based Simple API for Python Package Indexes](https://peps.python.org/pep-0691/),
     /// which the pypi json api approximately implements.
+    ///
+    /// Flat indexes (i.e., `format = "flat"`) are queried in the same order as any other index,
+    /// returning the matching distributions from the flat list.
     #[instrument("simple_api", skip_all, fields(package = % package_name))]
-    pub async fn simple<'index>(
+    pub async fn package_metadata<'index>(
         &'index self,
         package_name: &PackageName,
         index: Option<&'index IndexUrl>,
         capabilities: &IndexCapabilities,
         download_concurrency: &Semaphore,
-    ) -> Result<Vec<(&'index IndexUrl, OwnedArchive<SimpleMetadata>)>, Error> {
+    ) -> Result<Vec<(&'index IndexUrl, MetadataFormat)>, Error> {
         let indexes = if let Some(index) = index {
-            Either::Left(std::iter::once(index))
+            Either::Left(std::iter::once((index, self.index_format(index))))
         } else {
-            Either::Right(self.index_urls.indexes().map(Index::url))
+            Either::Right(
+                self.index_urls
+                    .indexes()
+                    .map(|index| (index.url(), index.format)),
+            )
         };
 
         let mut it = indexes.peekable();
@@ -259,10 +334,10 @@ impl RegistryClient {
         match self.index_strategy {
             // If we're searching for the first index that contains the package, fetch serially.
             IndexStrategy::FirstIndex => {
-                for index in it {
+                for (index, format) in it {
                     let _permit = download_concurrency.acquire().await;
                     if let Some(metadata) = self
-                        .simple_single_index(package_name, index, capabilities)
+                        .package_metadata_single_index(package_name, index, format, capabilities)
                         .await?
                     {
                         results.push((index, metadata));
@@ -274,10 +349,15 @@ impl RegistryClient {
             // Otherwise, fetch concurrently.
             IndexStrategy::UnsafeBestMatch | IndexStrategy::UnsafeFirstMatch => {
                 results = futures::stream::iter(it)
-                    .map(|index| async move {
+                    .map(|(index, format)| async move {
                         let _permit = download_concurrency.acquire().await;
                         let metadata = self
-                            .simple_single_index(package_name, index, capabilities)
+                            .package_metadata_single_index(
+                                package_name,
+                                index,
+                                format,
+                                capabilities,
+                            )
                             .await?;
                         Ok((index, metadata))
                     })
@@ -306,6 +386,69 @@ impl RegistryClient {
         Ok(results)
     }
 
+    /// Return the [`IndexFormat`] of the given index, defaulting to the Simple API for indexes that
+    /// aren't defined in the configuration.
+    fn index_format(&self, index: &IndexUrl) -> IndexFormat {
+        self.index_urls
+            .defined_indexes()
+            .find(|defined| defined.url() == index)
+            .map_or(IndexFormat::Simple, |defined| defined.format)
+    }
+
+    /// Fetch the metadata for a given package from a single index, in the format of the index.
+    ///
+    /// Returns `Ok(None)` if the package is not found in the index.
+    async fn package_metadata_single_index(
+        &self,
+        package_name: &PackageName,
+        index: &IndexUrl,
+        format: IndexFormat,
+        capabilities: &IndexCapabilities,
+    ) -> Result<Option<MetadataFormat>, Error> {
+        match format {
+            IndexFormat::Simple => Ok(self
+                .simple_single_index(package_name, index, capabilities)
+                .await?
+                .map(MetadataFormat::Simple)),
+            IndexFormat::Flat => Ok(self
+                .flat_single_index(package_name, index)
+                .await?
+                .map(MetadataFormat::Flat)),
+        }
+    }
+
+    /// Fetch the distributions for a given package from a single flat index.
+    ///
+    /// Returns `Ok(None)` if the package is not found in the index.
+    async fn flat_single_index(
+        &self,
+        package_name: &PackageName,
+        index: &IndexUrl,
+    ) -> Result<Option<Vec<FlatIndexEntry>>, Error> {
+        // Flat indexes aren't partitioned by package, so fetch each index once, rather than once
+        // per package.
+        let cell = self.flat_indexes.get(index);
+        let entries = cell
+            .get_or_try_init(|| async {
+                FlatIndexClient::new(self, &self.cache)
+                    .fetch_index(index)
+                    .await
+                    .map_err(ErrorKind::Flat)
+            })
+            .await?;
+        let entries = entries
+            .entries
+            .iter()
+            .filter(|entry| entry.filename.name() == package_name)
+            .cloned()
+            .collect::<Vec<_>>();
+        if entries.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(entries))
+        }
+    }
+
     /// Fetch the [`SimpleMetadata`] from a single index for a given package.
     ///
     /// The index can either be a PEP 503-compatible remote repository, or a local directory laid
@@ -336,10 +479,11 @@ impl RegistryClient {
             format!("{package_name}.rkyv"),
         );
         let cache_control = match self.connectivity {
-            Connectivity::Online => CacheControl::from(
+            Connectivity::Online => CacheControl::from_freshness(
                 self.cache
                     .freshness(&cache_entry, Some(package_name))
                     .map_err(ErrorKind::Io)?,
+                self.index_urls.simple_api_cache_control_for(index),
             ),
             Connectivity::Offline => CacheControl::AllowStale,
         };
@@ -621,10 +765,11 @@ impl RegistryClient {
                 format!("{}.msgpack", filename.stem()),
             );
             let cache_control = match self.connectivity {
-                Connectivity::Online => CacheControl::from(
+                Connectivity::Online => CacheControl::from_freshness(
                     self.cache
                         .freshness(&cache_entry, Some(&filename.name))
                         .map_err(ErrorKind::Io)?,
+                    self.index_urls.artifact_cache_control_for(index),
                 ),
                 Connectivity::Offline => CacheControl::AllowStale,
             };
@@ -691,10 +836,11 @@ impl RegistryClient {
             format!("{}.msgpack", filename.stem()),
         );
         let cache_control = match self.connectivity {
-            Connectivity::Online => CacheControl::from(
+            Connectivity::Online => CacheControl::from_freshness(
                 self.cache
                     .freshness(&cache_entry, Some(&filename.name))
                     .map_err(ErrorKind::Io)?,
+                index.and_then(|index| self.index_urls.artifact_cache_control_for(index)),
             ),
             Connectivity::Offline => CacheControl::AllowStale,
         };
@@ -757,7 +903,7 @@ impl RegistryClient {
                 .get_serde_with_retry(
                     req,
                     &cache_entry,
-                    cache_control,
+                    cache_control.clone(),
                     read_metadata_range_request,
                 )
                 .await
@@ -875,6 +1021,27 @@ pub struct VersionSourceDist {
     pub file: File,
 }
 
+/// The entries of each flat index, fetched at most once.
+#[derive(Debug, Default, Clone)]
+struct FlatIndexCache(Arc<Mutex<HashMap<IndexUrl, Arc<OnceCell<FlatIndexEntries>>>>>);
+
+impl FlatIndexCache {
+    /// Return the cell for the given index, to be initialized on first use.
+    fn get(&self, index: &IndexUrl) -> Arc<OnceCell<FlatIndexEntries>> {
+        let mut indexes = self.0.lock().unwrap();
+        indexes.entry(index.clone()).or_default().clone()
+    }
+}
+
+/// The metadata for a package from a single index, in the format of the index.
+#[derive(Debug)]
+pub enum MetadataFormat {
+    /// The metadata adheres to the Simple Repository API format.
+    Simple(OwnedArchive<SimpleMetadata>),
+    /// The metadata consists of a list of distributions from a flat index.
+    Flat(Vec<FlatIndexEntry>),
+}
+
 #[derive(Default, Debug, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
 #[rkyv(derive(Debug))]
 pub struct SimpleMetadata(Vec<SimpleMetadatum>);
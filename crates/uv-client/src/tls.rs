@@ -1,6 +1,9 @@
-use reqwest::Identity;
+use reqwest::{Certificate, Identity};
 use std::ffi::OsStr;
 use std::io::Read;
+use std::path::Path;
+
+use uv_configuration::HostTls;
 
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum CertificateError {
@@ -8,6 +11,8 @@ pub(crate) enum CertificateError {
     Io(#[from] std::io::Error),
     #[error(transparent)]
     Reqwest(reqwest::Error),
+    #[error("`client-key` was provided without a `client-cert`")]
+    MissingClientCert,
 }
 
 /// Return the `Identity` from the provided file.
@@ -19,3 +24,74 @@ pub(crate) fn read_identity(ssl_client_cert: &OsStr) -> Result<Identity, Certifi
         CertificateError::Reqwest(tls_err)
     })
 }
+
+/// Return the `Identity` from the provided certificate file and, if stored separately, the
+/// provided private key file.
+fn read_identity_with_key(cert: &Path, key: Option<&Path>) -> Result<Identity, CertificateError> {
+    let Some(key) = key else {
+        return read_identity(cert.as_os_str());
+    };
+    let mut buf = fs_err::read(cert)?;
+    buf.push(b'\n');
+    buf.extend(fs_err::read(key)?);
+    Identity::from_pem(&buf).map_err(CertificateError::Reqwest)
+}
+
+/// Return the certificates from the provided PEM bundle.
+fn read_certificates(path: &Path) -> Result<Vec<Certificate>, CertificateError> {
+    let buf = fs_err::read(path)?;
+    Certificate::from_pem_bundle(&buf).map_err(CertificateError::Reqwest)
+}
+
+/// Return the root certificates and client certificate from the paths in the [`HostTls`]
+/// settings.
+fn read_host_tls(
+    host_tls: &HostTls,
+) -> Result<(Option<Vec<Certificate>>, Option<Identity>), CertificateError> {
+    let roots = host_tls
+        .ca_cert
+        .as_deref()
+        .map(read_certificates)
+        .transpose()?;
+    let identity = match (&host_tls.client_cert, &host_tls.client_key) {
+        (Some(cert), key) => Some(read_identity_with_key(cert, key.as_deref())?),
+        (None, Some(_)) => return Err(CertificateError::MissingClientCert),
+        (None, None) => None,
+    };
+    Ok((roots, identity))
+}
+
+/// The custom TLS settings for a host, along with the TLS material read from its paths.
+#[derive(Debug, Clone)]
+pub struct HostTlsMaterial {
+    /// The settings from which the material was read.
+    pub(crate) host_tls: HostTls,
+    /// The root certificates to trust for the host, in place of the default roots.
+    pub(crate) roots: Option<Vec<Certificate>>,
+    /// The client certificate to present to the host.
+    pub(crate) identity: Option<Identity>,
+}
+
+impl HostTlsMaterial {
+    /// Read the TLS material for the given [`HostTls`] settings.
+    pub fn read(host_tls: HostTls) -> Result<Self, HostTlsError> {
+        let (roots, identity) = read_host_tls(&host_tls).map_err(|err| HostTlsError {
+            host: host_tls.host.to_string(),
+            err,
+        })?;
+        Ok(Self {
+            host_tls,
+            roots,
+            identity,
+        })
+    }
+}
+
+/// An error reading the TLS material for a host.
+#[derive(thiserror::Error, Debug)]
+#[error("Invalid TLS settings for `{host}`")]
+pub struct HostTlsError {
+    host: String,
+    #[source]
+    err: CertificateError,
+}
@@ -0,0 +1,118 @@
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use anyhow::Result;
+use futures::future;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::header::CACHE_CONTROL;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+use url::Url;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_client::{CacheControl, RegistryClient, RegistryClientBuilder};
+use uv_distribution_types::CacheControlHeader;
+
+/// Start a server that responds to every request with `Cache-Control: no-cache`, returning the
+/// URL of the server and a counter of the requests it has received.
+async fn start_no_cache_server() -> Result<(Url, Arc<AtomicUsize>)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let requests = Arc::new(AtomicUsize::new(0));
+
+    let counter = requests.clone();
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            let counter = counter.clone();
+            let svc = service_fn(move |_req: Request<hyper::body::Incoming>| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let response = Response::builder()
+                    .header(CACHE_CONTROL, "no-cache")
+                    .body(Full::new(Bytes::from("hello")))
+                    .unwrap();
+                future::ok::<_, hyper::Error>(response)
+            });
+            tokio::task::spawn(async move {
+                http1::Builder::new()
+                    .serve_connection(TokioIo::new(socket), svc)
+                    .await
+                    .expect("Server Started");
+            });
+        }
+    });
+
+    Ok((Url::from_str(&format!("http://{addr}/hello"))?, requests))
+}
+
+/// Send a cached request to the given URL, returning the response body.
+async fn get(
+    client: &RegistryClient,
+    cache: &Cache,
+    url: &Url,
+    cache_control: CacheControl,
+) -> Result<String> {
+    let cache_entry = cache.entry(CacheBucket::Simple, "cache-control", "hello.msgpack");
+    let req = client.uncached_client(url).get(url.clone()).build()?;
+    let body = client
+        .cached_client()
+        .get_serde(
+            req,
+            &cache_entry,
+            cache_control,
+            |response: reqwest::Response| async move { response.text().await },
+        )
+        .await?;
+    Ok(body)
+}
+
+/// Without an override, a `no-cache` response should be revalidated on every request.
+#[tokio::test]
+async fn cache_control_no_override() -> Result<()> {
+    let (url, requests) = start_no_cache_server().await?;
+    let cache = Cache::temp()?.init()?;
+    let client = RegistryClientBuilder::new(cache.clone()).build();
+
+    assert_eq!(
+        get(&client, &cache, &url, CacheControl::None).await?,
+        "hello"
+    );
+    assert_eq!(
+        get(&client, &cache, &url, CacheControl::None).await?,
+        "hello"
+    );
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
+
+/// With an override, the `no-cache` response should be replaced with the custom `Cache-Control`
+/// header, such that the cached response is reused.
+#[tokio::test]
+async fn cache_control_override() -> Result<()> {
+    let (url, requests) = start_no_cache_server().await?;
+    let cache = Cache::temp()?.init()?;
+    let client = RegistryClientBuilder::new(cache.clone()).build();
+
+    let cache_control = CacheControl::Override(CacheControlHeader::from_str("max-age=600")?);
+    assert_eq!(
+        get(&client, &cache, &url, cache_control.clone()).await?,
+        "hello"
+    );
+    assert_eq!(get(&client, &cache, &url, cache_control).await?, "hello");
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+    // A refresh should still revalidate the cached response.
+    assert_eq!(
+        get(&client, &cache, &url, CacheControl::MustRevalidate).await?,
+        "hello"
+    );
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
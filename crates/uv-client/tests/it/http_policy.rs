@@ -0,0 +1,234 @@
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use anyhow::Result;
+use futures::future;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+use url::Url;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_client::{CacheControl, RegistryClient, RegistryClientBuilder};
+use uv_distribution_types::{Index, IndexHttpPolicy, IndexUrl, IndexUrls};
+
+/// Start a server that responds with `429 Too Many Requests` to the first `failures` requests, and
+/// with `200 OK` afterwards, returning the URL of the server and a counter of the requests it has
+/// received.
+async fn start_flaky_server(failures: usize) -> Result<(Url, Arc<AtomicUsize>)> {
+    start_flaky_server_with_status(failures, StatusCode::TOO_MANY_REQUESTS).await
+}
+
+/// Start a server like [`start_flaky_server`], which responds with the given status code to the
+/// first `failures` requests.
+async fn start_flaky_server_with_status(
+    failures: usize,
+    failure_status: StatusCode,
+) -> Result<(Url, Arc<AtomicUsize>)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let requests = Arc::new(AtomicUsize::new(0));
+
+    let counter = requests.clone();
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            let counter = counter.clone();
+            let svc = service_fn(move |_req: Request<hyper::body::Incoming>| {
+                let status = if counter.fetch_add(1, Ordering::SeqCst) < failures {
+                    failure_status
+                } else {
+                    StatusCode::OK
+                };
+                let response = Response::builder()
+                    .status(status)
+                    .body(Full::new(Bytes::from("hello")))
+                    .unwrap();
+                future::ok::<_, hyper::Error>(response)
+            });
+            tokio::task::spawn(async move {
+                http1::Builder::new()
+                    .serve_connection(TokioIo::new(socket), svc)
+                    .await
+                    .expect("Server Started");
+            });
+        }
+    });
+
+    Ok((Url::from_str(&format!("http://{addr}/simple"))?, requests))
+}
+
+/// Create an index for the given URL with the given retry and timeout settings.
+fn index(url: &Url, http: IndexHttpPolicy) -> Result<IndexUrls> {
+    let mut index = Index::from_index_url(IndexUrl::from_str(url.as_str())?);
+    index.http = Some(http);
+    Ok(IndexUrls::from_indexes(vec![index]))
+}
+
+/// The per-index retry count should apply to requests to the host of the index.
+#[tokio::test]
+async fn index_retries() -> Result<()> {
+    let (url, requests) = start_flaky_server(4).await?;
+    let cache = Cache::temp()?.init()?;
+    let index_urls = index(
+        &url,
+        IndexHttpPolicy {
+            retries: Some(5),
+            retry_backoff_min: Some(0),
+            retry_backoff_max: Some(0),
+            ..IndexHttpPolicy::default()
+        },
+    )?;
+    let client = RegistryClientBuilder::new(cache)
+        .index_urls(index_urls)
+        .build();
+
+    let response = client.uncached_client(&url).get(url.clone()).send().await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(requests.load(Ordering::SeqCst), 5);
+
+    Ok(())
+}
+
+/// With `retry-status-codes`, status codes outside the list should not be retried.
+#[tokio::test]
+async fn index_retry_status_codes() -> Result<()> {
+    let (url, requests) = start_flaky_server(1).await?;
+    let cache = Cache::temp()?.init()?;
+    let index_urls = index(
+        &url,
+        IndexHttpPolicy {
+            retry_backoff_min: Some(0),
+            retry_backoff_max: Some(0),
+            retry_status_codes: Some(vec![503]),
+            ..IndexHttpPolicy::default()
+        },
+    )?;
+    let client = RegistryClientBuilder::new(cache)
+        .index_urls(index_urls)
+        .build();
+
+    let response = client.uncached_client(&url).get(url.clone()).send().await?;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
+
+/// The global retry settings should apply to requests to hosts without index settings.
+#[tokio::test]
+async fn global_retries() -> Result<()> {
+    let (url, requests) = start_flaky_server(4).await?;
+    let cache = Cache::temp()?.init()?;
+    let client = RegistryClientBuilder::new(cache)
+        .http_policy(IndexHttpPolicy {
+            retries: Some(5),
+            retry_backoff_min: Some(0),
+            retry_backoff_max: Some(0),
+            ..IndexHttpPolicy::default()
+        })
+        .build();
+
+    let response = client.uncached_client(&url).get(url.clone()).send().await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(requests.load(Ordering::SeqCst), 5);
+
+    Ok(())
+}
+
+/// The index settings should take precedence over the global settings.
+#[tokio::test]
+async fn index_retries_override_global() -> Result<()> {
+    let (url, requests) = start_flaky_server(2).await?;
+    let cache = Cache::temp()?.init()?;
+    let index_urls = index(
+        &url,
+        IndexHttpPolicy {
+            retries: Some(0),
+            ..IndexHttpPolicy::default()
+        },
+    )?;
+    let client = RegistryClientBuilder::new(cache)
+        .http_policy(IndexHttpPolicy {
+            retries: Some(5),
+            retry_backoff_min: Some(0),
+            retry_backoff_max: Some(0),
+            ..IndexHttpPolicy::default()
+        })
+        .index_urls(index_urls)
+        .build();
+
+    let response = client.uncached_client(&url).get(url.clone()).send().await?;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
+
+/// Send a cached request to the given URL with retries, returning the response body.
+async fn get_cached(client: &RegistryClient, cache: &Cache, url: &Url) -> Result<String> {
+    let cache_entry = cache.entry(CacheBucket::Simple, "http-policy", "flaky.msgpack");
+    let req = client.uncached_client(url).get(url.clone()).build()?;
+    let body = client
+        .cached_client()
+        .get_serde_with_retry(
+            req,
+            &cache_entry,
+            CacheControl::None,
+            |response: reqwest::Response| async move { response.text().await },
+        )
+        .await?;
+    Ok(body)
+}
+
+/// The per-index retry settings should apply to cached requests, without retrying a retryable
+/// status code twice.
+#[tokio::test]
+async fn cached_index_retry_status_codes() -> Result<()> {
+    let status = StatusCode::from_u16(520)?;
+
+    // A configured status code is retried.
+    let (url, requests) = start_flaky_server_with_status(2, status).await?;
+    let cache = Cache::temp()?.init()?;
+    let index_urls = index(
+        &url,
+        IndexHttpPolicy {
+            retries: Some(3),
+            retry_backoff_min: Some(0),
+            retry_backoff_max: Some(0),
+            retry_status_codes: Some(vec![520]),
+            ..IndexHttpPolicy::default()
+        },
+    )?;
+    let client = RegistryClientBuilder::new(cache.clone())
+        .index_urls(index_urls)
+        .build();
+    assert_eq!(get_cached(&client, &cache, &url).await?, "hello");
+    assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+    // A status code outside the configured list is not.
+    let (url, requests) = start_flaky_server_with_status(2, status).await?;
+    let cache = Cache::temp()?.init()?;
+    let index_urls = index(
+        &url,
+        IndexHttpPolicy {
+            retries: Some(3),
+            retry_backoff_min: Some(0),
+            retry_backoff_max: Some(0),
+            retry_status_codes: Some(vec![503]),
+            ..IndexHttpPolicy::default()
+        },
+    )?;
+    let client = RegistryClientBuilder::new(cache.clone())
+        .index_urls(index_urls)
+        .build();
+    assert!(get_cached(&client, &cache, &url).await.is_err());
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
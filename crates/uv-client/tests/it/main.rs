@@ -1,2 +1,5 @@
+mod cache_control;
+mod http_policy;
+mod proxy;
 mod remote_metadata;
 mod user_agent_version;
@@ -0,0 +1,200 @@
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use futures::future;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::header::PROXY_AUTHORIZATION;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+use url::Url;
+
+use uv_cache::Cache;
+use uv_client::{ProxyError, RegistryClientBuilder};
+use uv_configuration::ProxySettings;
+use uv_distribution_types::{Index, IndexUrl, IndexUrls, ProxyUrl};
+
+/// A request received by the test server: the request target and the `Proxy-Authorization`
+/// header, if any.
+type Received = Arc<Mutex<Vec<(String, Option<String>)>>>;
+
+/// Start a server that responds to every request with the given body, returning the address of
+/// the server and the requests it has received.
+///
+/// Used both as an HTTP proxy (which receives absolute-form request targets) and as an index.
+async fn start_server(body: &'static str) -> Result<(String, Received)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let received = Received::default();
+
+    let requests = received.clone();
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            let requests = requests.clone();
+            let svc = service_fn(move |req: Request<hyper::body::Incoming>| {
+                let authorization = req
+                    .headers()
+                    .get(PROXY_AUTHORIZATION)
+                    .map(|value| value.to_str().unwrap().to_string());
+                requests
+                    .lock()
+                    .unwrap()
+                    .push((req.uri().to_string(), authorization));
+                future::ok::<_, hyper::Error>(Response::new(Full::new(Bytes::from(body))))
+            });
+            tokio::task::spawn(async move {
+                http1::Builder::new()
+                    .serve_connection(TokioIo::new(socket), svc)
+                    .await
+                    .expect("Server Started");
+            });
+        }
+    });
+
+    Ok((addr.to_string(), received))
+}
+
+/// Create an index for the given URL, with the given proxy settings.
+fn index(url: &Url, proxy: Option<ProxyUrl>, no_proxy: bool) -> Result<IndexUrls> {
+    let mut index = Index::from_index_url(IndexUrl::from_str(url.as_str())?);
+    index.proxy = proxy;
+    index.no_proxy = no_proxy;
+    Ok(IndexUrls::from_indexes(vec![index]))
+}
+
+/// Requests to an index with a `proxy` should be sent through the proxy, with the credentials
+/// from the proxy URL.
+#[tokio::test]
+async fn index_proxy() -> Result<()> {
+    let (proxy_addr, proxied) = start_server("proxied").await?;
+    let cache = Cache::temp()?.init()?;
+
+    // The index host doesn't exist, so the request can only succeed through the proxy.
+    let url = Url::parse("http://pypi.internal.invalid/simple/")?;
+    let proxy = ProxyUrl::from_str(&format!("http://user:secret@{proxy_addr}"))?;
+    let client = RegistryClientBuilder::new(cache)
+        .index_urls(index(&url, Some(proxy), false)?)
+        .build();
+
+    let response = client.uncached_client(&url).get(url.clone()).send().await?;
+    assert_eq!(response.text().await?, "proxied");
+    assert_eq!(
+        *proxied.lock().unwrap(),
+        vec![(
+            "http://pypi.internal.invalid/simple/".to_string(),
+            // `user:secret`, base64-encoded.
+            Some("Basic dXNlcjpzZWNyZXQ=".to_string())
+        )]
+    );
+
+    Ok(())
+}
+
+/// Index proxy settings are matched by host: files the index serves from its own host use the
+/// index's proxy, while files served from a different host (e.g., a CDN) use the global settings.
+#[tokio::test]
+async fn index_proxy_other_host() -> Result<()> {
+    let (proxy_addr, proxied) = start_server("proxied").await?;
+    let (cdn_addr, direct) = start_server("direct").await?;
+    let cache = Cache::temp()?.init()?;
+
+    let url = Url::parse("http://pypi.internal.invalid/simple/")?;
+    let proxy = ProxyUrl::from_str(&format!("http://{proxy_addr}"))?;
+    let client = RegistryClientBuilder::new(cache)
+        .index_urls(index(&url, Some(proxy), false)?)
+        .build();
+
+    let file = Url::parse("http://pypi.internal.invalid/packages/file.whl")?;
+    let response = client
+        .uncached_client(&file)
+        .get(file.clone())
+        .send()
+        .await?;
+    assert_eq!(response.text().await?, "proxied");
+    assert_eq!(proxied.lock().unwrap().len(), 1);
+
+    // There's no global proxy, so a file on another host is downloaded directly.
+    let cdn = Url::parse(&format!("http://{cdn_addr}/packages/file.whl"))?;
+    let response = client.uncached_client(&cdn).get(cdn.clone()).send().await?;
+    assert_eq!(response.text().await?, "direct");
+    assert_eq!(direct.lock().unwrap().len(), 1);
+    assert_eq!(proxied.lock().unwrap().len(), 1);
+
+    Ok(())
+}
+
+/// Requests to an index with `no-proxy` should bypass the global proxy, while other requests
+/// should use it.
+#[tokio::test]
+async fn index_no_proxy() -> Result<()> {
+    let (proxy_addr, proxied) = start_server("proxied").await?;
+    let (index_addr, direct) = start_server("direct").await?;
+    let cache = Cache::temp()?.init()?;
+
+    let url = Url::parse(&format!("http://{index_addr}/simple/"))?;
+    let client = RegistryClientBuilder::new(cache)
+        .proxy(ProxySettings {
+            proxy: Some(ProxyUrl::from_str(&format!("http://{proxy_addr}"))?),
+            no_proxy: vec![],
+        })
+        .index_urls(index(&url, None, true)?)
+        .build();
+
+    let response = client.uncached_client(&url).get(url.clone()).send().await?;
+    assert_eq!(response.text().await?, "direct");
+    assert_eq!(direct.lock().unwrap().len(), 1);
+
+    let other = Url::parse("http://files.internal.invalid/file.whl")?;
+    let response = client
+        .uncached_client(&other)
+        .get(other.clone())
+        .send()
+        .await?;
+    assert_eq!(response.text().await?, "proxied");
+    assert_eq!(proxied.lock().unwrap().len(), 1);
+
+    Ok(())
+}
+
+/// Requests to an index with a proxy that can't be used should fail, rather than bypassing the
+/// proxy, while other requests should be unaffected.
+#[tokio::test]
+async fn index_invalid_proxy() -> Result<()> {
+    let (addr, direct) = start_server("direct").await?;
+    let cache = Cache::temp()?.init()?;
+
+    // The host of a SOCKS5 proxy is resolved when the client is built, which fails for a host
+    // that doesn't exist.
+    let url = Url::parse("http://pypi.internal.invalid/simple/")?;
+    let proxy = ProxyUrl::from_str("socks5://proxy.internal.invalid:1080")?;
+    let client = RegistryClientBuilder::new(cache)
+        .index_urls(index(&url, Some(proxy), false)?)
+        .build();
+
+    let Err(reqwest_middleware::Error::Middleware(err)) =
+        client.uncached_client(&url).get(url.clone()).send().await
+    else {
+        panic!("Expected the request to fail with a middleware error");
+    };
+    assert!(err.downcast_ref::<ProxyError>().is_some());
+    assert_eq!(
+        err.to_string(),
+        "Failed to configure proxy `socks5://proxy.internal.invalid:1080`"
+    );
+
+    let other = Url::parse(&format!("http://{addr}/file.whl"))?;
+    let response = client
+        .uncached_client(&other)
+        .get(other.clone())
+        .send()
+        .await?;
+    assert_eq!(response.text().await?, "direct");
+    assert_eq!(direct.lock().unwrap().len(), 1);
+
+    Ok(())
+}
@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use uv_auth::{self, KeyringProvider};
 
 /// Keyring provider type to use for credential lookup.
@@ -11,6 +13,8 @@ pub enum KeyringProviderType {
     Disabled,
     /// Use the `keyring` command for credential lookup.
     Subprocess,
+    /// Use the credential helper configured via `credential-helper` for credential lookup.
+    Helper,
     // /// Not yet implemented
     // Auto,
     // /// Not implemented yet. Maybe use <https://docs.rs/keyring/latest/keyring/> for this?
@@ -19,10 +23,24 @@ pub enum KeyringProviderType {
 // See <https://pip.pypa.io/en/stable/topics/authentication/#keyring-support> for details.
 
 impl KeyringProviderType {
-    pub fn to_provider(&self) -> Option<KeyringProvider> {
+    /// Returns the [`KeyringProvider`] to use for credential lookup, if any.
+    ///
+    /// The `credential_helper` is required for [`KeyringProviderType::Helper`].
+    pub fn to_provider(
+        &self,
+        credential_helper: Option<&Path>,
+    ) -> Result<Option<KeyringProvider>, MissingCredentialHelper> {
         match self {
-            Self::Disabled => None,
-            Self::Subprocess => Some(KeyringProvider::subprocess()),
+            Self::Disabled => Ok(None),
+            Self::Subprocess => Ok(Some(KeyringProvider::subprocess())),
+            Self::Helper => credential_helper
+                .map(|credential_helper| Some(KeyringProvider::helper(credential_helper)))
+                .ok_or(MissingCredentialHelper),
         }
     }
 }
+
+/// An error indicating that `--keyring-provider helper` was selected without a credential helper.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("`--keyring-provider helper` requires a credential helper to be set via `--credential-helper` (or `credential-helper` in `uv.toml`)")]
+pub struct MissingCredentialHelper;
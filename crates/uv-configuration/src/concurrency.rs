@@ -15,6 +15,10 @@ pub struct Concurrency {
     ///
     /// Note this value must be non-zero.
     pub installs: usize,
+    /// The maximum number of concurrent uploads.
+    ///
+    /// Note this value must be non-zero.
+    pub uploads: usize,
 }
 
 impl Default for Concurrency {
@@ -23,6 +27,7 @@ impl Default for Concurrency {
             downloads: Concurrency::DEFAULT_DOWNLOADS,
             builds: Concurrency::threads(),
             installs: Concurrency::threads(),
+            uploads: Concurrency::DEFAULT_UPLOADS,
         }
     }
 }
@@ -31,6 +36,9 @@ impl Concurrency {
     // The default concurrent downloads limit.
     pub const DEFAULT_DOWNLOADS: usize = 50;
 
+    // The default concurrent uploads limit.
+    pub const DEFAULT_UPLOADS: usize = 1;
+
     // The default concurrent builds and install limit.
     pub fn threads() -> usize {
         std::thread::available_parallelism()
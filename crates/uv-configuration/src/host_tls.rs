@@ -0,0 +1,37 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::TrustedHost;
+
+/// The TLS settings to use for requests to a specific host, e.g., to present a client certificate
+/// to an index that requires mutual TLS, or to trust a private certificate authority.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HostTls {
+    /// The host to which the settings apply.
+    ///
+    /// Expects to receive either a hostname (e.g., `pypi.internal.example.com`), a host-port pair
+    /// (e.g., `pypi.internal.example.com:8443`), or a URL (e.g.,
+    /// `https://pypi.internal.example.com`).
+    pub host: TrustedHost,
+    /// The path to a PEM bundle of certificate authorities to trust for the host.
+    ///
+    /// If set, _only_ the certificates in the bundle are trusted for the host, in place of the
+    /// system or bundled root certificates. The bundle is never trusted for any other host.
+    pub ca_cert: Option<PathBuf>,
+    /// The path to a PEM file containing the client certificate to present to the host.
+    ///
+    /// The file may also contain the private key, in which case `client-key` can be omitted.
+    pub client_cert: Option<PathBuf>,
+    /// The path to a PEM file containing the private key for the client certificate.
+    pub client_key: Option<PathBuf>,
+}
+
+impl HostTls {
+    /// Returns `true` if the settings apply to the given [`url::Url`].
+    pub fn matches(&self, url: &url::Url) -> bool {
+        self.host.matches(url)
+    }
+}
@@ -9,12 +9,14 @@ pub use editable::*;
 pub use export_format::*;
 pub use extras::*;
 pub use hash::*;
+pub use host_tls::*;
 pub use install_options::*;
 pub use name_specifiers::*;
 pub use overrides::*;
 pub use package_options::*;
 pub use preview::*;
 pub use project_build_backend::*;
+pub use proxy::*;
 pub use rayon::*;
 pub use required_version::*;
 pub use sources::*;
@@ -34,12 +36,14 @@ mod editable;
 mod export_format;
 mod extras;
 mod hash;
+mod host_tls;
 mod install_options;
 mod name_specifiers;
 mod overrides;
 mod package_options;
 mod preview;
 mod project_build_backend;
+mod proxy;
 mod rayon;
 mod required_version;
 mod sources;
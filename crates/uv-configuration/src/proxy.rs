@@ -0,0 +1,12 @@
+use uv_distribution_types::ProxyUrl;
+
+/// The proxy settings to use for HTTP requests.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProxySettings {
+    /// The proxy to use for all requests, in lieu of the `HTTP_PROXY`, `HTTPS_PROXY`, and
+    /// `ALL_PROXY` environment variables.
+    pub proxy: Option<ProxyUrl>,
+    /// The hosts for which requests bypass `proxy`, following the `NO_PROXY` conventions (e.g.,
+    /// `localhost`, `.example.com`, or `192.168.0.0/16`).
+    pub no_proxy: Vec<String>,
+}
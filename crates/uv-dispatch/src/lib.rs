@@ -192,6 +192,18 @@ impl BuildContext for BuildDispatch<'_> {
         self.config_settings
     }
 
+    fn build_constraints(&self) -> &Constraints {
+        &self.constraints
+    }
+
+    fn build_isolation(&self) -> BuildIsolation<'_> {
+        self.build_isolation
+    }
+
+    fn build_extra_env_vars(&self) -> &FxHashMap<OsString, OsString> {
+        &self.build_extra_env_vars
+    }
+
     fn sources(&self) -> SourceStrategy {
         self.sources
     }
@@ -12,7 +12,7 @@ use url::{ParseError, Url};
 
 use uv_pep508::{split_scheme, Scheme, VerbatimUrl, VerbatimUrlError};
 
-use crate::{Index, Verbatim};
+use crate::{CacheControlHeader, Index, IndexCacheControl, IndexHttpPolicy, ProxyUrl, Verbatim};
 
 static PYPI_URL: LazyLock<Url> = LazyLock::new(|| Url::parse("https://pypi.org/simple").unwrap());
 
@@ -304,6 +304,8 @@ impl<'a> IndexLocations {
 
     /// Return an iterator over all simple [`Index`] entries in order.
     ///
+    /// Includes flat indexes defined via `[[tool.uv.index]]`, but not `--find-links` entries.
+    ///
     /// If `no_index` was enabled, then this always returns an empty iterator.
     pub fn simple_indexes(&'a self) -> impl Iterator<Item = &'a Index> + 'a {
         if self.no_index {
@@ -318,7 +320,10 @@ impl<'a> IndexLocations {
         }
     }
 
-    /// Return an iterator over the [`FlatIndexLocation`] entries.
+    /// Return an iterator over the `--find-links` [`Index`] entries.
+    ///
+    /// Flat indexes defined via `[[tool.uv.index]]` are excluded, as they're queried alongside the
+    /// simple indexes, in priority order.
     pub fn flat_indexes(&'a self) -> impl Iterator<Item = &'a Index> + 'a {
         self.flat_index.iter()
     }
@@ -477,6 +482,66 @@ impl<'a> IndexUrls {
             )
         }
     }
+
+    /// Return the `Cache-Control` header override for Simple API responses from the given index,
+    /// if any.
+    pub fn simple_api_cache_control_for(
+        &'a self,
+        url: &IndexUrl,
+    ) -> Option<&'a CacheControlHeader> {
+        self.cache_control_for(url)
+            .and_then(|cache_control| cache_control.api.as_ref())
+    }
+
+    /// Return the `Cache-Control` header override for distribution downloads from the given index,
+    /// if any.
+    pub fn artifact_cache_control_for(&'a self, url: &IndexUrl) -> Option<&'a CacheControlHeader> {
+        self.cache_control_for(url)
+            .and_then(|cache_control| cache_control.files.as_ref())
+    }
+
+    /// Return the [`IndexCacheControl`] for the given index, if any.
+    fn cache_control_for(&'a self, url: &IndexUrl) -> Option<&'a IndexCacheControl> {
+        self.indexes
+            .iter()
+            .find(|index| index.url() == url)
+            .and_then(|index| index.cache_control.as_ref())
+    }
+
+    /// Return the retry and timeout settings of each index that overrides them, along with the
+    /// URL of the index.
+    pub fn http_policies(&'a self) -> impl Iterator<Item = (&'a Url, &'a IndexHttpPolicy)> + 'a {
+        self.defined_indexes()
+            .filter_map(|index| Some((index.raw_url(), index.http.as_ref()?)))
+    }
+
+    /// Return the proxy of each index that overrides the global proxy settings, along with the
+    /// URL of the index.
+    ///
+    /// A proxy of `None` indicates that requests to the index should bypass any proxy.
+    pub fn proxies(&'a self) -> impl Iterator<Item = (&'a Url, Option<&'a ProxyUrl>)> + 'a {
+        self.defined_indexes().filter_map(|index| {
+            if let Some(proxy) = &index.proxy {
+                Some((index.raw_url(), Some(proxy)))
+            } else if index.no_proxy {
+                Some((index.raw_url(), None))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl From<&IndexUrls> for uv_auth::Indexes {
+    fn from(index_urls: &IndexUrls) -> Self {
+        Self::from_indexes(index_urls.defined_indexes().map(|index| uv_auth::Index {
+            url: index.raw_url().clone(),
+            root_url: index.root_url().unwrap_or_else(|| index.raw_url().clone()),
+            auth_policy: index.authenticate,
+            auth_scheme: index.auth_scheme,
+            auth_header: index.auth_header.clone(),
+        }))
+    }
 }
 
 bitflags::bitflags! {
@@ -4,6 +4,7 @@ use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use fs_err as fs;
+use itertools::Itertools;
 use thiserror::Error;
 use tracing::warn;
 use url::Url;
@@ -13,6 +14,7 @@ use uv_distribution_filename::EggInfoFilename;
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
+use uv_platform_tags::{AbiTag, LanguageTag, PlatformTag, Tags};
 use uv_pypi_types::{DirectUrl, MetadataError};
 
 use crate::{DistributionMetadata, InstalledMetadata, InstalledVersion, Name, VersionOrUrlRef};
@@ -370,6 +372,60 @@ impl InstalledDist {
         }
     }
 
+    /// Return true if the distribution's wheel tags, as recorded in its `WHEEL` file, are
+    /// compatible with the given [`Tags`].
+    ///
+    /// Distributions that weren't installed from a wheel, or whose tags can't be determined, are
+    /// assumed to be compatible.
+    pub fn is_compatible(&self, tags: &Tags) -> Result<bool, InstalledDistError> {
+        let (Self::Registry(_) | Self::Url(_)) = self else {
+            return Ok(true);
+        };
+        let contents = match fs::read_to_string(self.path().join("WHEEL")) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(true),
+            Err(err) => return Err(err.into()),
+        };
+
+        // Each `Tag` line contains a single (possibly compressed) tag triple, e.g.,
+        // `cp312-cp312-manylinux_2_17_x86_64.manylinux2014_x86_64`.
+        let mut found = false;
+        for line in contents.lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            if !key.trim().eq_ignore_ascii_case("Tag") {
+                continue;
+            }
+            let Some((python_tag, abi_tag, platform_tag)) = value.trim().split('-').collect_tuple()
+            else {
+                continue;
+            };
+            let (Ok(python_tags), Ok(abi_tags), Ok(platform_tags)) = (
+                python_tag
+                    .split('.')
+                    .map(LanguageTag::from_str)
+                    .collect::<Result<Vec<_>, _>>(),
+                abi_tag
+                    .split('.')
+                    .map(AbiTag::from_str)
+                    .collect::<Result<Vec<_>, _>>(),
+                platform_tag
+                    .split('.')
+                    .map(PlatformTag::from_str)
+                    .collect::<Result<Vec<_>, _>>(),
+            ) else {
+                continue;
+            };
+            found = true;
+            if tags.is_compatible(&python_tags, &abi_tags, &platform_tags) {
+                return Ok(true);
+            }
+        }
+
+        Ok(!found)
+    }
+
     /// Return true if the distribution is editable.
     pub fn is_editable(&self) -> bool {
         matches!(
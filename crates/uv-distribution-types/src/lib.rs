@@ -69,6 +69,7 @@ pub use crate::installed::*;
 pub use crate::origin::*;
 pub use crate::pip_index::*;
 pub use crate::prioritized_distribution::*;
+pub use crate::proxy::*;
 pub use crate::requested::*;
 pub use crate::resolution::*;
 pub use crate::resolved::*;
@@ -93,6 +94,7 @@ mod installed;
 mod origin;
 mod pip_index;
 mod prioritized_distribution;
+mod proxy;
 mod requested;
 mod resolution;
 mod resolved;
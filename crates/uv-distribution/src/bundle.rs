@@ -0,0 +1,209 @@
+//! Collect the cache entries that are required to install a set of distributions, for the purpose
+//! of bundling them for use on another machine (e.g., via `uv cache export`).
+
+use std::collections::BTreeSet;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use tracing::debug;
+
+use uv_cache::{Cache, CacheBucket, WheelCache};
+use uv_cache_key::{cache_digest, RepositoryUrl};
+use uv_distribution_filename::WheelFilename;
+use uv_distribution_types::{BuiltDist, Dist, IndexUrl, SourceDist};
+use uv_normalize::PackageName;
+
+use crate::{Error, HttpArchivePointer, LocalArchivePointer};
+
+/// Return the files and links (relative to the cache root) that are required to install the given
+/// distributions from the cache, without network access.
+///
+/// This includes the pointers to any downloaded wheels, along with their unzipped archives; any
+/// unpacked source distributions, along with the wheels that were built from them; and, for Git
+/// dependencies, the underlying repository. Entries that are missing from the cache are omitted.
+pub fn bundle_entries<'a>(
+    cache: &Cache,
+    dists: impl IntoIterator<Item = &'a Dist>,
+) -> Result<BTreeSet<PathBuf>, Error> {
+    let mut bundle = Bundle {
+        cache,
+        entries: BTreeSet::new(),
+    };
+    for dist in dists {
+        bundle.add_dist(dist).map_err(Error::CacheRead)?;
+    }
+    Ok(bundle.entries)
+}
+
+struct Bundle<'a> {
+    cache: &'a Cache,
+    entries: BTreeSet<PathBuf>,
+}
+
+impl Bundle<'_> {
+    fn add_dist(&mut self, dist: &Dist) -> io::Result<()> {
+        let cache = self.cache;
+        match dist {
+            Dist::Built(BuiltDist::Registry(dist)) => {
+                let wheel = dist.best_wheel();
+                self.add_simple(&wheel.index, &wheel.filename.name)?;
+                self.add_wheel(
+                    WheelCache::Index(&wheel.index).wheel_dir(wheel.filename.name.as_ref()),
+                    &wheel.filename,
+                )?;
+            }
+            Dist::Built(BuiltDist::DirectUrl(dist)) => {
+                self.add_wheel(
+                    WheelCache::Url(&dist.url).wheel_dir(dist.filename.name.as_ref()),
+                    &dist.filename,
+                )?;
+            }
+            Dist::Built(BuiltDist::Path(dist)) => {
+                self.add_wheel(
+                    WheelCache::Url(&dist.url).wheel_dir(dist.filename.name.as_ref()),
+                    &dist.filename,
+                )?;
+            }
+            Dist::Source(SourceDist::Registry(dist)) => {
+                self.add_simple(&dist.index, &dist.name)?;
+                self.add_dir(
+                    &cache.bucket(CacheBucket::SourceDistributions).join(
+                        WheelCache::Index(&dist.index)
+                            .wheel_dir(dist.name.as_ref())
+                            .join(dist.version.to_string()),
+                    ),
+                )?;
+            }
+            Dist::Source(SourceDist::DirectUrl(dist)) => {
+                self.add_dir(
+                    &cache
+                        .bucket(CacheBucket::SourceDistributions)
+                        .join(WheelCache::Url(&dist.url).root()),
+                )?;
+            }
+            Dist::Source(SourceDist::Path(dist)) => {
+                self.add_dir(
+                    &cache
+                        .bucket(CacheBucket::SourceDistributions)
+                        .join(WheelCache::Path(&dist.url).root()),
+                )?;
+            }
+            Dist::Source(SourceDist::Git(dist)) => {
+                // Include the repository itself, such that the commit can be checked out offline.
+                let ident = cache_digest(&RepositoryUrl::new(dist.git.repository()));
+                let git = cache.bucket(CacheBucket::Git);
+                self.add_dir(&git.join("db").join(&ident))?;
+                self.add_dir(&git.join("checkouts").join(&ident))?;
+
+                if let Some(precise) = dist.git.precise() {
+                    self.add_dir(
+                        &cache
+                            .bucket(CacheBucket::SourceDistributions)
+                            .join(WheelCache::Git(&dist.url, precise.as_short_str()).root()),
+                    )?;
+                }
+            }
+            Dist::Source(SourceDist::Directory(dist)) => {
+                debug!("Skipping local directory in cache bundle: {dist}");
+            }
+        }
+        Ok(())
+    }
+
+    /// Add the Simple API response for a package.
+    fn add_simple(&mut self, index: &IndexUrl, name: &PackageName) -> io::Result<()> {
+        let entry = self.cache.entry(
+            CacheBucket::Simple,
+            WheelCache::Index(index).root(),
+            format!("{name}.rkyv"),
+        );
+        self.add_file(entry.path())
+    }
+
+    /// Add a downloaded wheel: its pointer, its metadata, and the unzipped archive.
+    fn add_wheel(&mut self, wheel_dir: PathBuf, filename: &WheelFilename) -> io::Result<()> {
+        let entry = self.cache.entry(
+            CacheBucket::Wheels,
+            wheel_dir,
+            format!("{}.msgpack", filename.stem()),
+        );
+        self.add_file(entry.path())?;
+
+        let http = entry.with_file(format!("{}.http", filename.stem()));
+        if let Ok(Some(pointer)) = HttpArchivePointer::read_from(http.path()) {
+            self.add_file(http.path())?;
+            let archive = self.cache.archive(&pointer.into_archive().id);
+            self.add_dir(&archive)?;
+        }
+
+        let rev = entry.with_file(format!("{}.rev", filename.stem()));
+        if let Ok(Some(pointer)) = LocalArchivePointer::read_from(rev.path()) {
+            self.add_file(rev.path())?;
+            let archive = self.cache.archive(&pointer.into_archive().id);
+            self.add_dir(&archive)?;
+        }
+
+        // Include the link to the unzipped archive, which marks the archive as reachable (e.g., for
+        // `uv cache prune`).
+        self.add_link(&entry.dir().join(filename.stem()))?;
+
+        Ok(())
+    }
+
+    /// Add a file, if it exists.
+    fn add_file(&mut self, path: &Path) -> io::Result<()> {
+        if path.is_file() {
+            self.insert(path);
+        }
+        Ok(())
+    }
+
+    /// Add the contents of a directory, if it exists, including the targets of any links into the
+    /// cache's archive bucket.
+    fn add_dir(&mut self, path: &Path) -> io::Result<()> {
+        if !path.is_dir() {
+            return Ok(());
+        }
+        for entry in walkdir::WalkDir::new(path) {
+            let entry = entry?;
+            if entry.file_type().is_symlink() {
+                self.add_link(entry.path())?;
+            } else if !entry.file_type().is_dir() {
+                self.insert(entry.path());
+            }
+        }
+        Ok(())
+    }
+
+    /// Add a link, if it exists, along with its target, if the target is in the cache's archive
+    /// bucket.
+    fn add_link(&mut self, path: &Path) -> io::Result<()> {
+        if !path.is_symlink() || !self.insert(path) {
+            return Ok(());
+        }
+        let Ok(target) = fs_err::canonicalize(path) else {
+            return Ok(());
+        };
+        let archive = self.cache.bucket(CacheBucket::Archive);
+        let archive = fs_err::canonicalize(&archive).unwrap_or(archive);
+        if target.starts_with(&archive) {
+            self.add_dir(&target)?;
+        }
+        Ok(())
+    }
+
+    /// Record a path, relative to the cache root, returning `true` if it wasn't already recorded.
+    fn insert(&mut self, path: &Path) -> bool {
+        if let Ok(relative) = path.strip_prefix(self.cache.root()) {
+            return self.entries.insert(relative.to_path_buf());
+        }
+        // The path may be canonicalized (e.g., if it was the target of a link).
+        let Ok(root) = fs_err::canonicalize(self.cache.root()) else {
+            return false;
+        };
+        match path.strip_prefix(root) {
+            Ok(relative) => self.entries.insert(relative.to_path_buf()),
+            Err(_) => false,
+        }
+    }
+}
@@ -600,11 +600,17 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         let req = self.request(url.clone())?;
 
         let cache_control = match self.client.unmanaged.connectivity() {
-            Connectivity::Online => CacheControl::from(
+            Connectivity::Online => CacheControl::from_freshness(
                 self.build_context
                     .cache()
                     .freshness(&http_entry, Some(&filename.name))
                     .map_err(Error::CacheRead)?,
+                dist.index().and_then(|index| {
+                    self.client
+                        .unmanaged
+                        .index_urls()
+                        .artifact_cache_control_for(index)
+                }),
             ),
             Connectivity::Offline => CacheControl::AllowStale,
         };
@@ -764,11 +770,17 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         let req = self.request(url.clone())?;
 
         let cache_control = match self.client.unmanaged.connectivity() {
-            Connectivity::Online => CacheControl::from(
+            Connectivity::Online => CacheControl::from_freshness(
                 self.build_context
                     .cache()
                     .freshness(&http_entry, Some(&filename.name))
                     .map_err(Error::CacheRead)?,
+                dist.index().and_then(|index| {
+                    self.client
+                        .unmanaged
+                        .index_urls()
+                        .artifact_cache_control_for(index)
+                }),
             ),
             Connectivity::Offline => CacheControl::AllowStale,
         };
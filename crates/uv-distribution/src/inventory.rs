@@ -0,0 +1,398 @@
+//! An inventory of the entries in the cache, for the purpose of inspection (e.g., via
+//! `uv cache list`).
+
+use std::ffi::OsStr;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::SystemTime;
+
+use uv_cache::{dir_size, Cache, CacheBucket};
+use uv_cache_key::{cache_digest, CanonicalUrl};
+use uv_distribution_filename::WheelFilename;
+use uv_distribution_types::IndexUrl;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pypi_types::ResolutionMetadata;
+
+use crate::index::CachedWheel;
+use crate::source::{METADATA, SOURCE};
+use crate::Error;
+
+/// The kind of an entry in the cache.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CacheItemKind {
+    /// A pre-built wheel, downloaded from an index or URL.
+    Wheel,
+    /// A wheel that was built from a source distribution.
+    BuiltWheel,
+    /// An unpacked source distribution.
+    SourceDistribution,
+    /// A checkout of a Git repository, at a specific commit.
+    GitCheckout,
+    /// The result of querying a Python interpreter.
+    Interpreter,
+    /// A cached virtual environment (e.g., for `uv run --with`).
+    Environment,
+}
+
+impl CacheItemKind {
+    /// Returns the kind as a string, for display.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Wheel => "wheel",
+            Self::BuiltWheel => "built-wheel",
+            Self::SourceDistribution => "sdist",
+            Self::GitCheckout => "git",
+            Self::Interpreter => "interpreter",
+            Self::Environment => "environment",
+        }
+    }
+}
+
+impl Display for CacheItemKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The source from which an entry in the cache was retrieved.
+///
+/// With the exception of PyPI, sources are identified by the digest of their URL (or path), as
+/// used to shard the cache.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CacheOrigin {
+    /// PyPI.
+    Pypi,
+    /// An alternative index.
+    Index(String),
+    /// A direct URL.
+    Url(String),
+    /// A local path.
+    Path(String),
+    /// An editable local path.
+    Editable(String),
+    /// A Git repository.
+    Git(String),
+}
+
+impl CacheOrigin {
+    /// Determine the [`CacheOrigin`] from the path of an entry, relative to its cache bucket
+    /// (e.g., `index/<digest>/flask/...`).
+    fn from_relative_path(path: &Path) -> Option<Self> {
+        let mut components = path.components().filter_map(|c| c.as_os_str().to_str());
+        let kind = components.next()?;
+        if kind == "pypi" {
+            return Some(Self::Pypi);
+        }
+        let digest = components.next()?.to_string();
+        match kind {
+            "index" => Some(Self::Index(digest)),
+            "url" => Some(Self::Url(digest)),
+            "path" => Some(Self::Path(digest)),
+            "editable" => Some(Self::Editable(digest)),
+            "git" => Some(Self::Git(digest)),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the entry was retrieved from the given index.
+    pub fn is_index(&self, url: &IndexUrl) -> bool {
+        match (self, url) {
+            (Self::Pypi, IndexUrl::Pypi(_)) => true,
+            (Self::Index(digest), IndexUrl::Url(_) | IndexUrl::Path(_)) => {
+                *digest == cache_digest(&CanonicalUrl::new(url))
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Display for CacheOrigin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pypi => f.write_str("pypi"),
+            Self::Index(digest) => write!(f, "index ({digest})"),
+            Self::Url(digest) => write!(f, "url ({digest})"),
+            Self::Path(digest) => write!(f, "path ({digest})"),
+            Self::Editable(digest) => write!(f, "editable ({digest})"),
+            Self::Git(digest) => write!(f, "git ({digest})"),
+        }
+    }
+}
+
+/// An entry in the cache.
+#[derive(Debug, Clone)]
+pub struct CacheItem {
+    /// The kind of the entry.
+    pub kind: CacheItemKind,
+    /// The name of the package, if the entry corresponds to a package.
+    pub name: Option<PackageName>,
+    /// The version of the package, if the entry corresponds to a package.
+    pub version: Option<Version>,
+    /// A human-readable identifier for the entry (e.g., the wheel filename).
+    pub identifier: String,
+    /// The path to the entry's contents.
+    pub path: PathBuf,
+    /// The size of the entry, in bytes.
+    pub size: u64,
+    /// The last modification time of the entry. For wheels, this reflects the last time the wheel
+    /// was installed.
+    pub modified: SystemTime,
+    /// The source from which the entry was retrieved, if known.
+    pub origin: Option<CacheOrigin>,
+}
+
+/// List the entries in the cache.
+///
+/// If a package is provided, only entries for that package are included; entries that don't
+/// correspond to a package (e.g., interpreters) are omitted.
+pub fn inventory(cache: &Cache, package: Option<&PackageName>) -> Result<Vec<CacheItem>, Error> {
+    let mut items = Vec::new();
+
+    wheels(cache, &mut items)?;
+    source_distributions(cache, &mut items)?;
+    if package.is_none() {
+        git_checkouts(cache, &mut items)?;
+        interpreters(cache, &mut items)?;
+        environments(cache, &mut items)?;
+    }
+
+    if let Some(package) = package {
+        items.retain(|item| item.name.as_ref() == Some(package));
+    }
+
+    items.sort_by(|a, b| {
+        (a.kind, &a.name, &a.version, &a.identifier).cmp(&(
+            b.kind,
+            &b.name,
+            &b.version,
+            &b.identifier,
+        ))
+    });
+
+    Ok(items)
+}
+
+/// Collect the pre-built wheels, which are stored as pointers into the archive bucket.
+fn wheels(cache: &Cache, items: &mut Vec<CacheItem>) -> Result<(), Error> {
+    let bucket = cache.bucket(CacheBucket::Wheels);
+    if !bucket.is_dir() {
+        return Ok(());
+    }
+
+    for entry in walkdir::WalkDir::new(&bucket) {
+        let entry = entry.map_err(Error::CacheWalk)?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let path = entry.path();
+        let wheel = match path.extension().and_then(OsStr::to_str) {
+            Some("http") => CachedWheel::from_http_pointer(path, cache),
+            Some("rev") => CachedWheel::from_local_pointer(path, cache),
+            _ => continue,
+        };
+        let Some(wheel) = wheel else {
+            continue;
+        };
+
+        // Skip any pointers to archives that no longer exist (e.g., following eviction).
+        let archive = wheel.entry.into_path_buf();
+        let Ok(metadata) = fs_err::metadata(&archive) else {
+            continue;
+        };
+
+        items.push(CacheItem {
+            kind: CacheItemKind::Wheel,
+            name: Some(wheel.filename.name.clone()),
+            version: Some(wheel.filename.version.clone()),
+            identifier: wheel.filename.to_string(),
+            size: dir_size(&archive).map_err(Error::CacheRead)?,
+            modified: metadata.modified().map_err(Error::CacheRead)?,
+            origin: path
+                .strip_prefix(&bucket)
+                .ok()
+                .and_then(CacheOrigin::from_relative_path),
+            path: archive,
+        });
+    }
+
+    Ok(())
+}
+
+/// Collect the unpacked source distributions, along with any wheels built from them.
+fn source_distributions(cache: &Cache, items: &mut Vec<CacheItem>) -> Result<(), Error> {
+    let bucket = cache.bucket(CacheBucket::SourceDistributions);
+    if !bucket.is_dir() {
+        return Ok(());
+    }
+
+    let mut walker = walkdir::WalkDir::new(&bucket).into_iter();
+    while let Some(entry) = walker.next() {
+        let entry = entry.map_err(Error::CacheWalk)?;
+        if !entry.file_type().is_dir() {
+            continue;
+        }
+
+        // Every revision contains the metadata of the source distribution.
+        let revision = entry.path();
+        let Ok(metadata) = fs_err::read(revision.join(METADATA)) else {
+            continue;
+        };
+        let Ok(metadata) = rmp_serde::from_slice::<ResolutionMetadata>(&metadata) else {
+            continue;
+        };
+
+        // Avoid descending into the revision (e.g., into the unpacked source tree).
+        walker.skip_current_dir();
+
+        let origin = revision
+            .strip_prefix(&bucket)
+            .ok()
+            .and_then(CacheOrigin::from_relative_path);
+
+        // The unpacked source distribution, which is absent for local source trees.
+        let source = revision.join(SOURCE);
+        if let Ok(modified) = fs_err::metadata(&source).and_then(|metadata| metadata.modified()) {
+            items.push(CacheItem {
+                kind: CacheItemKind::SourceDistribution,
+                name: Some(metadata.name.clone()),
+                version: Some(metadata.version.clone()),
+                identifier: format!("{}-{}", metadata.name, metadata.version),
+                size: dir_size(&source).map_err(Error::CacheRead)?,
+                modified,
+                origin: origin.clone(),
+                path: source,
+            });
+        }
+
+        // The wheels built from the source distribution.
+        for child in fs_err::read_dir(revision).map_err(Error::CacheRead)? {
+            let child = child.map_err(Error::CacheRead)?;
+            let path = child.path();
+            if path.extension().is_none_or(|ext| ext != "whl") {
+                continue;
+            }
+            let Some(filename) = path
+                .file_name()
+                .and_then(OsStr::to_str)
+                .and_then(|filename| WheelFilename::from_str(filename).ok())
+            else {
+                continue;
+            };
+
+            let metadata = child.metadata().map_err(Error::CacheRead)?;
+
+            // Include the unzipped wheel, if it was installed.
+            let mut size = metadata.len();
+            if let Ok(unzipped) = revision.join(filename.stem()).canonicalize() {
+                size += dir_size(unzipped).map_err(Error::CacheRead)?;
+            }
+
+            items.push(CacheItem {
+                kind: CacheItemKind::BuiltWheel,
+                name: Some(filename.name.clone()),
+                version: Some(filename.version.clone()),
+                identifier: filename.to_string(),
+                size,
+                modified: metadata.modified().map_err(Error::CacheRead)?,
+                origin: origin.clone(),
+                path,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Collect the checkouts of Git repositories.
+fn git_checkouts(cache: &Cache, items: &mut Vec<CacheItem>) -> Result<(), Error> {
+    let checkouts = cache.bucket(CacheBucket::Git).join("checkouts");
+    for repository in uv_fs::directories(&checkouts) {
+        let digest = repository
+            .file_name()
+            .and_then(OsStr::to_str)
+            .map(ToString::to_string);
+        for checkout in uv_fs::directories(&repository) {
+            let Some(identifier) = checkout.file_name().and_then(OsStr::to_str) else {
+                continue;
+            };
+            items.push(CacheItem {
+                kind: CacheItemKind::GitCheckout,
+                name: None,
+                version: None,
+                identifier: identifier.to_string(),
+                size: dir_size(&checkout).map_err(Error::CacheRead)?,
+                modified: modified(&checkout)?,
+                origin: digest.clone().map(CacheOrigin::Git),
+                path: checkout,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Collect the cached interpreter query results, which are sharded by architecture.
+fn interpreters(cache: &Cache, items: &mut Vec<CacheItem>) -> Result<(), Error> {
+    for shard in uv_fs::directories(cache.bucket(CacheBucket::Interpreter)) {
+        for path in uv_fs::files(&shard) {
+            if path.extension().is_none_or(|ext| ext != "msgpack") {
+                continue;
+            }
+            let Some(identifier) = path.file_stem().and_then(OsStr::to_str) else {
+                continue;
+            };
+            items.push(CacheItem {
+                kind: CacheItemKind::Interpreter,
+                name: None,
+                version: None,
+                identifier: identifier.to_string(),
+                size: dir_size(&path).map_err(Error::CacheRead)?,
+                modified: modified(&path)?,
+                origin: None,
+                path,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Collect the cached environments, which are linked into the archive bucket.
+fn environments(cache: &Cache, items: &mut Vec<CacheItem>) -> Result<(), Error> {
+    for interpreter in uv_fs::directories(cache.bucket(CacheBucket::Environments)) {
+        let Ok(entries) = fs_err::read_dir(&interpreter) else {
+            continue;
+        };
+        for entry in entries {
+            let entry = entry.map_err(Error::CacheRead)?;
+            let Some(identifier) = entry.file_name().to_str().map(ToString::to_string) else {
+                continue;
+            };
+
+            // Skip any links to environments that no longer exist.
+            let Ok(path) = entry.path().canonicalize() else {
+                continue;
+            };
+
+            items.push(CacheItem {
+                kind: CacheItemKind::Environment,
+                name: None,
+                version: None,
+                identifier,
+                size: dir_size(&path).map_err(Error::CacheRead)?,
+                modified: modified(&path)?,
+                origin: None,
+                path,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Return the modification time of a file or directory.
+fn modified(path: &Path) -> Result<SystemTime, Error> {
+    fs_err::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .map_err(Error::CacheRead)
+}
@@ -1,20 +1,26 @@
+pub use bundle::bundle_entries;
 pub use distribution_database::{DistributionDatabase, HttpArchivePointer, LocalArchivePointer};
 pub use download::LocalWheel;
 pub use error::Error;
 pub use index::{BuiltWheelIndex, RegistryWheelIndex};
+pub use inventory::{inventory, CacheItem, CacheItemKind, CacheOrigin};
 pub use metadata::{
     ArchiveMetadata, BuildRequires, FlatRequiresDist, LoweredRequirement, LoweringError, Metadata,
     MetadataError, RequiresDist,
 };
 pub use reporter::Reporter;
 pub use source::prune;
+pub use verify::{repair, verify, CacheIssue, CacheIssueKind};
 
 mod archive;
+mod bundle;
 mod distribution_database;
 mod download;
 mod error;
 mod index;
+mod inventory;
 mod locks;
 mod metadata;
 mod reporter;
 mod source;
+mod verify;
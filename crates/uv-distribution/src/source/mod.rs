@@ -18,6 +18,7 @@ use crate::distribution_database::ManagedClient;
 use crate::error::Error;
 use crate::metadata::{ArchiveMetadata, GitWorkspaceMember, Metadata};
 use crate::source::built_wheel_metadata::BuiltWheelMetadata;
+use crate::source::remote::RemoteKey;
 use crate::source::revision::Revision;
 use crate::{Reporter, RequiresDist};
 use fs_err::tokio as fs;
@@ -53,6 +54,7 @@ use uv_workspace::pyproject::ToolUvSources;
 use zip::ZipArchive;
 
 mod built_wheel_metadata;
+mod remote;
 mod revision;
 
 /// Fetch and build a source distribution from a remote source, or from a local cache.
@@ -203,6 +205,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     &cache_shard,
                     tags,
                     hashes,
+                    client,
                 )
                 .boxed_local()
                 .await?
@@ -242,7 +245,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     CacheBucket::SourceDistributions,
                     WheelCache::Path(resource.url).root(),
                 );
-                self.archive(source, resource, &cache_shard, tags, hashes)
+                self.archive(source, resource, &cache_shard, tags, hashes, client)
                     .boxed_local()
                     .await?
             }
@@ -440,6 +443,28 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             return Ok(built_wheel.with_hashes(revision.into_hashes()));
         }
 
+        // If the remote cache contains a compatible wheel, fetch it.
+        let remote_key = RemoteKey::archive(
+            source.name(),
+            revision.hashes(),
+            hashes,
+            subdirectory,
+            self.build_context,
+        );
+        if let Some(built_wheel) = self
+            .fetch_remote(
+                source,
+                remote_key.as_ref(),
+                tags,
+                &cache_shard,
+                &cache_shard.entry(METADATA),
+                client,
+            )
+            .await?
+        {
+            return Ok(built_wheel.with_hashes(revision.into_hashes()));
+        }
+
         // Otherwise, we need to build a wheel. Before building, ensure that the source is present.
         let revision = if source_dist_entry.path().is_dir() {
             revision
@@ -484,6 +509,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .await
             .map_err(Error::CacheWrite)?;
 
+        // Upload the wheel to the remote cache.
+        self.store_remote(remote_key.as_ref(), &cache_shard, &disk_filename, client)
+            .await;
+
         Ok(BuiltWheelMetadata {
             path: cache_shard.join(&disk_filename),
             target: cache_shard.join(wheel_filename.stem()),
@@ -685,11 +714,20 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
     ) -> Result<Revision, Error> {
         let cache_entry = cache_shard.entry(HTTP_REVISION);
         let cache_control = match client.unmanaged.connectivity() {
-            Connectivity::Online => CacheControl::from(
+            Connectivity::Online => CacheControl::from_freshness(
                 self.build_context
                     .cache()
                     .freshness(&cache_entry, source.name())
                     .map_err(Error::CacheRead)?,
+                source
+                    .as_dist()
+                    .and_then(SourceDist::index)
+                    .and_then(|index| {
+                        client
+                            .unmanaged
+                            .index_urls()
+                            .artifact_cache_control_for(index)
+                    }),
             ),
             Connectivity::Offline => CacheControl::AllowStale,
         };
@@ -703,7 +741,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 // Download the source distribution.
                 debug!("Downloading source distribution: {source}");
                 let entry = cache_shard.shard(revision.id()).entry(SOURCE);
-                let algorithms = hashes.algorithms();
+                let algorithms = self.hash_algorithms(hashes);
                 let hashes = self
                     .download_archive(response, source, ext, entry.path(), &algorithms)
                     .await?;
@@ -760,6 +798,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         cache_shard: &CacheShard,
         tags: &Tags,
         hashes: HashPolicy<'_>,
+        client: &ManagedClient<'_>,
     ) -> Result<BuiltWheelMetadata, Error> {
         let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
 
@@ -800,6 +839,31 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             return Ok(built_wheel);
         }
 
+        // If the remote cache contains a compatible wheel, fetch it.
+        let remote_key = RemoteKey::archive(
+            source.name(),
+            revision.hashes(),
+            hashes,
+            None,
+            self.build_context,
+        );
+        if let Some(built_wheel) = self
+            .fetch_remote(
+                source,
+                remote_key.as_ref(),
+                tags,
+                &cache_shard,
+                &cache_shard.entry(METADATA),
+                client,
+            )
+            .await?
+        {
+            return Ok(BuiltWheelMetadata {
+                cache_info,
+                ..built_wheel.with_hashes(revision.into_hashes())
+            });
+        }
+
         // Otherwise, we need to build a wheel, which requires a source distribution.
         let revision = if source_entry.path().is_dir() {
             revision
@@ -835,6 +899,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .await
             .map_err(Error::CacheWrite)?;
 
+        // Upload the wheel to the remote cache.
+        self.store_remote(remote_key.as_ref(), &cache_shard, &disk_filename, client)
+            .await;
+
         Ok(BuiltWheelMetadata {
             path: cache_shard.join(&disk_filename),
             target: cache_shard.join(filename.stem()),
@@ -1031,7 +1099,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         // Unzip the archive to a temporary directory.
         debug!("Unpacking source distribution: {source}");
         let entry = cache_shard.shard(revision.id()).entry(SOURCE);
-        let algorithms = hashes.algorithms();
+        let algorithms = self.hash_algorithms(hashes);
         let hashes = self
             .persist_archive(&resource.path, resource.ext, entry.path(), &algorithms)
             .await?;
@@ -1437,6 +1505,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .uncached_client(resource.git.repository())
                     .clone(),
                 client.unmanaged.disable_ssl(resource.git.repository()),
+                client
+                    .unmanaged
+                    .host_tls(resource.git.repository())
+                    .cloned(),
+                client.unmanaged.keyring(),
+                client.unmanaged.credential_helper(),
                 self.build_context.cache().bucket(CacheBucket::Git),
                 self.reporter
                     .clone()
@@ -1479,6 +1553,27 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             return Ok(built_wheel);
         }
 
+        // If the remote cache contains a compatible wheel, fetch it.
+        let remote_key = RemoteKey::git(
+            source.name(),
+            git_sha,
+            resource.subdirectory,
+            self.build_context,
+        );
+        if let Some(built_wheel) = self
+            .fetch_remote(
+                source,
+                remote_key.as_ref(),
+                tags,
+                &cache_shard,
+                &metadata_entry,
+                client,
+            )
+            .await?
+        {
+            return Ok(built_wheel);
+        }
+
         let task = self
             .reporter
             .as_ref()
@@ -1505,6 +1600,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .await
             .map_err(Error::CacheWrite)?;
 
+        // Upload the wheel to the remote cache.
+        self.store_remote(remote_key.as_ref(), &cache_shard, &disk_filename, client)
+            .await;
+
         Ok(BuiltWheelMetadata {
             path: cache_shard.join(&disk_filename),
             target: cache_shard.join(filename.stem()),
@@ -1596,6 +1695,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .uncached_client(resource.git.repository())
                     .clone(),
                 client.unmanaged.disable_ssl(resource.git.repository()),
+                client
+                    .unmanaged
+                    .host_tls(resource.git.repository())
+                    .cloned(),
+                client.unmanaged.keyring(),
+                client.unmanaged.credential_helper(),
                 self.build_context.cache().bucket(CacheBucket::Git),
                 self.reporter
                     .clone()
@@ -1826,6 +1931,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 git,
                 client.unmanaged.uncached_client(git.repository()).clone(),
                 client.unmanaged.disable_ssl(git.repository()),
+                client.unmanaged.host_tls(git.repository()).cloned(),
+                client.unmanaged.keyring(),
+                client.unmanaged.credential_helper(),
                 self.build_context.cache().bucket(CacheBucket::Git),
                 self.reporter
                     .clone()
@@ -2158,6 +2266,93 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         Ok(hashes)
     }
 
+    /// Return the hash algorithms to compute when downloading or unpacking a source distribution.
+    ///
+    /// If a remote cache is configured, the SHA-256 digest is always computed, as it identifies
+    /// the source distribution's built wheels in the remote cache.
+    fn hash_algorithms(&self, hashes: HashPolicy<'_>) -> Vec<HashAlgorithm> {
+        let mut algorithms = hashes.algorithms();
+        if self.build_context.cache().remote().is_some()
+            && !algorithms.contains(&HashAlgorithm::Sha256)
+        {
+            algorithms.push(HashAlgorithm::Sha256);
+        }
+        algorithms
+    }
+
+    /// Fetch a compatible wheel from the remote cache into the cache shard, if a remote cache is
+    /// configured.
+    ///
+    /// The wheel's metadata is validated (as for a local build) and stored in the metadata entry.
+    async fn fetch_remote(
+        &self,
+        source: &BuildableSource<'_>,
+        key: Option<&RemoteKey>,
+        tags: &Tags,
+        cache_shard: &CacheShard,
+        metadata_entry: &CacheEntry,
+        client: &ManagedClient<'_>,
+    ) -> Result<Option<BuiltWheelMetadata>, Error> {
+        let (Some(remote), Some(key)) = (self.build_context.cache().remote(), key) else {
+            return Ok(None);
+        };
+        let Some((disk_filename, filename)) =
+            remote::fetch(remote, key, tags, cache_shard, client).await
+        else {
+            return Ok(None);
+        };
+
+        // Validate the metadata, discarding the wheel if it doesn't match the source distribution.
+        let path = cache_shard.join(&disk_filename);
+        let metadata = match read_wheel_metadata(&filename, &path).and_then(|metadata| {
+            validate_metadata(source, &metadata)?;
+            validate_filename(&filename, &metadata)?;
+            Ok(metadata)
+        }) {
+            Ok(metadata) => metadata,
+            Err(err) => {
+                warn!("Discarding invalid wheel `{disk_filename}` from remote cache: {err}");
+                fs::remove_file(&path).await.map_err(Error::CacheWrite)?;
+                return Ok(None);
+            }
+        };
+
+        // Store the metadata.
+        write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+            .await
+            .map_err(Error::CacheWrite)?;
+
+        Ok(Some(BuiltWheelMetadata {
+            path,
+            target: cache_shard.join(filename.stem()),
+            filename,
+            hashes: vec![],
+            cache_info: CacheInfo::default(),
+        }))
+    }
+
+    /// Upload a built wheel from the cache shard to the remote cache, if a remote cache is
+    /// configured.
+    async fn store_remote(
+        &self,
+        key: Option<&RemoteKey>,
+        cache_shard: &CacheShard,
+        disk_filename: &str,
+        client: &ManagedClient<'_>,
+    ) {
+        let (Some(remote), Some(key)) = (self.build_context.cache().remote(), key) else {
+            return;
+        };
+        remote::store(
+            remote,
+            key,
+            &cache_shard.join(disk_filename),
+            disk_filename,
+            client,
+        )
+        .await;
+    }
+
     /// Build a source distribution, storing the built wheel in the cache.
     ///
     /// Returns the un-normalized disk filename, the parsed, normalized filename and the metadata
@@ -0,0 +1,492 @@
+//! A remote cache for wheels built from source distributions, shared across machines.
+//!
+//! Built wheels are stored under a key derived from the identity of the source distribution (the
+//! SHA-256 digest of its archive, or its Git commit), its subdirectory, and the build settings
+//! (the config settings, the build constraints, and any extra build environment variables):
+//!
+//! ```text
+//! built-wheels-v0/{name}/{key}/index.json
+//! built-wheels-v0/{name}/{key}/{wheel}
+//! ```
+//!
+//! The index lists the wheels that were built for the key (e.g., one per platform), alongside
+//! their SHA-256 digests, which are verified whenever a wheel is fetched from the remote cache.
+//! As the digests are stored in the remote cache itself, they guard against corrupted or truncated
+//! uploads, but not against tampering: the remote cache must be trusted (and writable) only by
+//! trusted parties. To that end, HTTP remote caches must use HTTPS (unless served from a loopback
+//! address), and the remote cache is bypassed entirely in hash-checking mode.
+//!
+//! The remote cache is strictly best-effort: any failures are logged, and treated as a cache miss.
+
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use fs_err::tokio as fs;
+use reqwest::header::{HeaderValue, ETAG, IF_MATCH, IF_NONE_MATCH};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+use url::Url;
+
+use uv_cache::{CacheShard, RemoteCache};
+use uv_cache_key::cache_digest;
+use uv_client::Connectivity;
+use uv_distribution_filename::WheelFilename;
+use uv_distribution_types::HashPolicy;
+use uv_extract::hash::Hasher;
+use uv_fs::{write_atomic, LockedFile};
+use uv_git::GitOid;
+use uv_normalize::PackageName;
+use uv_platform_tags::Tags;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+use uv_types::BuildContext;
+
+use crate::distribution_database::ManagedClient;
+
+/// The versioned root of the remote cache.
+const BUCKET: &str = "built-wheels-v0";
+
+/// The name of the file that lists the built wheels for a key.
+const INDEX: &str = "index.json";
+
+/// The name of the file that's locked while updating the index in a shared directory.
+const LOCK: &str = ".lock";
+
+/// The number of attempts to update the index, if it's modified concurrently.
+const INDEX_ATTEMPTS: usize = 3;
+
+#[derive(Debug, thiserror::Error)]
+enum RemoteError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Request(#[from] reqwest_middleware::Error),
+    #[error(transparent)]
+    Response(#[from] reqwest::Error),
+    #[error("Failed to parse `{INDEX}`")]
+    Index(#[from] serde_json::Error),
+    #[error("The remote cache rejected a conditional write")]
+    PreconditionFailed,
+    #[error("`{INDEX}` was modified concurrently {INDEX_ATTEMPTS} times")]
+    Contended,
+    #[error("Hash mismatch for `{filename}` (expected `{expected}`, computed `{actual}`)")]
+    HashMismatch {
+        filename: String,
+        expected: String,
+        actual: String,
+    },
+}
+
+/// The wheels that were built for a key in the remote cache.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Index {
+    wheels: Vec<IndexEntry>,
+}
+
+/// A built wheel in the remote cache.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct IndexEntry {
+    /// The (un-normalized) filename of the wheel.
+    filename: String,
+    /// The hex-encoded SHA-256 digest of the wheel.
+    sha256: String,
+}
+
+/// The location of a source distribution's built wheels in a [`RemoteCache`].
+#[derive(Debug, Clone)]
+pub(crate) struct RemoteKey(String);
+
+impl RemoteKey {
+    /// Create a key for a source distribution archive, identified by its SHA-256 digest.
+    ///
+    /// Returns `None` if the package name or the digest is unknown, if the build isn't
+    /// reproducible from the key alone, or if the source distribution is subject to hash-checking.
+    pub(crate) fn archive(
+        name: Option<&PackageName>,
+        hashes: &[HashDigest],
+        policy: HashPolicy<'_>,
+        subdirectory: Option<&Path>,
+        build_context: &impl BuildContext,
+    ) -> Option<Self> {
+        let name = name?;
+
+        // The remote cache can't vouch for the contents of a built wheel, so a wheel that was
+        // fetched from the remote cache is only as trustworthy as the remote cache itself. In
+        // hash-checking mode, only wheels built from the verified source distribution are used.
+        if policy.is_validate() {
+            debug!("Skipping remote cache for `{name}`, as hash-checking is enabled");
+            return None;
+        }
+
+        let Some(digest) = hashes
+            .iter()
+            .find(|digest| digest.algorithm == HashAlgorithm::Sha256)
+        else {
+            debug!("Skipping remote cache for `{name}`, as its SHA-256 digest is unknown");
+            return None;
+        };
+        Self::new(
+            name,
+            &format!("sha256:{}", digest.digest),
+            subdirectory,
+            build_context,
+        )
+    }
+
+    /// Create a key for a Git source distribution, identified by its commit.
+    ///
+    /// Returns `None` if the package name is unknown, or if the build isn't reproducible from the
+    /// key alone.
+    pub(crate) fn git(
+        name: Option<&PackageName>,
+        commit: GitOid,
+        subdirectory: Option<&Path>,
+        build_context: &impl BuildContext,
+    ) -> Option<Self> {
+        let name = name?;
+        Self::new(name, &format!("git:{commit}"), subdirectory, build_context)
+    }
+
+    fn new(
+        name: &PackageName,
+        source: &str,
+        subdirectory: Option<&Path>,
+        build_context: &impl BuildContext,
+    ) -> Option<Self> {
+        // Without build isolation, the built wheel depends on the contents of the environment,
+        // which aren't captured by the key.
+        if !build_context.build_isolation().is_isolated(Some(name)) {
+            debug!("Skipping remote cache for `{name}`, as build isolation is disabled");
+            return None;
+        }
+
+        // The build dependencies (and, in turn, the built wheel) depend on the build constraints,
+        // and the build may depend on any extra environment variables.
+        let mut constraints = build_context
+            .build_constraints()
+            .requirements()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        constraints.sort_unstable();
+        let mut env = build_context
+            .build_extra_env_vars()
+            .iter()
+            .map(|(key, value)| {
+                (
+                    key.to_string_lossy().into_owned(),
+                    value.to_string_lossy().into_owned(),
+                )
+            })
+            .collect::<Vec<_>>();
+        env.sort_unstable();
+
+        Some(Self(format!(
+            "{BUCKET}/{name}/{}",
+            cache_digest(&(
+                source,
+                subdirectory,
+                build_context.config_settings(),
+                constraints,
+                env
+            ))
+        )))
+    }
+
+    /// Return the path to a file within the key, relative to the root of the remote cache.
+    fn join(&self, file: &str) -> String {
+        format!("{}/{file}", self.0)
+    }
+}
+
+/// Fetch a wheel that's compatible with the given tags from the remote cache into the cache shard.
+///
+/// Returns the disk filename and the parsed filename of the fetched wheel, or `None` if the remote
+/// cache doesn't contain a compatible wheel.
+pub(crate) async fn fetch(
+    remote: &RemoteCache,
+    key: &RemoteKey,
+    tags: &Tags,
+    cache_shard: &CacheShard,
+    client: &ManagedClient<'_>,
+) -> Option<(String, WheelFilename)> {
+    match fetch_wheel(remote, key, tags, cache_shard, client).await {
+        Ok(wheel) => wheel,
+        Err(err) => {
+            warn!("Failed to fetch built wheel from remote cache `{remote}`: {err}");
+            None
+        }
+    }
+}
+
+async fn fetch_wheel(
+    remote: &RemoteCache,
+    key: &RemoteKey,
+    tags: &Tags,
+    cache_shard: &CacheShard,
+    client: &ManagedClient<'_>,
+) -> Result<Option<(String, WheelFilename)>, RemoteError> {
+    let Some(index) = read(remote, &key.join(INDEX), client).await? else {
+        debug!("No built wheels in remote cache for: {}", key.0);
+        return Ok(None);
+    };
+    let index = serde_json::from_slice::<Index>(&index)?;
+
+    // Find a compatible wheel.
+    let Some((entry, filename)) = index.wheels.into_iter().find_map(|entry| {
+        // Reject any filenames that would escape the cache shard.
+        if entry.filename.contains(['/', '\\']) {
+            return None;
+        }
+        let filename = WheelFilename::from_str(&entry.filename).ok()?;
+        filename.is_compatible(tags).then_some((entry, filename))
+    }) else {
+        debug!("No compatible built wheel in remote cache for: {}", key.0);
+        return Ok(None);
+    };
+
+    let Some(wheel) = read(remote, &key.join(&entry.filename), client).await? else {
+        debug!(
+            "Built wheel is missing from remote cache: {}",
+            entry.filename
+        );
+        return Ok(None);
+    };
+
+    // Verify the integrity of the wheel before persisting it.
+    let actual = sha256(&wheel);
+    if actual != entry.sha256 {
+        return Err(RemoteError::HashMismatch {
+            filename: entry.filename,
+            expected: entry.sha256,
+            actual,
+        });
+    }
+
+    debug!("Fetched built wheel from remote cache: {}", entry.filename);
+    fs::create_dir_all(cache_shard).await?;
+    write_atomic(cache_shard.join(&entry.filename), wheel).await?;
+
+    Ok(Some((entry.filename, filename)))
+}
+
+/// Upload a built wheel to the remote cache.
+pub(crate) async fn store(
+    remote: &RemoteCache,
+    key: &RemoteKey,
+    wheel: &Path,
+    disk_filename: &str,
+    client: &ManagedClient<'_>,
+) {
+    if let Err(err) = store_wheel(remote, key, wheel, disk_filename, client).await {
+        warn!("Failed to upload built wheel to remote cache `{remote}`: {err}");
+    }
+}
+
+async fn store_wheel(
+    remote: &RemoteCache,
+    key: &RemoteKey,
+    wheel: &Path,
+    disk_filename: &str,
+    client: &ManagedClient<'_>,
+) -> Result<(), RemoteError> {
+    if is_offline(remote, client) {
+        return Ok(());
+    }
+
+    let contents = fs::read(wheel).await?;
+    let digest = sha256(&contents);
+
+    // On a shared filesystem, lock the key for the duration of the read-modify-write of the
+    // index, such that concurrent uploads (e.g., for other platforms) aren't lost. HTTP servers
+    // can't be locked, so the index is instead written conditionally on its `ETag`, and the
+    // read-modify-write is retried if the index was modified concurrently.
+    let _lock = match remote {
+        RemoteCache::Path(base) => {
+            let dir = join_path(base, &key.0);
+            fs::create_dir_all(&dir).await?;
+            Some(LockedFile::acquire(dir.join(LOCK), &key.0).await?)
+        }
+        RemoteCache::Url(_) => None,
+    };
+
+    let mut wheel = Some(contents);
+    for _ in 0..INDEX_ATTEMPTS {
+        // Read the existing index, such that wheels for other platforms are retained.
+        let (index, etag) = read_versioned(remote, &key.join(INDEX), client).await?;
+        let exists = index.is_some();
+        let mut index = match index {
+            Some(index) => serde_json::from_slice::<Index>(&index).unwrap_or_else(|err| {
+                warn!("Replacing invalid `{INDEX}` in remote cache for: {}", key.0);
+                debug!("Failed to parse `{INDEX}`: {err}");
+                Index::default()
+            }),
+            None => Index::default(),
+        };
+        if index
+            .wheels
+            .iter()
+            .any(|entry| entry.filename == disk_filename && entry.sha256 == digest)
+        {
+            return Ok(());
+        }
+        index.wheels.retain(|entry| entry.filename != disk_filename);
+        index.wheels.push(IndexEntry {
+            filename: disk_filename.to_string(),
+            sha256: digest.clone(),
+        });
+
+        // Upload the wheel before the index, such that the index never refers to a missing wheel.
+        if let Some(wheel) = wheel.take() {
+            debug!("Uploading built wheel to remote cache: {disk_filename}");
+            write(remote, &key.join(disk_filename), wheel, None, client).await?;
+        }
+
+        let precondition = match (etag, exists) {
+            (Some(etag), _) => Some(Precondition::Matches(etag)),
+            (None, false) => Some(Precondition::Missing),
+            // Without an `ETag`, there's no way to detect concurrent modifications.
+            (None, true) => None,
+        };
+        match write(
+            remote,
+            &key.join(INDEX),
+            serde_json::to_vec_pretty(&index)?,
+            precondition,
+            client,
+        )
+        .await
+        {
+            Ok(()) => return Ok(()),
+            Err(RemoteError::PreconditionFailed) => {
+                debug!("`{INDEX}` in remote cache was modified concurrently, retrying");
+            }
+            Err(err) => return Err(err),
+        }
+    }
+
+    Err(RemoteError::Contended)
+}
+
+/// A precondition for writing a file to the remote cache.
+#[derive(Debug)]
+enum Precondition {
+    /// The file must match the given `ETag`.
+    Matches(HeaderValue),
+    /// The file must not exist.
+    Missing,
+}
+
+/// Read a file from the remote cache, returning `None` if it doesn't exist.
+async fn read(
+    remote: &RemoteCache,
+    path: &str,
+    client: &ManagedClient<'_>,
+) -> Result<Option<Vec<u8>>, RemoteError> {
+    Ok(read_versioned(remote, path, client).await?.0)
+}
+
+/// Read a file from the remote cache, returning `None` if it doesn't exist, alongside its `ETag`,
+/// if the remote cache provided one.
+async fn read_versioned(
+    remote: &RemoteCache,
+    path: &str,
+    client: &ManagedClient<'_>,
+) -> Result<(Option<Vec<u8>>, Option<HeaderValue>), RemoteError> {
+    if is_offline(remote, client) {
+        return Ok((None, None));
+    }
+    match remote {
+        RemoteCache::Url(base) => {
+            let url = join_url(base, path);
+            let response = client
+                .managed(|client| async {
+                    client.uncached_client(&url).get(url.clone()).send().await
+                })
+                .await?;
+            if response.status() == StatusCode::NOT_FOUND {
+                return Ok((None, None));
+            }
+            let response = response.error_for_status()?;
+            let etag = response.headers().get(ETAG).cloned();
+            let bytes = response.bytes().await?;
+            Ok((Some(bytes.to_vec()), etag))
+        }
+        RemoteCache::Path(base) => match fs::read(join_path(base, path)).await {
+            Ok(contents) => Ok((Some(contents), None)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok((None, None)),
+            Err(err) => Err(err.into()),
+        },
+    }
+}
+
+/// Write a file to the remote cache.
+///
+/// For HTTP servers, the [`Precondition`] (if any) is sent as a conditional request header, and
+/// [`RemoteError::PreconditionFailed`] is returned if it isn't met. Shared directories are locked
+/// by the caller instead, so the precondition is ignored.
+async fn write(
+    remote: &RemoteCache,
+    path: &str,
+    contents: Vec<u8>,
+    precondition: Option<Precondition>,
+    client: &ManagedClient<'_>,
+) -> Result<(), RemoteError> {
+    match remote {
+        RemoteCache::Url(base) => {
+            let url = join_url(base, path);
+            let response = client
+                .managed(|client| async {
+                    let request = client.uncached_client(&url).put(url.clone());
+                    let request = match &precondition {
+                        Some(Precondition::Matches(etag)) => request.header(IF_MATCH, etag),
+                        Some(Precondition::Missing) => {
+                            request.header(IF_NONE_MATCH, HeaderValue::from_static("*"))
+                        }
+                        None => request,
+                    };
+                    request.body(contents).send().await
+                })
+                .await?;
+            if response.status() == StatusCode::PRECONDITION_FAILED {
+                return Err(RemoteError::PreconditionFailed);
+            }
+            response.error_for_status()?;
+            Ok(())
+        }
+        RemoteCache::Path(base) => {
+            let path = join_path(base, path);
+            fs::create_dir_all(path.parent().expect("Remote cache entry to have parent")).await?;
+            write_atomic(path, contents).await?;
+            Ok(())
+        }
+    }
+}
+
+/// Returns `true` if the remote cache is unreachable, as the client is offline.
+fn is_offline(remote: &RemoteCache, client: &ManagedClient<'_>) -> bool {
+    matches!(remote, RemoteCache::Url(_))
+        && matches!(client.unmanaged.connectivity(), Connectivity::Offline)
+}
+
+/// Join a relative path (with `/` separators) onto the URL of a remote cache.
+fn join_url(base: &Url, path: &str) -> Url {
+    let mut url = base.clone();
+    url.path_segments_mut()
+        .expect("HTTP URLs can be a base")
+        .pop_if_empty()
+        .extend(path.split('/'));
+    url
+}
+
+/// Join a relative path (with `/` separators) onto the directory of a remote cache.
+fn join_path(base: &Path, path: &str) -> PathBuf {
+    path.split('/')
+        .fold(base.to_path_buf(), |acc, segment| acc.join(segment))
+}
+
+/// Compute the hex-encoded SHA-256 digest of the given contents.
+fn sha256(contents: &[u8]) -> String {
+    let mut hasher = Hasher::from(HashAlgorithm::Sha256);
+    hasher.update(contents);
+    HashDigest::from(hasher).digest.into_string()
+}
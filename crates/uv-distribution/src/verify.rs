@@ -0,0 +1,305 @@
+//! Verify the integrity of the wheels in the cache (e.g., via `uv cache verify`), to detect entries
+//! that were corrupted or only partially written (e.g., after the disk filled up).
+
+use std::ffi::OsStr;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use tracing::debug;
+
+use uv_cache::{rm_rf, Cache, CacheBucket, Removal};
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+
+use crate::{Error, HttpArchivePointer, LocalArchivePointer};
+
+/// The kind of an integrity issue in the cache.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CacheIssueKind {
+    /// An unzipped wheel whose contents don't match its `RECORD` file.
+    CorruptArchive,
+    /// A pointer to an unzipped wheel that can't be read, or that records invalid hashes.
+    InvalidPointer,
+    /// A pointer or link to an unzipped wheel that's missing or corrupt.
+    DanglingPointer,
+    /// An unzipped wheel that isn't referenced by any pointer or link.
+    ///
+    /// Unreferenced unzipped wheels are harmless (and may be the result of a concurrent write),
+    /// so they're reported for information only, and removed by `uv cache prune` rather than
+    /// repaired.
+    OrphanedArchive,
+    /// A temporary file or directory, left behind by an interrupted write.
+    PartialEntry,
+}
+
+impl CacheIssueKind {
+    /// Returns the kind as a string, for display.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::CorruptArchive => "corrupt-archive",
+            Self::InvalidPointer => "invalid-pointer",
+            Self::DanglingPointer => "dangling-pointer",
+            Self::OrphanedArchive => "orphaned-archive",
+            Self::PartialEntry => "partial-entry",
+        }
+    }
+
+    /// Returns `true` if the issue affects the integrity of the cache, and should be repaired.
+    pub fn is_error(self) -> bool {
+        !matches!(self, Self::OrphanedArchive)
+    }
+}
+
+impl Display for CacheIssueKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An integrity issue in the cache.
+#[derive(Debug, Clone)]
+pub struct CacheIssue {
+    /// The kind of the issue.
+    pub kind: CacheIssueKind,
+    /// The absolute path to the affected entry.
+    pub path: PathBuf,
+    /// A human-readable description of the issue.
+    pub reason: String,
+}
+
+/// Verify the integrity of the wheels in the cache.
+///
+/// Every unzipped wheel in the archive bucket is checked with the provided `verify_archive`
+/// function (e.g., against its `RECORD` file), and every pointer and link in the wheel bucket is
+/// checked to refer to a valid unzipped wheel. Any unreferenced unzipped wheels, and any temporary
+/// entries left behind by interrupted writes, are reported too.
+pub fn verify<E: Display>(
+    cache: &Cache,
+    verify_archive: impl Fn(&Path) -> Result<(), E>,
+) -> Result<Vec<CacheIssue>, Error> {
+    let mut issues = Vec::new();
+
+    // First, verify the unzipped wheels.
+    //
+    // Paths are canonicalized, to match the targets of any links.
+    let mut archives = FxHashMap::default();
+    let mut corrupt = FxHashSet::default();
+    let archive_bucket = cache.bucket(CacheBucket::Archive);
+    match fs_err::read_dir(&archive_bucket) {
+        Ok(entries) => {
+            for entry in entries {
+                let entry = entry.map_err(Error::CacheRead)?;
+                let path = entry.path();
+                if is_temporary(&path) || !path.is_dir() {
+                    issues.push(CacheIssue {
+                        kind: CacheIssueKind::PartialEntry,
+                        path,
+                        reason: "Incomplete entry".to_string(),
+                    });
+                    continue;
+                }
+                debug!("Verifying unzipped wheel: {}", path.display());
+                let canonical = fs_err::canonicalize(&path).map_err(Error::CacheRead)?;
+                if let Err(err) = verify_archive(&path) {
+                    issues.push(CacheIssue {
+                        kind: CacheIssueKind::CorruptArchive,
+                        path: path.clone(),
+                        reason: err.to_string(),
+                    });
+                    corrupt.insert(canonical.clone());
+                }
+                archives.insert(canonical, path);
+            }
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => return Err(Error::CacheRead(err)),
+    }
+
+    // Second, verify the pointers and links that refer to the unzipped wheels.
+    let canonical_archive_bucket =
+        fs_err::canonicalize(&archive_bucket).unwrap_or_else(|_| archive_bucket.clone());
+    let mut references = FxHashSet::default();
+    for bucket in CacheBucket::iter() {
+        if bucket == CacheBucket::Archive {
+            continue;
+        }
+        let bucket_path = cache.bucket(bucket);
+        if !bucket_path.is_dir() {
+            continue;
+        }
+
+        let mut walker = walkdir::WalkDir::new(&bucket_path).into_iter();
+        while let Some(entry) = walker.next() {
+            let entry = entry.map_err(|err| Error::CacheRead(err.into()))?;
+            let path = entry.path();
+
+            if bucket == CacheBucket::Wheels && is_temporary(path) {
+                issues.push(CacheIssue {
+                    kind: CacheIssueKind::PartialEntry,
+                    path: path.to_path_buf(),
+                    reason: "Incomplete entry".to_string(),
+                });
+                if entry.file_type().is_dir() {
+                    walker.skip_current_dir();
+                }
+                continue;
+            }
+
+            if entry.file_type().is_symlink() {
+                match fs_err::canonicalize(path) {
+                    // Only links into the archive bucket refer to unzipped wheels; other links
+                    // (e.g., within cached environments) are ignored.
+                    Ok(target) if target.starts_with(&canonical_archive_bucket) => {
+                        if let Some(reason) = check_reference(Some(&target), &archives, &corrupt) {
+                            issues.push(CacheIssue {
+                                kind: CacheIssueKind::DanglingPointer,
+                                path: path.to_path_buf(),
+                                reason,
+                            });
+                        } else {
+                            references.insert(target);
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(_) => {
+                        if matches!(
+                            bucket,
+                            CacheBucket::Wheels | CacheBucket::SourceDistributions
+                        ) {
+                            issues.push(CacheIssue {
+                                kind: CacheIssueKind::DanglingPointer,
+                                path: path.to_path_buf(),
+                                reason: "Refers to a missing unzipped wheel".to_string(),
+                            });
+                        }
+                    }
+                }
+                continue;
+            }
+
+            if bucket != CacheBucket::Wheels || !entry.file_type().is_file() {
+                continue;
+            }
+
+            let archive = match path.extension().and_then(OsStr::to_str) {
+                Some("http") => HttpArchivePointer::read_from(path)
+                    .map(|pointer| pointer.map(HttpArchivePointer::into_archive)),
+                Some("rev") => LocalArchivePointer::read_from(path)
+                    .map(|pointer| pointer.map(LocalArchivePointer::into_archive)),
+                _ => continue,
+            };
+            match archive {
+                Ok(Some(archive)) => {
+                    if let Some(reason) = check_hashes(&archive.hashes) {
+                        issues.push(CacheIssue {
+                            kind: CacheIssueKind::InvalidPointer,
+                            path: path.to_path_buf(),
+                            reason,
+                        });
+                        continue;
+                    }
+                    let target = fs_err::canonicalize(archive_bucket.join(&archive.id)).ok();
+                    if let Some(reason) = check_reference(target.as_deref(), &archives, &corrupt) {
+                        issues.push(CacheIssue {
+                            kind: CacheIssueKind::DanglingPointer,
+                            path: path.to_path_buf(),
+                            reason,
+                        });
+                    } else if let Some(target) = target {
+                        references.insert(target);
+                    }
+                }
+                Ok(None) => {}
+                Err(err) => issues.push(CacheIssue {
+                    kind: CacheIssueKind::InvalidPointer,
+                    path: path.to_path_buf(),
+                    reason: err.to_string(),
+                }),
+            }
+        }
+    }
+
+    // Third, detect any unzipped wheels that aren't referenced.
+    for (canonical, path) in archives {
+        if !corrupt.contains(&canonical) && !references.contains(&canonical) {
+            issues.push(CacheIssue {
+                kind: CacheIssueKind::OrphanedArchive,
+                path,
+                reason: "Not referenced by any cache entry".to_string(),
+            });
+        }
+    }
+
+    issues.sort_by(|a, b| a.kind.cmp(&b.kind).then_with(|| a.path.cmp(&b.path)));
+    Ok(issues)
+}
+
+/// Remove the entries affected by the given issues from the cache, such that they're re-fetched
+/// (or rebuilt) when next needed.
+///
+/// Informational issues (like unreferenced unzipped wheels) are skipped. The caller is expected to
+/// hold the cache lock (see [`Cache::lock_blocking`]).
+pub fn repair(issues: &[CacheIssue]) -> Result<Removal, Error> {
+    let mut summary = Removal::default();
+    for issue in issues.iter().filter(|issue| issue.kind.is_error()) {
+        debug!(
+            "Removing cache entry ({}): {}",
+            issue.kind,
+            issue.path.display()
+        );
+        summary += rm_rf(&issue.path).map_err(Error::CacheWrite)?;
+    }
+    Ok(summary)
+}
+
+/// Check that a reference points to a valid unzipped wheel, returning the reason if not.
+fn check_reference(
+    target: Option<&Path>,
+    archives: &FxHashMap<PathBuf, PathBuf>,
+    corrupt: &FxHashSet<PathBuf>,
+) -> Option<String> {
+    match target {
+        Some(target) if corrupt.contains(target) => Some(format!(
+            "Refers to a corrupt unzipped wheel: {}",
+            target.display()
+        )),
+        Some(target) if archives.contains_key(target) => None,
+        Some(target) => Some(format!(
+            "Refers to a missing unzipped wheel: {}",
+            target.display()
+        )),
+        None => Some("Refers to a missing unzipped wheel".to_string()),
+    }
+}
+
+/// Check that the hashes recorded in a pointer are well-formed, returning the reason if not.
+///
+/// The recorded hashes are used to satisfy `--require-hashes` without re-reading the wheel, so a
+/// corrupt hash would otherwise surface as a spurious hash mismatch on install.
+fn check_hashes(hashes: &[HashDigest]) -> Option<String> {
+    hashes.iter().find_map(|hash| {
+        let len = match hash.algorithm {
+            HashAlgorithm::Md5 => 32,
+            HashAlgorithm::Sha256 => 64,
+            HashAlgorithm::Sha384 => 96,
+            HashAlgorithm::Sha512 => 128,
+        };
+        if hash.digest.len() == len
+            && hash
+                .digest
+                .bytes()
+                .all(|byte| matches!(byte, b'0'..=b'9' | b'a'..=b'f'))
+        {
+            None
+        } else {
+            Some(format!("Records an invalid hash: `{hash}`"))
+        }
+    })
+}
+
+/// Returns `true` if the path is a temporary entry (as created by `tempfile`).
+fn is_temporary(path: &Path) -> bool {
+    path.file_name()
+        .and_then(OsStr::to_str)
+        .is_some_and(|name| name.starts_with(".tmp"))
+}
@@ -14,6 +14,7 @@ use reqwest_middleware::ClientWithMiddleware;
 use tracing::{debug, warn};
 use url::Url;
 
+use uv_configuration::HostTls;
 use uv_fs::Simplified;
 use uv_static::EnvVars;
 use uv_version::version;
@@ -304,6 +305,7 @@ impl GitRemote {
         locked_rev: Option<GitOid>,
         client: &ClientWithMiddleware,
         disable_ssl: bool,
+        tls: Option<&HostTls>,
     ) -> Result<(GitDatabase, GitOid)> {
         let reference = locked_rev
             .map(ReferenceOrOid::Oid)
@@ -311,7 +313,7 @@ impl GitRemote {
         let enable_lfs_fetch = env::var(EnvVars::UV_GIT_LFS).is_ok();
 
         if let Some(mut db) = db {
-            fetch(&mut db.repo, &self.url, reference, client, disable_ssl)
+            fetch(&mut db.repo, &self.url, reference, client, disable_ssl, tls)
                 .with_context(|| format!("failed to fetch into: {}", into.user_display()))?;
 
             let resolved_commit_hash = match locked_rev {
@@ -321,7 +323,7 @@ impl GitRemote {
 
             if let Some(rev) = resolved_commit_hash {
                 if enable_lfs_fetch {
-                    fetch_lfs(&mut db.repo, &self.url, &rev, disable_ssl)
+                    fetch_lfs(&mut db.repo, &self.url, &rev, disable_ssl, tls)
                         .with_context(|| format!("failed to fetch LFS objects at {rev}"))?;
                 }
                 return Ok((db, rev));
@@ -339,14 +341,14 @@ impl GitRemote {
 
         fs_err::create_dir_all(into)?;
         let mut repo = GitRepository::init(into)?;
-        fetch(&mut repo, &self.url, reference, client, disable_ssl)
+        fetch(&mut repo, &self.url, reference, client, disable_ssl, tls)
             .with_context(|| format!("failed to clone into: {}", into.user_display()))?;
         let rev = match locked_rev {
             Some(rev) => rev,
             None => reference.resolve(&repo)?,
         };
         if enable_lfs_fetch {
-            fetch_lfs(&mut repo, &self.url, &rev, disable_ssl)
+            fetch_lfs(&mut repo, &self.url, &rev, disable_ssl, tls)
                 .with_context(|| format!("failed to fetch LFS objects at {rev}"))?;
         }
 
@@ -515,6 +517,7 @@ fn fetch(
     reference: ReferenceOrOid<'_>,
     client: &ClientWithMiddleware,
     disable_ssl: bool,
+    tls: Option<&HostTls>,
 ) -> Result<()> {
     let oid_to_fetch = match github_fast_path(repo, remote_url, reference, client) {
         Ok(FastPathRev::UpToDate) => return Ok(()),
@@ -590,9 +593,14 @@ fn fetch(
 
     debug!("Performing a Git fetch for: {remote_url}");
     let result = match refspec_strategy {
-        RefspecStrategy::All => {
-            fetch_with_cli(repo, remote_url, refspecs.as_slice(), tags, disable_ssl)
-        }
+        RefspecStrategy::All => fetch_with_cli(
+            repo,
+            remote_url,
+            refspecs.as_slice(),
+            tags,
+            disable_ssl,
+            tls,
+        ),
         RefspecStrategy::First => {
             // Try each refspec
             let mut errors = refspecs
@@ -604,6 +612,7 @@ fn fetch(
                         std::slice::from_ref(refspec),
                         tags,
                         disable_ssl,
+                        tls,
                     );
 
                     // Stop after the first success and log failures
@@ -650,6 +659,7 @@ fn fetch_with_cli(
     refspecs: &[String],
     tags: bool,
     disable_ssl: bool,
+    tls: Option<&HostTls>,
 ) -> Result<()> {
     let mut cmd = ProcessBuilder::new(GIT.as_ref()?);
     cmd.arg("fetch");
@@ -659,6 +669,9 @@ fn fetch_with_cli(
     if disable_ssl {
         debug!("Disabling SSL verification for Git fetch");
         cmd.env(EnvVars::GIT_SSL_NO_VERIFY, "true");
+    } else if let Some(tls) = tls {
+        debug!("Using custom TLS settings for Git fetch");
+        configure_tls(&mut cmd, tls);
     }
     cmd.arg("--force") // handle force pushes
         .arg("--update-head-ok") // see discussion in #2078
@@ -685,6 +698,24 @@ fn fetch_with_cli(
     Ok(())
 }
 
+/// Apply the custom TLS settings for a host to a `git` command.
+///
+/// Git reads the CA bundle and client certificate for HTTPS remotes from the environment, which
+/// takes precedence over any `http.sslCAInfo` or `http.sslCert` in the user's Git configuration.
+fn configure_tls(cmd: &mut ProcessBuilder, tls: &HostTls) {
+    if let Some(ca_cert) = &tls.ca_cert {
+        cmd.env(EnvVars::GIT_SSL_CAINFO, ca_cert);
+    }
+    if let Some(client_cert) = &tls.client_cert {
+        cmd.env(EnvVars::GIT_SSL_CERT, client_cert);
+        // If the private key is stored alongside the certificate, Git reads it from the same file.
+        cmd.env(
+            EnvVars::GIT_SSL_KEY,
+            tls.client_key.as_ref().unwrap_or(client_cert),
+        );
+    }
+}
+
 /// A global cache of the `git lfs` command.
 ///
 /// Returns an error if Git LFS isn't available.
@@ -704,6 +735,7 @@ fn fetch_lfs(
     url: &Url,
     revision: &GitOid,
     disable_ssl: bool,
+    tls: Option<&HostTls>,
 ) -> Result<()> {
     let mut cmd = if let Ok(lfs) = GIT_LFS.as_ref() {
         debug!("Fetching Git LFS objects");
@@ -717,6 +749,9 @@ fn fetch_lfs(
     if disable_ssl {
         debug!("Disabling SSL verification for Git LFS");
         cmd.env(EnvVars::GIT_SSL_NO_VERIFY, "true");
+    } else if let Some(tls) = tls {
+        debug!("Using custom TLS settings for Git LFS");
+        configure_tls(&mut cmd, tls);
     }
 
     cmd.arg("fetch")
@@ -1,16 +1,19 @@
 use std::borrow::Cow;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 
 use tracing::debug;
 
-use crate::{Fetch, GitHubRepository, GitOid, GitReference, GitSource, GitUrl, Reporter};
+use crate::{
+    Fetch, GitHubRepository, GitOid, GitReference, GitSource, GitUrl, Reporter, GIT_STORE,
+};
 use dashmap::mapref::one::Ref;
 use dashmap::DashMap;
 use fs_err::tokio as fs;
 use reqwest_middleware::ClientWithMiddleware;
 use uv_cache_key::{cache_digest, RepositoryUrl};
+use uv_configuration::{HostTls, KeyringProviderType, MissingCredentialHelper};
 use uv_fs::LockedFile;
 use uv_version::version;
 
@@ -26,6 +29,8 @@ pub enum GitResolverError {
     Reqwest(#[from] reqwest::Error),
     #[error(transparent)]
     ReqwestMiddleware(#[from] reqwest_middleware::Error),
+    #[error(transparent)]
+    MissingCredentialHelper(#[from] MissingCredentialHelper),
 }
 
 /// A resolver for Git repositories.
@@ -107,6 +112,9 @@ impl GitResolver {
         url: &GitUrl,
         client: ClientWithMiddleware,
         disable_ssl: bool,
+        tls: Option<HostTls>,
+        keyring: KeyringProviderType,
+        credential_helper: Option<&Path>,
         cache: PathBuf,
         reporter: Option<Arc<dyn Reporter>>,
     ) -> Result<Fetch, GitResolverError> {
@@ -134,6 +142,31 @@ impl GitResolver {
         )
         .await?;
 
+        // If a credential helper is configured, query it for credentials for the repository.
+        if matches!(url.repository().scheme(), "http" | "https")
+            && GIT_STORE.get(&repository_url).is_none()
+        {
+            if let Some(keyring) = keyring
+                .to_provider(credential_helper)?
+                .filter(|keyring| !keyring.requires_username())
+            {
+                if let Some(credentials) = keyring.fetch_without_username(url.repository()).await {
+                    if credentials.token().is_some() {
+                        debug!(
+                            "Ignoring bearer token from credential helper for Git repository: {}",
+                            url.repository()
+                        );
+                    } else {
+                        debug!(
+                            "Using credentials from credential helper for Git repository: {}",
+                            url.repository()
+                        );
+                        GIT_STORE.insert(repository_url.clone(), credentials);
+                    }
+                }
+            }
+        }
+
         // Fetch the Git repository.
         let source = if let Some(reporter) = reporter {
             GitSource::new(url.as_ref().clone(), client, cache).with_reporter(reporter)
@@ -148,6 +181,13 @@ impl GitResolver {
             source
         };
 
+        // If necessary, apply the custom TLS settings for the host.
+        let source = if let Some(tls) = tls {
+            source.with_tls(tls)
+        } else {
+            source
+        };
+
         let fetch = tokio::task::spawn_blocking(move || source.fetch())
             .await?
             .map_err(GitResolverError::Git)?;
@@ -12,6 +12,7 @@ use tracing::{debug, instrument};
 use url::Url;
 
 use uv_cache_key::{cache_digest, RepositoryUrl};
+use uv_configuration::HostTls;
 
 use crate::git::GitRemote;
 use crate::{GitOid, GitUrl, GIT_STORE};
@@ -24,6 +25,8 @@ pub struct GitSource {
     client: ClientWithMiddleware,
     /// Whether to disable SSL verification.
     disable_ssl: bool,
+    /// The custom TLS settings for the repository's host, if any.
+    tls: Option<HostTls>,
     /// The path to the Git source database.
     cache: PathBuf,
     /// The reporter to use for this source.
@@ -40,6 +43,7 @@ impl GitSource {
         Self {
             git,
             disable_ssl: false,
+            tls: None,
             client: client.into(),
             cache: cache.into(),
             reporter: None,
@@ -55,6 +59,15 @@ impl GitSource {
         }
     }
 
+    /// Set the custom TLS settings to use for this [`GitSource`].
+    #[must_use]
+    pub fn with_tls(self, tls: HostTls) -> Self {
+        Self {
+            tls: Some(tls),
+            ..self
+        }
+    }
+
     /// Set the [`Reporter`] to use for the [`GitSource`].
     #[must_use]
     pub fn with_reporter(self, reporter: Arc<dyn Reporter>) -> Self {
@@ -109,6 +122,7 @@ impl GitSource {
                     locked_rev.map(GitOid::from),
                     &self.client,
                     self.disable_ssl,
+                    self.tls.as_ref(),
                 )?;
 
                 (db, actual_rev, task)
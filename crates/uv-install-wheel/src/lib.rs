@@ -16,7 +16,7 @@ use uv_pypi_types::Scheme;
 pub use install::install_wheel;
 pub use linker::{LinkMode, Locks};
 pub use uninstall::{uninstall_egg, uninstall_legacy_editable, uninstall_wheel, Uninstall};
-pub use wheel::{parse_wheel_file, read_record_file, LibKind};
+pub use wheel::{parse_wheel_file, read_record_file, verify_record, LibKind};
 
 mod install;
 mod linker;
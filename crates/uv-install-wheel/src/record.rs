@@ -11,6 +11,5 @@ use serde::{Deserialize, Serialize};
 pub struct RecordEntry {
     pub path: String,
     pub hash: Option<String>,
-    #[allow(dead_code)]
     pub size: Option<u64>,
 }
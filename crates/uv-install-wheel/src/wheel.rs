@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 use std::io;
 use std::io::{BufReader, Read, Seek, Write};
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 use data_encoding::BASE64URL_NOPAD;
 use fs_err as fs;
@@ -730,6 +730,62 @@ pub fn read_record_file(record: &mut impl Read) -> Result<Vec<RecordEntry>, Erro
         .collect()
 }
 
+/// Verify the contents of an unzipped wheel (e.g., in the cache) against its `RECORD` file.
+///
+/// Returns an error if the `RECORD` file is missing or invalid, or if any recorded file is missing
+/// or doesn't match its recorded size or hash. Entries without a hash (like the `RECORD` file
+/// itself), and entries outside of the wheel (like scripts), are skipped.
+pub fn verify_record(wheel: &Path) -> Result<(), Error> {
+    let dist_info_prefix = find_dist_info(wheel)?;
+    let record_path = wheel
+        .join(format!("{dist_info_prefix}.dist-info"))
+        .join("RECORD");
+    let mut record_file = match File::open(&record_path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Err(Error::InvalidWheel("Missing RECORD file".to_string()));
+        }
+        Err(err) => return Err(err.into()),
+    };
+    let record = read_record_file(&mut record_file)?;
+
+    for entry in record {
+        let Some(hash) = entry.hash.filter(|hash| !hash.is_empty()) else {
+            continue;
+        };
+        if Path::new(&entry.path)
+            .components()
+            .any(|component| !matches!(component, Component::Normal(_)))
+        {
+            continue;
+        }
+
+        let mut file = match File::open(wheel.join(&entry.path)) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Err(Error::RecordFile(format!("Missing file: {}", entry.path)));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let (size, actual) = copy_and_hash(&mut file, &mut io::sink())?;
+        if entry.size.is_some_and(|expected| expected != size) {
+            return Err(Error::RecordFile(format!(
+                "Size mismatch for: {}",
+                entry.path
+            )));
+        }
+        // Only SHA-256 digests are verified, which is what's used by (nearly) all wheels.
+        if hash.starts_with("sha256=") && hash != actual {
+            return Err(Error::RecordFile(format!(
+                "Hash mismatch for: {}",
+                entry.path
+            )));
+        }
+    }
+
+    Ok(())
+}
+
 /// Parse a file with email message format such as WHEEL and METADATA
 fn parse_email_message_file(
     file: impl Read,
@@ -181,6 +181,13 @@ fn install(
         )
         .with_context(|| format!("Failed to install: {} ({wheel})", wheel.filename()))?;
 
+        // Record the access, to retain recently-used wheels when evicting from the cache. Wheels
+        // that are symlinked into the environment must never be evicted.
+        uv_cache::touch(wheel.path());
+        if link_mode == LinkMode::Symlink {
+            uv_cache::pin(wheel.path());
+        }
+
         if let Some(reporter) = reporter.as_ref() {
             reporter.on_install_progress(wheel);
         }
@@ -0,0 +1,229 @@
+//! Discover and validate [PEP 740](https://peps.python.org/pep-0740/) attestations for upload.
+
+use std::path::{Path, PathBuf};
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+use thiserror::Error;
+use tracing::debug;
+
+use uv_distribution_filename::DistFilename;
+use uv_fs::Simplified;
+
+/// The suffix of attestation files for the publish attestation, e.g.,
+/// `foo-1.0.0-py3-none-any.whl.publish.attestation`.
+const PUBLISH_ATTESTATION_SUFFIX: &str = ".publish.attestation";
+
+#[derive(Debug, Error)]
+pub enum AttestationError {
+    #[error("Failed to read attestation: `{}`", _0.user_display())]
+    Read(PathBuf, #[source] std::io::Error),
+    #[error("Invalid attestation: `{}`", _0.user_display())]
+    Json(PathBuf, #[source] serde_json::Error),
+    #[error("The statement of the attestation `{}` is not valid base64", _0.user_display())]
+    Base64(PathBuf, #[source] base64::DecodeError),
+    #[error("The statement of the attestation `{}` is not a valid in-toto statement", _0.user_display())]
+    Statement(PathBuf, #[source] serde_json::Error),
+    #[error("The attestation `{}` must have exactly one subject, found {1}", _0.user_display())]
+    SubjectCount(PathBuf, usize),
+    #[error("The subject of the attestation `{}` is `{1}`, but the file is `{2}`", _0.user_display())]
+    SubjectName(PathBuf, String, DistFilename),
+    #[error("The attestation `{}` has no SHA-256 digest for its subject", _0.user_display())]
+    MissingDigest(PathBuf),
+    #[error("The SHA-256 digest of the attestation `{}` doesn't match the file. Attestation: {1}, file: {2}", _0.user_display())]
+    DigestMismatch(PathBuf, String, String),
+}
+
+/// A PEP 740 attestation, of which we only read the parts required for validation.
+#[derive(Deserialize)]
+struct Attestation {
+    envelope: Envelope,
+}
+
+#[derive(Deserialize)]
+struct Envelope {
+    /// The base64-encoded in-toto statement.
+    statement: String,
+}
+
+/// An [in-toto statement](https://github.com/in-toto/attestation/blob/main/spec/v1/statement.md).
+#[derive(Deserialize)]
+struct Statement {
+    subject: Vec<Subject>,
+}
+
+#[derive(Deserialize)]
+struct Subject {
+    name: String,
+    digest: FxHashMap<String, String>,
+}
+
+/// Find the publish attestation for a distribution, if any.
+///
+/// The attestation is named `<filename>.publish.attestation`, and is searched for in the
+/// attestations directory if provided, and next to the distribution otherwise.
+pub fn find_attestation(
+    file: &Path,
+    raw_filename: &str,
+    attestations_dir: Option<&Path>,
+) -> Option<PathBuf> {
+    let dir = attestations_dir.or_else(|| file.parent())?;
+    let attestation = dir.join(format!("{raw_filename}{PUBLISH_ATTESTATION_SUFFIX}"));
+    if attestation.is_file() {
+        debug!("Found attestation: `{}`", attestation.user_display());
+        Some(attestation)
+    } else {
+        None
+    }
+}
+
+/// Read an attestation and check that its subject is the file being uploaded.
+///
+/// Returns the `attestations` form field, a JSON array with the attestation.
+pub(crate) async fn read_attestation(
+    attestation: &Path,
+    filename: &DistFilename,
+    sha256: &str,
+) -> Result<String, AttestationError> {
+    let contents = fs_err::tokio::read(attestation)
+        .await
+        .map_err(|err| AttestationError::Read(attestation.to_path_buf(), err))?;
+    // Keep the attestation as-is, we only parse it for validation.
+    let value: serde_json::Value = serde_json::from_slice(&contents)
+        .map_err(|err| AttestationError::Json(attestation.to_path_buf(), err))?;
+    let parsed: Attestation = serde_json::from_value(value.clone())
+        .map_err(|err| AttestationError::Json(attestation.to_path_buf(), err))?;
+    validate_subject(attestation, &parsed, filename, sha256)?;
+    Ok(serde_json::Value::Array(vec![value]).to_string())
+}
+
+/// Check that the attestation has exactly one subject, which matches the filename and the
+/// SHA-256 digest of the file.
+fn validate_subject(
+    path: &Path,
+    attestation: &Attestation,
+    filename: &DistFilename,
+    sha256: &str,
+) -> Result<(), AttestationError> {
+    let statement = BASE64_STANDARD
+        .decode(&attestation.envelope.statement)
+        .map_err(|err| AttestationError::Base64(path.to_path_buf(), err))?;
+    let statement: Statement = serde_json::from_slice(&statement)
+        .map_err(|err| AttestationError::Statement(path.to_path_buf(), err))?;
+
+    let [subject] = statement.subject.as_slice() else {
+        return Err(AttestationError::SubjectCount(
+            path.to_path_buf(),
+            statement.subject.len(),
+        ));
+    };
+    // Compare the parsed filenames, since the subject name may not be normalized.
+    if DistFilename::try_from_normalized_filename(&subject.name).as_ref() != Some(filename) {
+        return Err(AttestationError::SubjectName(
+            path.to_path_buf(),
+            subject.name.clone(),
+            filename.clone(),
+        ));
+    }
+    let Some(digest) = subject.digest.get("sha256") else {
+        return Err(AttestationError::MissingDigest(path.to_path_buf()));
+    };
+    if !digest.eq_ignore_ascii_case(sha256) {
+        return Err(AttestationError::DigestMismatch(
+            path.to_path_buf(),
+            digest.clone(),
+            sha256.to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use base64::prelude::BASE64_STANDARD;
+    use base64::Engine;
+    use serde_json::json;
+
+    use uv_distribution_filename::DistFilename;
+
+    use super::{find_attestation, read_attestation, AttestationError};
+
+    const SHA256: &str = "89fa05cffa7f457658373b85de302d24d0c205ceda2819a8739e324b75e9430b";
+
+    fn write_attestation(dir: &Path, raw_filename: &str, subject: &str, sha256: &str) {
+        let statement = json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": [{"name": subject, "digest": {"sha256": sha256}}],
+            "predicateType": "https://docs.pypi.org/attestations/publish/v1",
+            "predicate": null,
+        });
+        let attestation = json!({
+            "version": 1,
+            "verification_material": {"certificate": "", "transparency_entries": []},
+            "envelope": {
+                "statement": BASE64_STANDARD.encode(statement.to_string()),
+                "signature": "",
+            },
+        });
+        fs_err::write(
+            dir.join(format!("{raw_filename}.publish.attestation")),
+            attestation.to_string(),
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn valid_attestation() {
+        let dir = tempfile::tempdir().unwrap();
+        let raw_filename = "tqdm-999.0.0.tar.gz";
+        let filename = DistFilename::try_from_normalized_filename(raw_filename).unwrap();
+        write_attestation(dir.path(), raw_filename, raw_filename, SHA256);
+
+        let attestation =
+            find_attestation(&dir.path().join(raw_filename), raw_filename, None).unwrap();
+        let field = read_attestation(&attestation, &filename, SHA256)
+            .await
+            .unwrap();
+        let field: serde_json::Value = serde_json::from_str(&field).unwrap();
+        assert_eq!(field.as_array().unwrap().len(), 1);
+        assert_eq!(field[0]["version"], 1);
+    }
+
+    #[test]
+    fn attestations_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let raw_filename = "tqdm-999.0.0.tar.gz";
+        write_attestation(dir.path(), raw_filename, raw_filename, SHA256);
+
+        let file = Path::new("../../scripts/links").join(raw_filename);
+        assert_eq!(find_attestation(&file, raw_filename, None), None);
+        assert_eq!(
+            find_attestation(&file, raw_filename, Some(dir.path())),
+            Some(dir.path().join("tqdm-999.0.0.tar.gz.publish.attestation"))
+        );
+    }
+
+    #[tokio::test]
+    async fn subject_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let raw_filename = "tqdm-999.0.0.tar.gz";
+        let filename = DistFilename::try_from_normalized_filename(raw_filename).unwrap();
+        let attestation = dir.path().join("tqdm-999.0.0.tar.gz.publish.attestation");
+
+        write_attestation(dir.path(), raw_filename, "tqdm-998.0.0.tar.gz", SHA256);
+        let err = read_attestation(&attestation, &filename, SHA256)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AttestationError::SubjectName(..)), "{err}");
+
+        write_attestation(dir.path(), raw_filename, raw_filename, &"0".repeat(64));
+        let err = read_attestation(&attestation, &filename, SHA256)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AttestationError::DigestMismatch(..)), "{err}");
+    }
+}
@@ -0,0 +1,702 @@
+//! Validate distributions locally before uploading them, akin to `twine check`.
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+use base64::Engine;
+use rustc_hash::{FxHashMap, FxHashSet};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use tracing::debug;
+use zip::result::ZipError;
+use zip::ZipArchive;
+
+use uv_distribution_filename::{DistFilename, WheelFilename};
+use uv_fs::Simplified;
+use uv_normalize::{InvalidNameError, PackageName};
+use uv_pep440::{Version, VersionParseError};
+use uv_pypi_types::Metadata23;
+
+use crate::{metadata, PublishPrepareError};
+
+/// The metadata versions accepted by PyPI.
+const SUPPORTED_METADATA_VERSIONS: &[&str] =
+    &["1.0", "1.1", "1.2", "2.0", "2.1", "2.2", "2.3", "2.4"];
+
+/// The maximum length of the summary accepted by PyPI.
+const MAX_SUMMARY_LENGTH: usize = 512;
+
+/// The interpreted text roles supported by docutils, which PyPI uses to render reStructuredText.
+/// Roles added by Sphinx (e.g., `:ref:` or `:func:`) can't be rendered.
+const RST_ROLES: &[&str] = &[
+    "abbreviation",
+    "ab",
+    "acronym",
+    "ac",
+    "code",
+    "emphasis",
+    "literal",
+    "math",
+    "pep-reference",
+    "pep",
+    "rfc-reference",
+    "rfc",
+    "strong",
+    "subscript",
+    "sub",
+    "superscript",
+    "sup",
+    "title-reference",
+    "title",
+    "t",
+];
+
+/// The directives supported by docutils, excluding `include` and `raw`, which PyPI disables.
+/// Directives added by Sphinx (e.g., `.. toctree::` or `.. automodule::`) can't be rendered.
+const RST_DIRECTIVES: &[&str] = &[
+    "admonition",
+    "attention",
+    "caution",
+    "class",
+    "code",
+    "code-block",
+    "compound",
+    "container",
+    "contents",
+    "csv-table",
+    "danger",
+    "date",
+    "default-role",
+    "epigraph",
+    "error",
+    "figure",
+    "footer",
+    "header",
+    "highlights",
+    "hint",
+    "image",
+    "important",
+    "line-block",
+    "list-table",
+    "math",
+    "meta",
+    "note",
+    "parsed-literal",
+    "pull-quote",
+    "replace",
+    "role",
+    "rubric",
+    "section-numbering",
+    "sectnum",
+    "sidebar",
+    "sourcecode",
+    "table",
+    "target-notes",
+    "tip",
+    "title",
+    "topic",
+    "unicode",
+    "warning",
+];
+
+/// A problem with a distribution that would cause the upload to fail, or produce a broken release.
+#[derive(Debug, Error)]
+pub enum CheckError {
+    #[error(transparent)]
+    Prepare(#[from] PublishPrepareError),
+    #[error(transparent)]
+    InvalidName(#[from] InvalidNameError),
+    #[error("The version in the metadata is invalid: `{0}`")]
+    InvalidVersion(String, #[source] VersionParseError),
+    #[error("The package name in the filename (`{filename}`) doesn't match the package name in the metadata (`{metadata}`)")]
+    NameMismatch {
+        filename: PackageName,
+        metadata: PackageName,
+    },
+    #[error("The version in the filename (`{filename}`) doesn't match the version in the metadata (`{metadata}`)")]
+    VersionMismatch {
+        filename: Version,
+        metadata: Version,
+    },
+    #[error("Unsupported metadata version `{0}`, expected one of: {versions}", versions = SUPPORTED_METADATA_VERSIONS.join(", "))]
+    UnsupportedMetadataVersion(String),
+    #[error("The summary must be a single line")]
+    MultilineSummary,
+    #[error("The summary is {0} characters long, but may be at most {MAX_SUMMARY_LENGTH} characters long")]
+    SummaryTooLong(usize),
+    #[error("Unsupported description content type `{0}`, expected one of `text/plain`, `text/x-rst`, or `text/markdown`")]
+    UnsupportedContentType(String),
+    #[error("Unsupported description charset `{0}`, expected `UTF-8`")]
+    UnsupportedCharset(String),
+    #[error("Unsupported Markdown variant `{0}`, expected `GFM` or `CommonMark`")]
+    UnsupportedMarkdownVariant(String),
+    #[error("Failed to read the wheel")]
+    Zip(#[from] ZipError),
+    #[error("Failed to find the `.dist-info` directory")]
+    DistInfo(#[from] uv_metadata::Error),
+    #[error("The wheel is missing a `RECORD` file")]
+    MissingRecord,
+    #[error("Failed to parse the `RECORD` file")]
+    InvalidRecord(#[from] uv_install_wheel::Error),
+    #[error("`{0}` is not listed in the `RECORD` file")]
+    UnrecordedFile(String),
+    #[error("`{0}` is listed in the `RECORD` file, but is missing from the wheel")]
+    MissingRecordedFile(String),
+    #[error("The hash of `{0}` doesn't match the hash in the `RECORD` file")]
+    RecordHashMismatch(String),
+    #[error("The same file is also included as `{}`", _0.user_display())]
+    DuplicateFile(PathBuf),
+    #[error("Another source distribution for the same version is included as `{}`", _0.user_display())]
+    DuplicateSourceDist(PathBuf),
+    #[error("Failed to check the `RECORD` file")]
+    Join(#[from] tokio::task::JoinError),
+}
+
+/// A problem with a distribution that doesn't prevent the upload.
+#[derive(Debug, Error)]
+pub enum CheckWarning {
+    #[error("The metadata doesn't include a description, so the project page on the index will be empty")]
+    MissingDescription,
+    #[error("The metadata doesn't include a description content type, so the description will be rendered as reStructuredText (`text/x-rst`)")]
+    MissingContentType,
+    #[error("`{0}` has no hash in the `RECORD` file")]
+    MissingRecordHash(String),
+    #[error("The reStructuredText description can't be rendered by the index, so it will be shown as plain text (line {line}: {problem})")]
+    InvalidRst { line: usize, problem: RstProblem },
+}
+
+/// A problem that prevents a reStructuredText description from being rendered.
+#[derive(Debug, Error)]
+pub enum RstProblem {
+    #[error("the title underline is shorter than the title")]
+    TitleUnderlineTooShort,
+    #[error("unknown directive `{0}`")]
+    UnknownDirective(String),
+    #[error("unknown role `{0}`")]
+    UnknownRole(String),
+}
+
+/// The result of validating a single distribution.
+#[derive(Debug, Default)]
+pub struct CheckReport {
+    pub errors: Vec<CheckError>,
+    pub warnings: Vec<CheckWarning>,
+}
+
+impl CheckReport {
+    /// Returns `true` if the distribution passed validation, i.e., had no errors.
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Validate the files collected by [`crate::files_for_publishing`], returning a report for each
+/// file, in the same order.
+pub async fn check_files(files: &[(PathBuf, String, DistFilename)]) -> Vec<CheckReport> {
+    let mut reports = Vec::with_capacity(files.len());
+    for (file, _raw_filename, filename) in files {
+        reports.push(check_file(file, filename).await);
+    }
+
+    // Check for files that would conflict with each other on the index.
+    let mut seen_filenames = FxHashMap::default();
+    let mut seen_source_dists = FxHashMap::default();
+    for ((file, _raw_filename, filename), report) in files.iter().zip(reports.iter_mut()) {
+        if let Some(previous) = seen_filenames.insert(filename.to_string(), file) {
+            report
+                .errors
+                .push(CheckError::DuplicateFile(previous.to_path_buf()));
+        } else if let DistFilename::SourceDistFilename(source_dist) = filename {
+            if let Some(previous) =
+                seen_source_dists.insert((&source_dist.name, &source_dist.version), file)
+            {
+                report
+                    .errors
+                    .push(CheckError::DuplicateSourceDist(previous.to_path_buf()));
+            }
+        }
+    }
+
+    reports
+}
+
+/// Validate a single distribution.
+async fn check_file(file: &Path, filename: &DistFilename) -> CheckReport {
+    debug!("Checking {}", file.user_display());
+    let mut report = CheckReport::default();
+
+    let metadata = match metadata(file, filename).await {
+        Ok(metadata) => metadata,
+        Err(err) => {
+            report.errors.push(CheckError::Prepare(err));
+            return report;
+        }
+    };
+    check_metadata(&metadata, filename, &mut report);
+
+    if let DistFilename::WheelFilename(wheel) = filename {
+        let file = file.to_path_buf();
+        let wheel = wheel.clone();
+        match tokio::task::spawn_blocking(move || check_record(&file, &wheel)).await {
+            Ok(Ok((errors, warnings))) => {
+                report.errors.extend(errors);
+                report.warnings.extend(warnings);
+            }
+            Ok(Err(err)) => report.errors.push(err),
+            Err(err) => report.errors.push(CheckError::Join(err)),
+        }
+    }
+
+    report
+}
+
+/// Validate the core metadata against the filename and the requirements of PyPI.
+fn check_metadata(metadata: &Metadata23, filename: &DistFilename, report: &mut CheckReport) {
+    if !SUPPORTED_METADATA_VERSIONS.contains(&metadata.metadata_version.as_str()) {
+        report.errors.push(CheckError::UnsupportedMetadataVersion(
+            metadata.metadata_version.clone(),
+        ));
+    }
+
+    match PackageName::from_str(&metadata.name) {
+        Ok(name) if &name != filename.name() => report.errors.push(CheckError::NameMismatch {
+            filename: filename.name().clone(),
+            metadata: name,
+        }),
+        Ok(_) => {}
+        Err(err) => report.errors.push(CheckError::InvalidName(err)),
+    }
+
+    match Version::from_str(&metadata.version) {
+        Ok(version) if &version != filename.version() => {
+            report.errors.push(CheckError::VersionMismatch {
+                filename: filename.version().clone(),
+                metadata: version,
+            });
+        }
+        Ok(_) => {}
+        Err(err) => report
+            .errors
+            .push(CheckError::InvalidVersion(metadata.version.clone(), err)),
+    }
+
+    if let Some(summary) = metadata.summary.as_deref() {
+        if summary.contains('\n') {
+            report.errors.push(CheckError::MultilineSummary);
+        }
+        let length = summary.chars().count();
+        if length > MAX_SUMMARY_LENGTH {
+            report.errors.push(CheckError::SummaryTooLong(length));
+        }
+    }
+
+    if metadata
+        .description
+        .as_deref()
+        .is_none_or(|description| description.trim().is_empty())
+    {
+        report.warnings.push(CheckWarning::MissingDescription);
+    } else if metadata.description_content_type.is_none() {
+        report.warnings.push(CheckWarning::MissingContentType);
+    }
+
+    if let Some(content_type) = metadata.description_content_type.as_deref() {
+        if let Err(err) = check_content_type(content_type) {
+            report.errors.push(err);
+        }
+    }
+
+    // Descriptions without a content type are rendered as reStructuredText.
+    let is_rst = metadata
+        .description_content_type
+        .as_deref()
+        .is_none_or(|content_type| {
+            content_type
+                .split(';')
+                .next()
+                .is_some_and(|media_type| media_type.trim().eq_ignore_ascii_case("text/x-rst"))
+        });
+    if is_rst {
+        if let Some(description) = metadata.description.as_deref() {
+            report.warnings.extend(
+                check_rst(description)
+                    .into_iter()
+                    .map(|(line, problem)| CheckWarning::InvalidRst { line, problem }),
+            );
+        }
+    }
+}
+
+/// Validate that the description content type is supported by PyPI.
+///
+/// See: <https://packaging.python.org/en/latest/specifications/core-metadata/#description-content-type>
+fn check_content_type(content_type: &str) -> Result<(), CheckError> {
+    let mut parts = content_type.split(';');
+    let media_type = parts.next().unwrap_or_default().trim().to_ascii_lowercase();
+    if !matches!(
+        media_type.as_str(),
+        "text/plain" | "text/x-rst" | "text/markdown"
+    ) {
+        return Err(CheckError::UnsupportedContentType(content_type.to_string()));
+    }
+
+    for parameter in parts {
+        let Some((key, value)) = parameter.split_once('=') else {
+            continue;
+        };
+        let value = value.trim().trim_matches('"');
+        match key.trim().to_ascii_lowercase().as_str() {
+            "charset" if !value.eq_ignore_ascii_case("utf-8") => {
+                return Err(CheckError::UnsupportedCharset(value.to_string()));
+            }
+            "variant"
+                if media_type == "text/markdown" && !matches!(value, "GFM" | "CommonMark") =>
+            {
+                return Err(CheckError::UnsupportedMarkdownVariant(value.to_string()));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Find constructs in a reStructuredText description that the index can't render, returning the
+/// (one-based) line number of each problem.
+///
+/// This isn't a full parser: it only detects common mistakes, i.e., short title underlines and
+/// Sphinx-only directives and roles, which make docutils reject the entire description.
+fn check_rst(description: &str) -> Vec<(usize, RstProblem)> {
+    let lines: Vec<&str> = description.lines().collect();
+    let mut problems = Vec::new();
+
+    for (index, line) in lines.iter().enumerate() {
+        let number = index + 1;
+
+        // A title, followed by an underline of at least four characters that's too short.
+        // Shorter underlines are treated as paragraph text.
+        if let Some(underline) = lines.get(index + 1) {
+            let title = line.trim_end();
+            if !title.is_empty()
+                && !title.starts_with(char::is_whitespace)
+                && !is_rst_adornment(title)
+                && is_rst_adornment(underline)
+                && (4..title.chars().count()).contains(&underline.trim_end().len())
+            {
+                problems.push((number + 1, RstProblem::TitleUnderlineTooShort));
+            }
+        }
+
+        let trimmed = line.trim_start();
+        if let Some(directive) = trimmed.strip_prefix(".. ") {
+            // Substitution definitions may use a directive, e.g., `.. |logo| image:: logo.png`.
+            let directive = match directive.strip_prefix('|') {
+                Some(substitution) => substitution
+                    .split_once("| ")
+                    .map_or("", |(_, directive)| directive),
+                None => directive,
+            };
+            if let Some((name, _)) = directive.split_once("::") {
+                if is_rst_name(name)
+                    && !name.starts_with('_')
+                    && !RST_DIRECTIVES.contains(&name.to_ascii_lowercase().as_str())
+                {
+                    problems.push((number, RstProblem::UnknownDirective(name.to_string())));
+                }
+            }
+            continue;
+        }
+
+        // Indented lines may be part of a literal block, which isn't parsed for roles.
+        if line.starts_with(char::is_whitespace) {
+            continue;
+        }
+        // Skip inline literals, e.g., ``:ref:`foo` ``.
+        for (segment, text) in line.split("``").enumerate() {
+            if segment % 2 == 1 {
+                continue;
+            }
+            for role in rst_roles(text) {
+                if !RST_ROLES.contains(&role.to_ascii_lowercase().as_str()) {
+                    problems.push((number, RstProblem::UnknownRole(role.to_string())));
+                }
+            }
+        }
+    }
+
+    problems
+}
+
+/// Returns `true` if the line is a section adornment, i.e., a repeated punctuation character.
+fn is_rst_adornment(line: &str) -> bool {
+    let line = line.trim_end();
+    let mut chars = line.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_punctuation() && chars.all(|c| c == first))
+}
+
+/// Returns `true` if the text is a valid name for a role or directive, e.g., `py:func`.
+fn is_rst_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(is_rst_name_char)
+}
+
+/// Returns `true` if the character may appear in the name of a role or directive.
+fn is_rst_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':' | '+')
+}
+
+/// Find the interpreted text roles in a line, e.g., `ref` in ``see :ref:`install` ``.
+fn rst_roles(text: &str) -> Vec<&str> {
+    let mut roles = Vec::new();
+    for (end, _) in text.match_indices(":`") {
+        let before = &text[..end];
+        let start = before
+            .char_indices()
+            .rev()
+            .take_while(|(_, c)| is_rst_name_char(*c))
+            .last()
+            .map_or(end, |(index, _)| index);
+        let Some(role) = before[start..].strip_prefix(':') else {
+            continue;
+        };
+        if is_rst_name(role) && !role.starts_with(':') {
+            roles.push(role);
+        }
+    }
+    roles
+}
+
+/// Validate that the `RECORD` file of a wheel lists every file in the wheel, with matching hashes.
+fn check_record(
+    file: &Path,
+    filename: &WheelFilename,
+) -> Result<(Vec<CheckError>, Vec<CheckWarning>), CheckError> {
+    let mut errors = Vec::new();
+    let mut warnings = Vec::new();
+
+    let mut archive = ZipArchive::new(fs_err::File::open(file).map_err(ZipError::Io)?)?;
+    let mut files: Vec<String> = archive
+        .file_names()
+        .filter(|name| !name.ends_with('/'))
+        .map(ToString::to_string)
+        .collect();
+    files.sort_unstable();
+
+    let (_, dist_info_prefix) = uv_metadata::find_archive_dist_info(
+        filename,
+        files.iter().map(|name| ((), name.as_str())),
+    )?;
+    let dist_info_dir = format!("{dist_info_prefix}.dist-info");
+    let record_path = format!("{dist_info_dir}/RECORD");
+
+    let record = match archive.by_name(&record_path) {
+        Ok(mut record) => uv_install_wheel::read_record_file(&mut record)?,
+        Err(ZipError::FileNotFound) => return Err(CheckError::MissingRecord),
+        Err(err) => return Err(err.into()),
+    };
+
+    // Every file in the wheel must be recorded, except for `RECORD` itself and its signatures.
+    let recorded: FxHashSet<&str> = record.iter().map(|entry| entry.path.as_str()).collect();
+    let unrecorded = [
+        record_path.clone(),
+        format!("{dist_info_dir}/RECORD.jws"),
+        format!("{dist_info_dir}/RECORD.p7s"),
+    ];
+    for name in &files {
+        if !recorded.contains(name.as_str()) && !unrecorded.contains(name) {
+            errors.push(CheckError::UnrecordedFile(name.clone()));
+        }
+    }
+
+    // Every recorded file must be present in the wheel, with a matching hash.
+    for entry in &record {
+        if entry.path == record_path {
+            continue;
+        }
+        let mut contents = match archive.by_name(&entry.path) {
+            Ok(contents) => contents,
+            Err(ZipError::FileNotFound) => {
+                errors.push(CheckError::MissingRecordedFile(entry.path.clone()));
+                continue;
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let Some(hash) = entry.hash.as_deref().filter(|hash| !hash.is_empty()) else {
+            warnings.push(CheckWarning::MissingRecordHash(entry.path.clone()));
+            continue;
+        };
+        // Only SHA-256 digests are verified, which is what's used by (nearly) all wheels.
+        if let Some(expected) = hash.strip_prefix("sha256=") {
+            let mut hasher = Sha256::new();
+            io::copy(&mut contents, &mut hasher).map_err(ZipError::Io)?;
+            if BASE64_URL_SAFE_NO_PAD.encode(hasher.finalize()) != expected {
+                errors.push(CheckError::RecordHashMismatch(entry.path.clone()));
+            }
+        }
+    }
+
+    Ok((errors, warnings))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use itertools::Itertools;
+
+    use uv_distribution_filename::DistFilename;
+
+    use super::{check_content_type, check_files, check_rst, CheckReport};
+
+    fn file(name: &str) -> (PathBuf, String, DistFilename) {
+        (
+            PathBuf::from("../../scripts/links/").join(name),
+            name.to_string(),
+            DistFilename::try_from_normalized_filename(name).unwrap(),
+        )
+    }
+
+    fn render(report: &CheckReport) -> String {
+        report
+            .errors
+            .iter()
+            .map(|err| format!("error: {err}"))
+            .chain(
+                report
+                    .warnings
+                    .iter()
+                    .map(|warning| format!("warning: {warning}")),
+            )
+            .join("\n")
+    }
+
+    #[tokio::test]
+    async fn valid_wheel() {
+        let reports = check_files(&[file("ok-1.0.0-py3-none-any.whl")]).await;
+        assert!(reports[0].is_ok());
+        assert_eq!(
+            render(&reports[0]),
+            "warning: The metadata doesn't include a description, so the project page on the index will be empty"
+        );
+    }
+
+    #[tokio::test]
+    async fn valid_source_dist() {
+        let reports = check_files(&[file("tqdm-999.0.0.tar.gz")]).await;
+        assert!(reports[0].is_ok(), "{}", render(&reports[0]));
+    }
+
+    #[tokio::test]
+    async fn record_mismatch() {
+        let reports = check_files(&[file("maturin-1.4.0-py3-none-any.whl")]).await;
+        assert_eq!(
+            render(&reports[0]),
+            "error: The hash of `maturin-1.4.0.dist-info/METADATA` doesn't match the hash in the `RECORD` file\n\
+             error: `maturin-1.4.0.data/scripts/maturin` is listed in the `RECORD` file, but is missing from the wheel\n\
+             warning: The metadata doesn't include a description, so the project page on the index will be empty"
+        );
+    }
+
+    #[tokio::test]
+    async fn record_missing_hashes() {
+        let reports = check_files(&[file("validation-1.0.0-py3-none-any.whl")]).await;
+        assert!(reports[0].is_ok());
+        assert_eq!(
+            render(&reports[0]),
+            "warning: The metadata doesn't include a description, so the project page on the index will be empty\n\
+             warning: `validation-1.0.0.dist-info/METADATA` has no hash in the `RECORD` file\n\
+             warning: `validation-1.0.0.dist-info/WHEEL` has no hash in the `RECORD` file"
+        );
+    }
+
+    #[tokio::test]
+    async fn duplicate_file() {
+        let reports = check_files(&[
+            file("ok-1.0.0-py3-none-any.whl"),
+            file("ok-1.0.0-py3-none-any.whl"),
+        ])
+        .await;
+        assert!(reports[0].is_ok());
+        assert_eq!(
+            reports[1].errors.iter().map(ToString::to_string).join("\n"),
+            "The same file is also included as `../../scripts/links/ok-1.0.0-py3-none-any.whl`"
+        );
+    }
+
+    #[test]
+    fn content_types() {
+        assert!(check_content_type("text/markdown").is_ok());
+        assert!(check_content_type("text/markdown; charset=UTF-8; variant=GFM").is_ok());
+        assert!(check_content_type("text/x-rst; charset=utf-8").is_ok());
+        assert!(check_content_type("text/plain").is_ok());
+        assert_eq!(
+            check_content_type("text/html").unwrap_err().to_string(),
+            "Unsupported description content type `text/html`, expected one of `text/plain`, `text/x-rst`, or `text/markdown`"
+        );
+        assert_eq!(
+            check_content_type("text/markdown; charset=latin-1")
+                .unwrap_err()
+                .to_string(),
+            "Unsupported description charset `latin-1`, expected `UTF-8`"
+        );
+        assert_eq!(
+            check_content_type("text/markdown; variant=rst")
+                .unwrap_err()
+                .to_string(),
+            "Unsupported Markdown variant `rst`, expected `GFM` or `CommonMark`"
+        );
+    }
+
+    #[test]
+    fn rst_descriptions() {
+        let render = |description: &str| {
+            check_rst(description)
+                .into_iter()
+                .map(|(line, problem)| format!("{line}: {problem}"))
+                .join("\n")
+        };
+
+        let valid = [
+            "Project",
+            "=======",
+            "",
+            "See the `docs <https://example.com>`_ and :pep:`440`, or ``:ref:`literal```.",
+            "",
+            ".. note:: Notes render.",
+            "",
+            ".. |logo| image:: logo.png",
+            "",
+            ".. _target: https://example.com",
+            "",
+            "Usage::",
+            "",
+            "    :func:`ignored` in a literal block",
+            "",
+            "Key",
+            "---",
+        ]
+        .join("\n");
+        assert_eq!(render(&valid), "");
+
+        let invalid = [
+            "A long project title",
+            "====",
+            "",
+            "See :ref:`install` and :py:func:`main`.",
+            "",
+            ".. toctree::",
+            "   :maxdepth: 2",
+        ]
+        .join("\n");
+        assert_eq!(
+            render(&invalid),
+            "2: the title underline is shorter than the title\n\
+             4: unknown role `ref`\n\
+             4: unknown role `py:func`\n\
+             6: unknown directive `toctree`"
+        );
+    }
+}
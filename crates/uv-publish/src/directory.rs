@@ -0,0 +1,333 @@
+//! Publish distributions to a local directory laid out as a
+//! [PEP 503](https://peps.python.org/pep-0503/) simple index, e.g., to serve it with a static file
+//! server.
+//!
+//! The directory contains a folder per project with the distributions and an `index.html` listing
+//! them, and a top-level `index.html` listing the projects:
+//!
+//! ```text
+//! /srv/wheels
+//! ├── index.html
+//! └── tqdm
+//!     ├── index.html
+//!     ├── tqdm-4.66.1-py3-none-any.whl
+//!     └── tqdm-4.66.1.tar.gz
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+use tracing::debug;
+
+use uv_distribution_filename::DistFilename;
+use uv_extract::hash::Hasher;
+use uv_fs::{LockedFile, Simplified};
+use uv_normalize::PackageName;
+use uv_pypi_types::HashAlgorithm;
+use uv_warnings::warn_user;
+
+use crate::{hash_file, metadata, PublishError};
+
+#[derive(Error, Debug)]
+pub enum DirectoryPublishError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("A different file with the same name already exists: `{}`", _0.user_display())]
+    Conflict(PathBuf),
+}
+
+/// A local simple index directory, locked against concurrent `uv publish` invocations.
+///
+/// Distributions are copied into the index with [`DirectoryIndex::publish`]; the index pages are
+/// regenerated once, with [`DirectoryIndex::write_indexes`], after all distributions were copied.
+pub struct DirectoryIndex {
+    root: PathBuf,
+    /// The directories of the projects that distributions were published to.
+    projects: BTreeMap<PackageName, PathBuf>,
+    _lock: LockedFile,
+}
+
+impl DirectoryIndex {
+    /// Lock the local simple index directory at `root`, creating it if necessary.
+    pub async fn lock(root: &Path) -> Result<Self, PublishError> {
+        Self::lock_inner(root)
+            .await
+            .map_err(|err| PublishError::DirectoryIndex(root.to_path_buf(), err))
+    }
+
+    async fn lock_inner(root: &Path) -> Result<Self, DirectoryPublishError> {
+        fs_err::tokio::create_dir_all(root).await?;
+        let lock = LockedFile::acquire(root.join(".lock"), root.user_display()).await?;
+        Ok(Self {
+            root: root.to_path_buf(),
+            projects: BTreeMap::new(),
+            _lock: lock,
+        })
+    }
+
+    /// Copy a distribution into the index.
+    ///
+    /// Returns `true` if the file was newly added and `false` if an identical file already existed.
+    pub async fn publish(
+        &mut self,
+        file: &Path,
+        raw_filename: &str,
+        filename: &DistFilename,
+    ) -> Result<bool, PublishError> {
+        self.publish_inner(file, raw_filename, filename)
+            .await
+            .map_err(|err| {
+                PublishError::PublishDirectory(file.to_path_buf(), self.root.clone(), err)
+            })
+    }
+
+    async fn publish_inner(
+        &mut self,
+        file: &Path,
+        raw_filename: &str,
+        filename: &DistFilename,
+    ) -> Result<bool, DirectoryPublishError> {
+        let project_dir = self.root.join(filename.name().as_str());
+        fs_err::tokio::create_dir_all(&project_dir).await?;
+        self.projects
+            .insert(filename.name().clone(), project_dir.clone());
+
+        let target = project_dir.join(raw_filename);
+        if target.is_file() {
+            let local = hash_file(file, Hasher::from(HashAlgorithm::Sha256)).await?;
+            let existing = hash_file(&target, Hasher::from(HashAlgorithm::Sha256)).await?;
+            if local.digest != existing.digest {
+                return Err(DirectoryPublishError::Conflict(target));
+            }
+            debug!("Identical file already exists: `{}`", target.user_display());
+            return Ok(false);
+        }
+
+        // Copy to a temporary file first, so that a server never sees a partial distribution.
+        debug!("Copying to `{}`", target.user_display());
+        let temp_file = uv_fs::tempfile_in(&project_dir)?;
+        fs_err::tokio::copy(file, temp_file.path()).await?;
+        uv_fs::persist_with_retry(temp_file, &target).await?;
+
+        Ok(true)
+    }
+
+    /// Regenerate the index pages of the projects that distributions were published to, and the
+    /// top-level index page.
+    pub async fn write_indexes(self) -> Result<(), PublishError> {
+        self.write_indexes_inner()
+            .await
+            .map_err(|err| PublishError::DirectoryIndex(self.root.clone(), err))
+    }
+
+    async fn write_indexes_inner(&self) -> Result<(), DirectoryPublishError> {
+        if self.projects.is_empty() {
+            return Ok(());
+        }
+        for (name, project_dir) in &self.projects {
+            write_project_index(project_dir, name).await?;
+        }
+        write_root_index(&self.root).await?;
+        Ok(())
+    }
+}
+
+/// Write the `index.html` of a project, listing all of its distributions.
+async fn write_project_index(
+    project_dir: &Path,
+    name: &PackageName,
+) -> Result<(), DirectoryPublishError> {
+    let mut files: Vec<(String, DistFilename, PathBuf)> = uv_fs::files(project_dir)
+        .filter_map(|path| {
+            let raw_filename = path.file_name()?.to_str()?.to_string();
+            let filename = DistFilename::try_from_normalized_filename(&raw_filename)?;
+            Some((raw_filename, filename, path))
+        })
+        .collect();
+    files.sort_by(|(a, ..), (b, ..)| a.cmp(b));
+
+    let mut html = String::new();
+    writeln!(html, "<!DOCTYPE html>").unwrap();
+    writeln!(html, "<html>").unwrap();
+    writeln!(html, "  <head>").unwrap();
+    writeln!(
+        html,
+        "    <meta name=\"pypi:repository-version\" content=\"1.0\">"
+    )
+    .unwrap();
+    writeln!(html, "    <title>Links for {name}</title>").unwrap();
+    writeln!(html, "  </head>").unwrap();
+    writeln!(html, "  <body>").unwrap();
+    writeln!(html, "    <h1>Links for {name}</h1>").unwrap();
+    for (raw_filename, filename, path) in files {
+        let hash = hash_file(&path, Hasher::from(HashAlgorithm::Sha256)).await?;
+        // Files that weren't published by uv, such as legacy `.zip` source distributions, may not
+        // have readable metadata; list them without a `Requires-Python`.
+        let requires_python = match metadata(&path, &filename).await {
+            Ok(metadata) => metadata.requires_python,
+            Err(err) => {
+                warn_user!(
+                    "Failed to read the metadata of `{}`, listing it without a `Requires-Python`: {err}",
+                    path.user_display()
+                );
+                None
+            }
+        };
+        let href = escape_html(&format!("{raw_filename}#sha256={}", hash.digest));
+        let text = escape_html(&raw_filename);
+        if let Some(requires_python) = requires_python {
+            let requires_python = escape_html(&requires_python);
+            writeln!(
+                html,
+                "    <a href=\"{href}\" data-requires-python=\"{requires_python}\">{text}</a><br/>"
+            )
+            .unwrap();
+        } else {
+            writeln!(html, "    <a href=\"{href}\">{text}</a><br/>").unwrap();
+        }
+    }
+    writeln!(html, "  </body>").unwrap();
+    writeln!(html, "</html>").unwrap();
+
+    uv_fs::write_atomic(project_dir.join("index.html"), html).await?;
+    Ok(())
+}
+
+/// Write the top-level `index.html`, listing all projects.
+async fn write_root_index(root: &Path) -> Result<(), DirectoryPublishError> {
+    let mut projects: Vec<String> = uv_fs::directories(root)
+        .filter(|path| !uv_fs::is_temporary(path) && path.join("index.html").is_file())
+        .filter_map(|path| Some(path.file_name()?.to_str()?.to_string()))
+        .collect();
+    projects.sort();
+
+    let mut html = String::new();
+    writeln!(html, "<!DOCTYPE html>").unwrap();
+    writeln!(html, "<html>").unwrap();
+    writeln!(html, "  <head>").unwrap();
+    writeln!(
+        html,
+        "    <meta name=\"pypi:repository-version\" content=\"1.0\">"
+    )
+    .unwrap();
+    writeln!(html, "    <title>Simple index</title>").unwrap();
+    writeln!(html, "  </head>").unwrap();
+    writeln!(html, "  <body>").unwrap();
+    for project in projects {
+        let project = escape_html(&project);
+        writeln!(html, "    <a href=\"{project}/\">{project}</a><br/>").unwrap();
+    }
+    writeln!(html, "  </body>").unwrap();
+    writeln!(html, "</html>").unwrap();
+
+    uv_fs::write_atomic(root.join("index.html"), html).await?;
+    Ok(())
+}
+
+/// Escape a string for use in an HTML attribute or text.
+fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#x27;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use insta::assert_snapshot;
+
+    use uv_distribution_filename::DistFilename;
+
+    use super::{DirectoryIndex, DirectoryPublishError};
+
+    #[tokio::test]
+    async fn publish_local() {
+        let root = tempfile::tempdir().unwrap();
+
+        // A legacy source distribution that wasn't published by uv, without readable metadata.
+        fs_err::create_dir_all(root.path().join("tqdm")).unwrap();
+        fs_err::write(
+            root.path().join("tqdm").join("tqdm-1.0.0.zip"),
+            "not a zip file",
+        )
+        .unwrap();
+
+        let mut index = DirectoryIndex::lock_inner(root.path()).await.unwrap();
+        let files = ["tqdm-999.0.0.tar.gz", "ok-1.0.0-py3-none-any.whl"];
+        for raw_filename in files {
+            let file = PathBuf::from("../../scripts/links/").join(raw_filename);
+            let filename = DistFilename::try_from_normalized_filename(raw_filename).unwrap();
+            assert!(index
+                .publish_inner(&file, raw_filename, &filename)
+                .await
+                .unwrap());
+            // Publishing the same file again is a no-op.
+            assert!(!index
+                .publish_inner(&file, raw_filename, &filename)
+                .await
+                .unwrap());
+        }
+
+        // The index pages are only written once all files were copied.
+        assert!(!root.path().join("index.html").exists());
+        index.write_indexes_inner().await.unwrap();
+
+        assert_snapshot!(fs_err::read_to_string(root.path().join("index.html")).unwrap(), @r###"
+        <!DOCTYPE html>
+        <html>
+          <head>
+            <meta name="pypi:repository-version" content="1.0">
+            <title>Simple index</title>
+          </head>
+          <body>
+            <a href="ok/">ok</a><br/>
+            <a href="tqdm/">tqdm</a><br/>
+          </body>
+        </html>
+        "###);
+        assert_snapshot!(fs_err::read_to_string(root.path().join("tqdm").join("index.html")).unwrap(), @r###"
+        <!DOCTYPE html>
+        <html>
+          <head>
+            <meta name="pypi:repository-version" content="1.0">
+            <title>Links for tqdm</title>
+          </head>
+          <body>
+            <h1>Links for tqdm</h1>
+            <a href="tqdm-1.0.0.zip#sha256=1721cb4d8c135c7575efd443d1bf10948601f454a3a59bb422e7258717f373bb">tqdm-1.0.0.zip</a><br/>
+            <a href="tqdm-999.0.0.tar.gz#sha256=89fa05cffa7f457658373b85de302d24d0c205ceda2819a8739e324b75e9430b" data-requires-python="&gt;=3.8">tqdm-999.0.0.tar.gz</a><br/>
+          </body>
+        </html>
+        "###);
+    }
+
+    #[tokio::test]
+    async fn publish_local_conflict() {
+        let root = tempfile::tempdir().unwrap();
+        let raw_filename = "tqdm-999.0.0.tar.gz";
+        let filename = DistFilename::try_from_normalized_filename(raw_filename).unwrap();
+        fs_err::create_dir_all(root.path().join("tqdm")).unwrap();
+        fs_err::write(root.path().join("tqdm").join(raw_filename), "not a tarball").unwrap();
+
+        let file = PathBuf::from("../../scripts/links/").join(raw_filename);
+        let mut index = DirectoryIndex::lock_inner(root.path()).await.unwrap();
+        let err = index
+            .publish_inner(&file, raw_filename, &filename)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DirectoryPublishError::Conflict(_)), "{err}");
+    }
+}
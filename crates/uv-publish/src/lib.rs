@@ -1,3 +1,6 @@
+mod attestations;
+mod check;
+mod directory;
 mod trusted_publishing;
 
 use crate::trusted_publishing::TrustedPublishingError;
@@ -17,23 +20,25 @@ use serde::Deserialize;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, SystemTime};
-use std::{env, fmt, io};
+use std::{fmt, io};
 use thiserror::Error;
 use tokio::io::{AsyncReadExt, BufReader};
 use tokio::sync::Semaphore;
 use tokio_util::io::ReaderStream;
 use tracing::{debug, enabled, trace, warn, Level};
 use url::Url;
-use uv_client::{BaseClient, OwnedArchive, RegistryClientBuilder, UvRetryableStrategy};
+use uv_client::{BaseClient, MetadataFormat, OwnedArchive, RegistryClientBuilder};
 use uv_configuration::{KeyringProviderType, TrustedPublishing};
 use uv_distribution_filename::{DistFilename, SourceDistExtension, SourceDistFilename};
 use uv_fs::{ProgressReader, Simplified};
 use uv_metadata::read_metadata_async_seek;
 use uv_pypi_types::{HashAlgorithm, HashDigest, Metadata23, MetadataError};
-use uv_static::EnvVars;
 use uv_warnings::{warn_user, warn_user_once};
 
-pub use trusted_publishing::TrustedPublishingToken;
+pub use attestations::{find_attestation, AttestationError};
+pub use check::{check_files, CheckError, CheckReport, CheckWarning, RstProblem};
+pub use directory::{DirectoryIndex, DirectoryPublishError};
+pub use trusted_publishing::{OidcProvider, TrustedPublishingToken};
 use uv_cache::{Cache, Refresh};
 use uv_distribution_types::{IndexCapabilities, IndexUrl};
 use uv_extract::hash::{HashReader, Hasher};
@@ -55,6 +60,10 @@ pub enum PublishError {
     PublishPrepare(PathBuf, #[source] Box<PublishPrepareError>),
     #[error("Failed to publish `{}` to {}", _0.user_display(), _1)]
     PublishSend(PathBuf, Url, #[source] PublishSendError),
+    #[error("Failed to publish `{}` to `{}`", _0.user_display(), _1.user_display())]
+    PublishDirectory(PathBuf, PathBuf, #[source] DirectoryPublishError),
+    #[error("Failed to update the index at `{}`", _0.user_display())]
+    DirectoryIndex(PathBuf, #[source] DirectoryPublishError),
     #[error("Failed to obtain token for trusted publishing")]
     TrustedPublishing(#[from] TrustedPublishingError),
     #[error("{0} are not allowed when using trusted publishing")]
@@ -92,6 +101,8 @@ pub enum PublishPrepareError {
     MultiplePkgInfo(String),
     #[error("Failed to read: `{0}`")]
     Read(String, #[source] io::Error),
+    #[error(transparent)]
+    Attestation(#[from] AttestationError),
 }
 
 /// Failure in or after (HTTP) transport for a specific file.
@@ -308,14 +319,17 @@ pub async fn check_trusted_publishing(
             {
                 return Ok(TrustedPublishResult::Skipped);
             }
-            // If we aren't in GitHub Actions, we can't use trusted publishing.
-            if env::var(EnvVars::GITHUB_ACTIONS) != Ok("true".to_string()) {
+            // If we aren't in a supported CI provider (and no OIDC token was provided), we can't
+            // use trusted publishing.
+            let Some(provider) = OidcProvider::detect() else {
                 return Ok(TrustedPublishResult::Skipped);
-            }
+            };
             // We could check for credentials from the keyring or netrc the auth middleware first, but
-            // given that we are in GitHub Actions we check for trusted publishing first.
-            debug!("Running on GitHub Actions without explicit credentials, checking for trusted publishing");
-            match trusted_publishing::get_token(registry, client.for_host(registry)).await {
+            // given that we are in CI we check for trusted publishing first.
+            debug!("Running on {provider} without explicit credentials, checking for trusted publishing");
+            match trusted_publishing::get_token(registry, &provider, client.for_host(registry))
+                .await
+            {
                 Ok(token) => Ok(TrustedPublishResult::Configured(token)),
                 Err(err) => {
                     // TODO(konsti): It would be useful if we could differentiate between actual errors
@@ -327,8 +341,6 @@ pub async fn check_trusted_publishing(
             }
         }
         TrustedPublishing::Always => {
-            debug!("Using trusted publishing for GitHub Actions");
-
             let mut conflicts = Vec::new();
             if username.is_some() {
                 conflicts.push("a username");
@@ -343,13 +355,17 @@ pub async fn check_trusted_publishing(
                 return Err(PublishError::MixedCredentials(conflicts.join(" and ")));
             }
 
-            if env::var(EnvVars::GITHUB_ACTIONS) != Ok("true".to_string()) {
+            let provider = OidcProvider::detect().unwrap_or_else(|| {
                 warn_user_once!(
-                    "Trusted publishing was requested, but you're not in GitHub Actions."
+                    "Trusted publishing was requested, but you're not in a supported CI provider (GitHub Actions, GitLab CI, or Google Cloud) and no OIDC token was provided."
                 );
-            }
+                OidcProvider::GitHubActions
+            });
+            debug!("Using trusted publishing for {provider}");
 
-            let token = trusted_publishing::get_token(registry, client.for_host(registry)).await?;
+            let token =
+                trusted_publishing::get_token(registry, &provider, client.for_host(registry))
+                    .await?;
             Ok(TrustedPublishResult::Configured(token))
         }
         TrustedPublishing::Never => Ok(TrustedPublishResult::Skipped),
@@ -365,6 +381,7 @@ pub async fn upload(
     file: &Path,
     raw_filename: &str,
     filename: &DistFilename,
+    attestation: Option<&Path>,
     registry: &Url,
     client: &BaseClient,
     username: Option<&str>,
@@ -373,13 +390,16 @@ pub async fn upload(
     download_concurrency: &Semaphore,
     reporter: Arc<impl Reporter>,
 ) -> Result<bool, PublishError> {
-    let form_metadata = form_metadata(file, filename)
+    let form_metadata = form_metadata(file, filename, attestation)
         .await
         .map_err(|err| PublishError::PublishPrepare(file.to_path_buf(), Box::new(err)))?;
 
     let mut n_past_retries = 0;
     let start_time = SystemTime::now();
-    let retry_policy = client.retry_policy();
+    // The retry middleware is disabled on the upload client since it can't clone the streamed
+    // request body, so we apply the retry policy and strategy for the registry here instead.
+    let retry_policy = client.retry_policy_for(registry);
+    let retry_strategy = client.retry_strategy_for(registry);
     loop {
         let (request, idx) = build_request(
             file,
@@ -396,7 +416,7 @@ pub async fn upload(
         .map_err(|err| PublishError::PublishPrepare(file.to_path_buf(), Box::new(err)))?;
 
         let result = request.send().await;
-        if UvRetryableStrategy.handle(&result) == Some(Retryable::Transient) {
+        if retry_strategy.handle(&result) == Some(Retryable::Transient) {
             let retry_decision = retry_policy.should_retry(start_time, n_past_retries);
             if let reqwest_retry::RetryDecision::Retry { execute_after } = retry_decision {
                 warn_user!("Transient failure while handling response for {registry}; retrying...");
@@ -474,7 +494,7 @@ pub async fn check_url(
 
     debug!("Checking for {filename} in the registry");
     let response = match registry_client
-        .simple(
+        .package_metadata(
             filename.name(),
             Some(index_url),
             index_capabilities,
@@ -496,30 +516,38 @@ pub async fn check_url(
             };
         }
     };
-    let [(_, simple_metadata)] = response.as_slice() else {
+    let [(_, metadata)] = response.as_slice() else {
         unreachable!("We queried a single index, we must get a single response");
     };
-    let simple_metadata = OwnedArchive::deserialize(simple_metadata);
-    let Some(metadatum) = simple_metadata
-        .iter()
-        .find(|metadatum| &metadatum.version == filename.version())
-    else {
-        return Ok(false);
-    };
+    let archived_file = match metadata {
+        MetadataFormat::Simple(simple_metadata) => {
+            let simple_metadata = OwnedArchive::deserialize(simple_metadata);
+            let Some(metadatum) = simple_metadata
+                .iter()
+                .find(|metadatum| &metadatum.version == filename.version())
+            else {
+                return Ok(false);
+            };
 
-    let archived_file = match filename {
-        DistFilename::SourceDistFilename(source_dist) => metadatum
-            .files
-            .source_dists
-            .iter()
-            .find(|entry| &entry.name == source_dist)
-            .map(|entry| &entry.file),
-        DistFilename::WheelFilename(wheel) => metadatum
-            .files
-            .wheels
+            match filename {
+                DistFilename::SourceDistFilename(source_dist) => metadatum
+                    .files
+                    .source_dists
+                    .iter()
+                    .find(|entry| &entry.name == source_dist)
+                    .map(|entry| entry.file.clone()),
+                DistFilename::WheelFilename(wheel) => metadatum
+                    .files
+                    .wheels
+                    .iter()
+                    .find(|entry| &entry.name == wheel)
+                    .map(|entry| entry.file.clone()),
+            }
+        }
+        MetadataFormat::Flat(entries) => entries
             .iter()
-            .find(|entry| &entry.name == wheel)
-            .map(|entry| &entry.file),
+            .find(|entry| &entry.filename == filename)
+            .map(|entry| entry.file.clone()),
     };
     let Some(archived_file) = archived_file else {
         return Ok(false);
@@ -633,6 +661,7 @@ async fn metadata(file: &Path, filename: &DistFilename) -> Result<Metadata23, Pu
 async fn form_metadata(
     file: &Path,
     filename: &DistFilename,
+    attestation: Option<&Path>,
 ) -> Result<Vec<(&'static str, String)>, PublishPrepareError> {
     let hash_hex = hash_file(file, Hasher::from(HashAlgorithm::Sha256)).await?;
 
@@ -728,6 +757,13 @@ async fn form_metadata(
     add_vec("requires_dist", requires_dist);
     add_vec("requires_external", requires_external);
 
+    // PEP 740 attestations, a JSON array of attestation objects.
+    if let Some(attestation) = attestation {
+        let attestations =
+            attestations::read_attestation(attestation, filename, &hash_hex.digest).await?;
+        form_metadata.push(("attestations", attestations));
+    }
+
     Ok(form_metadata)
 }
 
@@ -891,7 +927,7 @@ mod tests {
         let file = PathBuf::from("../../scripts/links/").join(raw_filename);
         let filename = DistFilename::try_from_normalized_filename(raw_filename).unwrap();
 
-        let form_metadata = form_metadata(&file, &filename).await.unwrap();
+        let form_metadata = form_metadata(&file, &filename, None).await.unwrap();
 
         let formatted_metadata = form_metadata
             .iter()
@@ -1003,7 +1039,7 @@ mod tests {
         let file = PathBuf::from("../../scripts/links/").join(raw_filename);
         let filename = DistFilename::try_from_normalized_filename(raw_filename).unwrap();
 
-        let form_metadata = form_metadata(&file, &filename).await.unwrap();
+        let form_metadata = form_metadata(&file, &filename, None).await.unwrap();
 
         let formatted_metadata = form_metadata
             .iter()
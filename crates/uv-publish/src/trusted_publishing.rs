@@ -1,4 +1,5 @@
-//! Trusted publishing (via OIDC) with GitHub actions.
+//! Trusted publishing (via OIDC) with GitHub Actions, GitLab CI, Google Cloud, or a user-provided
+//! OIDC token.
 
 use reqwest::{header, StatusCode};
 use reqwest_middleware::ClientWithMiddleware;
@@ -7,17 +8,28 @@ use std::env;
 use std::env::VarError;
 use std::ffi::OsString;
 use std::fmt::Display;
+use std::path::PathBuf;
 use thiserror::Error;
 use tracing::{debug, trace};
-use url::Url;
+use url::{Host, Url};
+use uv_fs::Simplified;
 use uv_static::EnvVars;
 
+/// The default host of the Google Cloud metadata server.
+const GCE_METADATA_HOST: &str = "metadata.google.internal";
+
 #[derive(Debug, Error)]
 pub enum TrustedPublishingError {
     #[error("Environment variable {0} not set, is the `id-token: write` permission missing?")]
     MissingEnvVar(&'static str),
     #[error("Environment variable {0} is not valid UTF-8: `{1:?}`")]
     InvalidEnvVar(&'static str, OsString),
+    #[error("Environment variable {0} not set, is an `id_tokens` entry with `aud: {1}` missing from the GitLab CI job?")]
+    MissingGitLabIdToken(String, String),
+    #[error("Failed to read the OIDC token")]
+    TokenFile(#[source] std::io::Error),
+    #[error("The OIDC token from {0} is empty")]
+    EmptyToken(OidcProvider),
     #[error(transparent)]
     Url(#[from] url::ParseError),
     #[error("Failed to fetch: `{0}`")]
@@ -51,6 +63,71 @@ impl Display for TrustedPublishingToken {
     }
 }
 
+/// A source of OIDC ID tokens, which are exchanged for a short-lived upload token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OidcProvider {
+    /// GitHub Actions, with a token requested via `ACTIONS_ID_TOKEN_REQUEST_URL`.
+    GitHubActions,
+    /// GitLab CI, with a token provided in the `<AUDIENCE>_ID_TOKEN` variable (e.g.,
+    /// `PYPI_ID_TOKEN`), as configured via `id_tokens` in the job.
+    GitLabCi,
+    /// Google Cloud, with a token requested from the metadata server at the given host.
+    GoogleCloud { metadata_host: String },
+    /// A token provided directly via `UV_PUBLISH_OIDC_TOKEN`.
+    Environment,
+    /// A token read from the given file, as provided via `UV_PUBLISH_OIDC_TOKEN_FILE`.
+    File(PathBuf),
+}
+
+impl OidcProvider {
+    /// Detect the OIDC provider for the current environment, if any.
+    ///
+    /// Explicitly provided tokens take precedence over CI providers.
+    pub fn detect() -> Option<Self> {
+        if env::var_os(EnvVars::UV_PUBLISH_OIDC_TOKEN).is_some() {
+            return Some(Self::Environment);
+        }
+        if let Some(path) = env::var_os(EnvVars::UV_PUBLISH_OIDC_TOKEN_FILE) {
+            return Some(Self::File(PathBuf::from(path)));
+        }
+        if env::var(EnvVars::GITHUB_ACTIONS) == Ok("true".to_string()) {
+            return Some(Self::GitHubActions);
+        }
+        if env::var(EnvVars::GITLAB_CI) == Ok("true".to_string()) {
+            return Some(Self::GitLabCi);
+        }
+        if let Ok(metadata_host) = env::var(EnvVars::GCE_METADATA_HOST) {
+            return Some(Self::GoogleCloud { metadata_host });
+        }
+        if is_google_cloud() {
+            return Some(Self::GoogleCloud {
+                metadata_host: GCE_METADATA_HOST.to_string(),
+            });
+        }
+        None
+    }
+}
+
+impl Display for OidcProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::GitHubActions => f.write_str("GitHub Actions"),
+            Self::GitLabCi => f.write_str("GitLab CI"),
+            Self::GoogleCloud { .. } => f.write_str("Google Cloud"),
+            Self::Environment => write!(f, "`{}`", EnvVars::UV_PUBLISH_OIDC_TOKEN),
+            Self::File(path) => write!(f, "`{}`", path.user_display()),
+        }
+    }
+}
+
+/// Returns `true` if we're running on a Google Cloud machine, following the same heuristic as
+/// the Google Cloud client libraries.
+fn is_google_cloud() -> bool {
+    cfg!(target_os = "linux")
+        && fs_err::read_to_string("/sys/class/dmi/id/product_name")
+            .is_ok_and(|product_name| product_name.contains("Google"))
+}
+
 /// The response from querying `https://pypi.org/_/oidc/audience`.
 #[derive(Deserialize)]
 struct Audience {
@@ -63,13 +140,13 @@ struct OidcToken {
     value: String,
 }
 
-/// The body for querying `$ACTIONS_ID_TOKEN_REQUEST_URL&audience=pypi`.
+/// The body for querying `https://pypi.org/_/oidc/mint-token`.
 #[derive(Serialize)]
 struct MintTokenRequest {
     token: String,
 }
 
-/// The response from querying `$ACTIONS_ID_TOKEN_REQUEST_URL&audience=pypi`.
+/// The response from querying `https://pypi.org/_/oidc/mint-token`.
 #[derive(Deserialize)]
 struct PublishToken {
     token: TrustedPublishingToken,
@@ -78,22 +155,45 @@ struct PublishToken {
 /// Returns the short-lived token to use for uploading.
 pub(crate) async fn get_token(
     registry: &Url,
+    provider: &OidcProvider,
     client: &ClientWithMiddleware,
 ) -> Result<TrustedPublishingToken, TrustedPublishingError> {
-    // If this fails, we can skip the audience request.
-    let oidc_token_request_token =
-        env::var(EnvVars::ACTIONS_ID_TOKEN_REQUEST_TOKEN).map_err(|err| {
-            TrustedPublishingError::from_var_err(EnvVars::ACTIONS_ID_TOKEN_REQUEST_TOKEN, err)
-        })?;
-
-    // Request 1: Get the audience
-    let audience = get_audience(registry, client).await?;
-
-    // Request 2: Get the OIDC token from GitHub.
-    let oidc_token = get_oidc_token(&audience, &oidc_token_request_token, client).await?;
+    // Request 1 and 2: Get the audience, and the OIDC token for it.
+    let oidc_token = match provider {
+        OidcProvider::GitHubActions => {
+            // If this fails, we can skip the audience request.
+            let oidc_token_request_token = env::var(EnvVars::ACTIONS_ID_TOKEN_REQUEST_TOKEN)
+                .map_err(|err| {
+                    TrustedPublishingError::from_var_err(
+                        EnvVars::ACTIONS_ID_TOKEN_REQUEST_TOKEN,
+                        err,
+                    )
+                })?;
+            let audience = get_audience(registry, client).await?;
+            get_github_oidc_token(&audience, &oidc_token_request_token, client).await?
+        }
+        OidcProvider::GitLabCi => {
+            let audience = get_audience(registry, client).await?;
+            get_gitlab_oidc_token(&audience)?
+        }
+        OidcProvider::GoogleCloud { metadata_host } => {
+            let audience = get_audience(registry, client).await?;
+            get_google_oidc_token(&audience, metadata_host, client).await?
+        }
+        OidcProvider::Environment => env::var(EnvVars::UV_PUBLISH_OIDC_TOKEN).map_err(|err| {
+            TrustedPublishingError::from_var_err(EnvVars::UV_PUBLISH_OIDC_TOKEN, err)
+        })?,
+        OidcProvider::File(path) => {
+            fs_err::read_to_string(path).map_err(TrustedPublishingError::TokenFile)?
+        }
+    };
+    let oidc_token = oidc_token.trim();
+    if oidc_token.is_empty() {
+        return Err(TrustedPublishingError::EmptyToken(provider.clone()));
+    }
 
     // Request 3: Get the publishing token from PyPI.
-    let publish_token = get_publish_token(registry, &oidc_token, client).await?;
+    let publish_token = get_publish_token(registry, oidc_token, client).await?;
 
     debug!("Received token, using trusted publishing");
 
@@ -106,13 +206,40 @@ pub(crate) async fn get_token(
     Ok(publish_token)
 }
 
+/// Return the base URL for the OIDC endpoints of the registry.
+///
+/// The OIDC token is sent to these endpoints, so they're always queried over HTTPS, unless the
+/// registry is served over HTTP on a loopback address (e.g., a local test registry).
+///
+/// `pypa/gh-action-pypi-publish` uses `netloc` (RFC 1808), which is deprecated for authority
+/// (RFC 3986).
+fn oidc_url(registry: &Url, path: &str) -> Result<Url, TrustedPublishingError> {
+    let scheme = if registry.scheme() == "http" && is_loopback(registry) {
+        "http"
+    } else {
+        "https"
+    };
+    Ok(Url::parse(&format!(
+        "{scheme}://{}/_/oidc/{path}",
+        registry.authority()
+    ))?)
+}
+
+/// Returns `true` if the URL points to a loopback address.
+fn is_loopback(url: &Url) -> bool {
+    match url.host() {
+        Some(Host::Domain(domain)) => domain == "localhost",
+        Some(Host::Ipv4(ip)) => ip.is_loopback(),
+        Some(Host::Ipv6(ip)) => ip.is_loopback(),
+        None => false,
+    }
+}
+
 async fn get_audience(
     registry: &Url,
     client: &ClientWithMiddleware,
 ) -> Result<String, TrustedPublishingError> {
-    // `pypa/gh-action-pypi-publish` uses `netloc` (RFC 1808), which is deprecated for authority
-    // (RFC 3986).
-    let audience_url = Url::parse(&format!("https://{}/_/oidc/audience", registry.authority()))?;
+    let audience_url = oidc_url(registry, "audience")?;
     debug!("Querying the trusted publishing audience from {audience_url}");
     let response = client
         .get(audience_url.clone())
@@ -129,7 +256,7 @@ async fn get_audience(
     Ok(audience.audience)
 }
 
-async fn get_oidc_token(
+async fn get_github_oidc_token(
     audience: &str,
     oidc_token_request_token: &str,
     client: &ClientWithMiddleware,
@@ -158,15 +285,70 @@ async fn get_oidc_token(
     Ok(oidc_token.value)
 }
 
+/// Return the name of the variable in which GitLab CI provides the ID token for the audience,
+/// following the convention of `pypa/id` (e.g., `PYPI_ID_TOKEN` for the `pypi` audience).
+fn gitlab_id_token_var(audience: &str) -> String {
+    let audience = audience
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    EnvVars::id_token(&audience)
+}
+
+fn get_gitlab_oidc_token(audience: &str) -> Result<String, TrustedPublishingError> {
+    read_gitlab_oidc_token(audience, |var| env::var(var).ok())
+}
+
+/// Read the GitLab CI ID token for the audience, using `read_var` to read environment variables.
+fn read_gitlab_oidc_token(
+    audience: &str,
+    read_var: impl FnOnce(&str) -> Option<String>,
+) -> Result<String, TrustedPublishingError> {
+    let var = gitlab_id_token_var(audience);
+    debug!("Reading the trusted publishing OIDC token from `{var}`");
+    read_var(&var)
+        .ok_or_else(|| TrustedPublishingError::MissingGitLabIdToken(var, audience.to_string()))
+}
+
+async fn get_google_oidc_token(
+    audience: &str,
+    metadata_host: &str,
+    client: &ClientWithMiddleware,
+) -> Result<String, TrustedPublishingError> {
+    let mut oidc_token_url = Url::parse(&format!(
+        "http://{metadata_host}/computeMetadata/v1/instance/service-accounts/default/identity"
+    ))?;
+    oidc_token_url
+        .query_pairs_mut()
+        .append_pair("audience", audience)
+        .append_pair("format", "full");
+    debug!("Querying the trusted publishing OIDC token from {oidc_token_url}");
+    let response = client
+        .get(oidc_token_url.clone())
+        .header("Metadata-Flavor", "Google")
+        .send()
+        .await
+        .map_err(|err| TrustedPublishingError::ReqwestMiddleware(oidc_token_url.clone(), err))?;
+    response
+        .error_for_status()
+        .map_err(|err| TrustedPublishingError::Reqwest(oidc_token_url.clone(), err))?
+        .text()
+        .await
+        .map_err(|err| TrustedPublishingError::Reqwest(oidc_token_url.clone(), err))
+}
+
 async fn get_publish_token(
     registry: &Url,
     oidc_token: &str,
     client: &ClientWithMiddleware,
 ) -> Result<TrustedPublishingToken, TrustedPublishingError> {
-    let mint_token_url = Url::parse(&format!(
-        "https://{}/_/oidc/mint-token",
-        registry.authority()
-    ))?;
+    let mint_token_url = oidc_url(registry, "mint-token")?;
     debug!("Querying the trusted publishing upload token from {mint_token_url}");
     let mint_token_payload = MintTokenRequest {
         token: oidc_token.to_string(),
@@ -199,3 +381,180 @@ async fn get_publish_token(
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use url::Url;
+    use uv_client::BaseClientBuilder;
+    use wiremock::matchers::{body_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    /// Start a registry that serves the OIDC audience and exchanges `oidc-token` for an upload
+    /// token.
+    async fn start_registry(audience: &str) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/_/oidc/audience"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "audience": audience })),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/_/oidc/mint-token"))
+            .and(body_json(serde_json::json!({ "token": "oidc-token" })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "token": "upload-token" })),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/_/oidc/mint-token"))
+            .respond_with(ResponseTemplate::new(422).set_body_string("invalid-publisher"))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    #[test]
+    fn gitlab_id_token_var_name() {
+        assert_eq!(gitlab_id_token_var("pypi"), "PYPI_ID_TOKEN");
+        assert_eq!(gitlab_id_token_var("testpypi"), "TESTPYPI_ID_TOKEN");
+        assert_eq!(gitlab_id_token_var("my-index.dev"), "MY_INDEX_DEV_ID_TOKEN");
+    }
+
+    #[tokio::test]
+    async fn token_from_file() {
+        let registry = start_registry("pypi").await;
+        let registry_url = Url::parse(&format!("{}/legacy/", registry.uri())).unwrap();
+        let client = BaseClientBuilder::new().build();
+
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "oidc-token").unwrap();
+        let provider = OidcProvider::File(file.path().to_path_buf());
+        let token = get_token(&registry_url, &provider, client.for_host(&registry_url))
+            .await
+            .unwrap();
+        assert_eq!(token.to_string(), "upload-token");
+
+        // The registry rejects unknown tokens.
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "other-token").unwrap();
+        let provider = OidcProvider::File(file.path().to_path_buf());
+        let err = get_token(&registry_url, &provider, client.for_host(&registry_url))
+            .await
+            .err()
+            .unwrap();
+        assert!(
+            matches!(err, TrustedPublishingError::Pypi(status, ref body) if status == 422 && body == "invalid-publisher"),
+            "{err}"
+        );
+
+        // Empty tokens are rejected before querying the registry.
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let provider = OidcProvider::File(file.path().to_path_buf());
+        let err = get_token(&registry_url, &provider, client.for_host(&registry_url))
+            .await
+            .err()
+            .unwrap();
+        assert!(
+            matches!(err, TrustedPublishingError::EmptyToken(_)),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn oidc_url_scheme() {
+        let registry = Url::parse("https://upload.pypi.org/legacy/").unwrap();
+        assert_eq!(
+            oidc_url(&registry, "audience").unwrap().as_str(),
+            "https://upload.pypi.org/_/oidc/audience"
+        );
+
+        // The OIDC token is never sent over plain HTTP to a remote host.
+        let registry = Url::parse("http://example.com/legacy/").unwrap();
+        assert_eq!(
+            oidc_url(&registry, "audience").unwrap().as_str(),
+            "https://example.com/_/oidc/audience"
+        );
+
+        // Except for local registries.
+        let registry = Url::parse("http://127.0.0.1:8080/legacy/").unwrap();
+        assert_eq!(
+            oidc_url(&registry, "audience").unwrap().as_str(),
+            "http://127.0.0.1:8080/_/oidc/audience"
+        );
+        let registry = Url::parse("http://localhost:8080/legacy/").unwrap();
+        assert_eq!(
+            oidc_url(&registry, "audience").unwrap().as_str(),
+            "http://localhost:8080/_/oidc/audience"
+        );
+    }
+
+    #[tokio::test]
+    async fn token_from_gitlab() {
+        // Use an audience that's unique to this test, such that the variable is never set.
+        let registry = start_registry("uv-test-gitlab").await;
+        let registry_url = Url::parse(&format!("{}/legacy/", registry.uri())).unwrap();
+        let client = BaseClientBuilder::new().build();
+
+        let err = get_token(
+            &registry_url,
+            &OidcProvider::GitLabCi,
+            client.for_host(&registry_url),
+        )
+        .await
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Environment variable UV_TEST_GITLAB_ID_TOKEN not set, is an `id_tokens` entry with `aud: uv-test-gitlab` missing from the GitLab CI job?"
+        );
+
+        // Read the ID token from the provided variables, rather than mutating the environment of
+        // the test process.
+        let token = read_gitlab_oidc_token("uv-test-gitlab", |var| {
+            (var == "UV_TEST_GITLAB_ID_TOKEN").then(|| "oidc-token".to_string())
+        })
+        .unwrap();
+        assert_eq!(token, "oidc-token");
+        let token = get_publish_token(&registry_url, &token, client.for_host(&registry_url))
+            .await
+            .unwrap();
+        assert_eq!(token.to_string(), "upload-token");
+    }
+
+    #[tokio::test]
+    async fn token_from_google_cloud() {
+        let registry = start_registry("pypi").await;
+        let registry_url = Url::parse(&format!("{}/legacy/", registry.uri())).unwrap();
+
+        let metadata = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/computeMetadata/v1/instance/service-accounts/default/identity",
+            ))
+            .and(query_param("audience", "pypi"))
+            .and(query_param("format", "full"))
+            .and(header("Metadata-Flavor", "Google"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("oidc-token"))
+            .expect(1)
+            .mount(&metadata)
+            .await;
+
+        let client = BaseClientBuilder::new().build();
+        let provider = OidcProvider::GoogleCloud {
+            metadata_host: metadata.address().to_string(),
+        };
+        let token = get_token(&registry_url, &provider, client.for_host(&registry_url))
+            .await
+            .unwrap();
+        assert_eq!(token.to_string(), "upload-token");
+    }
+}
@@ -50,7 +50,7 @@ import json
 import logging
 import os
 import re
-from dataclasses import asdict, dataclass, field
+from dataclasses import asdict, dataclass, field, replace
 from enum import StrEnum
 from pathlib import Path
 from typing import Generator, Iterable, NamedTuple, Self
@@ -138,6 +138,14 @@ class ImplementationName(StrEnum):
 class Variant(StrEnum):
     FREETHREADED = "freethreaded"
     DEBUG = "debug"
+    NOOPT = "noopt"
+    PGO = "pgo"
+    LTO = "lto"
+    PGO_LTO = "pgo+lto"
+
+
+# Variants that only differ from the default build in their optimizations.
+OPTIMIZATION_VARIANTS = (Variant.NOOPT, Variant.PGO, Variant.LTO, Variant.PGO_LTO)
 
 
 @dataclass
@@ -253,6 +261,12 @@ class CPythonFinder(Finder):
                     downloads_by_version.setdefault(download.version, []).append(
                         download
                     )
+                    # Builds with explicit optimizations are also candidates for the default
+                    # variant, e.g., for releases without `install_only` archives
+                    if download.variant in OPTIMIZATION_VARIANTS:
+                        downloads_by_version[download.version].append(
+                            replace(download, variant=None)
+                        )
 
         # Collapse CPython variants to a single flavor per triple and variant
         downloads = []
@@ -353,9 +367,13 @@ class CPythonFinder(Finder):
 
         build_options = build_options.split("+") if build_options else []
         variant: Variant | None
-        for variant in Variant:
-            if variant in build_options:
-                break
+        if Variant.FREETHREADED in build_options:
+            variant = Variant.FREETHREADED
+        elif Variant.DEBUG in build_options:
+            variant = Variant.DEBUG
+        elif flavor == "full":
+            # Only the `full` archives are published for each set of optimizations
+            variant = self._optimization_variant(build_options)
         else:
             variant = None
 
@@ -422,6 +440,18 @@ class CPythonFinder(Finder):
             priority = len(self.FLAVOR_PREFERENCES) + 1
         return priority
 
+    def _optimization_variant(self, build_options: list[str]) -> Variant | None:
+        if "noopt" in build_options:
+            return Variant.NOOPT
+        match ("pgo" in build_options, "lto" in build_options):
+            case (True, True):
+                return Variant.PGO_LTO
+            case (True, False):
+                return Variant.PGO
+            case (False, True):
+                return Variant.LTO
+        return None
+
     def _build_option_priority(self, build_options: list[str]) -> int:
         # Prefer optimized builds
         return -1 * sum(
@@ -542,6 +572,14 @@ def render(downloads: list[PythonDownload]) -> None:
                 return 1
             case Variant.DEBUG:
                 return 2
+            case Variant.NOOPT:
+                return 3
+            case Variant.PGO:
+                return 4
+            case Variant.LTO:
+                return 5
+            case Variant.PGO_LTO:
+                return 6
         raise ValueError(f"Missing sort key implementation for variant: {variant}")
 
     def sort_key(download: PythonDownload) -> tuple:
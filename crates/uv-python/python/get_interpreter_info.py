@@ -526,6 +526,20 @@ def get_operating_system_and_architecture():
     return {"os": operating_system, "arch": architecture}
 
 
+def get_build_optimizations():
+    """Determine whether the interpreter was built with profile-guided and link-time optimizations.
+
+    Returns `None` if the configure arguments are not available (e.g., on Windows).
+    """
+    config_args = sysconfig.get_config_var("CONFIG_ARGS")
+    if config_args is None:
+        return None
+    return {
+        "pgo": "--enable-optimizations" in config_args,
+        "lto": "--with-lto" in config_args,
+    }
+
+
 def main() -> None:
     markers = {
         "implementation_name": implementation_name,
@@ -618,6 +632,10 @@ def main() -> None:
         # The `t` abiflag for freethreading Python.
         # https://peps.python.org/pep-0703/#build-configuration-changes
         "gil_disabled": bool(sysconfig.get_config_var("Py_GIL_DISABLED")),
+        # Debug builds (`--with-pydebug`) expose `sys.gettotalrefcount` on all platforms, whereas the
+        # `Py_DEBUG` config variable is not available on Windows.
+        "debug_enabled": hasattr(sys, "gettotalrefcount"),
+        "build_optimizations": get_build_optimizations(),
         # Determine if the interpreter is 32-bit or 64-bit.
         # https://github.com/python/cpython/blob/b228655c227b2ca298a8ffac44d14ce3d22f6faa/Lib/venv/__init__.py#L136
         "pointer_size": "64" if sys.maxsize > 2**32 else "32",
@@ -17,8 +17,10 @@ use uv_static::EnvVars;
 use uv_warnings::warn_user_once;
 
 use crate::downloads::PythonDownloadRequest;
-use crate::implementation::ImplementationName;
+use crate::external::{find_external_python_executables, PythonDiscoverySource};
+use crate::implementation::{ImplementationName, LenientImplementationName};
 use crate::installation::PythonInstallation;
+use crate::interpreter::BuildOptimizations;
 use crate::interpreter::Error as InterpreterError;
 use crate::interpreter::{StatusCodeError, UnexpectedResponseError};
 use crate::managed::ManagedPythonInstallations;
@@ -146,6 +148,15 @@ pub enum PythonVariant {
     #[default]
     Default,
     Freethreaded,
+    Debug,
+    /// A build without profile-guided or link-time optimizations.
+    Noopt,
+    /// A build with profile-guided optimizations.
+    Pgo,
+    /// A build with link-time optimizations.
+    Lto,
+    /// A build with both profile-guided and link-time optimizations.
+    PgoLto,
 }
 
 /// A Python discovery version request.
@@ -203,6 +214,8 @@ pub enum PythonSource {
     Managed,
     /// The Python installation was found via the invoking interpreter i.e. via `python -m uv ...`
     ParentInterpreter,
+    /// The Python installation was found in the installation directory of another tool, e.g. pyenv
+    External(PythonDiscoverySource),
 }
 
 #[derive(Error, Debug)]
@@ -289,6 +302,7 @@ fn python_executables_from_virtual_environments<'a>(
 /// - Managed Python installations (e.g. `uv python install`)
 /// - The search path (i.e. `PATH`)
 /// - The registry (Windows only)
+/// - The installation directories of other tools, e.g. pyenv (see [`PythonDiscoverySource`])
 ///
 /// The ordering and presence of each source is determined by the [`PythonPreference`].
 ///
@@ -304,6 +318,7 @@ fn python_executables_from_installed<'a>(
     version: &'a VersionRequest,
     implementation: Option<&'a ImplementationName>,
     preference: PythonPreference,
+    discovery_sources: &'a [PythonDiscoverySource],
 ) -> Box<dyn Iterator<Item = Result<(PythonSource, PathBuf), Error>> + 'a> {
     let from_managed_installations = iter::once_with(move || {
         ManagedPythonInstallations::from_settings(None)
@@ -318,7 +333,12 @@ fn python_executables_from_installed<'a>(
                 Ok(installations
                     .into_iter()
                     .filter(move |installation| {
-                        if version.matches_version(&installation.version()) {
+                        if version.matches_version(&installation.version())
+                            && version.matches_variant(
+                                *installation.key().variant(),
+                                installation.key().implementation(),
+                            )
+                        {
                             true
                         } else {
                             debug!("Skipping incompatible managed installation `{installation}`");
@@ -388,19 +408,46 @@ fn python_executables_from_installed<'a>(
     })
     .flatten();
 
+    let from_external_sources = iter::once_with(move || {
+        find_external_python_executables(discovery_sources)
+            // Skip interpreter probing if we already know the version doesn't match.
+            .filter(move |(source, found, path)| {
+                if found
+                    .as_ref()
+                    .map_or(true, |found| version.matches_version(found))
+                {
+                    true
+                } else {
+                    debug!(
+                        "Skipping incompatible {source} installation at `{}`",
+                        path.user_display()
+                    );
+                    false
+                }
+            })
+            .map(|(source, _, path)| Ok((PythonSource::External(source), path)))
+    })
+    .flatten();
+
     match preference {
         PythonPreference::OnlyManaged => Box::new(from_managed_installations),
         PythonPreference::Managed => Box::new(
             from_managed_installations
                 .chain(from_search_path)
-                .chain(from_windows_registry),
+                .chain(from_windows_registry)
+                .chain(from_external_sources),
         ),
         PythonPreference::System => Box::new(
             from_search_path
                 .chain(from_windows_registry)
+                .chain(from_external_sources)
                 .chain(from_managed_installations),
         ),
-        PythonPreference::OnlySystem => Box::new(from_search_path.chain(from_windows_registry)),
+        PythonPreference::OnlySystem => Box::new(
+            from_search_path
+                .chain(from_windows_registry)
+                .chain(from_external_sources),
+        ),
     }
 }
 
@@ -417,6 +464,7 @@ fn python_executables<'a>(
     implementation: Option<&'a ImplementationName>,
     environments: EnvironmentPreference,
     preference: PythonPreference,
+    discovery_sources: &'a [PythonDiscoverySource],
 ) -> Box<dyn Iterator<Item = Result<(PythonSource, PathBuf), Error>> + 'a> {
     // Always read from `UV_INTERNAL__PARENT_INTERPRETER` — it could be a system interpreter
     let from_parent_interpreter = iter::once_with(|| {
@@ -436,7 +484,8 @@ fn python_executables<'a>(
     .flatten();
 
     let from_virtual_environments = python_executables_from_virtual_environments();
-    let from_installed = python_executables_from_installed(version, implementation, preference);
+    let from_installed =
+        python_executables_from_installed(version, implementation, preference, discovery_sources);
 
     // Limit the search to the relevant environment preference; this avoids unnecessary work like
     // traversal of the file system. Subsequent filtering should be done by the caller with
@@ -611,17 +660,23 @@ fn python_interpreters<'a>(
     implementation: Option<&'a ImplementationName>,
     environments: EnvironmentPreference,
     preference: PythonPreference,
+    discovery_sources: &'a [PythonDiscoverySource],
     cache: &'a Cache,
 ) -> impl Iterator<Item = Result<(PythonSource, Interpreter), Error>> + 'a {
     python_interpreters_from_executables(
         // Perform filtering on the discovered executables based on their source. This avoids
         // unnecessary interpreter queries, which are generally expensive. We'll filter again
         // with `interpreter_satisfies_environment_preference` after querying.
-        python_executables(version, implementation, environments, preference).filter_ok(
-            move |(source, path)| {
-                source_satisfies_environment_preference(*source, path, environments)
-            },
-        ),
+        python_executables(
+            version,
+            implementation,
+            environments,
+            preference,
+            discovery_sources,
+        )
+        .filter_ok(move |(source, path)| {
+            source_satisfies_environment_preference(*source, path, environments)
+        }),
         cache,
     )
     .filter_ok(move |(source, interpreter)| {
@@ -676,7 +731,11 @@ fn interpreter_satisfies_environment_preference(
     match (
         preference,
         // Conda environments are not conformant virtual environments but we treat them as such.
-        interpreter.is_virtualenv() || (matches!(source, PythonSource::CondaPrefix)),
+        interpreter.is_virtualenv()
+            || matches!(
+                source,
+                PythonSource::CondaPrefix | PythonSource::External(PythonDiscoverySource::Conda)
+            ),
     ) {
         (EnvironmentPreference::Any, _) => true,
         (EnvironmentPreference::OnlyVirtual, true) => true,
@@ -858,6 +917,7 @@ pub fn find_python_installations<'a>(
     request: &'a PythonRequest,
     environments: EnvironmentPreference,
     preference: PythonPreference,
+    discovery_sources: &'a [PythonDiscoverySource],
     cache: &'a Cache,
 ) -> Box<dyn Iterator<Item = Result<FindPythonResult, Error>> + 'a> {
     let sources = DiscoveryPreferences {
@@ -939,8 +999,15 @@ pub fn find_python_installations<'a>(
         }
         PythonRequest::Any => Box::new({
             debug!("Searching for any Python interpreter in {sources}");
-            python_interpreters(&VersionRequest::Any, None, environments, preference, cache)
-                .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
+            python_interpreters(
+                &VersionRequest::Any,
+                None,
+                environments,
+                preference,
+                discovery_sources,
+                cache,
+            )
+            .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
         }),
         PythonRequest::Default => Box::new({
             debug!("Searching for default Python interpreter in {sources}");
@@ -949,6 +1016,7 @@ pub fn find_python_installations<'a>(
                 None,
                 environments,
                 preference,
+                discovery_sources,
                 cache,
             )
             .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
@@ -959,8 +1027,15 @@ pub fn find_python_installations<'a>(
             };
             Box::new({
                 debug!("Searching for {request} in {sources}");
-                python_interpreters(version, None, environments, preference, cache)
-                    .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
+                python_interpreters(
+                    version,
+                    None,
+                    environments,
+                    preference,
+                    discovery_sources,
+                    cache,
+                )
+                .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
             })
         }
         PythonRequest::Implementation(implementation) => Box::new({
@@ -970,6 +1045,7 @@ pub fn find_python_installations<'a>(
                 Some(implementation),
                 environments,
                 preference,
+                discovery_sources,
                 cache,
             )
             .filter_ok(|(_source, interpreter)| {
@@ -990,6 +1066,7 @@ pub fn find_python_installations<'a>(
                     Some(implementation),
                     environments,
                     preference,
+                    discovery_sources,
                     cache,
                 )
                 .filter_ok(|(_source, interpreter)| {
@@ -1013,6 +1090,7 @@ pub fn find_python_installations<'a>(
                     request.implementation(),
                     environments,
                     preference,
+                    discovery_sources,
                     cache,
                 )
                 .filter_ok(|(_source, interpreter)| request.satisfied_by_interpreter(interpreter))
@@ -1030,9 +1108,11 @@ pub(crate) fn find_python_installation(
     request: &PythonRequest,
     environments: EnvironmentPreference,
     preference: PythonPreference,
+    discovery_sources: &[PythonDiscoverySource],
     cache: &Cache,
 ) -> Result<FindPythonResult, Error> {
-    let installations = find_python_installations(request, environments, preference, cache);
+    let installations =
+        find_python_installations(request, environments, preference, discovery_sources, cache);
     let mut first_prerelease = None;
     let mut first_error = None;
     for result in installations {
@@ -1127,13 +1207,15 @@ pub fn find_best_python_installation(
     request: &PythonRequest,
     environments: EnvironmentPreference,
     preference: PythonPreference,
+    discovery_sources: &[PythonDiscoverySource],
     cache: &Cache,
 ) -> Result<FindPythonResult, Error> {
     debug!("Starting Python discovery for {}", request);
 
     // First, check for an exact match (or the first available version if no Python version was provided)
     debug!("Looking for exact match for request {request}");
-    let result = find_python_installation(request, environments, preference, cache);
+    let result =
+        find_python_installation(request, environments, preference, discovery_sources, cache);
     match result {
         Ok(Ok(installation)) => {
             warn_on_unsupported_python(installation.interpreter());
@@ -1161,7 +1243,8 @@ pub fn find_best_python_installation(
         _ => None,
     } {
         debug!("Looking for relaxed patch version {request}");
-        let result = find_python_installation(&request, environments, preference, cache);
+        let result =
+            find_python_installation(&request, environments, preference, discovery_sources, cache);
         match result {
             Ok(Ok(installation)) => {
                 warn_on_unsupported_python(installation.interpreter());
@@ -1178,14 +1261,15 @@ pub fn find_best_python_installation(
     debug!("Looking for a default Python installation");
     let request = PythonRequest::Default;
     Ok(
-        find_python_installation(&request, environments, preference, cache)?.map_err(|err| {
-            // Use a more general error in this case since we looked for multiple versions
-            PythonNotFound {
-                request,
-                python_preference: err.python_preference,
-                environment_preference: err.environment_preference,
-            }
-        }),
+        find_python_installation(&request, environments, preference, discovery_sources, cache)?
+            .map_err(|err| {
+                // Use a more general error in this case since we looked for multiple versions
+                PythonNotFound {
+                    request,
+                    python_preference: err.python_preference,
+                    environment_preference: err.environment_preference,
+                }
+            }),
     )
 }
 
@@ -1343,18 +1427,89 @@ fn is_windows_store_shim(_path: &Path) -> bool {
 impl PythonVariant {
     fn matches_interpreter(self, interpreter: &Interpreter) -> bool {
         match self {
-            PythonVariant::Default => !interpreter.gil_disabled(),
+            PythonVariant::Default => !interpreter.gil_disabled() && !interpreter.debug_enabled(),
             PythonVariant::Freethreaded => interpreter.gil_disabled(),
+            PythonVariant::Debug => interpreter.debug_enabled() && !interpreter.gil_disabled(),
+            PythonVariant::Noopt
+            | PythonVariant::Pgo
+            | PythonVariant::Lto
+            | PythonVariant::PgoLto => {
+                !interpreter.gil_disabled()
+                    && !interpreter.debug_enabled()
+                    && interpreter
+                        .build_optimizations()
+                        .is_some_and(|optimizations| self.matches_optimizations(optimizations))
+            }
+        }
+    }
+
+    /// Return `true` if a build with the given optimizations satisfies the variant.
+    ///
+    /// The `pgo` and `lto` variants are satisfied by any build with at least those optimizations,
+    /// while the `noopt` variant requires a build without either.
+    fn matches_optimizations(self, optimizations: BuildOptimizations) -> bool {
+        match self {
+            Self::Default | Self::Freethreaded | Self::Debug => true,
+            Self::Noopt => !optimizations.pgo && !optimizations.lto,
+            Self::Pgo => optimizations.pgo,
+            Self::Lto => optimizations.lto,
+            Self::PgoLto => optimizations.pgo && optimizations.lto,
+        }
+    }
+
+    /// Return `true` if the variant can be satisfied by a default build of the given
+    /// implementation.
+    ///
+    /// The default CPython builds from `python-build-standalone` are compiled with profile-guided
+    /// and link-time optimizations, so they're used for the `pgo`, `lto`, and `pgo+lto` variants.
+    pub(crate) fn is_satisfied_by_default_build(
+        self,
+        implementation: &LenientImplementationName,
+    ) -> bool {
+        match self {
+            Self::Default => true,
+            Self::Pgo | Self::Lto | Self::PgoLto => {
+                *implementation == LenientImplementationName::Known(ImplementationName::CPython)
+            }
+            Self::Freethreaded | Self::Debug | Self::Noopt => false,
         }
     }
 
-    /// Return the lib or executable suffix for the variant, e.g., `t` for `python3.13t`.
+    /// Return the executable suffix for the variant, e.g., `t` for `python3.13t` or `d` for
+    /// `python3.13d`.
     ///
-    /// Returns an empty string for the default Python variant.
+    /// Returns an empty string for the default Python variant and for variants that only differ
+    /// in their optimizations, which use the default executable names.
     pub fn suffix(self) -> &'static str {
+        match self {
+            Self::Default | Self::Noopt | Self::Pgo | Self::Lto | Self::PgoLto => "",
+            Self::Freethreaded => "t",
+            Self::Debug => "d",
+        }
+    }
+
+    /// Return the suffix used to display a request for the variant, e.g., `t` for `3.13t` or
+    /// `+noopt` for `3.13+noopt`.
+    fn request_suffix(self) -> &'static str {
         match self {
             Self::Default => "",
             Self::Freethreaded => "t",
+            Self::Debug => "d",
+            Self::Noopt => "+noopt",
+            Self::Pgo => "+pgo",
+            Self::Lto => "+lto",
+            Self::PgoLto => "+pgo+lto",
+        }
+    }
+
+    /// Return the suffix for the variant's `lib` directory, e.g., `t` for `lib/python3.13t`.
+    ///
+    /// Unlike the executable, the `lib` directory of a debug build does not include the `d` ABI
+    /// flag.
+    pub fn lib_suffix(self) -> &'static str {
+        match self {
+            Self::Default | Self::Debug | Self::Noopt | Self::Pgo | Self::Lto | Self::PgoLto => "",
+            Self::Freethreaded => "t",
         }
     }
 }
@@ -1615,7 +1770,7 @@ impl PythonSource {
     /// Whether a pre-release Python installation from this source can be used without opt-in.
     pub(crate) fn allows_prereleases(self) -> bool {
         match self {
-            Self::Managed | Self::Registry | Self::MicrosoftStore => false,
+            Self::Managed | Self::Registry | Self::MicrosoftStore | Self::External(_) => false,
             Self::SearchPath
             | Self::SearchPathFirst
             | Self::CondaPrefix
@@ -1636,7 +1791,8 @@ impl PythonSource {
             // TODO(zanieb): We may want to allow this at some point, but when adding this variant
             // we want compatibility with existing behavior
             | Self::SearchPathFirst
-            | Self::MicrosoftStore => false,
+            | Self::MicrosoftStore
+            | Self::External(_) => false,
             Self::CondaPrefix
             | Self::BaseCondaPrefix
             | Self::ProvidedPath
@@ -1666,11 +1822,19 @@ impl PythonSource {
             | Self::BaseCondaPrefix
             | Self::ParentInterpreter
             | Self::SearchPathFirst => true,
-            Self::Managed | Self::SearchPath | Self::Registry | Self::MicrosoftStore => false,
+            Self::Managed
+            | Self::SearchPath
+            | Self::Registry
+            | Self::MicrosoftStore
+            | Self::External(_) => false,
         }
     }
 
     /// Whether this source **could** be a system interpreter.
+    ///
+    /// Conda environments found in the `envs` directory of a conda installation are environments,
+    /// not system interpreters. Since they are not active either, they are only considered when
+    /// any environment is allowed.
     pub(crate) fn is_maybe_system(self) -> bool {
         match self {
             Self::CondaPrefix
@@ -1681,8 +1845,16 @@ impl PythonSource {
             | Self::SearchPath
             | Self::SearchPathFirst
             | Self::Registry
-            | Self::MicrosoftStore => true,
-            Self::ActiveEnvironment | Self::DiscoveredEnvironment => false,
+            | Self::MicrosoftStore
+            | Self::External(
+                PythonDiscoverySource::Pyenv
+                | PythonDiscoverySource::Mise
+                | PythonDiscoverySource::Asdf
+                | PythonDiscoverySource::Homebrew,
+            ) => true,
+            Self::ActiveEnvironment
+            | Self::DiscoveredEnvironment
+            | Self::External(PythonDiscoverySource::Conda) => false,
         }
     }
 }
@@ -1692,7 +1864,10 @@ impl PythonPreference {
         // If not dealing with a system interpreter source, we don't care about the preference
         if !matches!(
             source,
-            PythonSource::Managed | PythonSource::SearchPath | PythonSource::Registry
+            PythonSource::Managed
+                | PythonSource::SearchPath
+                | PythonSource::Registry
+                | PythonSource::External(_)
         ) {
             return true;
         }
@@ -1701,10 +1876,16 @@ impl PythonPreference {
             PythonPreference::OnlyManaged => matches!(source, PythonSource::Managed),
             Self::Managed | Self::System => matches!(
                 source,
-                PythonSource::Managed | PythonSource::SearchPath | PythonSource::Registry
+                PythonSource::Managed
+                    | PythonSource::SearchPath
+                    | PythonSource::Registry
+                    | PythonSource::External(_)
             ),
             PythonPreference::OnlySystem => {
-                matches!(source, PythonSource::SearchPath | PythonSource::Registry)
+                matches!(
+                    source,
+                    PythonSource::SearchPath | PythonSource::Registry | PythonSource::External(_)
+                )
             }
         }
     }
@@ -1977,10 +2158,13 @@ impl VersionRequest {
             }
         }
 
-        // Include free-threaded variants
-        if self.is_freethreaded() {
+        // Include free-threaded and debug variants, e.g., `python3.13t` or `python3.13d`
+        if let Some(variant) = self
+            .variant()
+            .filter(|variant| !variant.suffix().is_empty())
+        {
             for i in 0..names.len() {
-                let name = names[i].with_variant(PythonVariant::Freethreaded);
+                let name = names[i].with_variant(variant);
                 names.push(name);
             }
         }
@@ -2094,7 +2278,8 @@ impl VersionRequest {
                 | PythonSource::SearchPathFirst
                 | PythonSource::Registry
                 | PythonSource::MicrosoftStore
-                | PythonSource::Managed => Self::Default,
+                | PythonSource::Managed
+                | PythonSource::External(_) => Self::Default,
             },
             _ => self,
         }
@@ -2140,6 +2325,30 @@ impl VersionRequest {
         }
     }
 
+    /// Check if a managed installation of the given variant and implementation can satisfy the
+    /// request.
+    ///
+    /// Installations of a non-default variant are only considered when that variant is requested,
+    /// while requests for a non-default variant are only satisfied by installations of that
+    /// variant, or by default installations if they're built with the requested optimizations.
+    ///
+    /// WARNING: Use [`VersionRequest::matches_interpreter`] too. This method is only suitable to
+    /// avoid querying interpreters if it's clear it cannot fulfill the request.
+    pub(crate) fn matches_variant(
+        &self,
+        variant: PythonVariant,
+        implementation: &LenientImplementationName,
+    ) -> bool {
+        match self.variant() {
+            None => true,
+            Some(requested) if requested == variant => true,
+            Some(requested) => {
+                variant == PythonVariant::Default
+                    && requested.is_satisfied_by_default_build(implementation)
+            }
+        }
+    }
+
     /// Check if a version is compatible with the request.
     ///
     /// WARNING: Use [`VersionRequest::matches_interpreter`] too. This method is only suitable to
@@ -2317,16 +2526,34 @@ impl FromStr for VersionRequest {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // Check if the version request is for a free-threaded Python version
-        let (s, variant) = s
-            .strip_suffix('t')
-            .map_or((s, PythonVariant::Default), |s| {
-                (s, PythonVariant::Freethreaded)
-            });
+        // Check if the version request is for a free-threaded or debug Python version, e.g.,
+        // `3.13t` or `3.13d`, or for a variant requested via a local segment, e.g.,
+        // `3.13+freethreaded` or `3.13+pgo+lto`.
+        let (s, variant) = if let Some((version, local)) = s.split_once('+') {
+            let variant = match local {
+                "freethreaded" => PythonVariant::Freethreaded,
+                "debug" => PythonVariant::Debug,
+                "noopt" => PythonVariant::Noopt,
+                "pgo" => PythonVariant::Pgo,
+                "lto" => PythonVariant::Lto,
+                "pgo+lto" => PythonVariant::PgoLto,
+                _ => return Err(Error::InvalidVersionRequest(s.to_string())),
+            };
+            (version, variant)
+        } else if let Some(s) = s.strip_suffix('t') {
+            (s, PythonVariant::Freethreaded)
+        } else if let Some(s) = s.strip_suffix('d') {
+            (s, PythonVariant::Debug)
+        } else {
+            (s, PythonVariant::Default)
+        };
 
-        if variant == PythonVariant::Freethreaded && s.ends_with('t') {
-            // More than one trailing "t" is not allowed
-            return Err(Error::InvalidVersionRequest(format!("{s}t")));
+        if variant != PythonVariant::Default && (s.ends_with('t') || s.ends_with('d')) {
+            // More than one variant is not allowed
+            return Err(Error::InvalidVersionRequest(format!(
+                "{s}{}",
+                variant.request_suffix()
+            )));
         }
 
         let Ok(version) = Version::from_str(s) else {
@@ -2341,27 +2568,6 @@ impl FromStr for VersionRequest {
             return Err(Error::InvalidVersionRequest(s.to_string()));
         }
 
-        // Check if the local version includes a variant
-        let variant = if version.local().is_empty() {
-            variant
-        } else {
-            // If we already have a variant, do not allow another to be requested
-            if variant != PythonVariant::Default {
-                return Err(Error::InvalidVersionRequest(s.to_string()));
-            }
-
-            let uv_pep440::LocalVersionSlice::Segments([uv_pep440::LocalSegment::String(local)]) =
-                version.local()
-            else {
-                return Err(Error::InvalidVersionRequest(s.to_string()));
-            };
-
-            match local.as_str() {
-                "freethreaded" => PythonVariant::Freethreaded,
-                _ => return Err(Error::InvalidVersionRequest(s.to_string())),
-            }
-        };
-
         // Cast the release components into u8s since that's what we use in `VersionRequest`
         let Ok(release) = try_into_u8_slice(&version.release()) else {
             return Err(Error::InvalidVersionRequest(s.to_string()));
@@ -2412,6 +2618,11 @@ impl FromStr for PythonVariant {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "t" | "freethreaded" => Ok(Self::Freethreaded),
+            "d" | "debug" => Ok(Self::Debug),
+            "noopt" => Ok(Self::Noopt),
+            "pgo" => Ok(Self::Pgo),
+            "lto" => Ok(Self::Lto),
+            "pgo+lto" => Ok(Self::PgoLto),
             "" => Ok(Self::Default),
             _ => Err(()),
         }
@@ -2423,6 +2634,11 @@ impl fmt::Display for PythonVariant {
         match self {
             Self::Default => f.write_str("default"),
             Self::Freethreaded => f.write_str("freethreaded"),
+            Self::Debug => f.write_str("debug"),
+            Self::Noopt => f.write_str("noopt"),
+            Self::Pgo => f.write_str("pgo"),
+            Self::Lto => f.write_str("lto"),
+            Self::PgoLto => f.write_str("pgo+lto"),
         }
     }
 }
@@ -2452,23 +2668,15 @@ impl fmt::Display for VersionRequest {
         match self {
             Self::Any => f.write_str("any"),
             Self::Default => f.write_str("default"),
-            Self::Major(major, PythonVariant::Default) => write!(f, "{major}"),
-            Self::Major(major, PythonVariant::Freethreaded) => write!(f, "{major}t"),
-            Self::MajorMinor(major, minor, PythonVariant::Default) => write!(f, "{major}.{minor}"),
-            Self::MajorMinor(major, minor, PythonVariant::Freethreaded) => {
-                write!(f, "{major}.{minor}t")
-            }
-            Self::MajorMinorPatch(major, minor, patch, PythonVariant::Default) => {
-                write!(f, "{major}.{minor}.{patch}")
-            }
-            Self::MajorMinorPatch(major, minor, patch, PythonVariant::Freethreaded) => {
-                write!(f, "{major}.{minor}.{patch}t")
+            Self::Major(major, variant) => write!(f, "{major}{}", variant.request_suffix()),
+            Self::MajorMinor(major, minor, variant) => {
+                write!(f, "{major}.{minor}{}", variant.request_suffix())
             }
-            Self::MajorMinorPrerelease(major, minor, prerelease, PythonVariant::Default) => {
-                write!(f, "{major}.{minor}{prerelease}")
+            Self::MajorMinorPatch(major, minor, patch, variant) => {
+                write!(f, "{major}.{minor}.{patch}{}", variant.request_suffix())
             }
-            Self::MajorMinorPrerelease(major, minor, prerelease, PythonVariant::Freethreaded) => {
-                write!(f, "{major}.{minor}{prerelease}t")
+            Self::MajorMinorPrerelease(major, minor, prerelease, variant) => {
+                write!(f, "{major}.{minor}{prerelease}{}", variant.request_suffix())
             }
             Self::Range(specifiers, _) => write!(f, "{specifiers}"),
         }
@@ -2508,6 +2716,7 @@ impl fmt::Display for PythonSource {
             Self::MicrosoftStore => f.write_str("Microsoft Store"),
             Self::Managed => f.write_str("managed installations"),
             Self::ParentInterpreter => f.write_str("parent interpreter"),
+            Self::External(source) => write!(f, "{source} installations"),
         }
     }
 }
@@ -3110,6 +3319,67 @@ mod tests {
             VersionRequest::from_str("3.13tt"),
             Err(Error::InvalidVersionRequest(_))
         ));
+        assert_eq!(
+            VersionRequest::from_str("3.13d").unwrap(),
+            VersionRequest::MajorMinor(3, 13, PythonVariant::Debug)
+        );
+        assert_eq!(
+            VersionRequest::from_str("3.13+debug").unwrap(),
+            VersionRequest::MajorMinor(3, 13, PythonVariant::Debug)
+        );
+        assert_eq!(
+            VersionRequest::from_str("3.12.1+debug").unwrap(),
+            VersionRequest::MajorMinorPatch(3, 12, 1, PythonVariant::Debug)
+        );
+        assert_eq!(
+            VersionRequest::from_str("3.13+debug").unwrap().to_string(),
+            "3.13d"
+        );
+        assert!(matches!(
+            VersionRequest::from_str("3.13td"),
+            Err(Error::InvalidVersionRequest(_))
+        ));
+        assert!(matches!(
+            VersionRequest::from_str("3.13d+debug"),
+            Err(Error::InvalidVersionRequest(_))
+        ));
+        assert_eq!(
+            VersionRequest::from_str("3.13+noopt").unwrap(),
+            VersionRequest::MajorMinor(3, 13, PythonVariant::Noopt)
+        );
+        assert_eq!(
+            VersionRequest::from_str("3.12.1+pgo").unwrap(),
+            VersionRequest::MajorMinorPatch(3, 12, 1, PythonVariant::Pgo)
+        );
+        assert_eq!(
+            VersionRequest::from_str("3.13+lto").unwrap(),
+            VersionRequest::MajorMinor(3, 13, PythonVariant::Lto)
+        );
+        assert_eq!(
+            VersionRequest::from_str("3.13+pgo+lto").unwrap(),
+            VersionRequest::MajorMinor(3, 13, PythonVariant::PgoLto)
+        );
+        assert_eq!(
+            VersionRequest::from_str(">=3.12,<3.14+noopt").unwrap(),
+            VersionRequest::Range(
+                VersionSpecifiers::from_str(">=3.12,<3.14").unwrap(),
+                PythonVariant::Noopt
+            )
+        );
+        assert_eq!(
+            VersionRequest::from_str("3.13+pgo+lto")
+                .unwrap()
+                .to_string(),
+            "3.13+pgo+lto"
+        );
+        assert!(matches!(
+            VersionRequest::from_str("3.13+lto+pgo"),
+            Err(Error::InvalidVersionRequest(_))
+        ));
+        assert!(matches!(
+            VersionRequest::from_str("3.13t+noopt"),
+            Err(Error::InvalidVersionRequest(_))
+        ));
     }
 
     #[test]
@@ -3186,6 +3456,18 @@ mod tests {
         );
         case("3t", &["python3t", "python3", "pythont", "python"]);
 
+        case(
+            "3.13+debug",
+            &[
+                "python3.13d",
+                "python3.13",
+                "python3d",
+                "python3",
+                "pythond",
+                "python",
+            ],
+        );
+
         case(
             "3.13.2",
             &["python3.13.2", "python3.13", "python3", "python"],
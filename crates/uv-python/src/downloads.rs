@@ -286,7 +286,12 @@ impl PythonDownloadRequest {
                 return false;
             }
             if let Some(variant) = version.variant() {
-                if variant != key.variant {
+                // There are no downloads for the optimization variants, but the default builds
+                // are optimized and can be used instead.
+                if variant != key.variant
+                    && !(key.variant == PythonVariant::Default
+                        && variant.is_satisfied_by_default_build(&key.implementation))
+                {
                     return false;
                 }
             }
@@ -807,3 +812,41 @@ async fn read_url(
         Ok((Either::Right(stream.compat()), size))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Requests for the optimization variants should be satisfied by the default builds.
+    #[test]
+    fn optimization_variant_downloads() {
+        for variant in ["pgo", "lto", "pgo+lto"] {
+            let request = PythonDownloadRequest::from_str(&format!(
+                "cpython-3.12+{variant}-linux-x86_64-gnu"
+            ))
+            .unwrap();
+            let download = ManagedPythonDownload::from_request(&request).unwrap();
+            assert_eq!(download.key().variant(), &PythonVariant::Default);
+            assert_eq!(
+                (download.key().major, download.key().minor),
+                (3, 12),
+                "{variant}"
+            );
+        }
+
+        // The default builds are optimized, so they can't be used for `noopt`.
+        let request =
+            PythonDownloadRequest::from_str("cpython-3.12+noopt-linux-x86_64-gnu").unwrap();
+        assert!(matches!(
+            ManagedPythonDownload::from_request(&request),
+            Err(Error::NoDownloadFound(_))
+        ));
+
+        // Nor for implementations other than CPython.
+        let request = PythonDownloadRequest::from_str("pypy-3.10+pgo-linux-x86_64-gnu").unwrap();
+        assert!(matches!(
+            ManagedPythonDownload::from_request(&request),
+            Err(Error::NoDownloadFound(_))
+        ));
+    }
+}
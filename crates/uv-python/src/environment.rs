@@ -152,6 +152,8 @@ impl PythonEnvironment {
             preference,
             // Ignore managed installations when looking for environments
             PythonPreference::OnlySystem,
+            // Environments are never found in the installation directories of other tools
+            &[],
             cache,
         )? {
             Ok(installation) => installation,
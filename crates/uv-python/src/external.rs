@@ -0,0 +1,333 @@
+//! Discovery of Python installations managed by other tools, e.g., pyenv or Homebrew.
+//!
+//! These tools often install Python versions into a well-known directory without adding them to
+//! the `PATH` (or only add a shim for the "global" version), so we look for them directly.
+
+use std::cmp::Reverse;
+use std::env;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use etcetera::BaseStrategy;
+use tracing::debug;
+
+use uv_fs::Simplified;
+use uv_static::EnvVars;
+
+use crate::virtualenv::virtualenv_python_executable;
+use crate::PythonVersion;
+
+/// A tool whose Python installations can be discovered outside the `PATH`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum PythonDiscoverySource {
+    /// Python versions installed by pyenv, in `$PYENV_ROOT/versions`.
+    Pyenv,
+    /// Python versions installed by mise, in `$MISE_DATA_DIR/installs/python`.
+    Mise,
+    /// Python versions installed by asdf, in `$ASDF_DATA_DIR/installs/python`.
+    Asdf,
+    /// Conda environments, in the `envs` directory of a conda installation.
+    Conda,
+    /// Python versions installed by Homebrew, in the Homebrew Cellar.
+    Homebrew,
+}
+
+impl PythonDiscoverySource {
+    /// The directories that may contain Python installations from this source.
+    fn roots(self) -> Vec<PathBuf> {
+        let home = etcetera::home_dir().ok();
+        match self {
+            Self::Pyenv => env_path(EnvVars::PYENV_ROOT)
+                .or_else(|| {
+                    home.map(|home| {
+                        if cfg!(windows) {
+                            home.join(".pyenv").join("pyenv-win")
+                        } else {
+                            home.join(".pyenv")
+                        }
+                    })
+                })
+                .map(|root| root.join("versions"))
+                .into_iter()
+                .collect(),
+            Self::Mise => env_path(EnvVars::MISE_DATA_DIR)
+                .or_else(|| {
+                    etcetera::base_strategy::choose_base_strategy()
+                        .ok()
+                        .map(|dirs| dirs.data_dir().join("mise"))
+                })
+                .map(|root| root.join("installs").join("python"))
+                .into_iter()
+                .collect(),
+            Self::Asdf => env_path(EnvVars::ASDF_DATA_DIR)
+                .or_else(|| home.map(|home| home.join(".asdf")))
+                .map(|root| root.join("installs").join("python"))
+                .into_iter()
+                .collect(),
+            Self::Conda => {
+                // `CONDA_EXE` points to `{root}/bin/conda` (or `{root}/Scripts/conda.exe`) for the
+                // conda installation used by the current shell.
+                let from_env = env_path(EnvVars::CONDA_EXE).and_then(|exe| {
+                    exe.parent()
+                        .and_then(Path::parent)
+                        .map(|root| root.join("envs"))
+                });
+                let from_home = home.into_iter().flat_map(|home| {
+                    [
+                        "miniconda3",
+                        "anaconda3",
+                        "miniforge3",
+                        "mambaforge",
+                        ".conda",
+                    ]
+                    .into_iter()
+                    .map(move |name| home.join(name).join("envs"))
+                });
+                let mut roots = Vec::new();
+                for root in from_env.into_iter().chain(from_home) {
+                    if !roots.contains(&root) {
+                        roots.push(root);
+                    }
+                }
+                roots
+            }
+            Self::Homebrew => {
+                if let Some(cellar) = env_path(EnvVars::HOMEBREW_CELLAR) {
+                    vec![cellar]
+                } else if cfg!(windows) {
+                    vec![]
+                } else {
+                    vec![
+                        PathBuf::from("/opt/homebrew/Cellar"),
+                        PathBuf::from("/usr/local/Cellar"),
+                        PathBuf::from("/home/linuxbrew/.linuxbrew/Cellar"),
+                    ]
+                }
+            }
+        }
+    }
+
+    /// Find the Python executables from this source in the given root directory.
+    ///
+    /// Returns the executables along with the Python version, if it can be determined from the
+    /// directory name, sorted from newest to oldest.
+    fn find_in(self, root: &Path) -> Vec<(Option<PythonVersion>, PathBuf)> {
+        let mut found = match self {
+            // e.g., `~/.pyenv/versions/3.12.1/bin/python3`
+            Self::Pyenv | Self::Mise | Self::Asdf => read_dirs(root)
+                .map(|dir| {
+                    let version = dir_version(&dir);
+                    (version, virtualenv_python_executable(dir))
+                })
+                .collect::<Vec<_>>(),
+            // e.g., `~/miniconda3/envs/science/bin/python3`
+            Self::Conda => read_dirs(root)
+                .map(|dir| (None, virtualenv_python_executable(dir)))
+                .collect(),
+            // e.g., `/opt/homebrew/Cellar/python@3.12/3.12.8/bin/python3.12`
+            Self::Homebrew => read_dirs(root)
+                .filter_map(|formula| {
+                    let name = formula.file_name()?.to_str()?;
+                    let minor = name.strip_prefix("python@")?.to_string();
+                    Some((formula, minor))
+                })
+                .flat_map(|(formula, minor)| {
+                    read_dirs(&formula).map(move |dir| {
+                        let version = dir_version(&dir);
+                        let executable = dir.join("bin").join(format!("python{minor}"));
+                        if executable.is_file() {
+                            (version, executable)
+                        } else {
+                            (version, dir.join("bin").join("python3"))
+                        }
+                    })
+                })
+                .collect(),
+        };
+        found.retain(|(_, executable)| executable.is_file());
+        found.sort_by_key(|(version, _)| Reverse(version.as_ref().map(|v| v.version().clone())));
+        found
+    }
+}
+
+impl fmt::Display for PythonDiscoverySource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pyenv => f.write_str("pyenv"),
+            Self::Mise => f.write_str("mise"),
+            Self::Asdf => f.write_str("asdf"),
+            Self::Conda => f.write_str("conda"),
+            Self::Homebrew => f.write_str("homebrew"),
+        }
+    }
+}
+
+/// Lazily iterate over the Python executables installed by the given external sources.
+///
+/// Executables are returned along with their Python version, if it is known from the directory
+/// layout, to allow skipping incompatible interpreters without querying them.
+pub(crate) fn find_external_python_executables(
+    sources: &[PythonDiscoverySource],
+) -> impl Iterator<Item = (PythonDiscoverySource, Option<PythonVersion>, PathBuf)> + '_ {
+    sources.iter().copied().flat_map(|source| {
+        source.roots().into_iter().flat_map(move |root| {
+            if root.is_dir() {
+                debug!(
+                    "Searching for {source} installations at `{}`",
+                    root.user_display()
+                );
+            }
+            source
+                .find_in(&root)
+                .into_iter()
+                .map(move |(version, executable)| (source, version, executable))
+        })
+    })
+}
+
+/// Read a path from an environment variable, ignoring empty values.
+fn env_path(name: &str) -> Option<PathBuf> {
+    env::var_os(name)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Iterate over the subdirectories of a directory, ignoring any errors.
+fn read_dirs(root: &Path) -> impl Iterator<Item = PathBuf> {
+    fs_err::read_dir(root)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+}
+
+/// Parse the Python version from an installation directory name, e.g., `3.12.1` or `3.12.8_1`.
+fn dir_version(dir: &Path) -> Option<PythonVersion> {
+    let name = dir.file_name()?.to_str()?;
+    // Homebrew appends a revision to the version, e.g., `3.12.8_1`.
+    let name = name.split_once('_').map_or(name, |(version, _)| version);
+    PythonVersion::from_str(name).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::PythonDiscoverySource;
+
+    fn touch(path: &Path) {
+        fs_err::create_dir_all(path.parent().unwrap()).unwrap();
+        fs_err::write(path, "").unwrap();
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn find_pyenv() {
+        let root = tempfile::tempdir().unwrap();
+        touch(&root.path().join("3.9.18").join("bin").join("python3"));
+        touch(&root.path().join("3.12.1").join("bin").join("python3"));
+        touch(
+            &root
+                .path()
+                .join("pypy3.10-7.3.12")
+                .join("bin")
+                .join("python"),
+        );
+        // Incomplete installations are skipped
+        fs_err::create_dir_all(root.path().join("3.11.7").join("bin")).unwrap();
+
+        let found = PythonDiscoverySource::Pyenv
+            .find_in(root.path())
+            .into_iter()
+            .map(|(version, executable)| {
+                (
+                    version.map(|version| version.to_string()),
+                    executable.strip_prefix(root.path()).unwrap().to_path_buf(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            vec![
+                (Some("3.12.1".to_string()), "3.12.1/bin/python3".into()),
+                (Some("3.9.18".to_string()), "3.9.18/bin/python3".into()),
+                (None, "pypy3.10-7.3.12/bin/python".into()),
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn find_homebrew() {
+        let root = tempfile::tempdir().unwrap();
+        touch(
+            &root
+                .path()
+                .join("python@3.12")
+                .join("3.12.8_1")
+                .join("bin")
+                .join("python3.12"),
+        );
+        touch(
+            &root
+                .path()
+                .join("python@3.13")
+                .join("3.13.1")
+                .join("bin")
+                .join("python3.13"),
+        );
+        // Other formulae are ignored
+        touch(
+            &root
+                .path()
+                .join("pipx")
+                .join("1.7.1")
+                .join("bin")
+                .join("python3"),
+        );
+
+        let found = PythonDiscoverySource::Homebrew
+            .find_in(root.path())
+            .into_iter()
+            .map(|(version, executable)| {
+                (
+                    version.map(|version| version.to_string()),
+                    executable.strip_prefix(root.path()).unwrap().to_path_buf(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    Some("3.13.1".to_string()),
+                    "python@3.13/3.13.1/bin/python3.13".into()
+                ),
+                (
+                    Some("3.12.8".to_string()),
+                    "python@3.12/3.12.8_1/bin/python3.12".into()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn find_conda() {
+        let root = tempfile::tempdir().unwrap();
+        touch(&root.path().join("science").join("bin").join("python3"));
+
+        let found = PythonDiscoverySource::Conda.find_in(root.path());
+        assert_eq!(
+            found,
+            vec![(
+                None,
+                root.path().join("science").join("bin").join("python3")
+            )]
+        );
+    }
+}
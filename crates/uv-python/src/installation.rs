@@ -15,8 +15,8 @@ use crate::implementation::LenientImplementationName;
 use crate::managed::{ManagedPythonInstallation, ManagedPythonInstallations};
 use crate::platform::{Arch, Libc, Os};
 use crate::{
-    downloads, Error, ImplementationName, Interpreter, PythonDownloads, PythonPreference,
-    PythonSource, PythonVariant, PythonVersion,
+    downloads, Error, ImplementationName, Interpreter, PythonDiscoverySource, PythonDownloads,
+    PythonPreference, PythonSource, PythonVariant, PythonVersion,
 };
 
 /// A Python interpreter and accompanying tools.
@@ -53,9 +53,11 @@ impl PythonInstallation {
         request: &PythonRequest,
         environments: EnvironmentPreference,
         preference: PythonPreference,
+        discovery_sources: &[PythonDiscoverySource],
         cache: &Cache,
     ) -> Result<Self, Error> {
-        let installation = find_python_installation(request, environments, preference, cache)??;
+        let installation =
+            find_python_installation(request, environments, preference, discovery_sources, cache)??;
         Ok(installation)
     }
 
@@ -65,12 +67,14 @@ impl PythonInstallation {
         request: &PythonRequest,
         environments: EnvironmentPreference,
         preference: PythonPreference,
+        discovery_sources: &[PythonDiscoverySource],
         cache: &Cache,
     ) -> Result<Self, Error> {
         Ok(find_best_python_installation(
             request,
             environments,
             preference,
+            discovery_sources,
             cache,
         )??)
     }
@@ -82,6 +86,7 @@ impl PythonInstallation {
         request: Option<&PythonRequest>,
         environments: EnvironmentPreference,
         preference: PythonPreference,
+        discovery_sources: &[PythonDiscoverySource],
         python_downloads: PythonDownloads,
         client_builder: &BaseClientBuilder<'_>,
         cache: &Cache,
@@ -92,7 +97,7 @@ impl PythonInstallation {
         let request = request.unwrap_or(&PythonRequest::Default);
 
         // Search for the installation
-        let err = match Self::find(request, environments, preference, cache) {
+        let err = match Self::find(request, environments, preference, discovery_sources, cache) {
             Ok(installation) => return Ok(installation),
             Err(err) => err,
         };
@@ -168,9 +173,9 @@ impl PythonInstallation {
             )
             .await?;
 
-        let path = match result {
-            DownloadResult::AlreadyAvailable(path) => path,
-            DownloadResult::Fetched(path) => path,
+        let (path, fetched) = match result {
+            DownloadResult::AlreadyAvailable(path) => (path, false),
+            DownloadResult::Fetched(path) => (path, true),
         };
 
         let installed = ManagedPythonInstallation::new(path, download);
@@ -180,6 +185,9 @@ impl PythonInstallation {
         if let Err(e) = installed.ensure_dylib_patched() {
             e.warn_user(&installed);
         }
+        if fetched {
+            installed.write_manifest().await;
+        }
 
         Ok(Self {
             source: PythonSource::Managed,
@@ -388,7 +396,7 @@ impl fmt::Display for PythonInstallationKey {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let variant = match self.variant {
             PythonVariant::Default => String::new(),
-            PythonVariant::Freethreaded => format!("+{}", self.variant),
+            _ => format!("+{}", self.variant),
         };
         write!(
             f,
@@ -35,6 +35,7 @@ use crate::{
 
 /// A Python executable and its associated platform markers.
 #[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct Interpreter {
     platform: Platform,
     markers: Box<MarkerEnvironment>,
@@ -54,6 +55,8 @@ pub struct Interpreter {
     prefix: Option<Prefix>,
     pointer_size: PointerSize,
     gil_disabled: bool,
+    debug_enabled: bool,
+    build_optimizations: Option<BuildOptimizations>,
 }
 
 impl Interpreter {
@@ -77,6 +80,8 @@ impl Interpreter {
             sys_base_exec_prefix: info.sys_base_exec_prefix,
             pointer_size: info.pointer_size,
             gil_disabled: info.gil_disabled,
+            debug_enabled: info.debug_enabled,
+            build_optimizations: info.build_optimizations,
             sys_base_prefix: info.sys_base_prefix,
             sys_base_executable: info.sys_base_executable,
             sys_executable: info.sys_executable,
@@ -161,7 +166,7 @@ impl Interpreter {
             base_executable,
             self.python_major(),
             self.python_minor(),
-            self.variant().suffix(),
+            self.variant().lib_suffix(),
         ) {
             Ok(path) => path,
             Err(err) => {
@@ -207,6 +212,8 @@ impl Interpreter {
     pub fn variant(&self) -> PythonVariant {
         if self.gil_disabled() {
             PythonVariant::Freethreaded
+        } else if self.debug_enabled() {
+            PythonVariant::Debug
         } else {
             PythonVariant::default()
         }
@@ -452,6 +459,22 @@ impl Interpreter {
         self.gil_disabled
     }
 
+    /// Return whether this is a debug build of Python, as indicated by the presence of
+    /// `sys.gettotalrefcount`.
+    ///
+    /// Debug builds use a distinct ABI, with a `d` abiflag on the executable and shared library.
+    pub fn debug_enabled(&self) -> bool {
+        self.debug_enabled
+    }
+
+    /// Return the optimizations this build of Python was configured with, as indicated by the
+    /// `CONFIG_ARGS` configuration variable.
+    ///
+    /// Returns `None` if the configure arguments are not available, e.g., on Windows.
+    pub fn build_optimizations(&self) -> Option<BuildOptimizations> {
+        self.build_optimizations
+    }
+
     /// Return the `--target` directory for this interpreter, if any.
     pub fn target(&self) -> Option<&Target> {
         self.target.as_ref()
@@ -714,6 +737,7 @@ pub enum InterpreterInfoError {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[allow(clippy::struct_excessive_bools)]
 struct InterpreterInfo {
     platform: Platform,
     markers: MarkerEnvironment,
@@ -730,6 +754,18 @@ struct InterpreterInfo {
     standalone: bool,
     pointer_size: PointerSize,
     gil_disabled: bool,
+    debug_enabled: bool,
+    #[serde(default)]
+    build_optimizations: Option<BuildOptimizations>,
+}
+
+/// The optimizations a Python build was configured with.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct BuildOptimizations {
+    /// Whether the build uses profile-guided optimizations (`--enable-optimizations`).
+    pub pgo: bool,
+    /// Whether the build uses link-time optimizations (`--with-lto`).
+    pub lto: bool,
 }
 
 impl InterpreterInfo {
@@ -1083,7 +1119,12 @@ mod tests {
                 "scripts": "bin"
             },
             "pointer_size": "64",
-            "gil_disabled": true
+            "gil_disabled": true,
+            "debug_enabled": false,
+            "build_optimizations": {
+                "pgo": true,
+                "lto": true
+            }
         }
     "##};
 
@@ -9,16 +9,18 @@ pub use crate::discovery::{
     PythonNotFound, PythonPreference, PythonRequest, PythonSource, PythonVariant, VersionRequest,
 };
 pub use crate::environment::{InvalidEnvironmentKind, PythonEnvironment};
+pub use crate::external::PythonDiscoverySource;
 pub use crate::implementation::ImplementationName;
 pub use crate::installation::{PythonInstallation, PythonInstallationKey};
-pub use crate::interpreter::{Error as InterpreterError, Interpreter};
+pub use crate::interpreter::{BuildOptimizations, Error as InterpreterError, Interpreter};
 pub use crate::pointer_size::PointerSize;
 pub use crate::prefix::Prefix;
 pub use crate::python_version::PythonVersion;
 pub use crate::target::Target;
 pub use crate::version_files::{
     DiscoveryOptions as VersionFileDiscoveryOptions, FilePreference as VersionFilePreference,
-    PythonVersionFile, PYTHON_VERSIONS_FILENAME, PYTHON_VERSION_FILENAME,
+    PythonVersionFile, VersionFileKind, MISE_TOML_FILENAMES, PYTHON_VERSIONS_FILENAME,
+    PYTHON_VERSION_FILENAME, RUNTIME_TXT_FILENAME, TOOL_VERSIONS_FILENAME,
 };
 pub use crate::virtualenv::{Error as VirtualEnvError, PyVenvConfiguration, VirtualEnvironment};
 
@@ -26,6 +28,7 @@ mod cpuinfo;
 mod discovery;
 pub mod downloads;
 mod environment;
+mod external;
 mod implementation;
 mod installation;
 mod interpreter;
@@ -268,7 +271,9 @@ mod tests {
                         "scripts": "bin"
                     },
                     "pointer_size": "64",
-                    "gil_disabled": {FREE_THREADED}
+                    "gil_disabled": {FREE_THREADED},
+                    "debug_enabled": false,
+                    "build_optimizations": null
                 }
             "##};
 
@@ -444,6 +449,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::default(),
+                &[],
                 &context.cache,
             )
         });
@@ -458,6 +464,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::default(),
+                &[],
                 &context.cache,
             )
         });
@@ -482,6 +489,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::default(),
+                &[],
                 &context.cache,
             )
         });
@@ -503,6 +511,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::default(),
+                &[],
                 &context.cache,
             )
         })??;
@@ -564,6 +573,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::default(),
+                &[],
                 &context.cache,
             )
         })??;
@@ -595,6 +605,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::default(),
+                &[],
                 &context.cache,
             )
         });
@@ -631,6 +642,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::default(),
+                &[],
                 &context.cache,
             )
         })??;
@@ -662,6 +674,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -683,6 +696,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -708,6 +722,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -733,6 +748,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -755,6 +771,7 @@ mod tests {
                 &PythonRequest::parse("3.11"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -788,6 +805,7 @@ mod tests {
                 &PythonRequest::parse("3.11.2"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -821,6 +839,7 @@ mod tests {
                 &PythonRequest::parse("3.9"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })?;
@@ -842,6 +861,7 @@ mod tests {
                 &PythonRequest::parse("3.11.9"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })?;
@@ -863,6 +883,7 @@ mod tests {
                 &PythonRequest::parse("3.11.3"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -896,6 +917,7 @@ mod tests {
                 &PythonRequest::parse("3.11.11"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -932,6 +954,7 @@ mod tests {
                     &PythonRequest::parse("3.10"),
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             })??;
@@ -962,6 +985,7 @@ mod tests {
                     &PythonRequest::parse("3.10.2"),
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             })??;
@@ -996,6 +1020,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             })??;
@@ -1021,6 +1046,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             })??;
@@ -1047,6 +1073,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::OnlyVirtual,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             },
@@ -1071,6 +1098,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::OnlyVirtual,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             },
@@ -1092,6 +1120,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::OnlySystem,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             },
@@ -1114,6 +1143,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::OnlyVirtual,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             },
@@ -1146,6 +1176,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             },
@@ -1166,6 +1197,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             },
@@ -1192,6 +1224,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -1209,6 +1242,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -1237,6 +1271,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             })??;
@@ -1274,6 +1309,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             },
@@ -1301,6 +1337,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             },
@@ -1325,6 +1362,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::ExplicitSystem,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             },
@@ -1349,6 +1387,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::OnlySystem,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             },
@@ -1373,6 +1412,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::OnlyVirtual,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             },
@@ -1410,6 +1450,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             },
@@ -1437,6 +1478,7 @@ mod tests {
                     &PythonRequest::Default,
                     EnvironmentPreference::OnlySystem,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             })??;
@@ -1453,6 +1495,7 @@ mod tests {
                     &PythonRequest::parse("3.12"),
                     EnvironmentPreference::OnlySystem,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             })??;
@@ -1469,6 +1512,7 @@ mod tests {
                     &PythonRequest::parse("3.12.3"),
                     EnvironmentPreference::OnlySystem,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             })?;
@@ -1490,6 +1534,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::OnlyVirtual,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })?;
@@ -1506,6 +1551,7 @@ mod tests {
                     &PythonRequest::parse("3.12.3"),
                     EnvironmentPreference::OnlySystem,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             },
@@ -1527,6 +1573,7 @@ mod tests {
                 &PythonRequest::parse("foobar"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -1541,6 +1588,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })?;
@@ -1554,6 +1602,7 @@ mod tests {
                 &PythonRequest::parse("3.10.0"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })?;
@@ -1582,6 +1631,7 @@ mod tests {
                 &PythonRequest::parse("./foo/bar"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -1597,6 +1647,7 @@ mod tests {
                 &PythonRequest::parse("./foo/bar"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -1626,6 +1677,7 @@ mod tests {
                 &PythonRequest::parse(python_path.to_str().unwrap()),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -1641,6 +1693,7 @@ mod tests {
                 &PythonRequest::parse(python_path.to_str().unwrap()),
                 EnvironmentPreference::ExplicitSystem,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -1656,6 +1709,7 @@ mod tests {
                 &PythonRequest::parse(python_path.to_str().unwrap()),
                 EnvironmentPreference::OnlyVirtual,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -1671,6 +1725,7 @@ mod tests {
                 &PythonRequest::parse(python_path.to_str().unwrap()),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -1694,6 +1749,7 @@ mod tests {
                 &PythonRequest::parse("../foo/.venv"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -1708,6 +1764,7 @@ mod tests {
                 &PythonRequest::parse(venv.to_str().unwrap()),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -1731,6 +1788,7 @@ mod tests {
                 &PythonRequest::parse(context.tempdir.child("bar").to_str().unwrap()),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -1750,6 +1808,7 @@ mod tests {
                     &PythonRequest::parse(venv.to_str().unwrap()),
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             },
@@ -1778,6 +1837,7 @@ mod tests {
                 &PythonRequest::parse("../proj/.venv"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -1799,6 +1859,7 @@ mod tests {
                 &PythonRequest::parse("./foo/bar"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })?;
@@ -1828,6 +1889,7 @@ mod tests {
                 &PythonRequest::parse("bar"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -1843,6 +1905,7 @@ mod tests {
                 &PythonRequest::parse("bar"),
                 EnvironmentPreference::ExplicitSystem,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })?;
@@ -1869,6 +1932,7 @@ mod tests {
                     &PythonRequest::parse("bar"),
                     EnvironmentPreference::ExplicitSystem,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             })
@@ -1893,6 +1957,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })?;
@@ -1909,6 +1974,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -1923,6 +1989,7 @@ mod tests {
                 &PythonRequest::parse("pypy"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -1948,6 +2015,7 @@ mod tests {
                 &PythonRequest::parse("pypy"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -1962,6 +2030,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -1987,6 +2056,7 @@ mod tests {
                 &PythonRequest::parse("pypy3.10"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -2013,6 +2083,7 @@ mod tests {
                 &PythonRequest::parse("pypy@3.10"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -2039,6 +2110,7 @@ mod tests {
                 &PythonRequest::parse(">= 3.11"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -2065,6 +2137,7 @@ mod tests {
                 &PythonRequest::parse(">= 3.11"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -2091,6 +2164,7 @@ mod tests {
                 &PythonRequest::parse(">= 3.11"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -2118,6 +2192,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })?;
@@ -2139,6 +2214,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -2153,6 +2229,7 @@ mod tests {
                 &PythonRequest::parse("graalpy"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -2178,6 +2255,7 @@ mod tests {
                 &PythonRequest::parse("graalpy"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -2192,6 +2270,7 @@ mod tests {
                 &PythonRequest::Default,
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -2229,6 +2308,7 @@ mod tests {
                     &PythonRequest::parse("pypy@3.10"),
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             })
@@ -2246,6 +2326,7 @@ mod tests {
                     &PythonRequest::parse("pypy"),
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             })
@@ -2287,6 +2368,7 @@ mod tests {
                     &PythonRequest::parse("pypy@3.10"),
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             })
@@ -2304,6 +2386,7 @@ mod tests {
                     &PythonRequest::parse("default"),
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             })
@@ -2340,6 +2423,7 @@ mod tests {
                     &PythonRequest::parse("graalpy@3.10"),
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             })
@@ -2362,6 +2446,7 @@ mod tests {
                     &PythonRequest::parse("graalpy@3.10"),
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             })
@@ -2384,6 +2469,7 @@ mod tests {
                     &PythonRequest::parse("graalpy@3.10"),
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    &[],
                     &context.cache,
                 )
             })
@@ -2422,6 +2508,7 @@ mod tests {
                 &PythonRequest::parse("3.13t"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
@@ -2474,6 +2561,7 @@ mod tests {
                 &PythonRequest::parse("3.13"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                &[],
                 &context.cache,
             )
         })??;
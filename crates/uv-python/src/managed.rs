@@ -1,5 +1,6 @@
 use core::fmt;
 use std::cmp::Reverse;
+use std::collections::BTreeMap;
 use std::ffi::OsStr;
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
@@ -8,6 +9,8 @@ use std::str::FromStr;
 use fs_err as fs;
 use itertools::Itertools;
 use same_file::is_same_file;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use thiserror::Error;
 use tracing::{debug, warn};
 
@@ -15,6 +18,7 @@ use uv_fs::{symlink_or_copy_file, LockedFile, Simplified};
 use uv_state::{StateBucket, StateStore};
 use uv_static::EnvVars;
 use uv_trampoline_builder::{windows_python_launcher, Launcher};
+use uv_warnings::warn_user;
 
 use crate::downloads::{Error as DownloadError, ManagedPythonDownload};
 use crate::implementation::{
@@ -91,6 +95,10 @@ pub enum Error {
     LibcDetection(#[from] LibcDetectionError),
     #[error(transparent)]
     MacOsDylib(#[from] macos_dylib::Error),
+    #[error(transparent)]
+    WalkDir(#[from] walkdir::Error),
+    #[error("Failed to read Python installation manifest: {}", _0.user_display())]
+    Manifest(PathBuf, #[source] serde_json::Error),
 }
 /// A collection of uv-managed Python installations installed on the current system.
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -287,6 +295,34 @@ static EXTERNALLY_MANAGED: &str = "[externally-managed]
 Error=This Python installation is managed by uv and should not be modified.
 ";
 
+/// The name of the file recording the contents of a managed Python installation.
+static MANIFEST: &str = ".uv-manifest.json";
+
+/// A record of the files in a managed Python installation, written at install time.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct InstallationManifest {
+    /// The SHA-256 digest of each file, keyed by its path relative to the installation root.
+    files: BTreeMap<String, String>,
+}
+
+/// A file in a managed Python installation that does not match the installation manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManifestMismatch {
+    /// The file was removed.
+    Missing(String),
+    /// The contents of the file changed.
+    Modified(String),
+}
+
+impl fmt::Display for ManifestMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing(path) => write!(f, "`{path}` is missing"),
+            Self::Modified(path) => write!(f, "`{path}` was modified"),
+        }
+    }
+}
+
 /// A uv-managed Python installation on the current system.
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub struct ManagedPythonInstallation {
@@ -506,7 +542,7 @@ impl ManagedPythonInstallation {
         let stdlib = if matches!(self.key.os, Os(target_lexicon::OperatingSystem::Windows)) {
             self.python_dir().join("Lib")
         } else {
-            let lib_suffix = self.key.variant.suffix();
+            let lib_suffix = self.key.variant.lib_suffix();
             let python = if matches!(
                 self.key.implementation,
                 LenientImplementationName::Known(ImplementationName::PyPy)
@@ -532,13 +568,130 @@ impl ManagedPythonInstallation {
                     self.path(),
                     self.key.major,
                     self.key.minor,
-                    self.key.variant.suffix(),
+                    self.key.variant.lib_suffix(),
                 )?;
             }
         }
         Ok(())
     }
 
+    /// Returns `true` if the `sysconfig` data has been patched to match the installation path.
+    ///
+    /// See [`ManagedPythonInstallation::ensure_sysconfig_patched`].
+    pub fn is_sysconfig_patched(&self) -> Result<bool, Error> {
+        if cfg!(unix) {
+            if *self.implementation() == ImplementationName::CPython {
+                return Ok(sysconfig::is_sysconfig_patched(
+                    self.path(),
+                    self.key.major,
+                    self.key.minor,
+                    self.key.variant.lib_suffix(),
+                )?);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Write a manifest of the files in the installation, for later use by
+    /// [`ManagedPythonInstallation::verify_manifest`].
+    ///
+    /// Should be called once the installation is complete, i.e., after any patching. The manifest
+    /// is only used for verification, so a failure to write it is reported as a warning rather
+    /// than failing the installation.
+    pub async fn write_manifest(&self) {
+        let installation = self.clone();
+        let result =
+            tokio::task::spawn_blocking(move || installation.write_manifest_blocking()).await;
+        let err = match result {
+            Ok(Ok(())) => return,
+            Ok(Err(err)) => err.to_string(),
+            Err(err) => err.to_string(),
+        };
+        warn_user!(
+            "Failed to write the manifest for `{}`; `uv python verify` will not be able to check its files: {err}",
+            self.key
+        );
+    }
+
+    /// Write a manifest of the files in the installation, blocking the current thread.
+    fn write_manifest_blocking(&self) -> Result<(), Error> {
+        let mut files = BTreeMap::new();
+        for entry in walkdir::WalkDir::new(&self.path)
+            .into_iter()
+            // Bytecode and installed packages are expected to change after installation.
+            .filter_entry(|entry| {
+                !(entry.file_type().is_dir()
+                    && matches!(
+                        entry.file_name().to_str(),
+                        Some("__pycache__" | "site-packages")
+                    ))
+            })
+        {
+            let entry = entry?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let Ok(relative) = entry.path().strip_prefix(&self.path) else {
+                continue;
+            };
+            let relative = relative
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .join("/");
+            if relative == MANIFEST {
+                continue;
+            }
+            // Scripts are rewritten when packages are installed into the base interpreter, e.g.,
+            // by `pip install --upgrade pip`, so only the Python executables are tracked.
+            let python_relative = relative.strip_prefix("install/").unwrap_or(&relative);
+            if let Some(script) = python_relative
+                .strip_prefix("bin/")
+                .or_else(|| python_relative.strip_prefix("Scripts/"))
+            {
+                if !script.starts_with("python") {
+                    continue;
+                }
+            }
+            files.insert(relative, hash_file(entry.path())?);
+        }
+
+        let path = self.path.join(MANIFEST);
+        let contents = serde_json::to_string(&InstallationManifest { files })
+            .map_err(|err| Error::Manifest(path.clone(), err))?;
+        fs_err::write(&path, contents)?;
+
+        Ok(())
+    }
+
+    /// Compare the files in the installation against the manifest written at install time.
+    ///
+    /// Returns `None` if the installation does not have a manifest, e.g., if it was installed by an
+    /// older version of uv.
+    pub fn verify_manifest(&self) -> Result<Option<Vec<ManifestMismatch>>, Error> {
+        let path = self.path.join(MANIFEST);
+        let contents = match fs_err::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let manifest: InstallationManifest =
+            serde_json::from_str(&contents).map_err(|err| Error::Manifest(path, err))?;
+
+        let mut mismatches = Vec::new();
+        for (relative, expected) in manifest.files {
+            match hash_file(&self.path.join(&relative)) {
+                Ok(digest) if digest == expected => {}
+                Ok(_) => mismatches.push(ManifestMismatch::Modified(relative)),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                    mismatches.push(ManifestMismatch::Missing(relative));
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        Ok(Some(mismatches))
+    }
+
     /// On macOS, ensure that the `install_name` for the Python dylib is set
     /// correctly, rather than pointing at `/install/lib/libpython{version}.dylib`.
     /// This is necessary to ensure that native extensions written in Rust
@@ -665,6 +818,14 @@ impl ManagedPythonInstallation {
     }
 }
 
+/// Compute the SHA-256 digest of a file, as a hex string.
+fn hash_file(path: &Path) -> Result<String, io::Error> {
+    let mut file = fs_err::File::open(path)?;
+    let mut hasher = Sha256::new();
+    io::copy(&mut file, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
 /// Generate a platform portion of a key from the environment.
 pub fn platform_key_from_env() -> Result<String, Error> {
     let os = Os::from_env();
@@ -691,3 +852,49 @@ pub fn python_executable_dir() -> Result<PathBuf, Error> {
     uv_dirs::user_executable_directory(Some(EnvVars::UV_PYTHON_BIN_DIR))
         .ok_or(Error::NoExecutableDirectory)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_manifest() -> Result<(), Error> {
+        let root = tempfile::tempdir()?;
+        let path = root.path().join("cpython-3.12.1-linux-x86_64-gnu");
+        let lib = path.join("lib").join("python3.12");
+        fs_err::create_dir_all(path.join("bin"))?;
+        fs_err::create_dir_all(lib.join("__pycache__"))?;
+        fs_err::create_dir_all(lib.join("site-packages"))?;
+        fs_err::write(path.join("bin").join("python3.12"), "python")?;
+        fs_err::write(path.join("bin").join("pip3"), "#!/usr/bin/env python3.12")?;
+        fs_err::write(lib.join("os.py"), "import abc")?;
+        fs_err::write(lib.join("__pycache__").join("os.pyc"), "bytecode")?;
+        fs_err::write(lib.join("site-packages").join("six.py"), "import sys")?;
+
+        let installation = ManagedPythonInstallation::from_path(path.clone())?;
+
+        // Installations without a manifest cannot be verified.
+        assert_eq!(installation.verify_manifest()?, None);
+
+        installation.write_manifest_blocking()?;
+        assert_eq!(installation.verify_manifest()?, Some(vec![]));
+
+        // Bytecode, installed packages, and scripts are not tracked.
+        fs_err::write(lib.join("__pycache__").join("os.pyc"), "changed")?;
+        fs_err::write(lib.join("site-packages").join("six.py"), "changed")?;
+        fs_err::write(path.join("bin").join("pip3"), "#!/usr/bin/env python3")?;
+        assert_eq!(installation.verify_manifest()?, Some(vec![]));
+
+        fs_err::remove_file(path.join("bin").join("python3.12"))?;
+        fs_err::write(lib.join("os.py"), "import collections")?;
+        assert_eq!(
+            installation.verify_manifest()?,
+            Some(vec![
+                ManifestMismatch::Missing("bin/python3.12".to_string()),
+                ManifestMismatch::Modified("lib/python3.12/os.py".to_string()),
+            ])
+        );
+
+        Ok(())
+    }
+}
@@ -187,6 +187,24 @@ pub(crate) fn update_sysconfig(
     Ok(())
 }
 
+/// Returns `true` if the `sysconfig` data in a Python installation has already been patched, i.e.,
+/// if patching it again would not change any values.
+pub(crate) fn is_sysconfig_patched(
+    install_root: &Path,
+    major: u8,
+    minor: u8,
+    suffix: &str,
+) -> Result<bool, Error> {
+    let real_prefix = std::path::absolute(install_root)?;
+    let sysconfigdata = find_sysconfigdata(&real_prefix, major, minor, suffix)?;
+
+    let contents = fs_err::read_to_string(&sysconfigdata)?;
+    let data = SysconfigData::from_str(&contents)?;
+    let patched = patch_sysconfigdata(data.clone(), &real_prefix);
+
+    Ok(data == patched)
+}
+
 /// Find the `_sysconfigdata_` file in a Python installation.
 ///
 /// For example, on macOS, returns `{real_prefix}/lib/python3.12/_sysconfigdata__darwin_darwin.py"`.
@@ -420,6 +438,26 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn is_patched() -> Result<(), Error> {
+        let root = tempfile::tempdir()?;
+        let lib = root.path().join("lib").join("python3.12");
+        fs_err::create_dir_all(&lib)?;
+        fs_err::write(
+            lib.join("_sysconfigdata__linux_x86_64-linux-gnu.py"),
+            indoc! {r"
+            # system configuration generated and used by the sysconfig module
+            build_time_vars = {'BINDIR': '/install/bin', 'CC': 'clang -pthread'}
+            "},
+        )?;
+
+        assert!(!is_sysconfig_patched(root.path(), 3, 12, "")?);
+        update_sysconfig(root.path(), 3, 12, "")?;
+        assert!(is_sysconfig_patched(root.path(), 3, 12, "")?);
+
+        Ok(())
+    }
+
     #[test]
     fn test_replacements() -> Result<(), Error> {
         let sysconfigdata = [
@@ -5,8 +5,11 @@ use fs_err as fs;
 use itertools::Itertools;
 use tracing::debug;
 use uv_fs::Simplified;
+use uv_pep440::Version;
 
-use crate::PythonRequest;
+use crate::discovery::PythonVariant;
+use crate::implementation::{ImplementationName, LenientImplementationName};
+use crate::{Interpreter, PythonRequest};
 
 /// The file name for Python version pins.
 pub static PYTHON_VERSION_FILENAME: &str = ".python-version";
@@ -14,11 +17,56 @@ pub static PYTHON_VERSION_FILENAME: &str = ".python-version";
 /// The file name for multiple Python version declarations.
 pub static PYTHON_VERSIONS_FILENAME: &str = ".python-versions";
 
-/// A `.python-version` or `.python-versions` file.
+/// The file name for asdf and mise tool version declarations.
+pub static TOOL_VERSIONS_FILENAME: &str = ".tool-versions";
+
+/// The file names for mise configuration files, in order of precedence.
+pub static MISE_TOML_FILENAMES: [&str; 2] = ["mise.toml", ".mise.toml"];
+
+/// The file name for Heroku-style runtime declarations.
+pub static RUNTIME_TXT_FILENAME: &str = "runtime.txt";
+
+/// The format of a Python version file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionFileKind {
+    /// A `.python-version` file.
+    PythonVersion,
+    /// A `.python-versions` file.
+    PythonVersions,
+    /// An asdf or mise `.tool-versions` file.
+    ToolVersions,
+    /// A mise `mise.toml` or `.mise.toml` file.
+    MiseToml,
+    /// A Heroku-style `runtime.txt` file.
+    RuntimeTxt,
+}
+
+impl VersionFileKind {
+    /// Determine the kind of version file from its file name.
+    fn from_path(path: &Path) -> Self {
+        match path.file_name().and_then(|name| name.to_str()) {
+            Some(name) if name == PYTHON_VERSIONS_FILENAME => Self::PythonVersions,
+            Some(name) if name == TOOL_VERSIONS_FILENAME => Self::ToolVersions,
+            Some(name) if MISE_TOML_FILENAMES.contains(&name) => Self::MiseToml,
+            Some(name) if name == RUNTIME_TXT_FILENAME => Self::RuntimeTxt,
+            _ => Self::PythonVersion,
+        }
+    }
+
+    /// Whether the file is owned by another tool, rather than uv.
+    pub fn is_external(self) -> bool {
+        matches!(self, Self::ToolVersions | Self::MiseToml | Self::RuntimeTxt)
+    }
+}
+
+/// A `.python-version` or `.python-versions` file, or a version declaration owned by another
+/// tool, i.e., `.tool-versions`, `mise.toml`, or `runtime.txt`.
 #[derive(Debug, Clone)]
 pub struct PythonVersionFile {
     /// The path to the version file.
     path: PathBuf,
+    /// The format of the version file.
+    kind: VersionFileKind,
     /// The Python version requests declared in the file.
     versions: Vec<PythonRequest>,
 }
@@ -31,7 +79,7 @@ pub enum FilePreference {
     Versions,
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Copy)]
 pub struct DiscoveryOptions<'a> {
     /// The path to stop discovery at.
     stop_discovery_at: Option<&'a Path>,
@@ -39,6 +87,9 @@ pub struct DiscoveryOptions<'a> {
     ///
     /// Discovery will still run in order to display a log about the ignored file.
     no_config: bool,
+    /// When `no_external` is set, version files owned by other tools (e.g., `.tool-versions`)
+    /// will be ignored.
+    no_external: bool,
     preference: FilePreference,
 }
 
@@ -48,6 +99,14 @@ impl<'a> DiscoveryOptions<'a> {
         Self { no_config, ..self }
     }
 
+    #[must_use]
+    pub fn with_no_external(self, no_external: bool) -> Self {
+        Self {
+            no_external,
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn with_preference(self, preference: FilePreference) -> Self {
         Self { preference, ..self }
@@ -68,34 +127,46 @@ impl PythonVersionFile {
         working_directory: impl AsRef<Path>,
         options: &DiscoveryOptions<'_>,
     ) -> Result<Option<Self>, std::io::Error> {
-        let Some(path) = Self::find_nearest(working_directory, options) else {
-            return Ok(None);
-        };
+        for directory in working_directory.as_ref().ancestors().take_while(|path| {
+            // Only walk up the given directory, if any.
+            options
+                .stop_discovery_at
+                .and_then(Path::parent)
+                .map(|stop_discovery_at| stop_discovery_at != *path)
+                .unwrap_or(true)
+        }) {
+            if let Some(path) = Self::find_in_directory(directory, options) {
+                if options.no_config {
+                    debug!(
+                        "Ignoring Python version file at `{}` due to `--no-config`",
+                        path.user_display()
+                    );
+                    return Ok(None);
+                }
 
-        if options.no_config {
-            debug!(
-                "Ignoring Python version file at `{}` due to `--no-config`",
-                path.user_display()
-            );
-            return Ok(None);
-        }
+                // Uses `try_from_path` instead of `from_path` to avoid TOCTOU failures.
+                return Self::try_from_path(path).await;
+            }
 
-        // Uses `try_from_path` instead of `from_path` to avoid TOCTOU failures.
-        Self::try_from_path(path).await
-    }
+            if options.no_external {
+                continue;
+            }
 
-    fn find_nearest(path: impl AsRef<Path>, options: &DiscoveryOptions<'_>) -> Option<PathBuf> {
-        path.as_ref()
-            .ancestors()
-            .take_while(|path| {
-                // Only walk up the given directory, if any.
-                options
-                    .stop_discovery_at
-                    .and_then(Path::parent)
-                    .map(|stop_discovery_at| stop_discovery_at != *path)
-                    .unwrap_or(true)
-            })
-            .find_map(|path| Self::find_in_directory(path, options))
+            // Fall back to version files owned by other tools, which only take effect if they
+            // declare a Python version.
+            if let Some(file) = Self::find_external_in_directory(directory).await? {
+                if options.no_config {
+                    debug!(
+                        "Ignoring Python version file at `{}` due to `--no-config`",
+                        file.path.user_display()
+                    );
+                    return Ok(None);
+                }
+                return Ok(Some(file));
+            }
+        }
+
+        Ok(None)
     }
 
     fn find_in_directory(path: &Path, options: &DiscoveryOptions<'_>) -> Option<PathBuf> {
@@ -110,6 +181,55 @@ impl PythonVersionFile {
         paths.into_iter().find(|path| path.is_file())
     }
 
+    /// Find the first version file owned by another tool in the given directory that declares a
+    /// Python version, reading each candidate at most once.
+    async fn find_external_in_directory(path: &Path) -> Result<Option<Self>, std::io::Error> {
+        let candidates = std::iter::once(TOOL_VERSIONS_FILENAME)
+            .chain(MISE_TOML_FILENAMES)
+            .chain(std::iter::once(RUNTIME_TXT_FILENAME))
+            .map(|name| path.join(name));
+
+        for path in candidates {
+            let content = match fs::tokio::read_to_string(&path).await {
+                Ok(content) => content,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(err) => {
+                    debug!(
+                        "Failed to read version file at `{}`: {err}",
+                        path.user_display()
+                    );
+                    continue;
+                }
+            };
+            let file = Self::from_content(path, &content)?;
+            if !file.versions.is_empty() {
+                debug!(
+                    "Reading Python requests from version file at `{}`",
+                    file.path.display()
+                );
+                return Ok(Some(file));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Parse the contents of a version file at the given path.
+    fn from_content(path: PathBuf, content: &str) -> Result<Self, std::io::Error> {
+        let kind = VersionFileKind::from_path(&path);
+        let versions = parse(kind, content).map_err(|err| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("Failed to parse `{}`: {err}", path.user_display()),
+            )
+        })?;
+        Ok(Self {
+            path,
+            kind,
+            versions,
+        })
+    }
+
     /// Try to read a Python version file at the given path.
     ///
     /// If the file does not exist, `Ok(None)` is returned.
@@ -120,17 +240,7 @@ impl PythonVersionFile {
                     "Reading Python requests from version file at `{}`",
                     path.display()
                 );
-                let versions = content
-                    .lines()
-                    .filter(|line| {
-                        // Skip comments and empty lines.
-                        let trimmed = line.trim();
-                        !(trimmed.is_empty() || trimmed.starts_with('#'))
-                    })
-                    .map(ToString::to_string)
-                    .map(|version| PythonRequest::parse(&version))
-                    .collect();
-                Ok(Some(Self { path, versions }))
+                Self::from_content(path, &content).map(Some)
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
             Err(err) => Err(err),
@@ -155,8 +265,10 @@ impl PythonVersionFile {
     /// The file will not any include versions; see [`PythonVersionFile::with_versions`].
     /// The file will not be created; see [`PythonVersionFile::write`].
     pub fn new(path: PathBuf) -> Self {
+        let kind = VersionFileKind::from_path(&path);
         Self {
             path,
+            kind,
             versions: vec![],
         }
     }
@@ -186,33 +298,513 @@ impl PythonVersionFile {
         &self.path
     }
 
-    /// Return the file name of the version file (guaranteed to be one of `.python-version` or
-    /// `.python-versions`).
+    /// Return the file name of the version file (e.g., `.python-version` or `.tool-versions`).
     pub fn file_name(&self) -> &str {
         self.path.file_name().unwrap().to_str().unwrap()
     }
 
+    /// Return the format of the version file.
+    pub fn kind(&self) -> VersionFileKind {
+        self.kind
+    }
+
     /// Set the versions for the file.
     #[must_use]
     pub fn with_versions(self, versions: Vec<PythonRequest>) -> Self {
         Self {
             path: self.path,
+            kind: self.kind,
             versions,
         }
     }
 
     /// Update the version file on the file system.
+    ///
+    /// Files owned by other tools can't represent arbitrary Python requests; see
+    /// [`PythonVersionFile::write_interpreter`].
     pub async fn write(&self) -> Result<(), std::io::Error> {
+        if self.kind.is_external() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "`{}` is owned by another tool and must declare a resolved interpreter",
+                    self.file_name()
+                ),
+            ));
+        }
+        let versions = self
+            .versions
+            .iter()
+            .map(PythonRequest::to_canonical_string)
+            .collect::<Vec<_>>();
+        self.write_versions(&versions).await
+    }
+
+    /// Update a version file owned by another tool (e.g., `.tool-versions`) to declare the given
+    /// interpreter, in that tool's syntax.
+    ///
+    /// The file is updated in place, preserving any unrelated declarations. Returns the file with
+    /// the requests it now declares, or an error if the format can't express the interpreter.
+    pub async fn write_interpreter(
+        self,
+        interpreter: &Interpreter,
+    ) -> Result<Self, std::io::Error> {
+        let Some(version) = render_version(
+            self.kind,
+            &LenientImplementationName::from(interpreter.implementation_name()),
+            interpreter.python_version(),
+            &interpreter.markers().implementation_version().version,
+            interpreter.variant(),
+        ) else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "`{}` can't declare {} {}{}",
+                    self.file_name(),
+                    LenientImplementationName::from(interpreter.implementation_name()).pretty(),
+                    interpreter.python_version(),
+                    interpreter.variant().suffix(),
+                ),
+            ));
+        };
+        self.write_versions(std::slice::from_ref(&version)).await?;
+        let versions = parse_tool_version(&version).into_iter().collect();
+        Ok(self.with_versions(versions))
+    }
+
+    /// Write the given versions, rendered in the syntax of the file.
+    async fn write_versions(&self, versions: &[String]) -> Result<(), std::io::Error> {
         debug!("Writing Python versions to `{}`", self.path.display());
-        fs::tokio::write(
-            &self.path,
-            self.versions
+        let content = match self.kind {
+            VersionFileKind::PythonVersion | VersionFileKind::PythonVersions => {
+                versions.iter().join("\n").add("\n")
+            }
+            VersionFileKind::ToolVersions => {
+                let existing = read_existing(&self.path).await?;
+                update_tool_versions(&existing, versions)
+            }
+            VersionFileKind::MiseToml => {
+                let existing = read_existing(&self.path).await?;
+                update_mise_toml(&existing, versions).map_err(|err| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        format!("Failed to update `{}`: {err}", self.path.user_display()),
+                    )
+                })?
+            }
+            VersionFileKind::RuntimeTxt => {
+                // `runtime.txt` only supports a single version.
+                versions
+                    .first()
+                    .map(|version| format!("python-{version}\n"))
+                    .unwrap_or_default()
+            }
+        };
+
+        fs::tokio::write(&self.path, content.as_bytes()).await
+    }
+}
+
+/// Read the existing contents of a version file, treating a missing file as empty.
+async fn read_existing(path: &Path) -> Result<String, std::io::Error> {
+    match fs::tokio::read_to_string(path).await {
+        Ok(content) => Ok(content),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(String::new()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Render an interpreter version in the syntax of a version file owned by another tool, e.g.,
+/// `3.12.8` or `pypy3.10-7.3.17` for asdf and mise.
+///
+/// Returns `None` if the format can't express the interpreter.
+fn render_version(
+    kind: VersionFileKind,
+    implementation: &LenientImplementationName,
+    python_version: &Version,
+    implementation_version: &Version,
+    variant: PythonVariant,
+) -> Option<String> {
+    match (kind, implementation, variant) {
+        (
+            VersionFileKind::ToolVersions | VersionFileKind::MiseToml,
+            LenientImplementationName::Known(ImplementationName::CPython),
+            PythonVariant::Default | PythonVariant::Freethreaded,
+        ) => Some(format!("{python_version}{}", variant.suffix())),
+        (
+            VersionFileKind::ToolVersions | VersionFileKind::MiseToml,
+            LenientImplementationName::Known(ImplementationName::PyPy),
+            PythonVariant::Default,
+        ) => {
+            let release = python_version.release();
+            Some(format!(
+                "pypy{}.{}-{implementation_version}",
+                release.first()?,
+                release.get(1)?
+            ))
+        }
+        // Heroku only provides standard CPython builds.
+        (
+            VersionFileKind::RuntimeTxt,
+            LenientImplementationName::Known(ImplementationName::CPython),
+            PythonVariant::Default,
+        ) => Some(python_version.to_string()),
+        _ => None,
+    }
+}
+
+/// Parse the Python requests declared in a version file of the given kind.
+fn parse(kind: VersionFileKind, content: &str) -> Result<Vec<PythonRequest>, toml::de::Error> {
+    Ok(match kind {
+        VersionFileKind::PythonVersion | VersionFileKind::PythonVersions => content
+            .lines()
+            .filter(|line| {
+                // Skip comments and empty lines.
+                let trimmed = line.trim();
+                !(trimmed.is_empty() || trimmed.starts_with('#'))
+            })
+            .map(ToString::to_string)
+            .map(|version| PythonRequest::parse(&version))
+            .collect(),
+        VersionFileKind::ToolVersions => parse_tool_versions(content),
+        VersionFileKind::MiseToml => parse_mise_toml(content)?,
+        VersionFileKind::RuntimeTxt => parse_runtime_txt(content),
+    })
+}
+
+/// Parse a version declared by asdf or mise into a [`PythonRequest`].
+///
+/// Returns `None` for entries that defer to another tool, e.g., `system` or `ref:<sha>`.
+fn parse_tool_version(version: &str) -> Option<PythonRequest> {
+    match version {
+        "latest" => Some(PythonRequest::Default),
+        "system" => {
+            debug!("Ignoring `system` Python version declaration");
+            None
+        }
+        _ if version.starts_with("ref:") || version.starts_with("path:") => {
+            debug!("Ignoring unsupported Python version declaration: `{version}`");
+            None
+        }
+        // PyPy versions include the PyPy release, e.g., `pypy3.10-7.3.17`.
+        _ if version.starts_with("pypy") => Some(PythonRequest::parse(
+            version
+                .split_once('-')
+                .map_or(version, |(version, _)| version),
+        )),
+        _ => Some(PythonRequest::parse(version)),
+    }
+}
+
+/// Parse the `python` entry of a `.tool-versions` file.
+///
+/// Each entry is a tool name followed by one or more versions, in order of preference, e.g.,
+/// `python 3.12.1 3.11.7`.
+fn parse_tool_versions(content: &str) -> Vec<PythonRequest> {
+    content
+        .lines()
+        .map(|line| {
+            // Strip trailing comments.
+            line.split_once('#').map_or(line, |(line, _)| line).trim()
+        })
+        .find_map(|line| {
+            let mut parts = line.split_whitespace();
+            (parts.next() == Some("python")).then_some(parts)
+        })
+        .map(|versions| versions.filter_map(parse_tool_version).collect())
+        .unwrap_or_default()
+}
+
+/// Parse the `tools.python` entry of a `mise.toml` file.
+///
+/// The entry may be a single version (`python = "3.12"`), a list of versions
+/// (`python = ["3.12", "3.11"]`), or a table with a `version` key
+/// (`python = { version = "3.12" }`).
+fn parse_mise_toml(content: &str) -> Result<Vec<PythonRequest>, toml::de::Error> {
+    let table = content.parse::<toml::Table>()?;
+
+    let Some(python) = table
+        .get("tools")
+        .and_then(toml::Value::as_table)
+        .and_then(|tools| tools.get("python"))
+    else {
+        return Ok(vec![]);
+    };
+
+    Ok(match python {
+        toml::Value::Array(values) => values
+            .iter()
+            .filter_map(mise_version)
+            .filter_map(parse_tool_version)
+            .collect(),
+        value => mise_version(value)
+            .and_then(parse_tool_version)
+            .into_iter()
+            .collect(),
+    })
+}
+
+/// Extract the version from a `mise.toml` tool entry, e.g., `"3.12"` or `{ version = "3.12" }`.
+fn mise_version(value: &toml::Value) -> Option<&str> {
+    match value {
+        toml::Value::String(version) => Some(version.as_str()),
+        toml::Value::Table(table) => table.get("version").and_then(toml::Value::as_str),
+        _ => None,
+    }
+}
+
+/// Parse a `runtime.txt` file, e.g., `python-3.12.1`.
+fn parse_runtime_txt(content: &str) -> Vec<PythonRequest> {
+    content
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .and_then(|line| line.strip_prefix("python-"))
+        .map(PythonRequest::parse)
+        .into_iter()
+        .collect()
+}
+
+/// Replace the `python` entry in a `.tool-versions` file, appending one if it is missing.
+fn update_tool_versions(existing: &str, versions: &[String]) -> String {
+    let entry = format!("python {}", versions.join(" "));
+    let mut replaced = false;
+    let mut lines = existing
+        .lines()
+        .map(|line| {
+            if !replaced && line.split_whitespace().next() == Some("python") {
+                replaced = true;
+                // Preserve any trailing comment.
+                match line.split_once('#') {
+                    Some((_, comment)) => format!("{entry} #{comment}"),
+                    None => entry.clone(),
+                }
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>();
+    if !replaced {
+        lines.push(entry);
+    }
+    lines.into_iter().join("\n").add("\n")
+}
+
+/// An error encountered while updating a `mise.toml` file.
+#[derive(Debug, thiserror::Error)]
+enum MiseTomlError {
+    #[error(transparent)]
+    Parse(#[from] toml_edit::TomlError),
+    #[error("Expected `tools` to be a table")]
+    ToolsNotTable,
+}
+
+/// Replace the `tools.python` entry in a `mise.toml` file, preserving formatting elsewhere.
+///
+/// If the entry is a table (e.g., `python = { version = "3.11", virtualenv = ".venv" }`), only
+/// its `version` is replaced.
+fn update_mise_toml(existing: &str, versions: &[String]) -> Result<String, MiseTomlError> {
+    let mut doc = existing.parse::<toml_edit::DocumentMut>()?;
+    let value = match versions {
+        [version] => toml_edit::value(version.as_str()),
+        versions => toml_edit::value(
+            versions
                 .iter()
-                .map(PythonRequest::to_canonical_string)
-                .join("\n")
-                .add("\n")
-                .as_bytes(),
+                .map(String::as_str)
+                .collect::<toml_edit::Array>(),
+        ),
+    };
+    let tools = doc
+        .entry("tools")
+        .or_insert_with(toml_edit::table)
+        .as_table_like_mut()
+        .ok_or(MiseTomlError::ToolsNotTable)?;
+    match tools
+        .get_mut("python")
+        .and_then(toml_edit::Item::as_table_like_mut)
+    {
+        Some(python) => {
+            python.insert("version", value);
+        }
+        None => {
+            tools.insert("python", value);
+        }
+    }
+    Ok(doc.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn tool_versions() {
+        let content = indoc! {"
+            nodejs 20.11.0
+            # python 3.10
+            python 3.12.1 system 3.11 # fallback
+        "};
+        assert_eq!(
+            parse_tool_versions(content),
+            vec![PythonRequest::parse("3.12.1"), PythonRequest::parse("3.11")]
+        );
+        assert!(parse_tool_versions("nodejs 20.11.0\n").is_empty());
+    }
+
+    #[test]
+    fn mise_toml() {
+        assert_eq!(
+            parse_mise_toml("[tools]\npython = \"3.12\"\n").unwrap(),
+            vec![PythonRequest::parse("3.12")]
+        );
+        assert_eq!(
+            parse_mise_toml("[tools]\npython = [\"3.12\", { version = \"3.11\" }]\n").unwrap(),
+            vec![PythonRequest::parse("3.12"), PythonRequest::parse("3.11")]
+        );
+        assert_eq!(
+            parse_mise_toml("[tools]\npython = { version = \"latest\" }\n").unwrap(),
+            vec![PythonRequest::Default]
+        );
+        assert!(parse_mise_toml("[tools]\nnode = \"20\"\n")
+            .unwrap()
+            .is_empty());
+        assert!(parse_mise_toml("[tools\npython = \"3.12\"\n").is_err());
+    }
+
+    #[test]
+    fn pypy_tool_version() {
+        assert_eq!(
+            parse_tool_version("pypy3.10-7.3.17"),
+            Some(PythonRequest::parse("pypy@3.10"))
+        );
+    }
+
+    #[test]
+    fn render_interpreter_version() {
+        let cpython = LenientImplementationName::Known(ImplementationName::CPython);
+        let pypy = LenientImplementationName::Known(ImplementationName::PyPy);
+        let version = Version::from_str("3.12.8").unwrap();
+        let pypy_version = Version::from_str("7.3.17").unwrap();
+
+        assert_eq!(
+            render_version(
+                VersionFileKind::ToolVersions,
+                &cpython,
+                &version,
+                &version,
+                PythonVariant::Default
+            ),
+            Some("3.12.8".to_string())
+        );
+        assert_eq!(
+            render_version(
+                VersionFileKind::MiseToml,
+                &cpython,
+                &version,
+                &version,
+                PythonVariant::Freethreaded
+            ),
+            Some("3.12.8t".to_string())
+        );
+        assert_eq!(
+            render_version(
+                VersionFileKind::ToolVersions,
+                &pypy,
+                &Version::from_str("3.10.14").unwrap(),
+                &pypy_version,
+                PythonVariant::Default
+            ),
+            Some("pypy3.10-7.3.17".to_string())
+        );
+        assert_eq!(
+            render_version(
+                VersionFileKind::RuntimeTxt,
+                &cpython,
+                &version,
+                &version,
+                PythonVariant::Default
+            ),
+            Some("3.12.8".to_string())
+        );
+        assert_eq!(
+            render_version(
+                VersionFileKind::RuntimeTxt,
+                &pypy,
+                &Version::from_str("3.10.14").unwrap(),
+                &pypy_version,
+                PythonVariant::Default
+            ),
+            None
+        );
+        assert_eq!(
+            render_version(
+                VersionFileKind::ToolVersions,
+                &cpython,
+                &version,
+                &version,
+                PythonVariant::Debug
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn runtime_txt() {
+        assert_eq!(
+            parse_runtime_txt("python-3.12.1\n"),
+            vec![PythonRequest::parse("3.12.1")]
+        );
+        assert!(parse_runtime_txt("ruby-3.3.0\n").is_empty());
+    }
+
+    #[test]
+    fn update_tool_versions_in_place() {
+        let existing = indoc! {"
+            nodejs 20.11.0
+            python 3.11 # pinned
+        "};
+        assert_eq!(
+            update_tool_versions(existing, &["3.12".to_string()]),
+            "nodejs 20.11.0\npython 3.12 # pinned\n"
+        );
+        assert_eq!(
+            update_tool_versions("nodejs 20.11.0\n", &["3.12".to_string()]),
+            "nodejs 20.11.0\npython 3.12\n"
+        );
+    }
+
+    #[test]
+    fn update_mise_toml_in_place() {
+        let existing = indoc! {r#"
+            [tools]
+            node = "20" # lts
+            python = "3.11"
+        "#};
+        assert_eq!(
+            update_mise_toml(existing, &["3.12".to_string()]).unwrap(),
+            "[tools]\nnode = \"20\" # lts\npython = \"3.12\"\n"
+        );
+        assert_eq!(
+            update_mise_toml("", &["3.12".to_string()]).unwrap(),
+            "[tools]\npython = \"3.12\"\n"
+        );
+        let updated = update_mise_toml(
+            "[tools]\npython = { version = \"3.11\", virtualenv = \".venv\" }\n",
+            &["3.12".to_string()],
         )
-        .await
+        .unwrap();
+        assert_eq!(
+            parse_mise_toml(&updated).unwrap(),
+            vec![PythonRequest::parse("3.12")]
+        );
+        assert!(updated.contains("virtualenv = \".venv\""));
+        assert!(matches!(
+            update_mise_toml("tools = \"python\"\n", &["3.12".to_string()]),
+            Err(MiseTomlError::ToolsNotTable)
+        ));
     }
 }
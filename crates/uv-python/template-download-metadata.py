@@ -58,6 +58,14 @@ def prepare_variant(variant: str | None) -> str | None:
             return "PythonVariant::Freethreaded"
         case "debug":
             return "PythonVariant::Debug"
+        case "noopt":
+            return "PythonVariant::Noopt"
+        case "pgo":
+            return "PythonVariant::Pgo"
+        case "lto":
+            return "PythonVariant::Lto"
+        case "pgo+lto":
+            return "PythonVariant::PgoLto"
         case _:
             raise ValueError(f"Unknown variant: {variant}")
 
@@ -126,8 +134,6 @@ def main() -> None:
     data["versions"] = [
         {"key": key, "value": prepare_value(value)}
         for key, value in json.loads(VERSION_METADATA.read_text()).items()
-        # Exclude debug variants for now, we don't support them in the Rust side
-        if value["variant"] != "debug"
     ]
 
     # Render the template
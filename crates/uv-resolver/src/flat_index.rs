@@ -4,7 +4,7 @@ use std::collections::BTreeMap;
 use rustc_hash::FxHashMap;
 use tracing::instrument;
 
-use uv_client::FlatIndexEntries;
+use uv_client::{FlatIndexEntries, FlatIndexEntry};
 use uv_configuration::BuildOptions;
 use uv_distribution_filename::{DistFilename, SourceDistFilename, WheelFilename};
 use uv_distribution_types::{
@@ -203,6 +203,29 @@ impl FlatIndex {
 pub struct FlatDistributions(BTreeMap<Version, PrioritizedDist>);
 
 impl FlatDistributions {
+    /// Collect all files from a single flat index for a given package into a
+    /// [`FlatDistributions`].
+    pub fn from_entries(
+        entries: Vec<FlatIndexEntry>,
+        tags: Option<&Tags>,
+        hasher: &HashStrategy,
+        build_options: &BuildOptions,
+    ) -> Self {
+        let mut distributions = Self::default();
+        for entry in entries {
+            FlatIndex::add_file(
+                &mut distributions,
+                entry.file,
+                entry.filename,
+                tags,
+                hasher,
+                build_options,
+                entry.index,
+            );
+        }
+        distributions
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = (&Version, &PrioritizedDist)> {
         self.0.iter()
     }
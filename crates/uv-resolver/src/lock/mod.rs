@@ -2532,6 +2532,19 @@ impl Package {
         self.id.version.as_ref()
     }
 
+    /// Convert the [`Package`] to a [`Dist`] for the given platform tags, irrespective of markers.
+    ///
+    /// Selects the most compatible wheel, falling back to the source distribution if none of the
+    /// wheels are compatible with the tags.
+    pub fn to_dist_for_tags(
+        &self,
+        workspace_root: &Path,
+        tags: &Tags,
+        build_options: &BuildOptions,
+    ) -> Result<Dist, LockError> {
+        self.to_dist(workspace_root, TagPolicy::Required(tags), build_options)
+    }
+
     /// Return the fork markers for this package, if any.
     pub fn fork_markers(&self) -> &[UniversalMarker] {
         self.fork_markers.as_slice()
@@ -2558,7 +2571,7 @@ impl Package {
     }
 
     /// Returns all the hashes associated with this [`Package`].
-    fn hashes(&self) -> Vec<HashDigest> {
+    pub fn hashes(&self) -> Vec<HashDigest> {
         let mut hashes = Vec::new();
         if let Some(ref sdist) = self.sdist {
             if let Some(hash) = sdist.hash() {
@@ -1,6 +1,7 @@
 use std::future::Future;
 use std::sync::Arc;
 
+use uv_client::MetadataFormat;
 use uv_configuration::BuildOptions;
 use uv_distribution::{ArchiveMetadata, DistributionDatabase, Reporter};
 use uv_distribution_types::{Dist, IndexCapabilities, IndexUrl, InstalledDist, RequestedDist};
@@ -9,7 +10,7 @@ use uv_pep440::{Version, VersionSpecifiers};
 use uv_platform_tags::Tags;
 use uv_types::{BuildContext, HashStrategy};
 
-use crate::flat_index::FlatIndex;
+use crate::flat_index::{FlatDistributions, FlatIndex};
 use crate::version_map::VersionMap;
 use crate::yanks::AllowedYanks;
 use crate::{ExcludeNewer, RequiresPython};
@@ -156,7 +157,7 @@ impl<Context: BuildContext> ResolverProvider for DefaultResolverProvider<'_, Con
             .fetcher
             .client()
             .manual(|client, semaphore| {
-                client.simple(package_name, index, self.capabilities, semaphore)
+                client.package_metadata(package_name, index, self.capabilities, semaphore)
             })
             .await;
 
@@ -164,8 +165,8 @@ impl<Context: BuildContext> ResolverProvider for DefaultResolverProvider<'_, Con
             Ok(results) => Ok(VersionsResponse::Found(
                 results
                     .into_iter()
-                    .map(|(index, metadata)| {
-                        VersionMap::from_metadata(
+                    .map(|(index, metadata)| match metadata {
+                        MetadataFormat::Simple(metadata) => VersionMap::from_metadata(
                             metadata,
                             package_name,
                             index,
@@ -176,7 +177,15 @@ impl<Context: BuildContext> ResolverProvider for DefaultResolverProvider<'_, Con
                             self.exclude_newer.as_ref(),
                             self.flat_index.get(package_name).cloned(),
                             self.build_options,
-                        )
+                        ),
+                        MetadataFormat::Flat(entries) => {
+                            VersionMap::from(FlatDistributions::from_entries(
+                                entries,
+                                self.tags.as_ref(),
+                                &self.hasher,
+                                self.build_options,
+                            ))
+                        }
                     })
                     .collect(),
             )),
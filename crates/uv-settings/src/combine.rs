@@ -3,11 +3,12 @@ use std::path::PathBuf;
 
 use url::Url;
 
+use uv_cache::{ByteSize, RemoteCache};
 use uv_configuration::{
     ConfigSettings, IndexStrategy, KeyringProviderType, RequiredVersion, TargetTriple,
     TrustedPublishing,
 };
-use uv_distribution_types::{Index, IndexUrl, PipExtraIndex, PipFindLinks, PipIndex};
+use uv_distribution_types::{Index, IndexUrl, PipExtraIndex, PipFindLinks, PipIndex, ProxyUrl};
 use uv_install_wheel::LinkMode;
 use uv_pypi_types::{SchemaConflicts, SupportedEnvironments};
 use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
@@ -73,6 +74,7 @@ macro_rules! impl_combine_or {
 }
 
 impl_combine_or!(AnnotationStyle);
+impl_combine_or!(ByteSize);
 impl_combine_or!(ExcludeNewer);
 impl_combine_or!(ForkStrategy);
 impl_combine_or!(Index);
@@ -86,9 +88,11 @@ impl_combine_or!(PipExtraIndex);
 impl_combine_or!(PipFindLinks);
 impl_combine_or!(PipIndex);
 impl_combine_or!(PrereleaseMode);
+impl_combine_or!(ProxyUrl);
 impl_combine_or!(PythonDownloads);
 impl_combine_or!(PythonPreference);
 impl_combine_or!(PythonVersion);
+impl_combine_or!(RemoteCache);
 impl_combine_or!(RequiredVersion);
 impl_combine_or!(ResolutionMode);
 impl_combine_or!(SchemaConflicts);
@@ -98,6 +102,8 @@ impl_combine_or!(TargetTriple);
 impl_combine_or!(TrustedPublishing);
 impl_combine_or!(Url);
 impl_combine_or!(bool);
+impl_combine_or!(u32);
+impl_combine_or!(u64);
 
 impl<T> Combine for Option<Vec<T>> {
     /// Combine two vectors by extending the vector in `self` with the vector in `other`, if they're
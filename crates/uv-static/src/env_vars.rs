@@ -34,6 +34,25 @@ impl EnvVars {
     /// directory for caching instead of the default cache directory.
     pub const UV_CACHE_DIR: &'static str = "UV_CACHE_DIR";
 
+    /// Equivalent to the `--max-size` argument to `uv cache prune`. If set, uv will evict
+    /// the least-recently-used wheels and source distributions from the cache until it's no
+    /// larger than the given size.
+    pub const UV_CACHE_MAX_SIZE: &'static str = "UV_CACHE_MAX_SIZE";
+
+    /// A list of read-only cache directories (separated by `:` on Unix and `;` on Windows) to
+    /// consult for any entries that are missing from the cache directory, e.g., a warm cache that
+    /// was baked into a CI image. Entries are hard-linked (or copied) into the cache directory
+    /// when used; the read-only directories are never modified. Equivalent to the
+    /// `cache-readonly-dirs` setting.
+    pub const UV_CACHE_READONLY_DIRS: &'static str = "UV_CACHE_READONLY_DIRS";
+
+    /// The location of a remote cache for wheels built from source distributions, shared across
+    /// machines. Either an `https://` URL to a server that supports `GET` and `PUT` requests, or a
+    /// path (or `file://` URL) to a shared directory. Before building a source distribution, uv
+    /// will attempt to download a compatible wheel from the remote cache; after building, uv will
+    /// upload the wheel. Equivalent to the `remote-build-cache` setting.
+    pub const UV_REMOTE_BUILD_CACHE: &'static str = "UV_REMOTE_BUILD_CACHE";
+
     /// Equivalent to the `--no-cache` command-line argument. If set, uv will not use the
     /// cache for any operations.
     pub const UV_NO_CACHE: &'static str = "UV_NO_CACHE";
@@ -116,6 +135,10 @@ impl EnvVars {
     /// will use this value as the keyring provider.
     pub const UV_KEYRING_PROVIDER: &'static str = "UV_KEYRING_PROVIDER";
 
+    /// Equivalent to the `--credential-helper` command-line argument. If set, uv will query this
+    /// executable for credentials with `--keyring-provider helper`.
+    pub const UV_CREDENTIAL_HELPER: &'static str = "UV_CREDENTIAL_HELPER";
+
     /// Equivalent to the `--config-file` command-line argument. Expects a path to a
     /// local `uv.toml` file to use as the configuration file.
     pub const UV_CONFIG_FILE: &'static str = "UV_CONFIG_FILE";
@@ -174,6 +197,14 @@ impl EnvVars {
     /// Don't upload a file if it already exists on the index. The value is the URL of the index.
     pub const UV_PUBLISH_CHECK_URL: &'static str = "UV_PUBLISH_CHECK_URL";
 
+    /// An OIDC ID token to exchange for an upload token when using trusted publishing with
+    /// `uv publish`, for CI providers without built-in support.
+    pub const UV_PUBLISH_OIDC_TOKEN: &'static str = "UV_PUBLISH_OIDC_TOKEN";
+
+    /// The path to a file containing an OIDC ID token to exchange for an upload token when using
+    /// trusted publishing with `uv publish`.
+    pub const UV_PUBLISH_OIDC_TOKEN_FILE: &'static str = "UV_PUBLISH_OIDC_TOKEN_FILE";
+
     /// Equivalent to the `--no-sync` command-line argument. If set, uv will skip updating
     /// the environment.
     pub const UV_NO_SYNC: &'static str = "UV_NO_SYNC";
@@ -211,6 +242,10 @@ impl EnvVars {
     /// packages.
     pub const UV_CONCURRENT_INSTALLS: &'static str = "UV_CONCURRENT_INSTALLS";
 
+    /// Sets the maximum number of files that `uv publish` will upload concurrently at any given
+    /// time.
+    pub const UV_CONCURRENT_UPLOADS: &'static str = "UV_CONCURRENT_UPLOADS";
+
     /// Equivalent to the `--no-progress` command-line argument. Disables all progress output. For
     /// example, spinners and progress bars.
     pub const UV_NO_PROGRESS: &'static str = "UV_NO_PROGRESS";
@@ -218,6 +253,9 @@ impl EnvVars {
     /// Specifies the directory where uv stores managed tools.
     pub const UV_TOOL_DIR: &'static str = "UV_TOOL_DIR";
 
+    /// Specifies the directory where uv stores credentials saved via `uv auth login`.
+    pub const UV_CREDENTIALS_DIR: &'static str = "UV_CREDENTIALS_DIR";
+
     /// Specifies the "bin" directory for installing tool executables.
     pub const UV_TOOL_BIN_DIR: &'static str = "UV_TOOL_BIN_DIR";
 
@@ -290,6 +328,16 @@ impl EnvVars {
         format!("UV_INDEX_{name}_PASSWORD")
     }
 
+    /// Provides a token for a named index, sent via HTTP Bearer authentication or the index's
+    /// `auth-header`.
+    ///
+    /// The `name` parameter is the name of the index. For example, given an index named `foo`,
+    /// the environment variable key would be `UV_INDEX_FOO_TOKEN`.
+    #[attr_env_var_pattern("UV_INDEX_{name}_TOKEN")]
+    pub fn index_token(name: &str) -> String {
+        format!("UV_INDEX_{name}_TOKEN")
+    }
+
     /// Used to set the uv commit hash at build time via `build.rs`.
     #[attr_hidden]
     pub const UV_COMMIT_HASH: &'static str = "UV_COMMIT_HASH";
@@ -356,9 +404,29 @@ impl EnvVars {
     /// General proxy for all network requests.
     pub const ALL_PROXY: &'static str = "ALL_PROXY";
 
+    /// Hosts for which network requests bypass the proxy, as a comma-separated list.
+    pub const NO_PROXY: &'static str = "NO_PROXY";
+
     /// Timeout (in seconds) for HTTP requests. (default: 30 s)
     pub const UV_HTTP_TIMEOUT: &'static str = "UV_HTTP_TIMEOUT";
 
+    /// Timeout (in seconds) for establishing a connection for HTTP requests. (default: none)
+    pub const UV_HTTP_CONNECT_TIMEOUT: &'static str = "UV_HTTP_CONNECT_TIMEOUT";
+
+    /// The number of times to retry a failed HTTP request. (default: 3)
+    pub const UV_HTTP_RETRIES: &'static str = "UV_HTTP_RETRIES";
+
+    /// The minimum delay (in seconds) between retries of a failed HTTP request, which grows
+    /// exponentially up to `UV_HTTP_RETRY_BACKOFF_MAX`. (default: 1 s)
+    pub const UV_HTTP_RETRY_BACKOFF_MIN: &'static str = "UV_HTTP_RETRY_BACKOFF_MIN";
+
+    /// The maximum delay (in seconds) between retries of a failed HTTP request. (default: 30 min)
+    pub const UV_HTTP_RETRY_BACKOFF_MAX: &'static str = "UV_HTTP_RETRY_BACKOFF_MAX";
+
+    /// A comma-separated list of HTTP status codes for which requests are retried (e.g.,
+    /// `429,502,503`), replacing the default set of retryable status codes.
+    pub const UV_HTTP_RETRY_STATUS_CODES: &'static str = "UV_HTTP_RETRY_STATUS_CODES";
+
     /// Timeout (in seconds) for HTTP requests. Equivalent to `UV_HTTP_TIMEOUT`.
     pub const UV_REQUEST_TIMEOUT: &'static str = "UV_REQUEST_TIMEOUT";
 
@@ -379,6 +447,21 @@ impl EnvVars {
     /// Used to determine if an active Conda environment is the base environment or not.
     pub const CONDA_DEFAULT_ENV: &'static str = "CONDA_DEFAULT_ENV";
 
+    /// Used to locate the `envs` directory of the current Conda installation.
+    pub const CONDA_EXE: &'static str = "CONDA_EXE";
+
+    /// Used to locate Python versions installed by pyenv.
+    pub const PYENV_ROOT: &'static str = "PYENV_ROOT";
+
+    /// Used to locate Python versions installed by mise.
+    pub const MISE_DATA_DIR: &'static str = "MISE_DATA_DIR";
+
+    /// Used to locate Python versions installed by asdf.
+    pub const ASDF_DATA_DIR: &'static str = "ASDF_DATA_DIR";
+
+    /// Used to locate Python versions installed by Homebrew.
+    pub const HOMEBREW_CELLAR: &'static str = "HOMEBREW_CELLAR";
+
     /// If set to `1` before a virtual environment is activated, then the
     /// virtual environment name will not be prepended to the terminal prompt.
     pub const VIRTUAL_ENV_DISABLE_PROMPT: &'static str = "VIRTUAL_ENV_DISABLE_PROMPT";
@@ -462,6 +545,18 @@ impl EnvVars {
     #[attr_hidden]
     pub const GIT_SSL_NO_VERIFY: &'static str = "GIT_SSL_NO_VERIFY";
 
+    /// The CA bundle to use for git operations over HTTPS.
+    #[attr_hidden]
+    pub const GIT_SSL_CAINFO: &'static str = "GIT_SSL_CAINFO";
+
+    /// The client certificate to use for git operations over HTTPS.
+    #[attr_hidden]
+    pub const GIT_SSL_CERT: &'static str = "GIT_SSL_CERT";
+
+    /// The client certificate private key to use for git operations over HTTPS.
+    #[attr_hidden]
+    pub const GIT_SSL_KEY: &'static str = "GIT_SSL_KEY";
+
     /// Used in tests for better git isolation.
     ///
     /// For example, we run some tests in ~/.local/share/uv/tests.
@@ -482,6 +577,23 @@ impl EnvVars {
     /// Used for trusted publishing via `uv publish`. Contains the oidc request token.
     pub const ACTIONS_ID_TOKEN_REQUEST_TOKEN: &'static str = "ACTIONS_ID_TOKEN_REQUEST_TOKEN";
 
+    /// Used for trusted publishing via `uv publish` to detect GitLab CI.
+    pub const GITLAB_CI: &'static str = "GITLAB_CI";
+
+    /// Used for trusted publishing via `uv publish` in GitLab CI. Contains the OIDC ID token for
+    /// the audience of the index (e.g., `PYPI_ID_TOKEN` for PyPI), as configured via `id_tokens`.
+    ///
+    /// The `audience` parameter is the uppercase audience, with non-alphanumeric characters
+    /// replaced by underscores.
+    #[attr_env_var_pattern("{AUDIENCE}_ID_TOKEN")]
+    pub fn id_token(audience: &str) -> String {
+        format!("{audience}_ID_TOKEN")
+    }
+
+    /// Used for trusted publishing via `uv publish` on Google Cloud. The host of the metadata
+    /// server from which the OIDC token is requested.
+    pub const GCE_METADATA_HOST: &'static str = "GCE_METADATA_HOST";
+
     /// Sets the encoding for standard I/O streams (e.g., PYTHONIOENCODING=utf-8).
     #[attr_hidden]
     pub const PYTHONIOENCODING: &'static str = "PYTHONIOENCODING";
@@ -621,6 +733,10 @@ impl EnvVars {
     /// Skip writing `uv` installer metadata files (e.g., `INSTALLER`, `REQUESTED`, and `direct_url.json`) to site-packages `.dist-info` directories.
     pub const UV_NO_INSTALLER_METADATA: &'static str = "UV_NO_INSTALLER_METADATA";
 
+    /// Ignore Python version declarations in files owned by other tools, i.e., `.tool-versions`,
+    /// `mise.toml`, and `runtime.txt`, only respecting `.python-version` files.
+    pub const UV_NO_EXTERNAL_VERSION_FILES: &'static str = "UV_NO_EXTERNAL_VERSION_FILES";
+
     /// Enables fetching files stored in Git LFS when installing a package from a Git repository.
     pub const UV_GIT_LFS: &'static str = "UV_GIT_LFS";
 
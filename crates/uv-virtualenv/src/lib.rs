@@ -71,3 +71,32 @@ pub fn create_venv(
     let interpreter = interpreter.with_virtualenv(virtualenv);
     Ok(PythonEnvironment::from_interpreter(interpreter))
 }
+
+/// Upgrade the Python interpreter of an existing virtualenv in place.
+///
+/// Installed packages are retained; it's the caller's responsibility to reinstall any packages
+/// that are incompatible with the new interpreter.
+#[allow(clippy::fn_params_excessive_bools)]
+pub fn upgrade_venv(
+    environment: &PythonEnvironment,
+    interpreter: Interpreter,
+    prompt: Prompt,
+    system_site_packages: bool,
+    relocatable: bool,
+    seed: bool,
+) -> Result<PythonEnvironment, Error> {
+    // Upgrade the virtualenv at its existing location.
+    let virtualenv = virtualenv::upgrade(
+        environment.root(),
+        environment.interpreter(),
+        &interpreter,
+        prompt,
+        system_site_packages,
+        relocatable,
+        seed,
+    )?;
+
+    // Create the corresponding `PythonEnvironment`.
+    let interpreter = interpreter.with_virtualenv(virtualenv);
+    Ok(PythonEnvironment::from_interpreter(interpreter))
+}
@@ -3,7 +3,7 @@
 use std::env::consts::EXE_SUFFIX;
 use std::io;
 use std::io::{BufWriter, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use fs_err as fs;
 use fs_err::File;
@@ -54,21 +54,7 @@ pub(crate) fn create(
     relocatable: bool,
     seed: bool,
 ) -> Result<VirtualEnvironment, Error> {
-    // Determine the base Python executable; that is, the Python executable that should be
-    // considered the "base" for the virtual environment.
-    //
-    // For consistency with the standard library, rely on `sys._base_executable`, _unless_ we're
-    // using a uv-managed Python (in which case, we can do better for symlinked executables).
-    let base_python = if cfg!(unix) && interpreter.is_standalone() {
-        interpreter.find_base_python()?
-    } else {
-        interpreter.to_base_python()?
-    };
-
-    debug!(
-        "Using base executable for virtual environment: {}",
-        base_python.display()
-    );
+    let base_python = base_python(interpreter)?;
 
     // Validate the existing location.
     match location.metadata() {
@@ -121,13 +107,6 @@ pub(crate) fn create(
 
     let location = std::path::absolute(location)?;
 
-    let bin_name = if cfg!(unix) {
-        "bin"
-    } else if cfg!(windows) {
-        "Scripts"
-    } else {
-        unimplemented!("Only Windows and Unix are supported")
-    };
     let scripts = location.join(&interpreter.virtualenv().scripts);
     let prompt = match prompt {
         Prompt::CurrentDirectoryName => CWD
@@ -152,12 +131,291 @@ pub(crate) fn create(
     })?;
 
     // Different names for the python interpreter
-    fs::create_dir_all(&scripts)?;
+    let executable = link_executables(interpreter, &base_python, &scripts, python_home)?;
+
+    // Add all the activate scripts for different shells
+    write_activation_scripts(
+        interpreter,
+        &location,
+        &scripts,
+        prompt.as_deref(),
+        relocatable,
+    )?;
+
+    write_pyvenv_cfg(
+        &location,
+        interpreter,
+        python_home,
+        prompt,
+        system_site_packages,
+        relocatable,
+        seed,
+    )?;
+
+    // Construct the path to the `site-packages` directory.
+    let site_packages = location.join(&interpreter.virtualenv().purelib);
+    fs::create_dir_all(&site_packages)?;
+
+    // If necessary, create a symlink from `lib64` to `lib`.
+    // See: https://github.com/python/cpython/blob/b228655c227b2ca298a8ffac44d14ce3d22f6faa/Lib/venv/__init__.py#L135C11-L135C16
+    #[cfg(unix)]
+    if interpreter.pointer_size().is_64()
+        && interpreter.markers().os_name() == "posix"
+        && interpreter.markers().sys_platform() != "darwin"
+    {
+        match std::os::unix::fs::symlink("lib", location.join("lib64")) {
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {}
+            Err(err) => {
+                return Err(err.into());
+            }
+        }
+    }
+
+    // Populate `site-packages` with a `_virtualenv.py` file.
+    fs::write(site_packages.join("_virtualenv.py"), VIRTUALENV_PATCH)?;
+    fs::write(site_packages.join("_virtualenv.pth"), "import _virtualenv")?;
+
+    Ok(VirtualEnvironment {
+        scheme: Scheme {
+            purelib: location.join(&interpreter.virtualenv().purelib),
+            platlib: location.join(&interpreter.virtualenv().platlib),
+            scripts: location.join(&interpreter.virtualenv().scripts),
+            data: location.join(&interpreter.virtualenv().data),
+            include: location.join(&interpreter.virtualenv().include),
+        },
+        root: location,
+        executable,
+        base_executable: base_python,
+    })
+}
+
+/// Upgrade the [`VirtualEnvironment`] at the given location to use a new base interpreter.
+///
+/// The `previous` interpreter is the one the environment was using before the upgrade. The
+/// `site-packages` directory is moved to its new location (if it's version-dependent), and the
+/// executables, activation scripts, and `pyvenv.cfg` are rewritten as if the environment were
+/// created with the given options.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) fn upgrade(
+    location: &Path,
+    previous: &Interpreter,
+    interpreter: &Interpreter,
+    prompt: Prompt,
+    system_site_packages: bool,
+    relocatable: bool,
+    seed: bool,
+) -> Result<VirtualEnvironment, Error> {
+    let base_python = base_python(interpreter)?;
+
+    let location = std::path::absolute(location)?;
+    let scripts = location.join(&interpreter.virtualenv().scripts);
+    let prompt = match prompt {
+        Prompt::CurrentDirectoryName => CWD
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string()),
+        Prompt::Static(value) => Some(value),
+        Prompt::None => None,
+    };
+
+    // Per PEP 405, the Python `home` is the parent directory of the interpreter.
+    let python_home = base_python.parent().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            "The Python interpreter needs to have a parent directory",
+        )
+    })?;
+
+    // Move the installed packages to the new `site-packages` directory, e.g., from
+    // `lib/python3.11/site-packages` to `lib/python3.12/site-packages`.
+    let site_packages = location.join(&interpreter.virtualenv().purelib);
+    for previous_site_packages in [previous.purelib(), previous.platlib()].into_iter().dedup() {
+        if previous_site_packages == site_packages || !previous_site_packages.is_dir() {
+            continue;
+        }
+        debug!(
+            "Moving `{}` to `{}`",
+            previous_site_packages.user_display(),
+            site_packages.user_display()
+        );
+        if let Some(parent) = site_packages.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // An empty target directory (e.g., left behind by a previous upgrade) can be replaced.
+        match fs::remove_dir(&site_packages) {
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+        fs::rename(previous_site_packages, &site_packages)?;
+
+        // Remove the previous version-specific directory, if it's now empty.
+        if let Some(parent) = previous_site_packages.parent() {
+            if parent.starts_with(&location) {
+                let _ = fs::remove_dir(parent);
+            }
+        }
+    }
+
+    // Remove the version-specific executable for the previous Python version.
+    #[cfg(unix)]
+    if (previous.python_major(), previous.python_minor())
+        != (interpreter.python_major(), interpreter.python_minor())
+    {
+        let name = format!(
+            "python{}.{}",
+            previous.python_major(),
+            previous.python_minor()
+        );
+        match fs::remove_file(scripts.join(name)) {
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    // Different names for the python interpreter
+    let executable = link_executables(interpreter, &base_python, &scripts, python_home)?;
+
+    // Regenerate the activation scripts, since the prompt or relocatability may have changed.
+    write_activation_scripts(
+        interpreter,
+        &location,
+        &scripts,
+        prompt.as_deref(),
+        relocatable,
+    )?;
+
+    write_pyvenv_cfg(
+        &location,
+        interpreter,
+        python_home,
+        prompt,
+        system_site_packages,
+        relocatable,
+        seed,
+    )?;
+
+    // Refresh the `_virtualenv.py` patch.
+    fs::create_dir_all(&site_packages)?;
+    fs::write(site_packages.join("_virtualenv.py"), VIRTUALENV_PATCH)?;
+    fs::write(site_packages.join("_virtualenv.pth"), "import _virtualenv")?;
+
+    Ok(VirtualEnvironment {
+        scheme: Scheme {
+            purelib: location.join(&interpreter.virtualenv().purelib),
+            platlib: location.join(&interpreter.virtualenv().platlib),
+            scripts: location.join(&interpreter.virtualenv().scripts),
+            data: location.join(&interpreter.virtualenv().data),
+            include: location.join(&interpreter.virtualenv().include),
+        },
+        root: location,
+        executable,
+        base_executable: base_python,
+    })
+}
+
+/// Write the `pyvenv.cfg` for a virtual environment with the given options.
+#[allow(clippy::fn_params_excessive_bools)]
+fn write_pyvenv_cfg(
+    location: &Path,
+    interpreter: &Interpreter,
+    python_home: &Path,
+    prompt: Option<String>,
+    system_site_packages: bool,
+    relocatable: bool,
+    seed: bool,
+) -> Result<(), Error> {
+    let mut pyvenv_cfg_data: Vec<(String, String)> = vec![
+        (
+            "home".to_string(),
+            python_home.simplified_display().to_string(),
+        ),
+        (
+            "implementation".to_string(),
+            interpreter
+                .markers()
+                .platform_python_implementation()
+                .to_string(),
+        ),
+        ("uv".to_string(), version().to_string()),
+        (
+            "version_info".to_string(),
+            interpreter.markers().python_full_version().string.clone(),
+        ),
+        (
+            "include-system-site-packages".to_string(),
+            if system_site_packages {
+                "true".to_string()
+            } else {
+                "false".to_string()
+            },
+        ),
+    ];
+
+    if relocatable {
+        pyvenv_cfg_data.push(("relocatable".to_string(), "true".to_string()));
+    }
+
+    if seed {
+        pyvenv_cfg_data.push(("seed".to_string(), "true".to_string()));
+    }
+
+    if let Some(prompt) = prompt {
+        pyvenv_cfg_data.push(("prompt".to_string(), prompt));
+    }
+
+    if cfg!(windows) && interpreter.markers().implementation_name() == "graalpy" {
+        pyvenv_cfg_data.push((
+            "venvlauncher_command".to_string(),
+            python_home
+                .join("graalpy.exe")
+                .simplified_display()
+                .to_string(),
+        ));
+    }
+
+    let mut pyvenv_cfg = BufWriter::new(File::create(location.join("pyvenv.cfg"))?);
+    write_cfg(&mut pyvenv_cfg, &pyvenv_cfg_data)?;
+    drop(pyvenv_cfg);
+
+    Ok(())
+}
+
+/// Determine the base Python executable; that is, the Python executable that should be
+/// considered the "base" for the virtual environment.
+fn base_python(interpreter: &Interpreter) -> Result<PathBuf, Error> {
+    // For consistency with the standard library, rely on `sys._base_executable`, _unless_ we're
+    // using a uv-managed Python (in which case, we can do better for symlinked executables).
+    let base_python = if cfg!(unix) && interpreter.is_standalone() {
+        interpreter.find_base_python()?
+    } else {
+        interpreter.to_base_python()?
+    };
+
+    debug!(
+        "Using base executable for virtual environment: {}",
+        base_python.display()
+    );
+
+    Ok(base_python)
+}
+
+/// Link (or copy, on Windows) the Python executables into the `scripts` directory.
+///
+/// Returns the path to the `python` executable.
+fn link_executables(
+    interpreter: &Interpreter,
+    base_python: &Path,
+    scripts: &Path,
+    python_home: &Path,
+) -> Result<PathBuf, Error> {
+    fs::create_dir_all(scripts)?;
     let executable = scripts.join(format!("python{EXE_SUFFIX}"));
 
     #[cfg(unix)]
     {
-        uv_fs::replace_symlink(&base_python, &executable)?;
+        uv_fs::replace_symlink(base_python, &executable)?;
         uv_fs::replace_symlink(
             "python",
             scripts.join(format!("python{}", interpreter.python_major())),
@@ -189,8 +447,8 @@ pub(crate) fn create(
         copy_launcher_windows(
             WindowsExecutable::Python,
             interpreter,
-            &base_python,
-            &scripts,
+            base_python,
+            scripts,
             python_home,
         )?;
 
@@ -198,23 +456,23 @@ pub(crate) fn create(
             copy_launcher_windows(
                 WindowsExecutable::GraalPy,
                 interpreter,
-                &base_python,
-                &scripts,
+                base_python,
+                scripts,
                 python_home,
             )?;
             copy_launcher_windows(
                 WindowsExecutable::PythonMajor,
                 interpreter,
-                &base_python,
-                &scripts,
+                base_python,
+                scripts,
                 python_home,
             )?;
         } else {
             copy_launcher_windows(
                 WindowsExecutable::Pythonw,
                 interpreter,
-                &base_python,
-                &scripts,
+                base_python,
+                scripts,
                 python_home,
             )?;
         }
@@ -223,50 +481,50 @@ pub(crate) fn create(
             copy_launcher_windows(
                 WindowsExecutable::PythonMajor,
                 interpreter,
-                &base_python,
-                &scripts,
+                base_python,
+                scripts,
                 python_home,
             )?;
             copy_launcher_windows(
                 WindowsExecutable::PythonMajorMinor,
                 interpreter,
-                &base_python,
-                &scripts,
+                base_python,
+                scripts,
                 python_home,
             )?;
             copy_launcher_windows(
                 WindowsExecutable::PyPy,
                 interpreter,
-                &base_python,
-                &scripts,
+                base_python,
+                scripts,
                 python_home,
             )?;
             copy_launcher_windows(
                 WindowsExecutable::PyPyMajor,
                 interpreter,
-                &base_python,
-                &scripts,
+                base_python,
+                scripts,
                 python_home,
             )?;
             copy_launcher_windows(
                 WindowsExecutable::PyPyMajorMinor,
                 interpreter,
-                &base_python,
-                &scripts,
+                base_python,
+                scripts,
                 python_home,
             )?;
             copy_launcher_windows(
                 WindowsExecutable::PyPyw,
                 interpreter,
-                &base_python,
-                &scripts,
+                base_python,
+                scripts,
                 python_home,
             )?;
             copy_launcher_windows(
                 WindowsExecutable::PyPyMajorMinorw,
                 interpreter,
-                &base_python,
-                &scripts,
+                base_python,
+                scripts,
                 python_home,
             )?;
         }
@@ -277,7 +535,25 @@ pub(crate) fn create(
         compile_error!("Only Windows and Unix are supported")
     }
 
-    // Add all the activate scripts for different shells
+    Ok(executable)
+}
+
+/// Write the activation scripts for each supported shell into the `scripts` directory.
+fn write_activation_scripts(
+    interpreter: &Interpreter,
+    location: &Path,
+    scripts: &Path,
+    prompt: Option<&str>,
+    relocatable: bool,
+) -> Result<(), Error> {
+    let bin_name = if cfg!(unix) {
+        "bin"
+    } else if cfg!(windows) {
+        "Scripts"
+    } else {
+        unimplemented!("Only Windows and Unix are supported")
+    };
+
     for (name, template) in ACTIVATE_TEMPLATES {
         let path_sep = if cfg!(windows) { ";" } else { ":" };
 
@@ -311,104 +587,13 @@ pub(crate) fn create(
         let activator = template
             .replace("{{ VIRTUAL_ENV_DIR }}", &virtual_env_dir)
             .replace("{{ BIN_NAME }}", bin_name)
-            .replace(
-                "{{ VIRTUAL_PROMPT }}",
-                prompt.as_deref().unwrap_or_default(),
-            )
+            .replace("{{ VIRTUAL_PROMPT }}", prompt.unwrap_or_default())
             .replace("{{ PATH_SEP }}", path_sep)
             .replace("{{ RELATIVE_SITE_PACKAGES }}", &relative_site_packages);
         fs::write(scripts.join(name), activator)?;
     }
 
-    let mut pyvenv_cfg_data: Vec<(String, String)> = vec![
-        (
-            "home".to_string(),
-            python_home.simplified_display().to_string(),
-        ),
-        (
-            "implementation".to_string(),
-            interpreter
-                .markers()
-                .platform_python_implementation()
-                .to_string(),
-        ),
-        ("uv".to_string(), version().to_string()),
-        (
-            "version_info".to_string(),
-            interpreter.markers().python_full_version().string.clone(),
-        ),
-        (
-            "include-system-site-packages".to_string(),
-            if system_site_packages {
-                "true".to_string()
-            } else {
-                "false".to_string()
-            },
-        ),
-    ];
-
-    if relocatable {
-        pyvenv_cfg_data.push(("relocatable".to_string(), "true".to_string()));
-    }
-
-    if seed {
-        pyvenv_cfg_data.push(("seed".to_string(), "true".to_string()));
-    }
-
-    if let Some(prompt) = prompt {
-        pyvenv_cfg_data.push(("prompt".to_string(), prompt));
-    }
-
-    if cfg!(windows) && interpreter.markers().implementation_name() == "graalpy" {
-        pyvenv_cfg_data.push((
-            "venvlauncher_command".to_string(),
-            python_home
-                .join("graalpy.exe")
-                .simplified_display()
-                .to_string(),
-        ));
-    }
-
-    let mut pyvenv_cfg = BufWriter::new(File::create(location.join("pyvenv.cfg"))?);
-    write_cfg(&mut pyvenv_cfg, &pyvenv_cfg_data)?;
-    drop(pyvenv_cfg);
-
-    // Construct the path to the `site-packages` directory.
-    let site_packages = location.join(&interpreter.virtualenv().purelib);
-    fs::create_dir_all(&site_packages)?;
-
-    // If necessary, create a symlink from `lib64` to `lib`.
-    // See: https://github.com/python/cpython/blob/b228655c227b2ca298a8ffac44d14ce3d22f6faa/Lib/venv/__init__.py#L135C11-L135C16
-    #[cfg(unix)]
-    if interpreter.pointer_size().is_64()
-        && interpreter.markers().os_name() == "posix"
-        && interpreter.markers().sys_platform() != "darwin"
-    {
-        match std::os::unix::fs::symlink("lib", location.join("lib64")) {
-            Ok(()) => {}
-            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {}
-            Err(err) => {
-                return Err(err.into());
-            }
-        }
-    }
-
-    // Populate `site-packages` with a `_virtualenv.py` file.
-    fs::write(site_packages.join("_virtualenv.py"), VIRTUALENV_PATCH)?;
-    fs::write(site_packages.join("_virtualenv.pth"), "import _virtualenv")?;
-
-    Ok(VirtualEnvironment {
-        scheme: Scheme {
-            purelib: location.join(&interpreter.virtualenv().purelib),
-            platlib: location.join(&interpreter.virtualenv().platlib),
-            scripts: location.join(&interpreter.virtualenv().scripts),
-            data: location.join(&interpreter.virtualenv().data),
-            include: location.join(&interpreter.virtualenv().include),
-        },
-        root: location,
-        executable,
-        base_executable: base_python,
-    })
+    Ok(())
 }
 
 #[derive(Debug, Copy, Clone)]
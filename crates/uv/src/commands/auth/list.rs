@@ -0,0 +1,44 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use uv_auth::CredentialStore;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// List the indexes and hosts with stored credentials.
+pub(crate) fn list(printer: Printer) -> Result<ExitStatus> {
+    let store = CredentialStore::from_settings()?;
+
+    let mut credentials = store.iter().collect::<Vec<_>>();
+    if credentials.is_empty() {
+        writeln!(printer.stderr(), "No credentials stored")?;
+        return Ok(ExitStatus::Success);
+    }
+    credentials.sort_by(|a, b| {
+        (a.service.url().as_str(), &a.username).cmp(&(b.service.url().as_str(), &b.username))
+    });
+
+    for credentials in credentials {
+        if credentials.keyring {
+            writeln!(
+                printer.stdout(),
+                "{} {} {}",
+                credentials.service.cyan(),
+                credentials.username,
+                "(keyring)".dimmed()
+            )?;
+        } else {
+            writeln!(
+                printer.stdout(),
+                "{} {}",
+                credentials.service.cyan(),
+                credentials.username
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
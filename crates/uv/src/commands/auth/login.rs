@@ -0,0 +1,106 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use console::Term;
+use owo_colors::OwoColorize;
+
+use uv_auth::{CredentialStore, KeyringProvider, StoredCredentials};
+use uv_configuration::KeyringProviderType;
+use uv_distribution_types::Index;
+use uv_warnings::warn_user;
+
+use crate::commands::auth::resolve_service;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Store credentials for an index or host.
+pub(crate) async fn login(
+    service: &str,
+    username: Option<String>,
+    password: Option<String>,
+    keyring_provider: KeyringProviderType,
+    credential_helper: Option<&Path>,
+    indexes: &[Index],
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let service = resolve_service(service, indexes)?;
+
+    // Prompt for any missing credentials.
+    let (username, password) = match (username, password) {
+        (Some(username), Some(password)) => (username, password),
+        (username, password) => {
+            let term = Term::stderr();
+            if !term.is_term() {
+                bail!(
+                    "No credentials provided for {service}. Provide a `--username` and \
+                    `--password`, or a `--token`."
+                );
+            }
+            let username = match username {
+                Some(username) => username,
+                None => uv_console::input("Enter username ('__token__' if using a token): ", &term)
+                    .context("Failed to read username")?,
+            };
+            let password = match password {
+                Some(password) => password,
+                None => uv_console::password("Enter password: ", &term)
+                    .context("Failed to read password")?,
+            };
+            (username, password)
+        }
+    };
+
+    if username.is_empty() {
+        bail!("The username for {service} must not be empty");
+    }
+
+    let path = CredentialStore::default_path()?;
+    let _lock = CredentialStore::lock(&path).await?;
+    let mut store = CredentialStore::read(path)?;
+
+    let credentials = match keyring_provider.to_provider(credential_helper)? {
+        Some(keyring) => {
+            keyring
+                .store(service.url().as_str(), &username, &password)
+                .await
+                .with_context(|| format!("Failed to store password for {service} in keyring"))?;
+            StoredCredentials {
+                service: service.clone(),
+                username: username.clone(),
+                password: None,
+                keyring: true,
+            }
+        }
+        None => StoredCredentials {
+            service: service.clone(),
+            username: username.clone(),
+            password: Some(password),
+            keyring: false,
+        },
+    };
+    let keyring = credentials.keyring;
+
+    let previous = store.insert(credentials);
+    store.write()?;
+
+    // If the password was previously stored in the keyring, remove the stale entry.
+    if previous.is_some_and(|previous| previous.keyring) && !keyring {
+        if let Err(err) = KeyringProvider::subprocess()
+            .remove(service.url().as_str(), &username)
+            .await
+        {
+            warn_user!("Failed to remove previous password for {service} from keyring: {err}");
+        }
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Stored credentials for {} on {}{}",
+        username.bold(),
+        service.cyan(),
+        if keyring { " (in keyring)" } else { "" }
+    )?;
+
+    Ok(ExitStatus::Success)
+}
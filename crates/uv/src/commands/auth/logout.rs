@@ -0,0 +1,68 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use uv_auth::{CredentialStore, KeyringProvider};
+use uv_distribution_types::Index;
+use uv_warnings::warn_user;
+
+use crate::commands::auth::resolve_service;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Remove stored credentials for an index or host.
+pub(crate) async fn logout(
+    service: &str,
+    username: Option<&str>,
+    indexes: &[Index],
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let service = resolve_service(service, indexes)?;
+
+    let path = CredentialStore::default_path()?;
+    let _lock = CredentialStore::lock(&path).await?;
+    let mut store = CredentialStore::read(path)?;
+
+    let removed = store.remove(&service, username);
+    if removed.is_empty() {
+        if let Some(username) = username {
+            writeln!(
+                printer.stderr(),
+                "No credentials found for {} on {}",
+                username.bold(),
+                service.cyan()
+            )?;
+        } else {
+            writeln!(
+                printer.stderr(),
+                "No credentials found for {}",
+                service.cyan()
+            )?;
+        }
+        return Ok(ExitStatus::Failure);
+    }
+    store.write()?;
+
+    for credentials in removed {
+        if credentials.keyring {
+            if let Err(err) = KeyringProvider::subprocess()
+                .remove(service.url().as_str(), &credentials.username)
+                .await
+            {
+                warn_user!(
+                    "Failed to remove password for `{}` on {service} from keyring: {err}",
+                    credentials.username
+                );
+            }
+        }
+        writeln!(
+            printer.stderr(),
+            "Removed credentials for {} on {}",
+            credentials.username.bold(),
+            service.cyan()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,27 @@
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+
+use uv_auth::Service;
+use uv_distribution_types::Index;
+
+pub(crate) mod list;
+pub(crate) mod login;
+pub(crate) mod logout;
+pub(crate) mod token;
+
+/// Resolve the [`Service`] for the name of a configured index, a URL, or a host.
+fn resolve_service(service: &str, indexes: &[Index]) -> Result<Service> {
+    if let Some(index) = indexes
+        .iter()
+        .find(|index| index.name.as_deref() == Some(service))
+    {
+        // Use the root of the index, such that the credentials also apply to file downloads.
+        let url = index.root_url().unwrap_or_else(|| index.raw_url().clone());
+        return Ok(Service::from(url));
+    }
+
+    Service::from_str(service).with_context(|| {
+        format!("Expected the name of an index, a URL, or a host, but found: `{service}`")
+    })
+}
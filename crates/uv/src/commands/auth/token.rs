@@ -0,0 +1,43 @@
+use std::fmt::Write;
+
+use anyhow::{bail, Result};
+
+use uv_auth::CredentialStore;
+use uv_distribution_types::Index;
+
+use crate::commands::auth::resolve_service;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Show the stored token (or password) for an index or host.
+pub(crate) async fn token(
+    service: &str,
+    username: Option<&str>,
+    indexes: &[Index],
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let service = resolve_service(service, indexes)?;
+    let store = CredentialStore::from_settings()?;
+
+    let Some(stored) = store.get(service.url(), username) else {
+        bail!("No credentials found for {service}");
+    };
+    let Some(credentials) = stored.resolve().await else {
+        bail!(
+            "No password found for `{}` on {}",
+            stored.username,
+            stored.service
+        );
+    };
+    let Some(password) = credentials.password() else {
+        bail!(
+            "No password found for `{}` on {}",
+            stored.username,
+            stored.service
+        );
+    };
+
+    writeln!(printer.stdout(), "{password}")?;
+
+    Ok(ExitStatus::Success)
+}
@@ -16,13 +16,13 @@ use crate::commands::project::{find_requires_python, ProjectError};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
-use crate::settings::{ResolverSettings, ResolverSettingsRef};
+use crate::settings::{NetworkSettings, NetworkSettingsExt, ResolverSettings, ResolverSettingsRef};
 use uv_build_backend::check_direct_build;
 use uv_cache::{Cache, CacheBucket};
-use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
+use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildKind, BuildOptions, BuildOutput, Concurrency, ConfigSettings, Constraints,
-    HashCheckingMode, IndexStrategy, KeyringProviderType, PreviewMode, SourceStrategy, TrustedHost,
+    HashCheckingMode, IndexStrategy, KeyringProviderType, PreviewMode, SourceStrategy,
 };
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution_filename::{
@@ -34,9 +34,9 @@ use uv_install_wheel::LinkMode;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
-    PythonPreference, PythonRequest, PythonVariant, PythonVersionFile, VersionFileDiscoveryOptions,
-    VersionRequest,
+    EnvironmentPreference, PythonDiscoverySource, PythonDownloads, PythonEnvironment,
+    PythonInstallation, PythonPreference, PythonRequest, PythonVariant, PythonVersionFile,
+    VersionFileDiscoveryOptions, VersionRequest,
 };
 use uv_requirements::RequirementsSource;
 use uv_resolver::{ExcludeNewer, FlatIndex, RequiresPython};
@@ -107,13 +107,12 @@ pub(crate) async fn build_frontend(
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverSettings,
-    no_config: bool,
+    version_files: VersionFileDiscoveryOptions<'_>,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     python_downloads: PythonDownloads,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     concurrency: Concurrency,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
     preview: PreviewMode,
@@ -134,13 +133,12 @@ pub(crate) async fn build_frontend(
         python.as_deref(),
         install_mirrors,
         settings.as_ref(),
-        no_config,
+        version_files,
         python_preference,
+        python_discovery_sources,
         python_downloads,
-        connectivity,
+        network_settings,
         concurrency,
-        native_tls,
-        allow_insecure_host,
         cache,
         printer,
         preview,
@@ -179,13 +177,12 @@ async fn build_impl(
     python_request: Option<&str>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverSettingsRef<'_>,
-    no_config: bool,
+    version_files: VersionFileDiscoveryOptions<'_>,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     python_downloads: PythonDownloads,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     concurrency: Concurrency,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
     preview: PreviewMode,
@@ -218,10 +215,7 @@ async fn build_impl(
         sources,
     } = settings;
 
-    let client_builder = BaseClientBuilder::default()
-        .connectivity(connectivity)
-        .native_tls(native_tls)
-        .allow_insecure_host(allow_insecure_host.to_vec());
+    let client_builder = BaseClientBuilder::default().network_settings(network_settings);
 
     // Determine the source to build.
     let src = if let Some(src) = src {
@@ -325,9 +319,10 @@ async fn build_impl(
             output_dir,
             python_request,
             install_mirrors.clone(),
-            no_config,
+            version_files,
             workspace.as_ref(),
             python_preference,
+            python_discovery_sources,
             python_downloads,
             cache,
             printer,
@@ -339,11 +334,9 @@ async fn build_impl(
             build_constraints,
             no_build_isolation,
             no_build_isolation_package,
-            native_tls,
-            connectivity,
+            network_settings,
             index_strategy,
             keyring_provider,
-            allow_insecure_host,
             exclude_newer,
             sources,
             concurrency,
@@ -405,9 +398,10 @@ async fn build_package(
     output_dir: Option<&Path>,
     python_request: Option<&str>,
     install_mirrors: PythonInstallMirrors,
-    no_config: bool,
+    version_files: VersionFileDiscoveryOptions<'_>,
     workspace: Result<&Workspace, &WorkspaceError>,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     python_downloads: PythonDownloads,
     cache: &Cache,
     printer: Printer,
@@ -419,11 +413,9 @@ async fn build_package(
     build_constraints: &[RequirementsSource],
     no_build_isolation: bool,
     no_build_isolation_package: &[PackageName],
-    native_tls: bool,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     index_strategy: IndexStrategy,
     keyring_provider: KeyringProviderType,
-    allow_insecure_host: &[TrustedHost],
     exclude_newer: Option<ExcludeNewer>,
     sources: SourceStrategy,
     concurrency: Concurrency,
@@ -454,12 +446,9 @@ async fn build_package(
 
     // (2) Request from `.python-version`
     if interpreter_request.is_none() {
-        interpreter_request = PythonVersionFile::discover(
-            source.directory(),
-            &VersionFileDiscoveryOptions::default().with_no_config(no_config),
-        )
-        .await?
-        .and_then(PythonVersionFile::into_version);
+        interpreter_request = PythonVersionFile::discover(source.directory(), &version_files)
+            .await?
+            .and_then(PythonVersionFile::into_version);
     }
 
     // (3) `Requires-Python` in `pyproject.toml`
@@ -482,6 +471,7 @@ async fn build_package(
         interpreter_request.as_ref(),
         EnvironmentPreference::Any,
         python_preference,
+        python_discovery_sources,
         python_downloads,
         client_builder,
         cache,
@@ -528,12 +518,10 @@ async fn build_package(
 
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
-        .native_tls(native_tls)
-        .connectivity(connectivity)
+        .network_settings(network_settings)
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
         .keyring(keyring_provider)
-        .allow_insecure_host(allow_insecure_host.to_vec())
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
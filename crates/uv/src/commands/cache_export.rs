@@ -0,0 +1,288 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{bail, Context, Result};
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+use tokio::io::AsyncWriteExt;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::{FlatIndexClient, RegistryClientBuilder};
+use uv_configuration::{Concurrency, Constraints, PreviewMode, TargetTriple};
+use uv_dispatch::{BuildDispatch, SharedState};
+use uv_distribution::DistributionDatabase;
+use uv_distribution_types::{Dist, HashPolicy, Index, SourceDist};
+use uv_fs::Simplified;
+use uv_pypi_types::HashDigest;
+use uv_python::{
+    EnvironmentPreference, PythonDiscoverySource, PythonInstallation, PythonPreference,
+    PythonRequest,
+};
+use uv_resolver::{FlatIndex, Lock, VERSION};
+use uv_types::{BuildIsolation, HashStrategy};
+use uv_warnings::warn_user;
+
+use crate::commands::pip::resolution_tags;
+use crate::commands::{human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+use crate::settings::{NetworkSettings, NetworkSettingsExt, ResolverSettings};
+
+/// A distribution to include in the bundle.
+struct BundleDist {
+    dist: Dist,
+    hashes: Vec<HashDigest>,
+    /// Whether the distribution is compatible with the current platform, in which case it can be
+    /// built (if necessary) on this machine.
+    host: bool,
+}
+
+/// Export the cache entries required to sync a lockfile to a portable bundle.
+pub(crate) async fn cache_export(
+    lock_path: &Path,
+    output: &Path,
+    python_platforms: &[TargetTriple],
+    python: Option<String>,
+    settings: ResolverSettings,
+    python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
+    network_settings: &NetworkSettings,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<ExitStatus> {
+    let ResolverSettings {
+        index_locations,
+        index_strategy,
+        keyring_provider,
+        dependency_metadata,
+        config_setting,
+        exclude_newer,
+        link_mode,
+        build_options,
+        sources,
+        ..
+    } = settings;
+
+    // Read the lockfile.
+    let encoded = fs_err::tokio::read_to_string(lock_path).await?;
+    let lock = toml::from_str::<Lock>(&encoded)
+        .with_context(|| format!("Failed to parse lockfile at: {}", lock_path.user_display()))?;
+    if lock.version() != VERSION {
+        bail!(
+            "Unsupported lockfile version at `{}` (expected {VERSION}, found {})",
+            lock_path.user_display(),
+            lock.version()
+        );
+    }
+    let workspace_root = std::path::absolute(lock_path)?
+        .parent()
+        .expect("Lockfile to have parent")
+        .to_path_buf();
+
+    // Find an interpreter, to determine the current platform and to build source distributions.
+    let request = python
+        .as_deref()
+        .map(PythonRequest::parse)
+        .unwrap_or_default();
+    let interpreter = PythonInstallation::find(
+        &request,
+        EnvironmentPreference::Any,
+        python_preference,
+        python_discovery_sources,
+        cache,
+    )?
+    .into_interpreter();
+
+    // Determine the tags for the current platform, and any additional platforms.
+    let host_tags = resolution_tags(None, None, &interpreter)?;
+    let mut tags = vec![host_tags.clone()];
+    for python_platform in python_platforms {
+        tags.push(resolution_tags(None, Some(python_platform), &interpreter)?);
+    }
+
+    // Select the distribution for every package in the lockfile, for each platform.
+    let mut dists = Vec::new();
+    let mut seen = FxHashSet::default();
+    for (i, tags) in tags.iter().enumerate() {
+        for package in lock.packages() {
+            let dist = match package.to_dist_for_tags(&workspace_root, tags, &build_options) {
+                Ok(dist) => dist,
+                Err(err) => {
+                    debug!("Skipping `{}` in cache bundle: {err}", package.name());
+                    continue;
+                }
+            };
+
+            // Local directories (e.g., workspace members) are read from disk, not the cache.
+            if matches!(dist, Dist::Source(SourceDist::Directory(_))) {
+                continue;
+            }
+
+            if seen.insert(dist.clone()) {
+                dists.push(BundleDist {
+                    dist,
+                    hashes: package.hashes(),
+                    host: i == 0,
+                });
+            }
+        }
+    }
+
+    // Add all authenticated sources to the cache.
+    for index in index_locations.allowed_indexes() {
+        if let Some(credentials) = index.credentials() {
+            let credentials = Arc::new(credentials);
+            uv_auth::store_credentials(index.raw_url(), credentials.clone());
+            if let Some(root_url) = index.root_url() {
+                uv_auth::store_credentials(&root_url, credentials.clone());
+            }
+        }
+    }
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::new(cache.clone())
+        .network_settings(network_settings)
+        .index_urls(index_locations.index_urls())
+        .index_strategy(index_strategy)
+        .keyring(keyring_provider)
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+
+    // TODO(charlie): These are all default values. We should consider whether we want to make them
+    // optional on the downstream APIs.
+    let build_constraints = Constraints::default();
+    let build_hasher = HashStrategy::default();
+
+    // Resolve the flat indexes from `--find-links`.
+    let flat_index = {
+        let client = FlatIndexClient::new(&client, cache);
+        let entries = client
+            .fetch(index_locations.flat_indexes().map(Index::url))
+            .await?;
+        FlatIndex::from_entries(
+            entries,
+            Some(&*host_tags),
+            &HashStrategy::None,
+            &build_options,
+        )
+    };
+
+    // Create a build dispatch.
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        cache,
+        build_constraints,
+        &interpreter,
+        &index_locations,
+        &flat_index,
+        &dependency_metadata,
+        SharedState::default(),
+        index_strategy,
+        &config_setting,
+        BuildIsolation::Isolated,
+        link_mode,
+        &build_options,
+        &build_hasher,
+        exclude_newer,
+        sources,
+        concurrency,
+        preview,
+    );
+    let database = DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads);
+
+    // Populate the cache with every distribution.
+    for BundleDist { dist, hashes, host } in &dists {
+        let hashes = if hashes.is_empty() {
+            HashPolicy::None
+        } else {
+            HashPolicy::Validate(hashes)
+        };
+        if matches!(dist, Dist::Built(_)) || *host {
+            database
+                .get_or_build_wheel(dist, &host_tags, hashes)
+                .await
+                .with_context(|| format!("Failed to fetch: `{dist}`"))?;
+        } else {
+            // Source distributions for other platforms can't be built on this machine, but the
+            // source itself can be fetched, to be built on the target machine.
+            if let Err(err) = database.get_or_build_wheel_metadata(dist, hashes).await {
+                warn_user!(
+                    "Failed to fetch source distribution for `{dist}`: {}",
+                    err.to_string().trim()
+                );
+            }
+        }
+    }
+
+    // Collect the cache entries for every distribution.
+    let entries = uv_distribution::bundle_entries(cache, dists.iter().map(|dist| &dist.dist))?;
+
+    write_bundle(cache, &entries, output)
+        .await
+        .with_context(|| format!("Failed to write bundle to: {}", output.user_display()))?;
+
+    let (bytes, unit) = human_readable_bytes(fs_err::metadata(output)?.len());
+    let s = if dists.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "Exported {} to: {} {}",
+        format!("{} distribution{s}", dists.len()).bold(),
+        output.user_display().cyan(),
+        format!("({bytes:.1}{unit})").dimmed()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Write the given cache entries (relative to the cache root) to a `.tar.zst` archive.
+///
+/// Links are stored relative to their location within the cache, such that the bundle can be
+/// unpacked into any cache directory.
+async fn write_bundle(cache: &Cache, entries: &BTreeSet<PathBuf>, output: &Path) -> Result<()> {
+    if let Some(parent) = output.parent() {
+        fs_err::tokio::create_dir_all(parent).await?;
+    }
+    let file = fs_err::tokio::File::create(output).await?;
+    let encoder = async_compression::tokio::write::ZstdEncoder::new(file);
+    let mut builder = tokio_tar::Builder::new(encoder);
+    builder.follow_symlinks(false);
+
+    let root = fs_err::canonicalize(cache.root())?;
+    for relative in entries {
+        let path = cache.root().join(relative);
+        let metadata = fs_err::tokio::symlink_metadata(&path).await?;
+        if metadata.is_symlink() {
+            let target = fs_err::canonicalize(&path)?;
+            let Ok(target) = target.strip_prefix(&root) else {
+                debug!("Skipping link outside of the cache: {}", relative.display());
+                continue;
+            };
+            let depth = relative.parent().map_or(0, |parent| {
+                parent
+                    .components()
+                    .filter(|component| matches!(component, Component::Normal(_)))
+                    .count()
+            });
+            let link = PathBuf::from("../".repeat(depth)).join(target);
+
+            let mut header = tokio_tar::Header::new_gnu();
+            header.set_entry_type(tokio_tar::EntryType::Symlink);
+            header.set_size(0);
+            header.set_link_name(&link)?;
+            builder
+                .append_data(&mut header, relative, tokio::io::empty())
+                .await?;
+        } else {
+            builder.append_path_with_name(&path, relative).await?;
+        }
+    }
+
+    let mut encoder = builder.into_inner().await?;
+    encoder.shutdown().await?;
+    Ok(())
+}
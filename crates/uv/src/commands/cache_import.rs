@@ -0,0 +1,45 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Import a bundle created by `uv cache export` into the cache.
+pub(crate) async fn cache_import(
+    bundle: &Path,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let file = fs_err::tokio::File::open(bundle).await?;
+
+    // Unpack the bundle into a staging directory within the cache, such that its contents can be
+    // hard-linked into place.
+    let staging = tempfile::tempdir_in(cache.root())?;
+    uv_extract::stream::untar_zst(file, staging.path())
+        .await
+        .with_context(|| format!("Failed to unpack bundle: {}", bundle.user_display()))?;
+
+    // Importing the bundle walks (and hard-links) every entry, so avoid blocking the runtime.
+    let import = {
+        let cache = cache.clone();
+        let staging = staging.path().to_path_buf();
+        tokio::task::spawn_blocking(move || cache.import_layer(&staging))
+    };
+    import
+        .await?
+        .with_context(|| format!("Failed to import bundle: {}", bundle.user_display()))?;
+
+    writeln!(
+        printer.stderr(),
+        "Imported bundle into: {}",
+        cache.root().user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
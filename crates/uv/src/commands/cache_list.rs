@@ -0,0 +1,146 @@
+use std::fmt::Write;
+use std::time::SystemTime;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cache::Cache;
+use uv_cli::OutputFormat;
+use uv_distribution::{CacheItem, CacheOrigin};
+use uv_distribution_types::IndexUrl;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+use crate::commands::{human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+
+#[derive(Debug, Serialize)]
+struct PrintData {
+    kind: &'static str,
+    name: Option<PackageName>,
+    version: Option<Version>,
+    identifier: String,
+    path: String,
+    size: u64,
+    modified: Option<String>,
+    origin: Option<String>,
+}
+
+/// List the entries in the cache.
+pub(crate) fn cache_list(
+    package: Option<&PackageName>,
+    output_format: OutputFormat,
+    indexes: &[IndexUrl],
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let items = uv_distribution::inventory(cache, package)
+        .with_context(|| format!("Failed to read cache at: {}", cache.root().user_display()))?;
+
+    match output_format {
+        OutputFormat::Json => {
+            let data = items
+                .iter()
+                .map(|item| PrintData {
+                    kind: item.kind.as_str(),
+                    name: item.name.clone(),
+                    version: item.version.clone(),
+                    identifier: item.identifier.clone(),
+                    path: item.path.user_display().to_string(),
+                    size: item.size,
+                    modified: format_modified(item.modified),
+                    origin: format_origin(item, indexes),
+                })
+                .collect::<Vec<_>>();
+            writeln!(printer.stdout(), "{}", serde_json::to_string(&data)?)?;
+        }
+        OutputFormat::Text => {
+            if items.is_empty() {
+                writeln!(printer.stderr(), "No cache entries found")?;
+                return Ok(ExitStatus::Success);
+            }
+
+            let rows = items
+                .iter()
+                .map(|item| {
+                    let (bytes, unit) = human_readable_bytes(item.size);
+                    [
+                        item.kind.to_string(),
+                        item.identifier.clone(),
+                        format!("{bytes:.1}{unit}"),
+                        format_modified(item.modified).unwrap_or_default(),
+                        format_origin(item, indexes).unwrap_or_default(),
+                    ]
+                })
+                .collect::<Vec<_>>();
+
+            // Compute the width of each column.
+            let header = ["Kind", "Entry", "Size", "Modified", "Origin"];
+            let mut widths = header.map(str::len);
+            for row in &rows {
+                for (width, cell) in widths.iter_mut().zip(row) {
+                    *width = (*width).max(cell.len());
+                }
+            }
+
+            let [kind, entry, size, modified, _] = widths;
+            writeln!(
+                printer.stdout(),
+                "{:kind$}  {:entry$}  {:>size$}  {:modified$}  {}",
+                header[0],
+                header[1],
+                header[2],
+                header[3],
+                header[4],
+            )?;
+            writeln!(
+                printer.stdout(),
+                "{}",
+                widths
+                    .iter()
+                    .map(|width| "-".repeat(*width))
+                    .collect::<Vec<_>>()
+                    .join("  ")
+            )?;
+            for [kind_cell, entry_cell, size_cell, modified_cell, origin_cell] in rows {
+                writeln!(
+                    printer.stdout(),
+                    "{kind_cell:kind$}  {entry_cell:entry$}  {}  {modified_cell:modified$}  {}",
+                    format!("{size_cell:>size$}").cyan(),
+                    origin_cell.dimmed(),
+                )?;
+            }
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Format a modification time as an RFC 3339 timestamp, at second precision.
+fn format_modified(modified: SystemTime) -> Option<String> {
+    let timestamp = jiff::Timestamp::try_from(modified).ok()?;
+    let timestamp = jiff::Timestamp::from_second(timestamp.as_second()).ok()?;
+    Some(timestamp.to_string())
+}
+
+/// Format the origin of a cache entry, resolving index digests against the configured indexes.
+fn format_origin(item: &CacheItem, indexes: &[IndexUrl]) -> Option<String> {
+    let origin = item.origin.as_ref()?;
+    if matches!(origin, CacheOrigin::Index(_)) {
+        if let Some(url) = indexes.iter().find(|url| origin.is_index(url)) {
+            return Some(url.to_string());
+        }
+    }
+    Some(origin.to_string())
+}
@@ -3,14 +3,23 @@ use std::fmt::Write;
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
 
-use uv_cache::{Cache, Removal};
+use uv_cache::{Age, ByteSize, Cache, Removal};
 use uv_fs::Simplified;
 
 use crate::commands::{human_readable_bytes, ExitStatus};
 use crate::printer::Printer;
 
 /// Prune all unreachable objects from the cache.
-pub(crate) fn cache_prune(ci: bool, cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+///
+/// If a maximum size or age is provided, evict the least-recently-used entries from the cache as
+/// well.
+pub(crate) fn cache_prune(
+    ci: bool,
+    max_size: Option<ByteSize>,
+    older_than: Option<Age>,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
     if !cache.root().exists() {
         writeln!(
             printer.stderr(),
@@ -26,6 +35,11 @@ pub(crate) fn cache_prune(ci: bool, cache: &Cache, printer: Printer) -> Result<E
         cache.root().user_display().cyan()
     )?;
 
+    // Guard against concurrent maintenance operations (e.g., `uv cache verify --repair`).
+    let _lock = cache
+        .lock_blocking()
+        .with_context(|| format!("Failed to lock cache at: {}", cache.root().user_display()))?;
+
     let mut summary = Removal::default();
 
     // Prune the source distribution cache, which is tightly coupled to the builder crate.
@@ -37,6 +51,11 @@ pub(crate) fn cache_prune(ci: bool, cache: &Cache, printer: Printer) -> Result<E
         .prune(ci)
         .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
 
+    // Evict the least-recently-used entries, if the cache exceeds the size or age limits.
+    summary += cache
+        .evict(max_size, older_than)
+        .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
+
     // Write a summary of the number of files and directories removed.
     match (summary.num_files, summary.num_dirs) {
         (0, 0) => {
@@ -0,0 +1,101 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cache::Cache;
+use uv_cli::OutputFormat;
+use uv_fs::Simplified;
+
+use crate::commands::{human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+
+#[derive(Debug, Serialize)]
+struct BucketSize {
+    bucket: String,
+    path: String,
+    size: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct CacheSize {
+    path: String,
+    size: u64,
+    buckets: Vec<BucketSize>,
+}
+
+/// Show the size of the cache, in total and per cache bucket.
+pub(crate) fn cache_size(
+    output_format: OutputFormat,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let buckets = cache.bucket_sizes().with_context(|| {
+        format!(
+            "Failed to compute the size of the cache at: {}",
+            cache.root().user_display()
+        )
+    })?;
+
+    // Note that the total may exceed the sum of the buckets, as it includes any stale buckets.
+    let total = uv_cache::dir_size(cache.root()).with_context(|| {
+        format!(
+            "Failed to compute the size of the cache at: {}",
+            cache.root().user_display()
+        )
+    })?;
+
+    match output_format {
+        OutputFormat::Json => {
+            let data = CacheSize {
+                path: cache.root().user_display().to_string(),
+                size: total,
+                buckets: buckets
+                    .into_iter()
+                    .map(|(bucket, size)| BucketSize {
+                        bucket: bucket.to_string(),
+                        path: cache.bucket(bucket).user_display().to_string(),
+                        size,
+                    })
+                    .collect(),
+            };
+            writeln!(printer.stdout(), "{}", serde_json::to_string(&data)?)?;
+        }
+        OutputFormat::Text => {
+            // Compute the width of the first column.
+            let width = buckets.iter().fold("Total".len(), |acc, (bucket, _)| {
+                acc.max(bucket.to_string().len())
+            });
+
+            for (bucket, size) in buckets {
+                let (bytes, unit) = human_readable_bytes(size);
+                writeln!(
+                    printer.stdout(),
+                    "{:width$}    {}",
+                    bucket.to_string(),
+                    format!("{bytes:.1}{unit}").cyan()
+                )?;
+            }
+
+            let (bytes, unit) = human_readable_bytes(total);
+            writeln!(
+                printer.stdout(),
+                "{}    {}",
+                format!("{:width$}", "Total").bold(),
+                format!("{bytes:.1}{unit}").green()
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,99 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+
+use crate::commands::{human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+
+/// Verify the integrity of the cached wheels, optionally removing any affected entries.
+pub(crate) fn cache_verify(repair: bool, cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Verifying cache at: {}",
+        cache.root().user_display().cyan()
+    )?;
+
+    // If repairing, hold the lock throughout, to avoid racing with other maintenance operations.
+    let _lock = repair
+        .then(|| cache.lock_blocking())
+        .transpose()
+        .with_context(|| format!("Failed to lock cache at: {}", cache.root().user_display()))?;
+
+    let issues = uv_distribution::verify(cache, uv_install_wheel::verify_record)
+        .with_context(|| format!("Failed to verify cache at: {}", cache.root().user_display()))?;
+
+    for issue in &issues {
+        let kind = if issue.kind.is_error() {
+            issue.kind.as_str().red().bold().to_string()
+        } else {
+            issue.kind.as_str().yellow().bold().to_string()
+        };
+        writeln!(printer.stderr(), "{kind}: {}", issue.path.user_display())?;
+        writeln!(printer.stderr(), "  {}", issue.reason.dimmed())?;
+    }
+
+    // Unreferenced unzipped wheels don't affect the integrity of the cache, so they're reported,
+    // but left to `uv cache prune`.
+    let orphans = issues.iter().filter(|issue| !issue.kind.is_error()).count();
+    if orphans > 0 {
+        let s = if orphans == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "Found {} (run `{}` to remove them)",
+            format!("{orphans} unreferenced unzipped wheel{s}").bold(),
+            "uv cache prune".green()
+        )?;
+    }
+
+    let errors = issues.len() - orphans;
+    if errors == 0 {
+        writeln!(printer.stderr(), "No issues found")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let s = if errors == 1 { "" } else { "s" };
+    if !repair {
+        writeln!(
+            printer.stderr(),
+            "Found {} (run `{}` to remove the affected entries)",
+            format!("{errors} issue{s}").bold(),
+            "uv cache verify --repair".green()
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    let summary = uv_distribution::repair(&issues)
+        .with_context(|| format!("Failed to repair cache at: {}", cache.root().user_display()))?;
+
+    // Write a summary of the number of issues repaired and the total byte count removed.
+    write!(
+        printer.stderr(),
+        "Repaired {}",
+        format!("{errors} issue{s}").bold()
+    )?;
+    if summary.total_bytes > 0 {
+        let bytes = if summary.total_bytes < 1024 {
+            format!("{}B", summary.total_bytes)
+        } else {
+            let (bytes, unit) = human_readable_bytes(summary.total_bytes);
+            format!("{bytes:.1}{unit}")
+        };
+        write!(printer.stderr(), " ({} removed)", bytes.green())?;
+    }
+    writeln!(printer.stderr())?;
+
+    Ok(ExitStatus::Success)
+}
@@ -7,10 +7,19 @@ use std::path::Path;
 use std::time::Duration;
 use std::{fmt::Display, fmt::Write, process::ExitCode};
 
+pub(crate) use auth::list::list as auth_list;
+pub(crate) use auth::login::login as auth_login;
+pub(crate) use auth::logout::logout as auth_logout;
+pub(crate) use auth::token::token as auth_token;
 pub(crate) use build_frontend::build_frontend;
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
+pub(crate) use cache_export::cache_export;
+pub(crate) use cache_import::cache_import;
+pub(crate) use cache_list::cache_list;
 pub(crate) use cache_prune::cache_prune;
+pub(crate) use cache_size::cache_size;
+pub(crate) use cache_verify::cache_verify;
 pub(crate) use help::help;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
@@ -36,6 +45,7 @@ pub(crate) use python::install::install as python_install;
 pub(crate) use python::list::list as python_list;
 pub(crate) use python::pin::pin as python_pin;
 pub(crate) use python::uninstall::uninstall as python_uninstall;
+pub(crate) use python::verify::verify as python_verify;
 #[cfg(feature = "self-update")]
 pub(crate) use self_update::self_update;
 pub(crate) use tool::dir::dir as tool_dir;
@@ -57,11 +67,17 @@ pub(crate) use version::version;
 
 use crate::printer::Printer;
 
+mod auth;
 pub(crate) mod build_backend;
 mod build_frontend;
 mod cache_clean;
 mod cache_dir;
+mod cache_export;
+mod cache_import;
+mod cache_list;
 mod cache_prune;
+mod cache_size;
+mod cache_verify;
 mod diagnostics;
 mod help;
 pub(crate) mod pip;
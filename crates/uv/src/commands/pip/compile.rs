@@ -10,25 +10,25 @@ use rustc_hash::FxHashSet;
 use tracing::debug;
 
 use uv_cache::Cache;
-use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
+use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildOptions, Concurrency, ConfigSettings, Constraints, DevGroupsSpecification,
     ExtrasSpecification, IndexStrategy, NoBinary, NoBuild, PreviewMode, Reinstall, SourceStrategy,
-    TrustedHost, Upgrade,
+    Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution_types::{
-    DependencyMetadata, HashGeneration, Index, IndexLocations, NameRequirementSpecification,
-    Origin, UnresolvedRequirementSpecification, Verbatim,
+    DependencyMetadata, HashGeneration, Index, IndexFormat, IndexLocations,
+    NameRequirementSpecification, Origin, UnresolvedRequirementSpecification, Verbatim,
 };
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_normalize::PackageName;
 use uv_pypi_types::{Conflicts, Requirement, SupportedEnvironments};
 use uv_python::{
-    EnvironmentPreference, PythonEnvironment, PythonInstallation, PythonPreference, PythonRequest,
-    PythonVersion, VersionRequest,
+    EnvironmentPreference, PythonDiscoverySource, PythonEnvironment, PythonInstallation,
+    PythonPreference, PythonRequest, PythonVersion, VersionRequest,
 };
 use uv_requirements::{
     upgrade::read_requirements_txt, RequirementsSource, RequirementsSpecification,
@@ -45,6 +45,7 @@ use crate::commands::pip::loggers::DefaultResolveLogger;
 use crate::commands::pip::{operations, resolution_environment};
 use crate::commands::{diagnostics, ExitStatus, OutputWriter};
 use crate::printer::Printer;
+use crate::settings::{NetworkSettings, NetworkSettingsExt};
 
 /// Resolve a set of requirements into a set of pinned versions.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -80,9 +81,8 @@ pub(crate) async fn pip_compile(
     index_strategy: IndexStrategy,
     dependency_metadata: DependencyMetadata,
     keyring_provider: KeyringProviderType,
-    allow_insecure_host: &[TrustedHost],
+    network_settings: &NetworkSettings,
     config_settings: ConfigSettings,
-    connectivity: Connectivity,
     no_build_isolation: bool,
     no_build_isolation_package: Vec<PackageName>,
     build_options: BuildOptions,
@@ -96,8 +96,8 @@ pub(crate) async fn pip_compile(
     python: Option<String>,
     system: bool,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     concurrency: Concurrency,
-    native_tls: bool,
     quiet: bool,
     cache: Cache,
     printer: Printer,
@@ -112,10 +112,8 @@ pub(crate) async fn pip_compile(
     }
 
     let client_builder = BaseClientBuilder::new()
-        .connectivity(connectivity)
-        .native_tls(native_tls)
-        .keyring(keyring_provider)
-        .allow_insecure_host(allow_insecure_host.to_vec());
+        .network_settings(network_settings)
+        .keyring(keyring_provider);
 
     // Read all requirements from the provided sources.
     let RequirementsSpecification {
@@ -187,7 +185,13 @@ pub(crate) async fn pip_compile(
     let environment_preference = EnvironmentPreference::from_system_flag(system, false);
     let interpreter = if let Some(python) = python.as_ref() {
         let request = PythonRequest::parse(python);
-        PythonInstallation::find(&request, environment_preference, python_preference, &cache)
+        PythonInstallation::find(
+            &request,
+            environment_preference,
+            python_preference,
+            python_discovery_sources,
+            &cache,
+        )
     } else {
         // TODO(zanieb): The split here hints at a problem with the abstraction; we should be able to use
         // `PythonInstallation::find(...)` here.
@@ -197,7 +201,13 @@ pub(crate) async fn pip_compile(
         } else {
             PythonRequest::default()
         };
-        PythonInstallation::find_best(&request, environment_preference, python_preference, &cache)
+        PythonInstallation::find_best(
+            &request,
+            environment_preference,
+            python_preference,
+            python_discovery_sources,
+            &cache,
+        )
     }?
     .into_interpreter();
 
@@ -408,7 +418,7 @@ pub(crate) async fn pip_compile(
     {
         Ok(resolution) => resolution,
         Err(err) => {
-            return diagnostics::OperationDiagnostic::native_tls(native_tls)
+            return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
                 .report(err)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
         }
@@ -461,7 +471,10 @@ pub(crate) async fn pip_compile(
             wrote_preamble = true;
         }
         let mut seen = FxHashSet::default();
-        for extra_index in index_locations.implicit_indexes() {
+        for extra_index in index_locations
+            .implicit_indexes()
+            .filter(|index| index.format == IndexFormat::Simple)
+        {
             if seen.insert(extra_index.url()) {
                 writeln!(writer, "--extra-index-url {}", extra_index.url().verbatim())?;
                 wrote_preamble = true;
@@ -471,7 +484,11 @@ pub(crate) async fn pip_compile(
 
     // If necessary, include the `--find-links` locations.
     if include_find_links {
-        for flat_index in index_locations.flat_indexes() {
+        for flat_index in index_locations.flat_indexes().chain(
+            index_locations
+                .implicit_indexes()
+                .filter(|index| index.format == IndexFormat::Flat),
+        ) {
             writeln!(writer, "--find-links {}", flat_index.url().verbatim())?;
             wrote_preamble = true;
         }
@@ -1,6 +1,6 @@
 use tokio::sync::Semaphore;
 use tracing::debug;
-use uv_client::{RegistryClient, VersionFiles};
+use uv_client::{MetadataFormat, RegistryClient, VersionFiles};
 use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{IndexCapabilities, IndexUrl};
 use uv_normalize::PackageName;
@@ -34,7 +34,7 @@ impl LatestClient<'_> {
 
         let archives = match self
             .client
-            .simple(package, index, self.capabilities, download_concurrency)
+            .package_metadata(package, index, self.capabilities, download_concurrency)
             .await
         {
             Ok(archives) => archives,
@@ -49,7 +49,12 @@ impl LatestClient<'_> {
         };
 
         let mut latest: Option<DistFilename> = None;
-        for (_, archive) in archives {
+        for (_, metadata) in archives {
+            // Like `--find-links`, flat indexes aren't considered when determining the latest
+            // version.
+            let MetadataFormat::Simple(archive) = metadata else {
+                continue;
+            };
             for datum in archive.iter().rev() {
                 // Find the first compatible distribution.
                 let files = rkyv::deserialize::<VersionFiles, rkyv::rancor::Error>(&datum.files)
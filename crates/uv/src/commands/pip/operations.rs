@@ -373,15 +373,6 @@ impl Changelog {
         }
     }
 
-    /// Create a [`Changelog`] from a list of installed distributions.
-    pub(crate) fn from_installed(installed: Vec<CachedDist>) -> Self {
-        Self {
-            installed: installed.into_iter().map(LocalDist::from).collect(),
-            uninstalled: HashSet::default(),
-            reinstalled: HashSet::default(),
-        }
-    }
-
     /// Returns `true` if the changelog includes a distribution with the given name, either via
     /// an installation or uninstallation.
     pub(crate) fn includes(&self, name: &PackageName) -> bool {
@@ -566,6 +557,40 @@ pub(crate) async fn install(
     Ok(changelog)
 }
 
+/// Uninstall any distributions that are incompatible with the environment's interpreter, e.g.,
+/// after upgrading the interpreter of a virtual environment in place.
+///
+/// Distributions are considered incompatible if none of the tags in their `WHEEL` file match the
+/// interpreter. Editable installations are always retained, since they're linked to their source
+/// tree rather than installed from a wheel.
+///
+/// Returns the distributions that were uninstalled.
+pub(crate) async fn uninstall_incompatible(
+    venv: &PythonEnvironment,
+) -> Result<Vec<InstalledDist>, Error> {
+    let tags = venv.interpreter().tags()?;
+    let site_packages = SitePackages::from_environment(venv)?;
+
+    let mut uninstalled = Vec::new();
+    for dist_info in site_packages.iter() {
+        if dist_info.is_editable() || dist_info.is_compatible(tags)? {
+            continue;
+        }
+        let summary = uv_installer::uninstall(dist_info).await?;
+        debug!(
+            "Uninstalled incompatible {} ({} file{}, {} director{})",
+            dist_info.name(),
+            summary.file_count,
+            if summary.file_count == 1 { "" } else { "s" },
+            summary.dir_count,
+            if summary.dir_count == 1 { "y" } else { "ies" },
+        );
+        uninstalled.push(dist_info.clone());
+    }
+
+    Ok(uninstalled)
+}
+
 /// Display a message about the interpreter that was selected for the operation.
 pub(crate) fn report_interpreter(
     python: &PythonInstallation,
@@ -850,6 +875,12 @@ pub(crate) enum Error {
     #[error(transparent)]
     Hash(#[from] uv_types::HashStrategyError),
 
+    #[error(transparent)]
+    Tags(#[from] uv_platform_tags::TagsError),
+
+    #[error(transparent)]
+    InstalledDist(#[from] uv_distribution_types::InstalledDistError),
+
     #[error(transparent)]
     Io(#[from] std::io::Error),
 
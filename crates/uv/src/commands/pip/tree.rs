@@ -12,8 +12,8 @@ use tokio::sync::Semaphore;
 
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
-use uv_client::{Connectivity, RegistryClientBuilder};
-use uv_configuration::{Concurrency, IndexStrategy, KeyringProviderType, TrustedHost};
+use uv_client::RegistryClientBuilder;
+use uv_configuration::{Concurrency, IndexStrategy, KeyringProviderType};
 use uv_distribution_types::{Diagnostic, IndexCapabilities, IndexLocations, Name};
 use uv_installer::SitePackages;
 use uv_normalize::PackageName;
@@ -28,6 +28,7 @@ use crate::commands::pip::operations::report_target_environment;
 use crate::commands::reporters::LatestVersionReporter;
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
+use crate::settings::{NetworkSettings, NetworkSettingsExt};
 
 /// Display the installed packages in the current environment as a dependency tree.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -43,14 +44,12 @@ pub(crate) async fn pip_tree(
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
     keyring_provider: KeyringProviderType,
-    allow_insecure_host: Vec<TrustedHost>,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     concurrency: Concurrency,
     strict: bool,
     exclude_newer: Option<ExcludeNewer>,
     python: Option<&str>,
     system: bool,
-    native_tls: bool,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -87,12 +86,10 @@ pub(crate) async fn pip_tree(
         // Initialize the registry client.
         let client =
             RegistryClientBuilder::new(cache.clone().with_refresh(Refresh::All(Timestamp::now())))
-                .native_tls(native_tls)
-                .connectivity(connectivity)
+                .network_settings(network_settings)
                 .index_urls(index_locations.index_urls())
                 .index_strategy(index_strategy)
                 .keyring(keyring_provider)
-                .allow_insecure_host(allow_insecure_host.clone())
                 .markers(environment.interpreter().markers())
                 .platform(environment.interpreter().platform())
                 .build();
@@ -6,8 +6,8 @@ use owo_colors::OwoColorize;
 use tracing::debug;
 
 use uv_cache::Cache;
-use uv_client::{BaseClientBuilder, Connectivity};
-use uv_configuration::{DryRun, KeyringProviderType, TrustedHost};
+use uv_client::BaseClientBuilder;
+use uv_configuration::{DryRun, KeyringProviderType};
 use uv_distribution_types::{InstalledMetadata, Name, UnresolvedRequirement};
 use uv_fs::Simplified;
 use uv_pep508::UnnamedRequirement;
@@ -21,6 +21,7 @@ use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use crate::commands::pip::operations::report_target_environment;
 use crate::commands::{elapsed, ExitStatus};
 use crate::printer::Printer;
+use crate::settings::{NetworkSettings, NetworkSettingsExt};
 
 /// Uninstall packages from the current environment.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -32,20 +33,16 @@ pub(crate) async fn pip_uninstall(
     target: Option<Target>,
     prefix: Option<Prefix>,
     cache: Cache,
-    connectivity: Connectivity,
-    native_tls: bool,
+    network_settings: &NetworkSettings,
     keyring_provider: KeyringProviderType,
-    allow_insecure_host: &[TrustedHost],
     dry_run: DryRun,
     printer: Printer,
 ) -> Result<ExitStatus> {
     let start = std::time::Instant::now();
 
     let client_builder = BaseClientBuilder::new()
-        .connectivity(connectivity)
-        .native_tls(native_tls)
-        .keyring(keyring_provider)
-        .allow_insecure_host(allow_insecure_host.to_vec());
+        .network_settings(network_settings)
+        .keyring(keyring_provider);
 
     // Read all requirements from the provided sources.
     let spec = RequirementsSpecification::from_simple_sources(sources, &client_builder).await?;
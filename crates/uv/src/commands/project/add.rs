@@ -14,10 +14,10 @@ use url::Url;
 
 use uv_cache::Cache;
 use uv_cache_key::RepositoryUrl;
-use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
+use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, DevGroupsSpecification, DevMode, DryRun, EditableMode,
-    ExtrasSpecification, InstallOptions, PreviewMode, SourceStrategy, TrustedHost,
+    ExtrasSpecification, InstallOptions, PreviewMode, SourceStrategy,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::DistributionDatabase;
@@ -27,7 +27,10 @@ use uv_git::{GitReference, GIT_STORE};
 use uv_normalize::{PackageName, DEV_DEPENDENCIES};
 use uv_pep508::{ExtraName, Requirement, UnnamedRequirement, VersionOrUrl};
 use uv_pypi_types::{redact_credentials, ParsedUrl, RequirementSource, VerbatimParsedUrl};
-use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_python::{
+    Interpreter, PythonDiscoverySource, PythonDownloads, PythonEnvironment, PythonPreference,
+    PythonRequest, VersionFileDiscoveryOptions,
+};
 use uv_requirements::{NamedRequirementsResolver, RequirementsSource, RequirementsSpecification};
 use uv_resolver::FlatIndex;
 use uv_scripts::{Pep723ItemRef, Pep723Metadata, Pep723Script};
@@ -52,7 +55,9 @@ use crate::commands::project::{
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
 use crate::commands::{diagnostics, project, ExitStatus};
 use crate::printer::Printer;
-use crate::settings::{ResolverInstallerSettings, ResolverInstallerSettingsRef};
+use crate::settings::{
+    NetworkSettings, NetworkSettingsExt, ResolverInstallerSettings, ResolverInstallerSettingsRef,
+};
 
 /// Add one or more packages to the project requirements.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -77,13 +82,12 @@ pub(crate) async fn add(
     settings: ResolverInstallerSettings,
     script: Option<PathBuf>,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     python_downloads: PythonDownloads,
     installer_metadata: bool,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     concurrency: Concurrency,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
-    no_config: bool,
+    version_files: VersionFileDiscoveryOptions<'_>,
     cache: &Cache,
     printer: Printer,
     preview: PreviewMode,
@@ -128,10 +132,7 @@ pub(crate) async fn add(
             );
         }
 
-        let client_builder = BaseClientBuilder::new()
-            .connectivity(connectivity)
-            .native_tls(native_tls)
-            .allow_insecure_host(allow_insecure_host.to_vec());
+        let client_builder = BaseClientBuilder::new().network_settings(network_settings);
 
         // If we found a script, add to the existing metadata. Otherwise, create a new inline
         // metadata tag.
@@ -144,8 +145,9 @@ pub(crate) async fn add(
                 project_dir,
                 false,
                 python_preference,
+                python_discovery_sources,
                 python_downloads,
-                no_config,
+                version_files,
                 &client_builder,
                 cache,
                 &reporter,
@@ -159,12 +161,11 @@ pub(crate) async fn add(
             Pep723ItemRef::Script(&script),
             python.as_deref().map(PythonRequest::parse),
             python_preference,
+            python_discovery_sources,
             python_downloads,
-            connectivity,
-            native_tls,
-            allow_insecure_host,
+            network_settings,
             &install_mirrors,
-            no_config,
+            version_files,
             cache,
             printer,
         )
@@ -207,12 +208,11 @@ pub(crate) async fn add(
                 project_dir,
                 python.as_deref().map(PythonRequest::parse),
                 python_preference,
+                python_discovery_sources,
                 python_downloads,
-                connectivity,
-                native_tls,
-                allow_insecure_host,
+                network_settings,
                 &install_mirrors,
-                no_config,
+                version_files,
                 active,
                 cache,
                 printer,
@@ -228,11 +228,10 @@ pub(crate) async fn add(
                 python.as_deref().map(PythonRequest::parse),
                 &install_mirrors,
                 python_preference,
+                python_discovery_sources,
                 python_downloads,
-                connectivity,
-                native_tls,
-                allow_insecure_host,
-                no_config,
+                network_settings,
+                version_files,
                 active,
                 cache,
                 DryRun::Disabled,
@@ -246,10 +245,8 @@ pub(crate) async fn add(
     };
 
     let client_builder = BaseClientBuilder::new()
-        .connectivity(connectivity)
-        .native_tls(native_tls)
-        .keyring(settings.keyring_provider)
-        .allow_insecure_host(allow_insecure_host.to_vec());
+        .network_settings(network_settings)
+        .keyring(settings.keyring_provider);
 
     // Read the requirements.
     let RequirementsSpecification { requirements, .. } =
@@ -630,10 +627,8 @@ pub(crate) async fn add(
         raw_sources,
         settings.as_ref(),
         installer_metadata,
-        connectivity,
+        network_settings,
         concurrency,
-        native_tls,
-        allow_insecure_host,
         cache,
         printer,
         preview,
@@ -646,7 +641,7 @@ pub(crate) async fn add(
                 let _ = snapshot.revert();
             }
             match err {
-                ProjectError::Operation(err) => diagnostics::OperationDiagnostic::native_tls(native_tls).with_hint(format!("If you want to add the package regardless of the failed resolution, provide the `{}` flag to skip locking and syncing.", "--frozen".green()))
+                ProjectError::Operation(err) => diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls).with_hint(format!("If you want to add the package regardless of the failed resolution, provide the `{}` flag to skip locking and syncing.", "--frozen".green()))
                     .report(err)
                     .map_or(Ok(ExitStatus::Failure), |err| Err(err.into())),
                 err => Err(err.into()),
@@ -668,10 +663,8 @@ async fn lock_and_sync(
     raw_sources: bool,
     settings: ResolverInstallerSettingsRef<'_>,
     installer_metadata: bool,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     concurrency: Concurrency,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
     preview: PreviewMode,
@@ -686,10 +679,8 @@ async fn lock_and_sync(
         settings.into(),
         &lock_state,
         Box::new(DefaultResolveLogger),
-        connectivity,
+        network_settings,
         concurrency,
-        native_tls,
-        allow_insecure_host,
         cache,
         printer,
         preview,
@@ -806,10 +797,8 @@ async fn lock_and_sync(
                 settings.into(),
                 &lock_state,
                 Box::new(SummaryResolveLogger),
-                connectivity,
+                network_settings,
                 concurrency,
-                native_tls,
-                allow_insecure_host,
                 cache,
                 printer,
                 preview,
@@ -878,10 +867,8 @@ async fn lock_and_sync(
         &sync_state,
         Box::new(DefaultInstallLogger),
         installer_metadata,
-        connectivity,
+        network_settings,
         concurrency,
-        native_tls,
-        allow_insecure_host,
         cache,
         DryRun::Disabled,
         printer,
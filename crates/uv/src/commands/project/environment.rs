@@ -2,8 +2,8 @@ use tracing::debug;
 
 use uv_cache::{Cache, CacheBucket};
 use uv_cache_key::{cache_digest, hash_digest};
-use uv_client::Connectivity;
-use uv_configuration::{Concurrency, PreviewMode, TrustedHost};
+
+use uv_configuration::{Concurrency, PreviewMode};
 use uv_distribution_types::{Name, Resolution};
 use uv_python::{Interpreter, PythonEnvironment};
 
@@ -13,7 +13,7 @@ use crate::commands::project::{
     resolve_environment, sync_environment, EnvironmentSpecification, PlatformState, ProjectError,
 };
 use crate::printer::Printer;
-use crate::settings::ResolverInstallerSettings;
+use crate::settings::{NetworkSettings, ResolverInstallerSettings};
 
 /// A [`PythonEnvironment`] stored in the cache.
 #[derive(Debug)]
@@ -35,10 +35,8 @@ impl CachedEnvironment {
         resolve: Box<dyn ResolveLogger>,
         install: Box<dyn InstallLogger>,
         installer_metadata: bool,
-        connectivity: Connectivity,
+        network_settings: &NetworkSettings,
         concurrency: Concurrency,
-        native_tls: bool,
-        allow_insecure_host: &[TrustedHost],
         cache: &Cache,
         printer: Printer,
         preview: PreviewMode,
@@ -53,10 +51,8 @@ impl CachedEnvironment {
                 settings.as_ref().into(),
                 state,
                 resolve,
-                connectivity,
+                network_settings,
                 concurrency,
-                native_tls,
-                allow_insecure_host,
                 cache,
                 printer,
                 preview,
@@ -108,10 +104,8 @@ impl CachedEnvironment {
             state,
             install,
             installer_metadata,
-            connectivity,
+            network_settings,
             concurrency,
-            native_tls,
-            allow_insecure_host,
             cache,
             printer,
             preview,
@@ -7,13 +7,16 @@ use std::path::{Path, PathBuf};
 use uv_settings::PythonInstallMirrors;
 
 use uv_cache::Cache;
-use uv_client::Connectivity;
+
 use uv_configuration::{
     Concurrency, DevGroupsSpecification, EditableMode, ExportFormat, ExtrasSpecification,
-    InstallOptions, PreviewMode, TrustedHost,
+    InstallOptions, PreviewMode,
 };
 use uv_normalize::PackageName;
-use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
+use uv_python::{
+    PythonDiscoverySource, PythonDownloads, PythonPreference, PythonRequest,
+    VersionFileDiscoveryOptions,
+};
 use uv_resolver::RequirementsTxtExport;
 use uv_scripts::{Pep723ItemRef, Pep723Script};
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace};
@@ -28,7 +31,7 @@ use crate::commands::project::{
 };
 use crate::commands::{diagnostics, ExitStatus, OutputWriter};
 use crate::printer::Printer;
-use crate::settings::ResolverSettings;
+use crate::settings::{NetworkSettings, ResolverSettings};
 
 #[derive(Debug, Clone)]
 enum ExportTarget {
@@ -70,12 +73,11 @@ pub(crate) async fn export(
     install_mirrors: PythonInstallMirrors,
     settings: ResolverSettings,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     python_downloads: PythonDownloads,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     concurrency: Concurrency,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
-    no_config: bool,
+    version_files: VersionFileDiscoveryOptions<'_>,
     quiet: bool,
     cache: &Cache,
     printer: Printer,
@@ -141,12 +143,11 @@ pub(crate) async fn export(
                 Pep723ItemRef::Script(script),
                 python.as_deref().map(PythonRequest::parse),
                 python_preference,
+                python_discovery_sources,
                 python_downloads,
-                connectivity,
-                native_tls,
-                allow_insecure_host,
+                network_settings,
                 &install_mirrors,
-                no_config,
+                version_files,
                 cache,
                 printer,
             )
@@ -157,12 +158,11 @@ pub(crate) async fn export(
                 project_dir,
                 python.as_deref().map(PythonRequest::parse),
                 python_preference,
+                python_discovery_sources,
                 python_downloads,
-                connectivity,
-                native_tls,
-                allow_insecure_host,
+                network_settings,
                 &install_mirrors,
-                no_config,
+                version_files,
                 Some(false),
                 cache,
                 printer,
@@ -196,10 +196,8 @@ pub(crate) async fn export(
         settings.as_ref(),
         &state,
         Box::new(DefaultResolveLogger),
-        connectivity,
+        network_settings,
         concurrency,
-        native_tls,
-        allow_insecure_host,
         cache,
         printer,
         preview,
@@ -208,7 +206,7 @@ pub(crate) async fn export(
     {
         Ok(result) => result.into_lock(),
         Err(ProjectError::Operation(err)) => {
-            return diagnostics::OperationDiagnostic::native_tls(native_tls)
+            return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
                 .report(err)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
         }
@@ -8,18 +8,18 @@ use std::str::FromStr;
 use tracing::{debug, warn};
 use uv_cache::Cache;
 use uv_cli::AuthorFrom;
-use uv_client::{BaseClientBuilder, Connectivity};
+use uv_client::BaseClientBuilder;
 use uv_configuration::{
-    PreviewMode, ProjectBuildBackend, TrustedHost, VersionControlError, VersionControlSystem,
+    PreviewMode, ProjectBuildBackend, VersionControlError, VersionControlSystem,
 };
 use uv_fs::{Simplified, CWD};
 use uv_git::GIT;
 use uv_pep440::Version;
 use uv_pep508::PackageName;
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
-    PythonPreference, PythonRequest, PythonVariant, PythonVersionFile, VersionFileDiscoveryOptions,
-    VersionRequest,
+    EnvironmentPreference, PythonDiscoverySource, PythonDownloads, PythonEnvironment,
+    PythonInstallation, PythonPreference, PythonRequest, PythonVariant, PythonVersionFile,
+    VersionFileDiscoveryOptions, VersionRequest,
 };
 use uv_resolver::RequiresPython;
 use uv_scripts::{Pep723Script, ScriptTag};
@@ -32,6 +32,7 @@ use crate::commands::project::{find_requires_python, init_script_python_requirem
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
+use crate::settings::{NetworkSettings, NetworkSettingsExt};
 
 /// Add one or more packages to the project requirements.
 #[allow(clippy::single_match_else, clippy::fn_params_excessive_bools)]
@@ -53,11 +54,10 @@ pub(crate) async fn init(
     install_mirrors: PythonInstallMirrors,
     no_workspace: bool,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     python_downloads: PythonDownloads,
-    connectivity: Connectivity,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
-    no_config: bool,
+    network_settings: &NetworkSettings,
+    version_files: VersionFileDiscoveryOptions<'_>,
     cache: &Cache,
     printer: Printer,
     preview: PreviewMode,
@@ -75,8 +75,9 @@ pub(crate) async fn init(
                 path,
                 python,
                 install_mirrors,
-                connectivity,
+                network_settings,
                 python_preference,
+                python_discovery_sources,
                 python_downloads,
                 cache,
                 printer,
@@ -85,9 +86,7 @@ pub(crate) async fn init(
                 author_from,
                 pin_python,
                 package,
-                native_tls,
-                allow_insecure_host,
-                no_config,
+                version_files,
             )
             .await?;
 
@@ -147,11 +146,10 @@ pub(crate) async fn init(
                 install_mirrors,
                 no_workspace,
                 python_preference,
+                python_discovery_sources,
                 python_downloads,
-                connectivity,
-                native_tls,
-                allow_insecure_host,
-                no_config,
+                network_settings,
+                version_files,
                 cache,
                 printer,
             )
@@ -193,8 +191,9 @@ async fn init_script(
     script_path: &Path,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     python_downloads: PythonDownloads,
     cache: &Cache,
     printer: Printer,
@@ -203,9 +202,7 @@ async fn init_script(
     author_from: Option<AuthorFrom>,
     pin_python: bool,
     package: bool,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
-    no_config: bool,
+    version_files: VersionFileDiscoveryOptions<'_>,
 ) -> Result<()> {
     if no_workspace {
         warn_user_once!("`--no-workspace` is a no-op for Python scripts, which are standalone");
@@ -219,10 +216,7 @@ async fn init_script(
     if package {
         warn_user_once!("`--package` is a no-op for Python scripts, which are standalone");
     }
-    let client_builder = BaseClientBuilder::new()
-        .connectivity(connectivity)
-        .native_tls(native_tls)
-        .allow_insecure_host(allow_insecure_host.to_vec());
+    let client_builder = BaseClientBuilder::new().network_settings(network_settings);
 
     let reporter = PythonDownloadReporter::single(printer);
 
@@ -255,8 +249,9 @@ async fn init_script(
         &CWD,
         pin_python,
         python_preference,
+        python_discovery_sources,
         python_downloads,
-        no_config,
+        version_files,
         &client_builder,
         cache,
         &reporter,
@@ -291,11 +286,10 @@ async fn init_project(
     install_mirrors: PythonInstallMirrors,
     no_workspace: bool,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     python_downloads: PythonDownloads,
-    connectivity: Connectivity,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
-    no_config: bool,
+    network_settings: &NetworkSettings,
+    version_files: VersionFileDiscoveryOptions<'_>,
     cache: &Cache,
     printer: Printer,
 ) -> Result<()> {
@@ -343,10 +337,7 @@ async fn init_project(
     };
 
     let reporter = PythonDownloadReporter::single(printer);
-    let client_builder = BaseClientBuilder::new()
-        .connectivity(connectivity)
-        .native_tls(native_tls)
-        .allow_insecure_host(allow_insecure_host.to_vec());
+    let client_builder = BaseClientBuilder::new().network_settings(network_settings);
 
     // First, determine if there is an request for Python
     let python_request = if let Some(request) = python {
@@ -354,14 +345,12 @@ async fn init_project(
         Some(PythonRequest::parse(&request))
     } else if let Some(file) = PythonVersionFile::discover(
         path,
-        &VersionFileDiscoveryOptions::default()
-            .with_stop_discovery_at(
-                workspace
-                    .as_ref()
-                    .map(Workspace::install_path)
-                    .map(PathBuf::as_ref),
-            )
-            .with_no_config(no_config),
+        &version_files.with_stop_discovery_at(
+            workspace
+                .as_ref()
+                .map(Workspace::install_path)
+                .map(PathBuf::as_ref),
+        ),
     )
     .await?
     {
@@ -433,6 +422,7 @@ async fn init_project(
                         Some(python_request),
                         EnvironmentPreference::OnlySystem,
                         python_preference,
+                        python_discovery_sources,
                         python_downloads,
                         &client_builder,
                         cache,
@@ -459,6 +449,7 @@ async fn init_project(
                     Some(&python_request),
                     EnvironmentPreference::OnlySystem,
                     python_preference,
+                    python_discovery_sources,
                     python_downloads,
                     &client_builder,
                     cache,
@@ -524,6 +515,7 @@ async fn init_project(
                 Some(&python_request),
                 EnvironmentPreference::OnlySystem,
                 python_preference,
+                python_discovery_sources,
                 python_downloads,
                 &client_builder,
                 cache,
@@ -550,6 +542,7 @@ async fn init_project(
             None,
             EnvironmentPreference::OnlySystem,
             python_preference,
+            python_discovery_sources,
             python_downloads,
             &client_builder,
             cache,
@@ -631,7 +624,7 @@ async fn init_project(
         }
         // Write .python-version if it doesn't exist in the workspace or if the version differs
         if let Some(python_request) = python_request {
-            if PythonVersionFile::discover(path, &VersionFileDiscoveryOptions::default())
+            if PythonVersionFile::discover(path, &version_files.with_no_config(false))
                 .await?
                 .filter(|file| {
                     file.version()
@@ -651,7 +644,7 @@ async fn init_project(
     } else {
         // Write .python-version if it doesn't exist in the project directory.
         if let Some(python_request) = python_request {
-            if PythonVersionFile::discover(path, &VersionFileDiscoveryOptions::default())
+            if PythonVersionFile::discover(path, &version_files.with_no_config(false))
                 .await?
                 .filter(|file| file.version().is_some())
                 .filter(|file| file.path().parent().is_some_and(|parent| parent == path))
@@ -813,7 +806,6 @@ impl InitProjectKind {
                     def main():
                         print("Hello from {name}!")
 
-
                     if __name__ == "__main__":
                         main()
                     "#},
@@ -1093,7 +1085,6 @@ fn generate_package_scripts(
         indoc::formatdoc! {r"
         from {module_name}._core import hello_from_bin
 
-
         def hello() -> str:
             return hello_from_bin()
         "}
@@ -1101,7 +1092,6 @@ fn generate_package_scripts(
         indoc::formatdoc! {r"
         from {module_name}._core import hello_from_bin
 
-
         def main() -> None:
             print(hello_from_bin())
         "}
@@ -10,10 +10,10 @@ use rustc_hash::{FxBuildHasher, FxHashMap};
 use tracing::debug;
 
 use uv_cache::Cache;
-use uv_client::{Connectivity, FlatIndexClient, RegistryClientBuilder};
+use uv_client::{FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, DevGroupsSpecification, DryRun, ExtrasSpecification, PreviewMode,
-    Reinstall, TrustedHost, Upgrade,
+    Reinstall, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::DistributionDatabase;
@@ -25,7 +25,10 @@ use uv_git::ResolvedRepositoryReference;
 use uv_normalize::{GroupName, PackageName};
 use uv_pep440::Version;
 use uv_pypi_types::{Conflicts, Requirement, SupportedEnvironments};
-use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_python::{
+    Interpreter, PythonDiscoverySource, PythonDownloads, PythonEnvironment, PythonPreference,
+    PythonRequest, VersionFileDiscoveryOptions,
+};
 use uv_requirements::upgrade::{read_lock_requirements, LockedRequirements};
 use uv_requirements::ExtrasResolver;
 use uv_resolver::{
@@ -46,7 +49,7 @@ use crate::commands::project::{
 use crate::commands::reporters::ResolverReporter;
 use crate::commands::{diagnostics, pip, ExitStatus};
 use crate::printer::Printer;
-use crate::settings::{ResolverSettings, ResolverSettingsRef};
+use crate::settings::{NetworkSettings, NetworkSettingsExt, ResolverSettings, ResolverSettingsRef};
 
 /// The result of running a lock operation.
 #[derive(Debug, Clone)]
@@ -85,12 +88,11 @@ pub(crate) async fn lock(
     settings: ResolverSettings,
     script: Option<Pep723Script>,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     python_downloads: PythonDownloads,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     concurrency: Concurrency,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
-    no_config: bool,
+    version_files: VersionFileDiscoveryOptions<'_>,
     cache: &Cache,
     printer: Printer,
     preview: PreviewMode,
@@ -115,12 +117,11 @@ pub(crate) async fn lock(
                 project_dir,
                 python.as_deref().map(PythonRequest::parse),
                 python_preference,
+                python_discovery_sources,
                 python_downloads,
-                connectivity,
-                native_tls,
-                allow_insecure_host,
+                network_settings,
                 &install_mirrors,
-                no_config,
+                version_files,
                 Some(false),
                 cache,
                 printer,
@@ -131,12 +132,11 @@ pub(crate) async fn lock(
                 Pep723ItemRef::Script(script),
                 python.as_deref().map(PythonRequest::parse),
                 python_preference,
+                python_discovery_sources,
                 python_downloads,
-                connectivity,
-                native_tls,
-                allow_insecure_host,
+                network_settings,
                 &install_mirrors,
-                no_config,
+                version_files,
                 cache,
                 printer,
             )
@@ -163,10 +163,8 @@ pub(crate) async fn lock(
         settings.as_ref(),
         &state,
         Box::new(DefaultResolveLogger),
-        connectivity,
+        network_settings,
         concurrency,
-        native_tls,
-        allow_insecure_host,
         cache,
         printer,
         preview,
@@ -201,7 +199,7 @@ pub(crate) async fn lock(
             Ok(ExitStatus::Success)
         }
         Err(ProjectError::Operation(err)) => {
-            diagnostics::OperationDiagnostic::native_tls(native_tls)
+            diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
                 .report(err)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
         }
@@ -229,10 +227,8 @@ pub(super) async fn do_safe_lock(
     settings: ResolverSettingsRef<'_>,
     state: &UniversalState,
     logger: Box<dyn ResolveLogger>,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     concurrency: Concurrency,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
     preview: PreviewMode,
@@ -261,10 +257,8 @@ pub(super) async fn do_safe_lock(
                 settings,
                 state,
                 logger,
-                connectivity,
+                network_settings,
                 concurrency,
-                native_tls,
-                allow_insecure_host,
                 cache,
                 printer,
                 preview,
@@ -300,10 +294,8 @@ pub(super) async fn do_safe_lock(
                 settings,
                 state,
                 logger,
-                connectivity,
+                network_settings,
                 concurrency,
-                native_tls,
-                allow_insecure_host,
                 cache,
                 printer,
                 preview,
@@ -330,10 +322,8 @@ async fn do_lock(
     settings: ResolverSettingsRef<'_>,
     state: &UniversalState,
     logger: Box<dyn ResolveLogger>,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     concurrency: Concurrency,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
     preview: PreviewMode,
@@ -487,12 +477,10 @@ async fn do_lock(
 
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
-        .native_tls(native_tls)
-        .connectivity(connectivity)
+        .network_settings(network_settings)
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
         .keyring(keyring_provider)
-        .allow_insecure_host(allow_insecure_host.to_vec())
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
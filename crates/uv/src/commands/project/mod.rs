@@ -10,10 +10,10 @@ use tracing::{debug, warn};
 
 use uv_cache::{Cache, CacheBucket};
 use uv_cache_key::cache_digest;
-use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
+use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, DevGroupsManifest, DevGroupsSpecification, DryRun,
-    ExtrasSpecification, PreviewMode, Reinstall, SourceStrategy, TrustedHost, Upgrade,
+    ExtrasSpecification, PreviewMode, Reinstall, SourceStrategy, Upgrade,
 };
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::{DistributionDatabase, LoweredRequirement};
@@ -28,9 +28,9 @@ use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::MarkerTreeContents;
 use uv_pypi_types::{ConflictPackage, ConflictSet, Conflicts, Requirement};
 use uv_python::{
-    EnvironmentPreference, Interpreter, InvalidEnvironmentKind, PythonDownloads, PythonEnvironment,
-    PythonInstallation, PythonPreference, PythonRequest, PythonVariant, PythonVersionFile,
-    VersionFileDiscoveryOptions, VersionRequest,
+    EnvironmentPreference, Interpreter, InvalidEnvironmentKind, PythonDiscoverySource,
+    PythonDownloads, PythonEnvironment, PythonInstallation, PythonPreference, PythonRequest,
+    PythonVariant, PythonVersionFile, VersionFileDiscoveryOptions, VersionRequest,
 };
 use uv_requirements::upgrade::{read_lock_requirements, LockedRequirements};
 use uv_requirements::{NamedRequirementsResolver, RequirementsSpecification};
@@ -51,7 +51,10 @@ use crate::commands::pip::operations::{Changelog, Modifications};
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
 use crate::commands::{capitalize, conjunction, pip};
 use crate::printer::Printer;
-use crate::settings::{InstallerSettingsRef, ResolverInstallerSettings, ResolverSettingsRef};
+use crate::settings::{
+    InstallerSettingsRef, NetworkSettings, NetworkSettingsExt, ResolverInstallerSettings,
+    ResolverSettingsRef,
+};
 
 pub(crate) mod add;
 pub(crate) mod environment;
@@ -563,12 +566,11 @@ impl ScriptInterpreter {
         script: Pep723ItemRef<'_>,
         python_request: Option<PythonRequest>,
         python_preference: PythonPreference,
+        python_discovery_sources: &[PythonDiscoverySource],
         python_downloads: PythonDownloads,
-        connectivity: Connectivity,
-        native_tls: bool,
-        allow_insecure_host: &[TrustedHost],
+        network_settings: &NetworkSettings,
         install_mirrors: &PythonInstallMirrors,
-        no_config: bool,
+        version_files: VersionFileDiscoveryOptions<'_>,
         cache: &Cache,
         printer: Printer,
     ) -> Result<Self, ProjectError> {
@@ -579,7 +581,7 @@ impl ScriptInterpreter {
             source,
             python_request,
             requires_python,
-        } = ScriptPython::from_request(python_request, workspace, script, no_config).await?;
+        } = ScriptPython::from_request(python_request, workspace, script, version_files).await?;
 
         let root = Self::root(script, cache);
         match PythonEnvironment::from_root(&root, cache) {
@@ -615,10 +617,7 @@ impl ScriptInterpreter {
             Err(err) => warn!("Ignoring existing script environment: {err}"),
         };
 
-        let client_builder = BaseClientBuilder::new()
-            .connectivity(connectivity)
-            .native_tls(native_tls)
-            .allow_insecure_host(allow_insecure_host.to_vec());
+        let client_builder = BaseClientBuilder::new().network_settings(network_settings);
 
         let reporter = PythonDownloadReporter::single(printer);
 
@@ -626,6 +625,7 @@ impl ScriptInterpreter {
             python_request.as_ref(),
             EnvironmentPreference::Any,
             python_preference,
+            python_discovery_sources,
             python_downloads,
             &client_builder,
             cache,
@@ -704,12 +704,11 @@ impl ProjectInterpreter {
         project_dir: &Path,
         python_request: Option<PythonRequest>,
         python_preference: PythonPreference,
+        python_discovery_sources: &[PythonDiscoverySource],
         python_downloads: PythonDownloads,
-        connectivity: Connectivity,
-        native_tls: bool,
-        allow_insecure_host: &[TrustedHost],
+        network_settings: &NetworkSettings,
         install_mirrors: &PythonInstallMirrors,
-        no_config: bool,
+        version_files: VersionFileDiscoveryOptions<'_>,
         active: Option<bool>,
         cache: &Cache,
         printer: Printer,
@@ -719,8 +718,13 @@ impl ProjectInterpreter {
             source,
             python_request,
             requires_python,
-        } = WorkspacePython::from_request(python_request, Some(workspace), project_dir, no_config)
-            .await?;
+        } = WorkspacePython::from_request(
+            python_request,
+            Some(workspace),
+            project_dir,
+            version_files,
+        )
+        .await?;
 
         // Read from the virtual environment first.
         let venv = workspace.venv(active);
@@ -790,10 +794,7 @@ impl ProjectInterpreter {
             Err(err) => return Err(err.into()),
         };
 
-        let client_builder = BaseClientBuilder::default()
-            .connectivity(connectivity)
-            .native_tls(native_tls)
-            .allow_insecure_host(allow_insecure_host.to_vec());
+        let client_builder = BaseClientBuilder::default().network_settings(network_settings);
 
         let reporter = PythonDownloadReporter::single(printer);
 
@@ -802,6 +803,7 @@ impl ProjectInterpreter {
             python_request.as_ref(),
             EnvironmentPreference::OnlySystem,
             python_preference,
+            python_discovery_sources,
             python_downloads,
             &client_builder,
             cache,
@@ -916,7 +918,7 @@ impl WorkspacePython {
         python_request: Option<PythonRequest>,
         workspace: Option<&Workspace>,
         project_dir: &Path,
-        no_config: bool,
+        version_files: VersionFileDiscoveryOptions<'_>,
     ) -> Result<Self, ProjectError> {
         let requires_python = workspace.map(find_requires_python).transpose()?.flatten();
 
@@ -929,9 +931,7 @@ impl WorkspacePython {
             (source, request)
         } else if let Some(file) = PythonVersionFile::discover(
             project_dir,
-            &VersionFileDiscoveryOptions::default()
-                .with_stop_discovery_at(workspace_root.map(PathBuf::as_ref))
-                .with_no_config(no_config),
+            &version_files.with_stop_discovery_at(workspace_root.map(PathBuf::as_ref)),
         )
         .await?
         {
@@ -989,7 +989,7 @@ impl ScriptPython {
         python_request: Option<PythonRequest>,
         workspace: Option<&Workspace>,
         script: Pep723ItemRef<'_>,
-        no_config: bool,
+        version_files: VersionFileDiscoveryOptions<'_>,
     ) -> Result<Self, ProjectError> {
         // First, discover a requirement from the workspace
         let WorkspacePython {
@@ -1000,7 +1000,7 @@ impl ScriptPython {
             python_request,
             workspace,
             script.path().and_then(Path::parent).unwrap_or(&**CWD),
-            no_config,
+            version_files,
         )
         .await?;
 
@@ -1042,6 +1042,9 @@ enum ProjectEnvironment {
     /// An existing [`PythonEnvironment`] was discovered, but did not satisfy the project's
     /// requirements, and so was replaced.
     Replaced(PythonEnvironment),
+    /// An existing [`PythonEnvironment`] was discovered, but did not satisfy the project's
+    /// requirements, and so its interpreter was upgraded in place.
+    Upgraded(PythonEnvironment),
     /// A new [`PythonEnvironment`] was created.
     Created(PythonEnvironment),
     /// An existing [`PythonEnvironment`] was discovered, but did not satisfy the project's
@@ -1068,11 +1071,10 @@ impl ProjectEnvironment {
         python: Option<PythonRequest>,
         install_mirrors: &PythonInstallMirrors,
         python_preference: PythonPreference,
+        python_discovery_sources: &[PythonDiscoverySource],
         python_downloads: PythonDownloads,
-        connectivity: Connectivity,
-        native_tls: bool,
-        allow_insecure_host: &[TrustedHost],
-        no_config: bool,
+        network_settings: &NetworkSettings,
+        version_files: VersionFileDiscoveryOptions<'_>,
         active: Option<bool>,
         cache: &Cache,
         dry_run: DryRun,
@@ -1086,12 +1088,11 @@ impl ProjectEnvironment {
             workspace.install_path().as_ref(),
             python,
             python_preference,
+            python_discovery_sources,
             python_downloads,
-            connectivity,
-            native_tls,
-            allow_insecure_host,
+            network_settings,
             install_mirrors,
-            no_config,
+            version_files,
             active,
             cache,
             printer,
@@ -1170,6 +1171,33 @@ impl ProjectEnvironment {
                     });
                 }
 
+                // If the existing virtual environment was created by uv for the same Python
+                // implementation, upgrade its interpreter in place, retaining any compatible
+                // packages.
+                if replace {
+                    if let Some(existing) = upgradable_environment(&root, &interpreter, cache) {
+                        let environment = uv_virtualenv::upgrade_venv(
+                            &existing,
+                            interpreter,
+                            prompt,
+                            false,
+                            false,
+                            false,
+                        )?;
+                        writeln!(
+                            printer.stderr(),
+                            "Updated Python interpreter for virtual environment at: {}",
+                            root.user_display().cyan()
+                        )?;
+
+                        // Remove any packages that were built for the previous interpreter, such
+                        // that they're reinstalled on the next sync.
+                        pip::operations::uninstall_incompatible(&environment).await?;
+
+                        return Ok(Self::Upgraded(environment));
+                    }
+                }
+
                 // Remove the existing virtual environment if it doesn't meet the requirements.
                 if replace {
                     match fs_err::remove_dir_all(&root) {
@@ -1219,6 +1247,7 @@ impl ProjectEnvironment {
         match self {
             Self::Existing(environment) => Ok(environment),
             Self::Replaced(environment) => Ok(environment),
+            Self::Upgraded(environment) => Ok(environment),
             Self::Created(environment) => Ok(environment),
             Self::WouldReplace(..) => Err(ProjectError::DroppedEnvironment),
             Self::WouldCreate(..) => Err(ProjectError::DroppedEnvironment),
@@ -1233,6 +1262,7 @@ impl std::ops::Deref for ProjectEnvironment {
         match self {
             Self::Existing(environment) => environment,
             Self::Replaced(environment) => environment,
+            Self::Upgraded(environment) => environment,
             Self::Created(environment) => environment,
             Self::WouldReplace(_, environment, _) => environment,
             Self::WouldCreate(_, environment, _) => environment,
@@ -1240,6 +1270,45 @@ impl std::ops::Deref for ProjectEnvironment {
     }
 }
 
+/// Return the virtual environment at the given root, if its interpreter can be upgraded in place
+/// to the given [`Interpreter`].
+///
+/// Only virtual environments created by uv with the same Python implementation are eligible. Any
+/// installed packages that are incompatible with the new interpreter must be reinstalled by the
+/// caller.
+pub(crate) fn upgradable_environment(
+    root: &Path,
+    interpreter: &Interpreter,
+    cache: &Cache,
+) -> Option<PythonEnvironment> {
+    let environment = match PythonEnvironment::from_root(root, cache) {
+        Ok(environment) => environment,
+        Err(err) => {
+            debug!(
+                "Unable to upgrade environment at `{}` in place: {err}",
+                root.user_display()
+            );
+            return None;
+        }
+    };
+    if !environment.interpreter().is_virtualenv() || !environment.cfg().is_ok_and(|cfg| cfg.is_uv())
+    {
+        debug!(
+            "Unable to upgrade environment at `{}` in place: not created by uv",
+            root.user_display()
+        );
+        return None;
+    }
+    if environment.interpreter().implementation_name() != interpreter.implementation_name() {
+        debug!(
+            "Unable to upgrade environment at `{}` in place: the Python implementation differs",
+            root.user_display()
+        );
+        return None;
+    }
+    Some(environment)
+}
+
 /// The Python environment for a script.
 #[derive(Debug)]
 enum ScriptEnvironment {
@@ -1273,12 +1342,11 @@ impl ScriptEnvironment {
         script: Pep723ItemRef<'_>,
         python_request: Option<PythonRequest>,
         python_preference: PythonPreference,
+        python_discovery_sources: &[PythonDiscoverySource],
         python_downloads: PythonDownloads,
-        connectivity: Connectivity,
-        native_tls: bool,
-        allow_insecure_host: &[TrustedHost],
+        network_settings: &NetworkSettings,
         install_mirrors: &PythonInstallMirrors,
-        no_config: bool,
+        version_files: VersionFileDiscoveryOptions<'_>,
         cache: &Cache,
         dry_run: DryRun,
         printer: Printer,
@@ -1290,12 +1358,11 @@ impl ScriptEnvironment {
             script,
             python_request,
             python_preference,
+            python_discovery_sources,
             python_downloads,
-            connectivity,
-            native_tls,
-            allow_insecure_host,
+            network_settings,
             install_mirrors,
-            no_config,
+            version_files,
             cache,
             printer,
         )
@@ -1411,10 +1478,8 @@ pub(crate) async fn resolve_names(
     interpreter: &Interpreter,
     settings: &ResolverInstallerSettings,
     state: &SharedState,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     concurrency: Concurrency,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
     preview: PreviewMode,
@@ -1469,12 +1534,10 @@ pub(crate) async fn resolve_names(
 
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
-        .native_tls(native_tls)
-        .connectivity(connectivity)
+        .network_settings(network_settings)
         .index_urls(index_locations.index_urls())
         .index_strategy(*index_strategy)
         .keyring(*keyring_provider)
-        .allow_insecure_host(allow_insecure_host.to_vec())
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
@@ -1566,10 +1629,8 @@ pub(crate) async fn resolve_environment(
     settings: ResolverSettingsRef<'_>,
     state: &PlatformState,
     logger: Box<dyn ResolveLogger>,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     concurrency: Concurrency,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
     preview: PreviewMode,
@@ -1622,12 +1683,10 @@ pub(crate) async fn resolve_environment(
 
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
-        .native_tls(native_tls)
-        .connectivity(connectivity)
+        .network_settings(network_settings)
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
         .keyring(keyring_provider)
-        .allow_insecure_host(allow_insecure_host.to_vec())
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
@@ -1750,10 +1809,8 @@ pub(crate) async fn sync_environment(
     state: &PlatformState,
     logger: Box<dyn InstallLogger>,
     installer_metadata: bool,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     concurrency: Concurrency,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
     preview: PreviewMode,
@@ -1793,12 +1850,10 @@ pub(crate) async fn sync_environment(
 
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
-        .native_tls(native_tls)
-        .connectivity(connectivity)
+        .network_settings(network_settings)
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
         .keyring(keyring_provider)
-        .allow_insecure_host(allow_insecure_host.to_vec())
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
@@ -1908,10 +1963,8 @@ pub(crate) async fn update_environment(
     resolve: Box<dyn ResolveLogger>,
     install: Box<dyn InstallLogger>,
     installer_metadata: bool,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     concurrency: Concurrency,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     dry_run: DryRun,
     printer: Printer,
@@ -1997,12 +2050,10 @@ pub(crate) async fn update_environment(
 
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
-        .native_tls(native_tls)
-        .connectivity(connectivity)
+        .network_settings(network_settings)
         .index_urls(index_locations.index_urls())
         .index_strategy(*index_strategy)
         .keyring(*keyring_provider)
-        .allow_insecure_host(allow_insecure_host.to_vec())
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
@@ -2145,8 +2196,9 @@ pub(crate) async fn init_script_python_requirement(
     directory: &Path,
     no_pin_python: bool,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     python_downloads: PythonDownloads,
-    no_config: bool,
+    version_files: VersionFileDiscoveryOptions<'_>,
     client_builder: &BaseClientBuilder<'_>,
     cache: &Cache,
     reporter: &PythonDownloadReporter,
@@ -2156,12 +2208,9 @@ pub(crate) async fn init_script_python_requirement(
         PythonRequest::parse(request)
     } else if let (false, Some(request)) = (
         no_pin_python,
-        PythonVersionFile::discover(
-            directory,
-            &VersionFileDiscoveryOptions::default().with_no_config(no_config),
-        )
-        .await?
-        .and_then(PythonVersionFile::into_version),
+        PythonVersionFile::discover(directory, &version_files)
+            .await?
+            .and_then(PythonVersionFile::into_version),
     ) {
         // (2) Request from `.python-version`
         request
@@ -2174,6 +2223,7 @@ pub(crate) async fn init_script_python_requirement(
         Some(&python_request),
         EnvironmentPreference::Any,
         python_preference,
+        python_discovery_sources,
         python_downloads,
         client_builder,
         cache,
@@ -8,15 +8,18 @@ use owo_colors::OwoColorize;
 use tracing::debug;
 
 use uv_cache::Cache;
-use uv_client::Connectivity;
+
 use uv_configuration::{
     Concurrency, DevGroupsSpecification, DryRun, EditableMode, ExtrasSpecification, InstallOptions,
-    PreviewMode, TrustedHost,
+    PreviewMode,
 };
 use uv_fs::Simplified;
 use uv_normalize::DEV_DEPENDENCIES;
 use uv_pep508::PackageName;
-use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
+use uv_python::{
+    PythonDiscoverySource, PythonDownloads, PythonPreference, PythonRequest,
+    VersionFileDiscoveryOptions,
+};
 use uv_scripts::{Pep723ItemRef, Pep723Metadata, Pep723Script};
 use uv_settings::PythonInstallMirrors;
 use uv_warnings::warn_user_once;
@@ -36,7 +39,7 @@ use crate::commands::project::{
 };
 use crate::commands::{diagnostics, project, ExitStatus};
 use crate::printer::Printer;
-use crate::settings::ResolverInstallerSettings;
+use crate::settings::{NetworkSettings, ResolverInstallerSettings};
 
 /// Remove one or more packages from the project requirements.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -54,13 +57,12 @@ pub(crate) async fn remove(
     settings: ResolverInstallerSettings,
     script: Option<Pep723Script>,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     python_downloads: PythonDownloads,
     installer_metadata: bool,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     concurrency: Concurrency,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
-    no_config: bool,
+    version_files: VersionFileDiscoveryOptions<'_>,
     cache: &Cache,
     printer: Printer,
     preview: PreviewMode,
@@ -205,12 +207,11 @@ pub(crate) async fn remove(
                     project_dir,
                     python.as_deref().map(PythonRequest::parse),
                     python_preference,
+                    python_discovery_sources,
                     python_downloads,
-                    connectivity,
-                    native_tls,
-                    allow_insecure_host,
+                    network_settings,
                     &install_mirrors,
-                    no_config,
+                    version_files,
                     active,
                     cache,
                     printer,
@@ -226,11 +227,10 @@ pub(crate) async fn remove(
                     python.as_deref().map(PythonRequest::parse),
                     &install_mirrors,
                     python_preference,
+                    python_discovery_sources,
                     python_downloads,
-                    connectivity,
-                    native_tls,
-                    allow_insecure_host,
-                    no_config,
+                    network_settings,
+                    version_files,
                     active,
                     cache,
                     DryRun::Disabled,
@@ -247,12 +247,11 @@ pub(crate) async fn remove(
                 Pep723ItemRef::Script(&script),
                 python.as_deref().map(PythonRequest::parse),
                 python_preference,
+                python_discovery_sources,
                 python_downloads,
-                connectivity,
-                native_tls,
-                allow_insecure_host,
+                network_settings,
                 &install_mirrors,
-                no_config,
+                version_files,
                 cache,
                 printer,
             )
@@ -280,10 +279,8 @@ pub(crate) async fn remove(
         settings.as_ref().into(),
         &state,
         Box::new(DefaultResolveLogger),
-        connectivity,
+        network_settings,
         concurrency,
-        native_tls,
-        allow_insecure_host,
         cache,
         printer,
         preview,
@@ -292,7 +289,7 @@ pub(crate) async fn remove(
     {
         Ok(result) => result.into_lock(),
         Err(ProjectError::Operation(err)) => {
-            return diagnostics::OperationDiagnostic::native_tls(native_tls)
+            return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
                 .report(err)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
         }
@@ -344,10 +341,8 @@ pub(crate) async fn remove(
         &state,
         Box::new(DefaultInstallLogger),
         installer_metadata,
-        connectivity,
+        network_settings,
         concurrency,
-        native_tls,
-        allow_insecure_host,
         cache,
         DryRun::Disabled,
         printer,
@@ -357,7 +352,7 @@ pub(crate) async fn remove(
     {
         Ok(()) => {}
         Err(ProjectError::Operation(err)) => {
-            return diagnostics::OperationDiagnostic::native_tls(native_tls)
+            return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
                 .report(err)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
         }
@@ -15,18 +15,19 @@ use url::Url;
 
 use uv_cache::Cache;
 use uv_cli::ExternalCommand;
-use uv_client::{BaseClientBuilder, Connectivity};
+use uv_client::BaseClientBuilder;
 use uv_configuration::{
     Concurrency, DevGroupsSpecification, DryRun, EditableMode, ExtrasSpecification, InstallOptions,
-    PreviewMode, TrustedHost,
+    PreviewMode,
 };
 use uv_fs::which::is_executable;
 use uv_fs::{PythonExt, Simplified};
 use uv_installer::{SatisfiesResult, SitePackages};
 use uv_normalize::PackageName;
 use uv_python::{
-    EnvironmentPreference, Interpreter, PythonDownloads, PythonEnvironment, PythonInstallation,
-    PythonPreference, PythonRequest, PythonVersionFile, VersionFileDiscoveryOptions,
+    EnvironmentPreference, Interpreter, PythonDiscoverySource, PythonDownloads, PythonEnvironment,
+    PythonInstallation, PythonPreference, PythonRequest, PythonVersionFile,
+    VersionFileDiscoveryOptions,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_resolver::Lock;
@@ -54,7 +55,7 @@ use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::run::run_to_completion;
 use crate::commands::{diagnostics, project, ExitStatus};
 use crate::printer::Printer;
-use crate::settings::ResolverInstallerSettings;
+use crate::settings::{NetworkSettings, NetworkSettingsExt, ResolverInstallerSettings};
 
 /// Run a command.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -72,7 +73,7 @@ pub(crate) async fn run(
     all_packages: bool,
     package: Option<PackageName>,
     no_project: bool,
-    no_config: bool,
+    version_files: VersionFileDiscoveryOptions<'_>,
     extras: ExtrasSpecification,
     dev: DevGroupsSpecification,
     editable: EditableMode,
@@ -81,12 +82,11 @@ pub(crate) async fn run(
     install_mirrors: PythonInstallMirrors,
     settings: ResolverInstallerSettings,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     python_downloads: PythonDownloads,
     installer_metadata: bool,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     concurrency: Concurrency,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
     env_file: Vec<PathBuf>,
@@ -220,12 +220,11 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 (&script).into(),
                 python.as_deref().map(PythonRequest::parse),
                 python_preference,
+                python_discovery_sources,
                 python_downloads,
-                connectivity,
-                native_tls,
-                allow_insecure_host,
+                network_settings,
                 &install_mirrors,
-                no_config,
+                version_files,
                 cache,
                 DryRun::Disabled,
                 printer,
@@ -253,10 +252,8 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 } else {
                     Box::new(SummaryResolveLogger)
                 },
-                connectivity,
+                network_settings,
                 concurrency,
-                native_tls,
-                allow_insecure_host,
                 cache,
                 printer,
                 preview,
@@ -265,10 +262,12 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             {
                 Ok(result) => result.into_lock(),
                 Err(ProjectError::Operation(err)) => {
-                    return diagnostics::OperationDiagnostic::native_tls(native_tls)
-                        .with_context("script")
-                        .report(err)
-                        .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                    return diagnostics::OperationDiagnostic::native_tls(
+                        network_settings.native_tls,
+                    )
+                    .with_context("script")
+                    .report(err)
+                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
                 }
                 Err(err) => return Err(err.into()),
             };
@@ -297,10 +296,8 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     Box::new(SummaryInstallLogger)
                 },
                 installer_metadata,
-                connectivity,
+                network_settings,
                 concurrency,
-                native_tls,
-                allow_insecure_host,
                 cache,
                 DryRun::Disabled,
                 printer,
@@ -310,10 +307,12 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             {
                 Ok(()) => {}
                 Err(ProjectError::Operation(err)) => {
-                    return diagnostics::OperationDiagnostic::native_tls(native_tls)
-                        .with_context("script")
-                        .report(err)
-                        .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                    return diagnostics::OperationDiagnostic::native_tls(
+                        network_settings.native_tls,
+                    )
+                    .with_context("script")
+                    .report(err)
+                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
                 }
                 Err(err) => return Err(err.into()),
             }
@@ -340,12 +339,11 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     (&script).into(),
                     python.as_deref().map(PythonRequest::parse),
                     python_preference,
+                    python_discovery_sources,
                     python_downloads,
-                    connectivity,
-                    native_tls,
-                    allow_insecure_host,
+                    network_settings,
                     &install_mirrors,
-                    no_config,
+                    version_files,
                     cache,
                     DryRun::Disabled,
                     printer,
@@ -370,10 +368,8 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                         Box::new(SummaryInstallLogger)
                     },
                     installer_metadata,
-                    connectivity,
+                    network_settings,
                     concurrency,
-                    native_tls,
-                    allow_insecure_host,
                     cache,
                     DryRun::Disabled,
                     printer,
@@ -383,10 +379,12 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 {
                     Ok(update) => Some(update.into_environment().into_interpreter()),
                     Err(ProjectError::Operation(err)) => {
-                        return diagnostics::OperationDiagnostic::native_tls(native_tls)
-                            .with_context("script")
-                            .report(err)
-                            .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                        return diagnostics::OperationDiagnostic::native_tls(
+                            network_settings.native_tls,
+                        )
+                        .with_context("script")
+                        .report(err)
+                        .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
                     }
                     Err(err) => return Err(err.into()),
                 }
@@ -396,12 +394,11 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     (&script).into(),
                     python.as_deref().map(PythonRequest::parse),
                     python_preference,
+                    python_discovery_sources,
                     python_downloads,
-                    connectivity,
-                    native_tls,
-                    allow_insecure_host,
+                    network_settings,
                     &install_mirrors,
-                    no_config,
+                    version_files,
                     cache,
                     printer,
                 )
@@ -556,10 +553,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
 
                 // If we're isolating the environment, use an ephemeral virtual environment as the
                 // base environment for the project.
-                let client_builder = BaseClientBuilder::new()
-                    .connectivity(connectivity)
-                    .native_tls(native_tls)
-                    .allow_insecure_host(allow_insecure_host.to_vec());
+                let client_builder = BaseClientBuilder::new().network_settings(network_settings);
 
                 // Resolve the Python request and requirement for the workspace.
                 let WorkspacePython {
@@ -570,7 +564,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     python.as_deref().map(PythonRequest::parse),
                     Some(project.workspace()),
                     project_dir,
-                    no_config,
+                    version_files,
                 )
                 .await?;
 
@@ -578,6 +572,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     python_request.as_ref(),
                     EnvironmentPreference::Any,
                     python_preference,
+                    python_discovery_sources,
                     python_downloads,
                     &client_builder,
                     cache,
@@ -616,11 +611,10 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     python.as_deref().map(PythonRequest::parse),
                     &install_mirrors,
                     python_preference,
+                    python_discovery_sources,
                     python_downloads,
-                    connectivity,
-                    native_tls,
-                    allow_insecure_host,
-                    no_config,
+                    network_settings,
+                    version_files,
                     active,
                     cache,
                     DryRun::Disabled,
@@ -683,10 +677,8 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     } else {
                         Box::new(SummaryResolveLogger)
                     },
-                    connectivity,
+                    network_settings,
                     concurrency,
-                    native_tls,
-                    allow_insecure_host,
                     cache,
                     printer,
                     preview,
@@ -695,9 +687,11 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 {
                     Ok(result) => result,
                     Err(ProjectError::Operation(err)) => {
-                        return diagnostics::OperationDiagnostic::native_tls(native_tls)
-                            .report(err)
-                            .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                        return diagnostics::OperationDiagnostic::native_tls(
+                            network_settings.native_tls,
+                        )
+                        .report(err)
+                        .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
                     }
                     Err(err) => return Err(err.into()),
                 };
@@ -765,10 +759,8 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                         Box::new(SummaryInstallLogger)
                     },
                     installer_metadata,
-                    connectivity,
+                    network_settings,
                     concurrency,
-                    native_tls,
-                    allow_insecure_host,
                     cache,
                     DryRun::Disabled,
                     printer,
@@ -778,9 +770,11 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 {
                     Ok(()) => {}
                     Err(ProjectError::Operation(err)) => {
-                        return diagnostics::OperationDiagnostic::native_tls(native_tls)
-                            .report(err)
-                            .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                        return diagnostics::OperationDiagnostic::native_tls(
+                            network_settings.native_tls,
+                        )
+                        .report(err)
+                        .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
                     }
                     Err(err) => return Err(err.into()),
                 }
@@ -796,22 +790,16 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             debug!("No project found; searching for Python interpreter");
 
             let interpreter = {
-                let client_builder = BaseClientBuilder::new()
-                    .connectivity(connectivity)
-                    .native_tls(native_tls)
-                    .allow_insecure_host(allow_insecure_host.to_vec());
+                let client_builder = BaseClientBuilder::new().network_settings(network_settings);
 
                 // (1) Explicit request from user
                 let python_request = if let Some(request) = python.as_deref() {
                     Some(PythonRequest::parse(request))
                 // (2) Request from `.python-version`
                 } else {
-                    PythonVersionFile::discover(
-                        &project_dir,
-                        &VersionFileDiscoveryOptions::default().with_no_config(no_config),
-                    )
-                    .await?
-                    .and_then(PythonVersionFile::into_version)
+                    PythonVersionFile::discover(&project_dir, &version_files)
+                        .await?
+                        .and_then(PythonVersionFile::into_version)
                 };
 
                 let python = PythonInstallation::find_or_download(
@@ -819,6 +807,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     // No opt-in is required for system environments, since we are not mutating it.
                     EnvironmentPreference::Any,
                     python_preference,
+                    python_discovery_sources,
                     python_downloads,
                     &client_builder,
                     cache,
@@ -864,10 +853,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
     let spec = if requirements.is_empty() {
         None
     } else {
-        let client_builder = BaseClientBuilder::new()
-            .connectivity(connectivity)
-            .native_tls(native_tls)
-            .allow_insecure_host(allow_insecure_host.to_vec());
+        let client_builder = BaseClientBuilder::new().network_settings(network_settings);
 
         let spec =
             RequirementsSpecification::from_simple_sources(&requirements, &client_builder).await?;
@@ -901,10 +887,8 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     Box::new(SummaryInstallLogger)
                 },
                 installer_metadata,
-                connectivity,
+                network_settings,
                 concurrency,
-                native_tls,
-                allow_insecure_host,
                 cache,
                 printer,
                 preview,
@@ -914,10 +898,12 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             let environment = match result {
                 Ok(resolution) => resolution,
                 Err(ProjectError::Operation(err)) => {
-                    return diagnostics::OperationDiagnostic::native_tls(native_tls)
-                        .with_context("`--with`")
-                        .report(err)
-                        .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                    return diagnostics::OperationDiagnostic::native_tls(
+                        network_settings.native_tls,
+                    )
+                    .with_context("`--with`")
+                    .report(err)
+                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
                 }
                 Err(err) => return Err(err.into()),
             };
@@ -1373,9 +1359,7 @@ impl RunCommand {
         module: bool,
         script: bool,
         gui_script: bool,
-        connectivity: Connectivity,
-        native_tls: bool,
-        allow_insecure_host: &[TrustedHost],
+        network_settings: &NetworkSettings,
     ) -> anyhow::Result<Self> {
         let (target, args) = command.split();
         let Some(target) = target else {
@@ -1417,9 +1401,7 @@ impl RunCommand {
                     .tempfile()?;
 
                 let client = BaseClientBuilder::new()
-                    .connectivity(connectivity)
-                    .native_tls(native_tls)
-                    .allow_insecure_host(allow_insecure_host.to_vec())
+                    .network_settings(network_settings)
                     .build();
                 let response = client.for_host(&url).get(url.clone()).send().await?;
 
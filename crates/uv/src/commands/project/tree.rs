@@ -6,13 +6,14 @@ use futures::StreamExt;
 use tokio::sync::Semaphore;
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
-use uv_client::{Connectivity, RegistryClientBuilder};
-use uv_configuration::{
-    Concurrency, DevGroupsSpecification, PreviewMode, TargetTriple, TrustedHost,
-};
+use uv_client::RegistryClientBuilder;
+use uv_configuration::{Concurrency, DevGroupsSpecification, PreviewMode, TargetTriple};
 use uv_distribution_types::IndexCapabilities;
 use uv_pep508::PackageName;
-use uv_python::{PythonDownloads, PythonPreference, PythonRequest, PythonVersion};
+use uv_python::{
+    PythonDiscoverySource, PythonDownloads, PythonPreference, PythonRequest, PythonVersion,
+    VersionFileDiscoveryOptions,
+};
 use uv_resolver::{PackageMap, TreeDisplay};
 use uv_scripts::{Pep723ItemRef, Pep723Script};
 use uv_settings::PythonInstallMirrors;
@@ -30,7 +31,7 @@ use crate::commands::project::{
 use crate::commands::reporters::LatestVersionReporter;
 use crate::commands::{diagnostics, ExitStatus};
 use crate::printer::Printer;
-use crate::settings::ResolverSettings;
+use crate::settings::{NetworkSettings, NetworkSettingsExt, ResolverSettings};
 
 /// Run a command.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -53,12 +54,11 @@ pub(crate) async fn tree(
     settings: ResolverSettings,
     script: Option<Pep723Script>,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     python_downloads: PythonDownloads,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     concurrency: Concurrency,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
-    no_config: bool,
+    version_files: VersionFileDiscoveryOptions<'_>,
     cache: &Cache,
     printer: Printer,
     preview: PreviewMode,
@@ -96,12 +96,11 @@ pub(crate) async fn tree(
                 Pep723ItemRef::Script(script),
                 python.as_deref().map(PythonRequest::parse),
                 python_preference,
+                python_discovery_sources,
                 python_downloads,
-                connectivity,
-                native_tls,
-                allow_insecure_host,
+                network_settings,
                 &install_mirrors,
-                no_config,
+                version_files,
                 cache,
                 printer,
             )
@@ -112,12 +111,11 @@ pub(crate) async fn tree(
                 project_dir,
                 python.as_deref().map(PythonRequest::parse),
                 python_preference,
+                python_discovery_sources,
                 python_downloads,
-                connectivity,
-                native_tls,
-                allow_insecure_host,
+                network_settings,
                 &install_mirrors,
-                no_config,
+                version_files,
                 Some(false),
                 cache,
                 printer,
@@ -149,10 +147,8 @@ pub(crate) async fn tree(
         settings.as_ref(),
         &state,
         Box::new(DefaultResolveLogger),
-        connectivity,
+        network_settings,
         concurrency,
-        native_tls,
-        allow_insecure_host,
         cache,
         printer,
         preview,
@@ -161,7 +157,7 @@ pub(crate) async fn tree(
     {
         Ok(result) => result.into_lock(),
         Err(ProjectError::Operation(err)) => {
-            return diagnostics::OperationDiagnostic::native_tls(native_tls)
+            return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
                 .report(err)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
         }
@@ -220,10 +216,8 @@ pub(crate) async fn tree(
             let client = RegistryClientBuilder::new(
                 cache.clone().with_refresh(Refresh::All(Timestamp::now())),
             )
-            .native_tls(native_tls)
-            .connectivity(connectivity)
+            .network_settings(network_settings)
             .keyring(*keyring_provider)
-            .allow_insecure_host(allow_insecure_host.to_vec())
             .build();
             let download_concurrency = Semaphore::new(concurrency.downloads);
 
@@ -1,55 +1,152 @@
 use crate::commands::reporters::PublishReporter;
 use crate::commands::{human_readable_bytes, ExitStatus};
 use crate::printer::Printer;
+use crate::settings::{NetworkSettings, NetworkSettingsExt};
 use anyhow::{bail, Context, Result};
 use console::Term;
+use futures::StreamExt;
 use owo_colors::OwoColorize;
 use std::fmt::Write;
 use std::iter;
+use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::Semaphore;
 use tracing::{debug, info};
 use url::Url;
 use uv_cache::Cache;
-use uv_client::{
-    AuthIntegration, BaseClient, BaseClientBuilder, Connectivity, RegistryClientBuilder,
-};
-use uv_configuration::{KeyringProviderType, TrustedHost, TrustedPublishing};
-use uv_distribution_types::{Index, IndexCapabilities, IndexLocations, IndexUrl};
+use uv_client::{AuthIntegration, BaseClient, BaseClientBuilder, RegistryClientBuilder};
+use uv_configuration::{Concurrency, KeyringProviderType, TrustedPublishing};
+use uv_distribution_filename::DistFilename;
+use uv_distribution_types::{Index, IndexCapabilities, IndexHttpPolicy, IndexLocations, IndexUrl};
+use uv_fs::Simplified;
 use uv_publish::{
-    check_trusted_publishing, files_for_publishing, upload, CheckUrlClient, TrustedPublishResult,
+    check_files, check_trusted_publishing, files_for_publishing, find_attestation, upload,
+    CheckUrlClient, DirectoryIndex, TrustedPublishResult,
 };
-use uv_warnings::warn_user_once;
+use uv_warnings::{warn_user, warn_user_once};
 
 pub(crate) async fn publish(
     paths: Vec<String>,
     publish_url: Url,
     trusted_publishing: TrustedPublishing,
     keyring_provider: KeyringProviderType,
-    allow_insecure_host: &[TrustedHost],
+    network_settings: &NetworkSettings,
     username: Option<String>,
     password: Option<String>,
-    check_url: Option<IndexUrl>,
+    check_index: Option<Index>,
+    publish_http: Option<IndexHttpPolicy>,
+    check: bool,
+    attestations: Option<&Path>,
+    concurrency: Concurrency,
     cache: &Cache,
-    connectivity: Connectivity,
-    native_tls: bool,
     printer: Printer,
 ) -> Result<ExitStatus> {
-    if connectivity.is_offline() {
+    // Publishing to a local directory doesn't require network access.
+    let directory = if publish_url.scheme() == "file" {
+        let Ok(directory) = publish_url.to_file_path() else {
+            bail!("Invalid publish URL, expected a path to a local directory: `{publish_url}`");
+        };
+        Some(directory)
+    } else {
+        None
+    };
+
+    if network_settings.connectivity.is_offline() && directory.is_none() {
         bail!("Unable to publish files in offline mode");
     }
 
     let files = files_for_publishing(paths)?;
+    if files.is_empty() {
+        bail!("No files found to publish");
+    }
+
+    if check {
+        let reports = check_files(&files).await;
+        let mut failed = 0;
+        for ((_, raw_filename, _), report) in files.iter().zip(&reports) {
+            if report.is_ok() {
+                writeln!(
+                    printer.stderr(),
+                    "Checking {}: {}",
+                    raw_filename.bold(),
+                    "PASSED".green()
+                )?;
+            } else {
+                failed += 1;
+                writeln!(
+                    printer.stderr(),
+                    "Checking {}: {}",
+                    raw_filename.bold(),
+                    "FAILED".red()
+                )?;
+            }
+            for err in &report.errors {
+                writeln!(printer.stderr(), "  {}: {err}", "error".red().bold())?;
+                let causes = iter::successors(std::error::Error::source(err), |err| err.source());
+                for cause in causes {
+                    writeln!(
+                        printer.stderr(),
+                        "    {}: {}",
+                        "Caused by".red().bold(),
+                        cause.to_string().trim()
+                    )?;
+                }
+            }
+            for warning in &report.warnings {
+                writeln!(
+                    printer.stderr(),
+                    "  {}: {warning}",
+                    "warning".yellow().bold()
+                )?;
+            }
+        }
+        match failed {
+            0 => {}
+            1 => bail!("1 file failed validation, no files were uploaded"),
+            n => bail!("{n} files failed validation, no files were uploaded"),
+        }
+    }
+
     match files.len() {
-        0 => bail!("No files found to publish"),
         1 => writeln!(printer.stderr(), "Publishing 1 file to {publish_url}")?,
         n => writeln!(printer.stderr(), "Publishing {n} files {publish_url}")?,
     }
 
+    if let Some(directory) = directory {
+        let mut index = DirectoryIndex::lock(&directory).await?;
+        let mut result = Ok(());
+        for (file, raw_filename, filename) in files {
+            writeln!(
+                printer.stderr(),
+                "{} {filename} to {}",
+                "Copying".bold().green(),
+                directory.user_display().cyan()
+            )?;
+            match index.publish(&file, &raw_filename, &filename).await {
+                Ok(true) => {}
+                Ok(false) => {
+                    writeln!(
+                        printer.stderr(),
+                        "{}",
+                        "File already exists, skipping".dimmed()
+                    )?;
+                }
+                Err(err) => {
+                    result = Err(err);
+                    break;
+                }
+            }
+        }
+        // Update the index pages once, including for any files copied before a failure.
+        index.write_indexes().await?;
+        result?;
+        return Ok(ExitStatus::Success);
+    }
+
     // * For the uploads themselves, we roll our own retries due to
-    //   https://github.com/seanmonstar/reqwest/issues/2416, but for trusted publishing, we want
-    //   the default retries.
+    //   https://github.com/seanmonstar/reqwest/issues/2416, with the retry settings of the
+    //   publish index, but for trusted publishing, we want the default retries.
     // * We want to allow configuring TLS for the registry, while for trusted publishing we know the
     //   defaults are correct.
     // * For the uploads themselves, we know we need an authorization header and we can't nor
@@ -57,10 +154,15 @@ pub(crate) async fn publish(
     //   keyring integration. For trusted publishing, we use an OIDC auth routine without keyring
     //   or other auth integration.
     let upload_client = BaseClientBuilder::new()
-        .retries(0)
+        .retry_middleware(false)
+        .http_policies(
+            publish_http
+                .into_iter()
+                .map(|http| (publish_url.clone(), http))
+                .collect(),
+        )
         .keyring(keyring_provider)
-        .native_tls(native_tls)
-        .allow_insecure_host(allow_insecure_host.to_vec())
+        .network_settings(network_settings)
         // Don't try cloning the request to make an unauthenticated request first.
         .auth_integration(AuthIntegration::OnlyAuthenticated)
         // Set a very high timeout for uploads, connections are often 10x slower on upload than
@@ -79,29 +181,24 @@ pub(crate) async fn publish(
         password,
         trusted_publishing,
         keyring_provider,
+        network_settings.credential_helper.as_deref(),
         &oidc_client,
-        check_url.as_ref(),
+        check_index.as_ref().map(|index| &index.url),
         Prompt::Enabled,
         printer,
     )
     .await?;
 
     // Initialize the registry client.
-    let check_url_client = if let Some(index_url) = &check_url {
-        let index_urls = IndexLocations::new(
-            vec![Index::from_index_url(index_url.clone())],
-            Vec::new(),
-            false,
-        )
-        .index_urls();
+    let check_url_client = if let Some(index) = &check_index {
+        // Retain the format of the index, such that flat indexes aren't queried as simple indexes.
+        let index_urls = IndexLocations::new(vec![index.clone()], Vec::new(), false).index_urls();
         let registry_client_builder = RegistryClientBuilder::new(cache.clone())
-            .native_tls(native_tls)
-            .connectivity(connectivity)
+            .network_settings(network_settings)
             .index_urls(index_urls)
-            .keyring(keyring_provider)
-            .allow_insecure_host(allow_insecure_host.to_vec());
+            .keyring(keyring_provider);
         Some(CheckUrlClient {
-            index_url: index_url.clone(),
+            index_url: index.url.clone(),
             registry_client_builder,
             client: &upload_client,
             index_capabilities: IndexCapabilities::default(),
@@ -111,52 +208,171 @@ pub(crate) async fn publish(
         None
     };
 
-    for (file, raw_filename, filename) in files {
-        if let Some(check_url_client) = &check_url_client {
-            if uv_publish::check_url(check_url_client, &file, &filename, &download_concurrency)
-                .await?
-            {
-                writeln!(printer.stderr(), "File {filename} already exists, skipping")?;
-                continue;
+    // Upload the files concurrently, but report the results in order.
+    let reporter = Arc::new(PublishReporter::new(printer, files.len() as u64));
+    let results: Vec<(DistFilename, Result<UploadOutcome>)> = futures::stream::iter(files)
+        .map(|(file, raw_filename, filename)| {
+            let publish_url = &publish_url;
+            let upload_client = &upload_client;
+            let username = username.as_deref();
+            let password = password.as_deref();
+            let check_url_client = check_url_client.as_ref();
+            let download_concurrency = &download_concurrency;
+            let reporter = reporter.clone();
+            async move {
+                let outcome = publish_file(
+                    &file,
+                    &raw_filename,
+                    &filename,
+                    attestations,
+                    publish_url,
+                    upload_client,
+                    username,
+                    password,
+                    check_url_client,
+                    download_concurrency,
+                    reporter,
+                    printer,
+                )
+                .await;
+                (filename, outcome)
             }
-        }
+        })
+        .buffered(concurrency.uploads)
+        .collect()
+        .await;
 
-        let size = fs_err::metadata(&file)?.len();
-        let (bytes, unit) = human_readable_bytes(size);
+    // Summarize the results when publishing multiple files, so failures aren't lost in the output.
+    if results.len() > 1 {
+        writeln!(printer.stderr())?;
+        for (filename, outcome) in &results {
+            // Pad before coloring, the escape codes would otherwise count towards the width.
+            let status = match outcome {
+                Ok(UploadOutcome::Uploaded) => {
+                    format!("{:>8}", "Uploaded").green().bold().to_string()
+                }
+                Ok(UploadOutcome::Skipped) => format!("{:>8}", "Skipped").dimmed().to_string(),
+                Err(_) => format!("{:>8}", "Failed").red().bold().to_string(),
+            };
+            writeln!(printer.stderr(), "{status} {filename}")?;
+        }
+        let count = |expected: Option<UploadOutcome>| {
+            results
+                .iter()
+                .filter(|(_, outcome)| outcome.as_ref().ok().copied() == expected)
+                .count()
+        };
         writeln!(
             printer.stderr(),
-            "{} {filename} {}",
-            "Uploading".bold().green(),
-            format!("({bytes:.1}{unit})").dimmed()
+            "{} uploaded, {} skipped, {} failed",
+            count(Some(UploadOutcome::Uploaded)),
+            count(Some(UploadOutcome::Skipped)),
+            count(None),
         )?;
-        let reporter = PublishReporter::single(printer);
-        let uploaded = upload(
-            &file,
-            &raw_filename,
-            &filename,
-            &publish_url,
-            &upload_client,
-            username.as_deref(),
-            password.as_deref(),
-            check_url_client.as_ref(),
-            &download_concurrency,
-            // Needs to be an `Arc` because the reqwest `Body` static lifetime requirement
-            Arc::new(reporter),
-        )
-        .await?; // Filename and/or URL are already attached, if applicable.
-        info!("Upload succeeded");
-        if !uploaded {
+    }
+
+    // Only real failures, not skipped files, lead to a non-zero exit. The last error is returned
+    // and rendered by the caller, the others are rendered the same way here.
+    let mut errors: Vec<anyhow::Error> = results
+        .into_iter()
+        .filter_map(|(_, outcome)| outcome.err())
+        .collect();
+    if let Some(last) = errors.pop() {
+        for err in errors {
+            let mut causes = err.chain();
             writeln!(
                 printer.stderr(),
-                "{}",
-                "File already exists, skipping".dimmed()
+                "{}: {}",
+                "error".red().bold(),
+                causes.next().unwrap().to_string().trim()
             )?;
+            for err in causes {
+                writeln!(
+                    printer.stderr(),
+                    "  {}: {}",
+                    "Caused by".red().bold(),
+                    err.to_string().trim()
+                )?;
+            }
         }
+        return Err(last);
     }
 
     Ok(ExitStatus::Success)
 }
 
+/// The outcome of publishing a single file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UploadOutcome {
+    /// The file was uploaded.
+    Uploaded,
+    /// The file already exists on the index.
+    Skipped,
+}
+
+/// Upload a single file, skipping it if it already exists on the index.
+async fn publish_file(
+    file: &Path,
+    raw_filename: &str,
+    filename: &DistFilename,
+    attestations: Option<&Path>,
+    publish_url: &Url,
+    upload_client: &BaseClient,
+    username: Option<&str>,
+    password: Option<&str>,
+    check_url_client: Option<&CheckUrlClient<'_>>,
+    download_concurrency: &Semaphore,
+    reporter: Arc<PublishReporter>,
+    printer: Printer,
+) -> Result<UploadOutcome> {
+    if let Some(check_url_client) = check_url_client {
+        if uv_publish::check_url(check_url_client, file, filename, download_concurrency).await? {
+            writeln!(printer.stderr(), "File {filename} already exists, skipping")?;
+            return Ok(UploadOutcome::Skipped);
+        }
+    }
+
+    let attestation = find_attestation(file, raw_filename, attestations);
+    if attestation.is_none() && attestations.is_some() {
+        warn_user!("No attestation found for {filename}, uploading without attestation");
+    }
+
+    let size = fs_err::metadata(file)?.len();
+    let (bytes, unit) = human_readable_bytes(size);
+    writeln!(
+        printer.stderr(),
+        "{} {filename} {}",
+        "Uploading".bold().green(),
+        format!("({bytes:.1}{unit})").dimmed()
+    )?;
+    let uploaded = upload(
+        file,
+        raw_filename,
+        filename,
+        attestation.as_deref(),
+        publish_url,
+        upload_client,
+        username,
+        password,
+        check_url_client,
+        download_concurrency,
+        // Needs to be an `Arc` because the reqwest `Body` static lifetime requirement
+        reporter,
+    )
+    .await?; // Filename and/or URL are already attached, if applicable.
+    info!("Upload succeeded");
+    if uploaded {
+        Ok(UploadOutcome::Uploaded)
+    } else {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "File already exists, skipping".dimmed()
+        )?;
+        Ok(UploadOutcome::Skipped)
+    }
+}
+
 /// Whether to allow prompting for username and password.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Prompt {
@@ -202,6 +418,7 @@ async fn gather_credentials(
     mut password: Option<String>,
     trusted_publishing: TrustedPublishing,
     keyring_provider: KeyringProviderType,
+    credential_helper: Option<&Path>,
     oidc_client: &BaseClient,
     check_url: Option<&IndexUrl>,
     prompt: Prompt,
@@ -239,11 +456,15 @@ async fn gather_credentials(
     )
     .await?;
 
-    let (username, mut password) =
+    let (mut username, mut password) =
         if let TrustedPublishResult::Configured(password) = &trusted_publishing_token {
             (Some("__token__".to_string()), Some(password.to_string()))
         } else {
-            if username.is_none() && password.is_none() {
+            // If a credential helper is configured, it provides the credentials instead.
+            if username.is_none()
+                && password.is_none()
+                && keyring_provider != KeyringProviderType::Helper
+            {
                 match prompt {
                     Prompt::Enabled => prompt_username_and_password()?,
                     Prompt::Disabled => (None, None),
@@ -292,7 +513,7 @@ async fn gather_credentials(
 
     // If applicable, fetch the password from the keyring eagerly to avoid user confusion about
     // missing keyring entries later.
-    if let Some(keyring_provider) = keyring_provider.to_provider() {
+    if let Some(keyring_provider) = keyring_provider.to_provider(credential_helper)? {
         if password.is_none() {
             if let Some(username) = &username {
                 debug!("Fetching password from keyring");
@@ -308,6 +529,24 @@ async fn gather_credentials(
                         "Keyring has no password for URL `{publish_url}` and username `{username}`"
                     );
                 }
+            } else if !keyring_provider.requires_username() {
+                debug!("Fetching credentials from credential helper");
+                match keyring_provider.fetch_without_username(&publish_url).await {
+                    Some(credentials) if credentials.password().is_some() => {
+                        username = credentials.username().map(ToString::to_string);
+                        password = credentials.password().map(ToString::to_string);
+                    }
+                    Some(_) => {
+                        warn_user_once!(
+                            "The credential helper returned a bearer token for URL `{publish_url}`, but uploads require a username and password"
+                        );
+                    }
+                    None => {
+                        warn_user_once!(
+                            "The credential helper has no credentials for URL `{publish_url}`"
+                        );
+                    }
+                }
             }
         } else if check_url.is_none() {
             warn_user_once!(
@@ -354,6 +593,7 @@ mod tests {
             password,
             TrustedPublishing::Never,
             KeyringProviderType::Disabled,
+            None,
             &client,
             None,
             Prompt::Disabled,
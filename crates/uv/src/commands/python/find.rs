@@ -4,7 +4,10 @@ use std::path::Path;
 
 use uv_cache::Cache;
 use uv_fs::Simplified;
-use uv_python::{EnvironmentPreference, PythonInstallation, PythonPreference, PythonRequest};
+use uv_python::{
+    EnvironmentPreference, PythonDiscoverySource, PythonInstallation, PythonPreference,
+    PythonRequest, VersionFileDiscoveryOptions,
+};
 use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceError};
 
@@ -18,9 +21,10 @@ pub(crate) async fn find(
     project_dir: &Path,
     request: Option<String>,
     no_project: bool,
-    no_config: bool,
+    version_files: VersionFileDiscoveryOptions<'_>,
     system: bool,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     cache: &Cache,
 ) -> Result<ExitStatus> {
     let environment_preference = if system {
@@ -52,7 +56,7 @@ pub(crate) async fn find(
         request.map(|request| PythonRequest::parse(&request)),
         project.as_ref().map(VirtualProject::workspace),
         project_dir,
-        no_config,
+        version_files,
     )
     .await?;
 
@@ -60,6 +64,7 @@ pub(crate) async fn find(
         &python_request.unwrap_or_default(),
         environment_preference,
         python_preference,
+        python_discovery_sources,
         cache,
     )?;
 
@@ -11,9 +11,8 @@ use owo_colors::OwoColorize;
 use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{debug, trace};
 
-use uv_client::Connectivity;
+use uv_configuration::KeyringProviderType;
 use uv_configuration::PreviewMode;
-use uv_configuration::TrustedHost;
 use uv_fs::Simplified;
 use uv_python::downloads::{self, DownloadResult, ManagedPythonDownload, PythonDownloadRequest};
 use uv_python::managed::{
@@ -32,6 +31,7 @@ use crate::commands::python::{ChangeEvent, ChangeEventKind};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::{elapsed, ExitStatus};
 use crate::printer::Printer;
+use crate::settings::{NetworkSettings, NetworkSettingsExt};
 
 #[derive(Debug, Clone)]
 struct InstallRequest {
@@ -132,11 +132,10 @@ pub(crate) async fn install(
     python_install_mirror: Option<String>,
     pypy_install_mirror: Option<String>,
     default: bool,
+    keyring_provider: KeyringProviderType,
     python_downloads: PythonDownloads,
-    native_tls: bool,
-    connectivity: Connectivity,
-    allow_insecure_host: &[TrustedHost],
-    no_config: bool,
+    network_settings: &NetworkSettings,
+    version_files: VersionFileDiscoveryOptions<'_>,
     preview: PreviewMode,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -156,9 +155,7 @@ pub(crate) async fn install(
     let requests: Vec<_> = if targets.is_empty() {
         PythonVersionFile::discover(
             project_dir,
-            &VersionFileDiscoveryOptions::default()
-                .with_no_config(no_config)
-                .with_preference(VersionFilePreference::Versions),
+            &version_files.with_preference(VersionFilePreference::Versions),
         )
         .await?
         .map(PythonVersionFile::into_versions)
@@ -296,9 +293,8 @@ pub(crate) async fn install(
 
     // Download and unpack the Python versions concurrently
     let client = uv_client::BaseClientBuilder::new()
-        .connectivity(connectivity)
-        .native_tls(native_tls)
-        .allow_insecure_host(allow_insecure_host.to_vec())
+        .network_settings(network_settings)
+        .keyring(keyring_provider)
         .build();
     let reporter = PythonDownloadReporter::new(printer, downloads.len() as u64);
     let mut tasks = FuturesUnordered::new();
@@ -363,6 +359,11 @@ pub(crate) async fn install(
             e.warn_user(installation);
         }
 
+        // Record the contents of new installations, for `uv python verify`
+        if changelog.installed.contains(installation.key()) {
+            installation.write_manifest().await;
+        }
+
         if preview.is_disabled() {
             debug!("Skipping installation of Python executables, use `--preview` to enable.");
             continue;
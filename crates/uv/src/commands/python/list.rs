@@ -1,7 +1,7 @@
 use serde::Serialize;
 use std::collections::BTreeSet;
 use std::fmt::Write;
-use uv_cli::PythonListFormat;
+use uv_cli::OutputFormat;
 use uv_pep440::Version;
 
 use anyhow::Result;
@@ -12,8 +12,9 @@ use uv_cache::Cache;
 use uv_fs::Simplified;
 use uv_python::downloads::PythonDownloadRequest;
 use uv_python::{
-    find_python_installations, DiscoveryError, EnvironmentPreference, PythonDownloads,
-    PythonInstallation, PythonNotFound, PythonPreference, PythonRequest, PythonSource,
+    find_python_installations, DiscoveryError, EnvironmentPreference, PythonDiscoverySource,
+    PythonDownloads, PythonInstallation, PythonNotFound, PythonPreference, PythonRequest,
+    PythonSource,
 };
 
 use crate::commands::ExitStatus;
@@ -25,6 +26,20 @@ enum Kind {
     Download,
     Managed,
     System,
+    /// An installation managed by another tool, e.g., pyenv.
+    External(PythonDiscoverySource),
+}
+
+impl Kind {
+    /// A description of where an installed Python was found, for display.
+    fn source(&self) -> Option<String> {
+        match self {
+            Self::Download => None,
+            Self::Managed => Some("managed".to_string()),
+            Self::System => Some("system".to_string()),
+            Self::External(source) => Some(source.to_string()),
+        }
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -47,6 +62,7 @@ struct PrintData {
     implementation: String,
     arch: String,
     libc: String,
+    source: Option<String>,
 }
 
 /// List available Python installations.
@@ -57,8 +73,9 @@ pub(crate) async fn list(
     all_platforms: bool,
     all_arches: bool,
     show_urls: bool,
-    output_format: PythonListFormat,
+    output_format: OutputFormat,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     python_downloads: PythonDownloads,
     cache: &Cache,
     printer: Printer,
@@ -105,36 +122,43 @@ pub(crate) async fn list(
         }
     };
 
-    let installed =
-        match kinds {
-            PythonListKinds::Installed | PythonListKinds::Default => {
-                Some(find_python_installations(
-                &PythonRequest::Any,
+    let installed = match kinds {
+        PythonListKinds::Installed | PythonListKinds::Default => {
+            let mut installed = find_installed(
                 EnvironmentPreference::OnlySystem,
                 python_preference,
+                python_discovery_sources,
                 cache,
-            )
-            // Raise discovery errors if critical
-            .filter(|result| {
-                result
-                    .as_ref()
-                    .err()
-                    .map_or(true, DiscoveryError::is_critical)
-            })
-            .collect::<Result<Vec<Result<PythonInstallation, PythonNotFound>>, DiscoveryError>>()?
-            .into_iter()
-            // Drop any "missing" installations
-            .filter_map(Result::ok))
+            )?;
+            // Conda environments are not system interpreters, so they're searched separately
+            if python_discovery_sources.contains(&PythonDiscoverySource::Conda) {
+                installed.extend(
+                    find_installed(
+                        EnvironmentPreference::Any,
+                        PythonPreference::OnlySystem,
+                        &[PythonDiscoverySource::Conda],
+                        cache,
+                    )?
+                    .into_iter()
+                    .filter(|installation| {
+                        matches!(
+                            installation.source(),
+                            PythonSource::External(PythonDiscoverySource::Conda)
+                        )
+                    }),
+                );
             }
-            PythonListKinds::Downloads => None,
-        };
+            Some(installed)
+        }
+        PythonListKinds::Downloads => None,
+    };
 
     if let Some(installed) = installed {
         for installation in installed {
-            let kind = if matches!(installation.source(), PythonSource::Managed) {
-                Kind::Managed
-            } else {
-                Kind::System
+            let kind = match installation.source() {
+                PythonSource::Managed => Kind::Managed,
+                PythonSource::External(source) => Kind::External(*source),
+                _ => Kind::System,
             };
             output.insert((
                 installation.key(),
@@ -157,7 +181,7 @@ pub(crate) async fn list(
         }
 
         // Only show the latest patch version for each download unless all were requested
-        if !matches!(kind, Kind::System) {
+        if !matches!(kind, Kind::System | Kind::External(_)) {
             if let [major, minor, ..] = *key.version().release() {
                 if !seen_minor.insert((
                     *key.os(),
@@ -190,14 +214,14 @@ pub(crate) async fn list(
                 }
             }
         }
-        include.push((key, uri));
+        include.push((key, kind, uri));
     }
 
     match output_format {
-        PythonListFormat::Json => {
+        OutputFormat::Json => {
             let data = include
                 .iter()
-                .map(|(key, uri)| -> Result<_> {
+                .map(|(key, kind, uri)| -> Result<_> {
                     let mut path_or_none: Option<String> = None;
                     let mut symlink_or_none: Option<String> = None;
                     let mut url_or_none: Option<String> = None;
@@ -235,34 +259,43 @@ pub(crate) async fn list(
                         os: key.os().to_string(),
                         variant: key.variant().to_string(),
                         libc: key.libc().to_string(),
+                        source: kind.source(),
                     })
                 })
                 .collect::<Result<Vec<_>>>()?;
             writeln!(printer.stdout(), "{}", serde_json::to_string(&data)?)?;
         }
-        PythonListFormat::Text => {
+        OutputFormat::Text => {
             // Compute the width of the first column.
             let width = include
                 .iter()
-                .fold(0usize, |acc, (key, _)| acc.max(key.to_string().len()));
+                .fold(0usize, |acc, (key, ..)| acc.max(key.to_string().len()));
 
-            for (key, uri) in include {
+            for (key, kind, uri) in include {
                 let key = key.to_string();
                 match uri {
                     Either::Left(path) => {
+                        // Note installations that were found via another tool, e.g., pyenv.
+                        let source = if let Kind::External(source) = kind {
+                            format!(" ({source})")
+                        } else {
+                            String::new()
+                        };
                         let is_symlink = fs_err::symlink_metadata(path)?.is_symlink();
                         if is_symlink {
                             writeln!(
                                 printer.stdout(),
-                                "{key:width$}    {} -> {}",
+                                "{key:width$}    {} -> {}{}",
                                 path.user_display().cyan(),
-                                path.read_link()?.user_display().cyan()
+                                path.read_link()?.user_display().cyan(),
+                                source.dimmed()
                             )?;
                         } else {
                             writeln!(
                                 printer.stdout(),
-                                "{key:width$}    {}",
-                                path.user_display().cyan()
+                                "{key:width$}    {}{}",
+                                path.user_display().cyan(),
+                                source.dimmed()
                             )?;
                         }
                     }
@@ -284,3 +317,31 @@ pub(crate) async fn list(
 
     Ok(ExitStatus::Success)
 }
+
+/// Find all installed Python interpreters, raising critical discovery errors.
+fn find_installed(
+    environments: EnvironmentPreference,
+    python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
+    cache: &Cache,
+) -> Result<Vec<PythonInstallation>, DiscoveryError> {
+    Ok(find_python_installations(
+        &PythonRequest::Any,
+        environments,
+        python_preference,
+        python_discovery_sources,
+        cache,
+    )
+    // Raise discovery errors if critical
+    .filter(|result| {
+        result
+            .as_ref()
+            .err()
+            .map_or(true, DiscoveryError::is_critical)
+    })
+    .collect::<Result<Vec<Result<PythonInstallation, PythonNotFound>>, DiscoveryError>>()?
+    .into_iter()
+    // Drop any "missing" installations
+    .filter_map(Result::ok)
+    .collect())
+}
@@ -2,15 +2,15 @@ use std::fmt::Write;
 use std::path::Path;
 use std::str::FromStr;
 
-use anyhow::{bail, Result};
+use anyhow::{bail, Context, Result};
 use owo_colors::OwoColorize;
 use tracing::debug;
 
 use uv_cache::Cache;
 use uv_fs::Simplified;
 use uv_python::{
-    EnvironmentPreference, PythonInstallation, PythonPreference, PythonRequest, PythonVersionFile,
-    VersionFileDiscoveryOptions, PYTHON_VERSION_FILENAME,
+    EnvironmentPreference, PythonDiscoverySource, PythonInstallation, PythonPreference,
+    PythonRequest, PythonVersionFile, VersionFileDiscoveryOptions, PYTHON_VERSION_FILENAME,
 };
 use uv_warnings::warn_user_once;
 use uv_workspace::{DiscoveryOptions, VirtualProject};
@@ -24,6 +24,8 @@ pub(crate) async fn pin(
     request: Option<String>,
     resolved: bool,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
+    version_files: VersionFileDiscoveryOptions<'_>,
     no_project: bool,
     cache: &Cache,
     printer: Printer,
@@ -40,8 +42,9 @@ pub(crate) async fn pin(
         }
     };
 
+    // Discover the existing pin even with `--no-config`, since it may be updated in place.
     let version_file =
-        PythonVersionFile::discover(project_dir, &VersionFileDiscoveryOptions::default()).await;
+        PythonVersionFile::discover(project_dir, &version_files.with_no_config(false)).await;
 
     let Some(request) = request else {
         // Display the current pinned Python version
@@ -53,6 +56,7 @@ pub(crate) async fn pin(
                         pin,
                         virtual_project,
                         python_preference,
+                        python_discovery_sources,
                         cache,
                     );
                 }
@@ -67,6 +71,7 @@ pub(crate) async fn pin(
         &request,
         EnvironmentPreference::OnlySystem,
         python_preference,
+        python_discovery_sources,
         cache,
     ) {
         Ok(python) => Some(python),
@@ -115,6 +120,7 @@ pub(crate) async fn pin(
         // TODO(zanieb): Maybe avoid reparsing here?
         PythonRequest::parse(
             &python
+                .as_ref()
                 .unwrap()
                 .interpreter()
                 .sys_executable()
@@ -126,11 +132,30 @@ pub(crate) async fn pin(
     };
 
     let existing = version_file.ok().flatten();
-    // TODO(zanieb): Allow updating the discovered version file with an `--update` flag.
-    let new = PythonVersionFile::new(project_dir.join(PYTHON_VERSION_FILENAME))
-        .with_versions(vec![request]);
-
-    new.write().await?;
+    let new = if let Some(external) = existing.as_ref().filter(|existing| {
+        existing.kind().is_external() && existing.path().parent() == Some(project_dir)
+    }) {
+        // Update version files owned by other tools (e.g., `.tool-versions`) in place, rather than
+        // shadowing them with a new `.python-version` file. These files can only declare concrete
+        // versions, so we write the version of the resolved interpreter.
+        let Some(python) = &python else {
+            bail!(
+                "Failed to update `{}`: no Python installation found for `{}`",
+                external.path().user_display(),
+                request.to_canonical_string()
+            );
+        };
+        external
+            .clone()
+            .write_interpreter(python.interpreter())
+            .await
+            .with_context(|| format!("Failed to update `{}`", external.path().user_display()))?
+    } else {
+        let new = PythonVersionFile::new(project_dir.join(PYTHON_VERSION_FILENAME))
+            .with_versions(vec![request]);
+        new.write().await?;
+        new
+    };
 
     // If we updated an existing version file to a new version
     if let Some(existing) = existing
@@ -185,6 +210,7 @@ fn warn_if_existing_pin_incompatible_with_project(
     pin: &PythonRequest,
     virtual_project: &VirtualProject,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     cache: &Cache,
 ) {
     // Check if the pinned version is compatible with the project.
@@ -209,6 +235,7 @@ fn warn_if_existing_pin_incompatible_with_project(
         pin,
         EnvironmentPreference::OnlySystem,
         python_preference,
+        python_discovery_sources,
         cache,
     ) {
         Ok(python) => {
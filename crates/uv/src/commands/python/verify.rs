@@ -0,0 +1,211 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::{Cache, Refresh};
+use uv_cache_info::Timestamp;
+
+use uv_configuration::{KeyringProviderType, PreviewMode};
+use uv_python::downloads::PythonDownloadRequest;
+use uv_python::managed::{ManagedPythonInstallation, ManagedPythonInstallations};
+use uv_python::{Interpreter, PythonDownloads, PythonRequest, VersionFileDiscoveryOptions};
+use uv_warnings::warn_user;
+
+use crate::commands::{elapsed, ExitStatus};
+use crate::printer::Printer;
+use crate::settings::NetworkSettings;
+
+/// Verify the integrity of managed Python installations.
+pub(crate) async fn verify(
+    project_dir: &Path,
+    install_dir: Option<PathBuf>,
+    targets: Vec<String>,
+    repair: bool,
+    python_install_mirror: Option<String>,
+    pypy_install_mirror: Option<String>,
+    keyring_provider: KeyringProviderType,
+    python_downloads: PythonDownloads,
+    network_settings: &NetworkSettings,
+    version_files: VersionFileDiscoveryOptions<'_>,
+    preview: PreviewMode,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let start = std::time::Instant::now();
+
+    let download_requests = targets
+        .iter()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|target| {
+            let request = PythonRequest::parse(target);
+            PythonDownloadRequest::from_request(&request)
+                .ok_or_else(|| {
+                    anyhow::anyhow!("Cannot verify managed Python for request: {request}")
+                })
+                .map(|request| request.with_prereleases(true))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Always query the interpreters, rather than trusting cached results.
+    let cache = cache.clone().with_refresh(Refresh::All(Timestamp::now()));
+
+    let installations = ManagedPythonInstallations::from_settings(install_dir.clone())?.init()?;
+
+    let (verified, broken) = {
+        // Lock the directory for the duration of the verification, but not the repair, which
+        // acquires its own lock.
+        let _lock = installations.lock().await?;
+
+        let matching_installations: Vec<_> = installations
+            .find_all()?
+            .filter(|installation| {
+                download_requests.is_empty()
+                    || download_requests
+                        .iter()
+                        .any(|request| request.satisfied_by_key(installation.key()))
+            })
+            .collect();
+
+        if matching_installations.is_empty() {
+            if download_requests.is_empty() {
+                writeln!(printer.stderr(), "No Python installations found")?;
+            } else {
+                writeln!(
+                    printer.stderr(),
+                    "No Python installations found matching the requests"
+                )?;
+            }
+            return Ok(ExitStatus::Failure);
+        }
+
+        let mut verified = 0usize;
+        let mut broken = Vec::new();
+        for installation in matching_installations {
+            let problems = verify_installation(&installation, &cache);
+            if problems.is_empty() {
+                debug!("Verified `{}`", installation.key());
+                verified += 1;
+            } else {
+                broken.push((installation, problems));
+            }
+        }
+        (verified, broken)
+    };
+
+    if verified > 0 {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Verified {} {}",
+                format!(
+                    "{verified} {}",
+                    if verified == 1 {
+                        "installation"
+                    } else {
+                        "installations"
+                    }
+                )
+                .bold(),
+                format!("in {}", elapsed(start.elapsed())).dimmed()
+            )
+            .dimmed()
+        )?;
+    }
+
+    if broken.is_empty() {
+        return Ok(ExitStatus::Success);
+    }
+
+    // Broken installations are only an error if they won't be repaired.
+    let level = if repair {
+        "warning".yellow().bold().to_string()
+    } else {
+        "error".red().bold().to_string()
+    };
+    for (installation, problems) in &broken {
+        writeln!(
+            printer.stderr(),
+            "{level}: Python installation {} failed verification",
+            installation.key().green()
+        )?;
+        for problem in problems {
+            writeln!(printer.stderr(), "  {} {problem}", "-".red())?;
+        }
+    }
+
+    if !repair {
+        writeln!(
+            printer.stderr(),
+            "\n{}{} Use `{}` to reinstall the broken installations",
+            "hint".bold().cyan(),
+            ":".bold(),
+            "uv python verify --repair".green(),
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    // Reinstall the broken installations.
+    let targets = broken
+        .iter()
+        .map(|(installation, _)| installation.key().to_string())
+        .collect();
+
+    crate::commands::python_install(
+        project_dir,
+        install_dir,
+        targets,
+        true,
+        false,
+        python_install_mirror,
+        pypy_install_mirror,
+        false,
+        keyring_provider,
+        python_downloads,
+        network_settings,
+        version_files,
+        preview,
+        printer,
+    )
+    .await
+}
+
+/// Check a managed Python installation, returning a description of each problem found.
+fn verify_installation(installation: &ManagedPythonInstallation, cache: &Cache) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    // Compare the installed files against the manifest written at install time.
+    match installation.verify_manifest() {
+        Ok(Some(mismatches)) => {
+            problems.extend(mismatches.iter().map(ToString::to_string));
+        }
+        Ok(None) => {
+            warn_user!(
+                "No manifest found for `{}`; skipping file verification. Reinstall with `uv python install --reinstall` to create one.",
+                installation.key().green()
+            );
+        }
+        Err(err) => problems.push(err.to_string()),
+    }
+
+    // Ensure the `sysconfig` data was patched for the installation path.
+    match installation.is_sysconfig_patched() {
+        Ok(true) => {}
+        Ok(false) => {
+            problems.push("`sysconfig` data does not match the installation path".to_string());
+        }
+        Err(err) => problems.push(format!("Failed to read `sysconfig` data: {err}")),
+    }
+
+    // Ensure the interpreter can be executed.
+    if let Err(err) = Interpreter::query(installation.executable(false), cache) {
+        problems.push(format!("Failed to query interpreter: {err}"));
+    }
+
+    problems
+}
@@ -16,8 +16,8 @@ use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
 use uv_pep508::PackageName;
 use uv_pypi_types::Requirement;
 use uv_python::{
-    EnvironmentPreference, Interpreter, PythonDownloads, PythonEnvironment, PythonInstallation,
-    PythonPreference, PythonRequest, PythonVariant, VersionRequest,
+    EnvironmentPreference, Interpreter, PythonDiscoverySource, PythonDownloads, PythonEnvironment,
+    PythonInstallation, PythonPreference, PythonRequest, PythonVariant, VersionRequest,
 };
 use uv_settings::{PythonInstallMirrors, ToolOptions};
 use uv_shell::Shell;
@@ -78,6 +78,7 @@ pub(crate) async fn refine_interpreter(
     reporter: &PythonDownloadReporter,
     install_mirrors: &PythonInstallMirrors,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     python_downloads: PythonDownloads,
     cache: &Cache,
 ) -> anyhow::Result<Option<Interpreter>, ProjectError> {
@@ -144,6 +145,7 @@ pub(crate) async fn refine_interpreter(
         Some(&python_request),
         EnvironmentPreference::OnlySystem,
         python_preference,
+        python_discovery_sources,
         python_downloads,
         client_builder,
         cache,
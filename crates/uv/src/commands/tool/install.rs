@@ -7,15 +7,16 @@ use tracing::{debug, trace};
 
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
-use uv_client::{BaseClientBuilder, Connectivity};
-use uv_configuration::{Concurrency, DryRun, PreviewMode, Reinstall, TrustedHost, Upgrade};
+use uv_client::BaseClientBuilder;
+use uv_configuration::{Concurrency, DryRun, PreviewMode, Reinstall, Upgrade};
 use uv_distribution_types::{NameRequirementSpecification, UnresolvedRequirementSpecification};
 use uv_normalize::PackageName;
 use uv_pep440::{VersionSpecifier, VersionSpecifiers};
 use uv_pep508::MarkerTree;
 use uv_pypi_types::{Requirement, RequirementSource};
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+    EnvironmentPreference, PythonDiscoverySource, PythonDownloads, PythonInstallation,
+    PythonPreference, PythonRequest,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_settings::{PythonInstallMirrors, ResolverInstallerOptions, ToolOptions};
@@ -34,7 +35,7 @@ use crate::commands::tool::{Target, ToolRequest};
 use crate::commands::ExitStatus;
 use crate::commands::{diagnostics, reporters::PythonDownloadReporter};
 use crate::printer::Printer;
-use crate::settings::ResolverInstallerSettings;
+use crate::settings::{NetworkSettings, NetworkSettingsExt, ResolverInstallerSettings};
 
 /// Install a tool.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -51,20 +52,16 @@ pub(crate) async fn install(
     options: ResolverInstallerOptions,
     settings: ResolverInstallerSettings,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     python_downloads: PythonDownloads,
     installer_metadata: bool,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     concurrency: Concurrency,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
     cache: Cache,
     printer: Printer,
     preview: PreviewMode,
 ) -> Result<ExitStatus> {
-    let client_builder = BaseClientBuilder::new()
-        .connectivity(connectivity)
-        .native_tls(native_tls)
-        .allow_insecure_host(allow_insecure_host.to_vec());
+    let client_builder = BaseClientBuilder::new().network_settings(network_settings);
 
     let reporter = PythonDownloadReporter::single(printer);
 
@@ -76,6 +73,7 @@ pub(crate) async fn install(
         python_request.as_ref(),
         EnvironmentPreference::OnlySystem,
         python_preference,
+        python_discovery_sources,
         python_downloads,
         &client_builder,
         &cache,
@@ -89,10 +87,7 @@ pub(crate) async fn install(
     // Initialize any shared state.
     let state = PlatformState::default();
 
-    let client_builder = BaseClientBuilder::new()
-        .connectivity(connectivity)
-        .native_tls(native_tls)
-        .allow_insecure_host(allow_insecure_host.to_vec());
+    let client_builder = BaseClientBuilder::new().network_settings(network_settings);
 
     // Parse the input requirement.
     let request = ToolRequest::parse(&package, from.as_deref());
@@ -132,10 +127,8 @@ pub(crate) async fn install(
                 &interpreter,
                 &settings,
                 &state,
-                connectivity,
+                network_settings,
                 concurrency,
-                native_tls,
-                allow_insecure_host,
                 &cache,
                 printer,
                 preview,
@@ -246,10 +239,8 @@ pub(crate) async fn install(
                 &interpreter,
                 &settings,
                 &state,
-                connectivity,
+                network_settings,
                 concurrency,
-                native_tls,
-                allow_insecure_host,
                 &cache,
                 printer,
                 preview,
@@ -272,10 +263,8 @@ pub(crate) async fn install(
         &interpreter,
         &settings,
         &state,
-        connectivity,
+        network_settings,
         concurrency,
-        native_tls,
-        allow_insecure_host,
         &cache,
         printer,
         preview,
@@ -405,10 +394,8 @@ pub(crate) async fn install(
             Box::new(DefaultResolveLogger),
             Box::new(DefaultInstallLogger),
             installer_metadata,
-            connectivity,
+            network_settings,
             concurrency,
-            native_tls,
-            allow_insecure_host,
             &cache,
             DryRun::Disabled,
             printer,
@@ -418,7 +405,7 @@ pub(crate) async fn install(
         {
             Ok(update) => update.into_environment(),
             Err(ProjectError::Operation(err)) => {
-                return diagnostics::OperationDiagnostic::native_tls(native_tls)
+                return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
                     .report(err)
                     .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
             }
@@ -443,10 +430,8 @@ pub(crate) async fn install(
             settings.as_ref().into(),
             &state,
             Box::new(DefaultResolveLogger),
-            connectivity,
+            network_settings,
             concurrency,
-            native_tls,
-            allow_insecure_host,
             &cache,
             printer,
             preview,
@@ -472,15 +457,18 @@ pub(crate) async fn install(
                         &reporter,
                         &install_mirrors,
                         python_preference,
+                        python_discovery_sources,
                         python_downloads,
                         &cache,
                     )
                     .await
                     .ok()
                     .flatten() else {
-                        return diagnostics::OperationDiagnostic::native_tls(native_tls)
-                            .report(err)
-                            .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                        return diagnostics::OperationDiagnostic::native_tls(
+                            network_settings.native_tls,
+                        )
+                        .report(err)
+                        .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
                     };
 
                     debug!(
@@ -495,10 +483,8 @@ pub(crate) async fn install(
                         settings.as_ref().into(),
                         &state,
                         Box::new(DefaultResolveLogger),
-                        connectivity,
+                        network_settings,
                         concurrency,
-                        native_tls,
-                        allow_insecure_host,
                         &cache,
                         printer,
                         preview,
@@ -507,9 +493,11 @@ pub(crate) async fn install(
                     {
                         Ok(resolution) => resolution,
                         Err(ProjectError::Operation(err)) => {
-                            return diagnostics::OperationDiagnostic::native_tls(native_tls)
-                                .report(err)
-                                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                            return diagnostics::OperationDiagnostic::native_tls(
+                                network_settings.native_tls,
+                            )
+                            .report(err)
+                            .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
                         }
                         Err(err) => return Err(err.into()),
                     }
@@ -535,10 +523,8 @@ pub(crate) async fn install(
             &state,
             Box::new(DefaultInstallLogger),
             installer_metadata,
-            connectivity,
+            network_settings,
             concurrency,
-            native_tls,
-            allow_insecure_host,
             &cache,
             printer,
             preview,
@@ -551,7 +537,7 @@ pub(crate) async fn install(
         }) {
             Ok(environment) => environment,
             Err(ProjectError::Operation(err)) => {
-                return diagnostics::OperationDiagnostic::native_tls(native_tls)
+                return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
                     .report(err)
                     .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
             }
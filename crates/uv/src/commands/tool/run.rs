@@ -13,8 +13,8 @@ use tracing::{debug, warn};
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
 use uv_cli::ExternalCommand;
-use uv_client::{BaseClientBuilder, Connectivity};
-use uv_configuration::{Concurrency, PreviewMode, TrustedHost};
+use uv_client::BaseClientBuilder;
+use uv_configuration::{Concurrency, PreviewMode};
 use uv_distribution_types::{Name, UnresolvedRequirement, UnresolvedRequirementSpecification};
 use uv_installer::{SatisfiesResult, SitePackages};
 use uv_normalize::PackageName;
@@ -23,8 +23,8 @@ use uv_pep508::MarkerTree;
 use uv_pypi_types::{Requirement, RequirementSource};
 use uv_python::VersionRequest;
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
-    PythonPreference, PythonRequest,
+    EnvironmentPreference, PythonDiscoverySource, PythonDownloads, PythonEnvironment,
+    PythonInstallation, PythonPreference, PythonRequest,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_settings::PythonInstallMirrors;
@@ -45,7 +45,7 @@ use crate::commands::tool::{Target, ToolRequest};
 use crate::commands::ExitStatus;
 use crate::commands::{diagnostics, project::environment::CachedEnvironment};
 use crate::printer::Printer;
-use crate::settings::ResolverInstallerSettings;
+use crate::settings::{NetworkSettings, NetworkSettingsExt, ResolverInstallerSettings};
 
 /// The user-facing command used to invoke a tool run.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -78,12 +78,11 @@ pub(crate) async fn run(
     invocation_source: ToolRunCommand,
     isolated: bool,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     python_downloads: PythonDownloads,
     installer_metadata: bool,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     concurrency: Concurrency,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
     cache: Cache,
     printer: Printer,
     preview: PreviewMode,
@@ -123,12 +122,11 @@ pub(crate) async fn run(
         &settings,
         isolated,
         python_preference,
+        python_discovery_sources,
         python_downloads,
         installer_metadata,
-        connectivity,
+        network_settings,
         concurrency,
-        native_tls,
-        allow_insecure_host,
         &cache,
         printer,
         preview,
@@ -138,7 +136,7 @@ pub(crate) async fn run(
     let (from, environment) = match result {
         Ok(resolution) => resolution,
         Err(ProjectError::Operation(err)) => {
-            return diagnostics::OperationDiagnostic::native_tls(native_tls)
+            return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
                 .with_context("tool")
                 .report(err)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
@@ -455,20 +453,16 @@ async fn get_or_create_environment(
     settings: &ResolverInstallerSettings,
     isolated: bool,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     python_downloads: PythonDownloads,
     installer_metadata: bool,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     concurrency: Concurrency,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
     preview: PreviewMode,
 ) -> Result<(ToolRequirement, PythonEnvironment), ProjectError> {
-    let client_builder = BaseClientBuilder::new()
-        .connectivity(connectivity)
-        .native_tls(native_tls)
-        .allow_insecure_host(allow_insecure_host.to_vec());
+    let client_builder = BaseClientBuilder::new().network_settings(network_settings);
 
     let reporter = PythonDownloadReporter::single(printer);
 
@@ -509,6 +503,7 @@ async fn get_or_create_environment(
         python_request.as_ref(),
         EnvironmentPreference::OnlySystem,
         python_preference,
+        python_discovery_sources,
         python_downloads,
         &client_builder,
         cache,
@@ -544,10 +539,8 @@ async fn get_or_create_environment(
                     &interpreter,
                     settings,
                     &state,
-                    connectivity,
+                    network_settings,
                     concurrency,
-                    native_tls,
-                    allow_insecure_host,
                     cache,
                     printer,
                     preview,
@@ -620,10 +613,7 @@ async fn get_or_create_environment(
 
     // Read the `--with` requirements.
     let spec = {
-        let client_builder = BaseClientBuilder::new()
-            .connectivity(connectivity)
-            .native_tls(native_tls)
-            .allow_insecure_host(allow_insecure_host.to_vec());
+        let client_builder = BaseClientBuilder::new().network_settings(network_settings);
         RequirementsSpecification::from_simple_sources(with, &client_builder).await?
     };
 
@@ -640,10 +630,8 @@ async fn get_or_create_environment(
                 &interpreter,
                 settings,
                 &state,
-                connectivity,
+                network_settings,
                 concurrency,
-                native_tls,
-                allow_insecure_host,
                 cache,
                 printer,
                 preview,
@@ -720,10 +708,8 @@ async fn get_or_create_environment(
             Box::new(SummaryInstallLogger)
         },
         installer_metadata,
-        connectivity,
+        network_settings,
         concurrency,
-        native_tls,
-        allow_insecure_host,
         cache,
         printer,
         preview,
@@ -748,6 +734,7 @@ async fn get_or_create_environment(
                     &reporter,
                     &install_mirrors,
                     python_preference,
+                    python_discovery_sources,
                     python_downloads,
                     cache,
                 )
@@ -779,10 +766,8 @@ async fn get_or_create_environment(
                         Box::new(SummaryInstallLogger)
                     },
                     installer_metadata,
-                    connectivity,
+                    network_settings,
                     concurrency,
-                    native_tls,
-                    allow_insecure_host,
                     cache,
                     printer,
                     preview,
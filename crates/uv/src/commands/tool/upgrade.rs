@@ -6,14 +6,14 @@ use std::fmt::Write;
 use tracing::debug;
 
 use uv_cache::Cache;
-use uv_client::{BaseClientBuilder, Connectivity};
-use uv_configuration::{Concurrency, DryRun, PreviewMode, TrustedHost};
+use uv_client::BaseClientBuilder;
+use uv_configuration::{Concurrency, DryRun, PreviewMode};
 use uv_fs::CWD;
 use uv_normalize::PackageName;
 use uv_pypi_types::Requirement;
 use uv_python::{
-    EnvironmentPreference, Interpreter, PythonDownloads, PythonInstallation, PythonPreference,
-    PythonRequest,
+    EnvironmentPreference, Interpreter, PythonDiscoverySource, PythonDownloads, PythonInstallation,
+    PythonPreference, PythonRequest,
 };
 use uv_requirements::RequirementsSpecification;
 use uv_settings::{Combine, PythonInstallMirrors, ResolverInstallerOptions, ToolOptions};
@@ -30,22 +30,21 @@ use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::tool::common::remove_entrypoints;
 use crate::commands::{conjunction, tool::common::install_executables, ExitStatus};
 use crate::printer::Printer;
-use crate::settings::ResolverInstallerSettings;
+use crate::settings::{NetworkSettings, NetworkSettingsExt, ResolverInstallerSettings};
 
 /// Upgrade a tool.
 pub(crate) async fn upgrade(
     names: Vec<String>,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     args: ResolverInstallerOptions,
     filesystem: ResolverInstallerOptions,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     python_downloads: PythonDownloads,
     installer_metadata: bool,
     concurrency: Concurrency,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
     preview: PreviewMode,
@@ -80,10 +79,7 @@ pub(crate) async fn upgrade(
     }
 
     let reporter = PythonDownloadReporter::single(printer);
-    let client_builder = BaseClientBuilder::new()
-        .connectivity(connectivity)
-        .native_tls(native_tls)
-        .allow_insecure_host(allow_insecure_host.to_vec());
+    let client_builder = BaseClientBuilder::new().network_settings(network_settings);
 
     let python_request = python.as_deref().map(PythonRequest::parse);
 
@@ -93,6 +89,7 @@ pub(crate) async fn upgrade(
                 python_request.as_ref(),
                 EnvironmentPreference::OnlySystem,
                 python_preference,
+                python_discovery_sources,
                 python_downloads,
                 &client_builder,
                 cache,
@@ -126,10 +123,8 @@ pub(crate) async fn upgrade(
             cache,
             &filesystem,
             installer_metadata,
-            connectivity,
+            network_settings,
             concurrency,
-            native_tls,
-            allow_insecure_host,
             preview,
         )
         .await;
@@ -219,10 +214,8 @@ async fn upgrade_tool(
     cache: &Cache,
     filesystem: &ResolverInstallerOptions,
     installer_metadata: bool,
-    connectivity: Connectivity,
+    network_settings: &NetworkSettings,
     concurrency: Concurrency,
-    native_tls: bool,
-    allow_insecure_host: &[TrustedHost],
     preview: PreviewMode,
 ) -> Result<UpgradeOutcome> {
     // Ensure the tool is installed.
@@ -300,10 +293,8 @@ async fn upgrade_tool(
             settings.as_ref().into(),
             &state,
             Box::new(SummaryResolveLogger),
-            connectivity,
+            network_settings,
             concurrency,
-            native_tls,
-            allow_insecure_host,
             cache,
             printer,
             preview,
@@ -320,10 +311,8 @@ async fn upgrade_tool(
             &state,
             Box::new(DefaultInstallLogger),
             installer_metadata,
-            connectivity,
+            network_settings,
             concurrency,
-            native_tls,
-            allow_insecure_host,
             cache,
             printer,
             preview,
@@ -347,10 +336,8 @@ async fn upgrade_tool(
             Box::new(SummaryResolveLogger),
             Box::new(UpgradeInstallLogger::new(name.clone())),
             installer_metadata,
-            connectivity,
+            network_settings,
             concurrency,
-            native_tls,
-            allow_insecure_host,
             cache,
             DryRun::Disabled,
             printer,
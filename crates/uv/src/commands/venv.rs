@@ -11,18 +11,19 @@ use owo_colors::OwoColorize;
 use thiserror::Error;
 
 use uv_cache::Cache;
-use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
+use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildOptions, Concurrency, ConfigSettings, Constraints, IndexStrategy, KeyringProviderType,
-    NoBinary, NoBuild, PreviewMode, SourceStrategy, TrustedHost,
+    NoBinary, NoBuild, PreviewMode, SourceStrategy,
 };
 use uv_dispatch::{BuildDispatch, SharedState};
-use uv_distribution_types::{DependencyMetadata, Index, IndexLocations};
+use uv_distribution_types::{DependencyMetadata, Index, IndexLocations, InstalledDist, Name};
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_pypi_types::Requirement;
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+    EnvironmentPreference, PythonDiscoverySource, PythonDownloads, PythonInstallation,
+    PythonPreference, PythonRequest, VersionFileDiscoveryOptions,
 };
 use uv_resolver::{ExcludeNewer, FlatIndex};
 use uv_settings::PythonInstallMirrors;
@@ -32,11 +33,14 @@ use uv_warnings::warn_user;
 use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceError};
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, InstallLogger};
-use crate::commands::pip::operations::{report_interpreter, Changelog};
-use crate::commands::project::{validate_project_requires_python, WorkspacePython};
+use crate::commands::pip::operations::{self, report_interpreter, Changelog};
+use crate::commands::project::{
+    upgradable_environment, validate_project_requires_python, WorkspacePython,
+};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
+use crate::settings::{NetworkSettings, NetworkSettingsExt};
 
 /// Create a virtual environment.
 #[allow(clippy::unnecessary_wraps, clippy::fn_params_excessive_bools)]
@@ -46,22 +50,22 @@ pub(crate) async fn venv(
     python_request: Option<&str>,
     install_mirrors: PythonInstallMirrors,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     python_downloads: PythonDownloads,
     link_mode: LinkMode,
     index_locations: &IndexLocations,
     index_strategy: IndexStrategy,
     dependency_metadata: DependencyMetadata,
     keyring_provider: KeyringProviderType,
-    allow_insecure_host: &[TrustedHost],
+    network_settings: &NetworkSettings,
     prompt: uv_virtualenv::Prompt,
     system_site_packages: bool,
-    connectivity: Connectivity,
     seed: bool,
     allow_existing: bool,
+    upgrade_python: bool,
     exclude_newer: Option<ExcludeNewer>,
     concurrency: Concurrency,
-    native_tls: bool,
-    no_config: bool,
+    version_files: VersionFileDiscoveryOptions<'_>,
     no_project: bool,
     cache: &Cache,
     printer: Printer,
@@ -78,18 +82,18 @@ pub(crate) async fn venv(
         index_strategy,
         dependency_metadata,
         keyring_provider,
-        allow_insecure_host,
+        network_settings,
         prompt,
         system_site_packages,
-        connectivity,
         seed,
         python_preference,
+        python_discovery_sources,
         python_downloads,
         allow_existing,
+        upgrade_python,
         exclude_newer,
         concurrency,
-        native_tls,
-        no_config,
+        version_files,
         no_project,
         cache,
         printer,
@@ -112,6 +116,14 @@ enum VenvError {
     #[diagnostic(code(uv::venv::creation))]
     Creation(#[source] uv_virtualenv::Error),
 
+    #[error("Failed to upgrade virtualenv")]
+    #[diagnostic(code(uv::venv::upgrade))]
+    Upgrade(#[source] uv_virtualenv::Error),
+
+    #[error("Failed to remove packages that are incompatible with the upgraded interpreter")]
+    #[diagnostic(code(uv::venv::uninstall))]
+    Uninstall(#[source] operations::Error),
+
     #[error("Failed to install seed packages")]
     #[diagnostic(code(uv::venv::seed))]
     Seed(#[source] AnyErrorBuild),
@@ -137,18 +149,18 @@ async fn venv_impl(
     index_strategy: IndexStrategy,
     dependency_metadata: DependencyMetadata,
     keyring_provider: KeyringProviderType,
-    allow_insecure_host: &[TrustedHost],
+    network_settings: &NetworkSettings,
     prompt: uv_virtualenv::Prompt,
     system_site_packages: bool,
-    connectivity: Connectivity,
     seed: bool,
     python_preference: PythonPreference,
+    python_discovery_sources: &[PythonDiscoverySource],
     python_downloads: PythonDownloads,
     allow_existing: bool,
+    upgrade_python: bool,
     exclude_newer: Option<ExcludeNewer>,
     concurrency: Concurrency,
-    native_tls: bool,
-    no_config: bool,
+    version_files: VersionFileDiscoveryOptions<'_>,
     no_project: bool,
     cache: &Cache,
     printer: Printer,
@@ -192,10 +204,7 @@ async fn venv_impl(
             .unwrap_or(PathBuf::from(".venv")),
     );
 
-    let client_builder = BaseClientBuilder::default()
-        .connectivity(connectivity)
-        .native_tls(native_tls)
-        .allow_insecure_host(allow_insecure_host.to_vec());
+    let client_builder = BaseClientBuilder::default().network_settings(network_settings);
 
     let reporter = PythonDownloadReporter::single(printer);
 
@@ -207,7 +216,7 @@ async fn venv_impl(
         python_request.map(PythonRequest::parse),
         project.as_ref().map(VirtualProject::workspace),
         project_dir,
-        no_config,
+        version_files,
     )
     .await
     .into_diagnostic()?;
@@ -218,6 +227,7 @@ async fn venv_impl(
             python_request.as_ref(),
             EnvironmentPreference::OnlySystem,
             python_preference,
+            python_discovery_sources,
             python_downloads,
             &client_builder,
             cache,
@@ -257,28 +267,89 @@ async fn venv_impl(
         }
     };
 
-    writeln!(
-        printer.stderr(),
-        "Creating virtual environment {}at: {}",
-        if seed { "with seed packages " } else { "" },
-        path.user_display().cyan()
-    )
-    .into_diagnostic()?;
+    // If requested, upgrade the interpreter of an existing virtual environment in place.
+    let existing = if upgrade_python {
+        let existing = upgradable_environment(&path, &interpreter, cache);
+        if existing.is_none() && path.join("pyvenv.cfg").is_file() {
+            warn_user!(
+                "The virtual environment at `{}` can't be upgraded in place, and will be recreated",
+                path.user_display().cyan()
+            );
+        }
+        existing
+    } else {
+        None
+    };
 
-    // Create the virtual environment.
-    let venv = uv_virtualenv::create_venv(
-        &path,
-        interpreter,
-        prompt,
-        system_site_packages,
-        allow_existing,
-        relocatable,
-        seed,
-    )
-    .map_err(VenvError::Creation)?;
+    let (venv, uninstalled) = if let Some(existing) = existing {
+        writeln!(
+            printer.stderr(),
+            "Updating virtual environment {}at: {}",
+            if seed { "with seed packages " } else { "" },
+            path.user_display().cyan()
+        )
+        .into_diagnostic()?;
+
+        // Upgrade the virtual environment, retaining the installed packages.
+        let venv = uv_virtualenv::upgrade_venv(
+            &existing,
+            interpreter,
+            prompt,
+            system_site_packages,
+            relocatable,
+            seed,
+        )
+        .map_err(VenvError::Upgrade)?;
+
+        // Remove any packages that were built for the previous interpreter.
+        let uninstalled = operations::uninstall_incompatible(&venv)
+            .await
+            .map_err(VenvError::Uninstall)?;
+
+        (venv, uninstalled)
+    } else {
+        writeln!(
+            printer.stderr(),
+            "Creating virtual environment {}at: {}",
+            if seed { "with seed packages " } else { "" },
+            path.user_display().cyan()
+        )
+        .into_diagnostic()?;
+
+        // Create the virtual environment.
+        let venv = uv_virtualenv::create_venv(
+            &path,
+            interpreter,
+            prompt,
+            system_site_packages,
+            allow_existing,
+            relocatable,
+            seed,
+        )
+        .map_err(VenvError::Creation)?;
+
+        (venv, vec![])
+    };
+
+    // Reinstall any incompatible packages from the registry. Packages installed from a URL can't
+    // be reinstalled without the original requirement.
+    let mut reinstalls = vec![];
+    for dist in &uninstalled {
+        if let InstalledDist::Registry(dist) = dist {
+            reinstalls.push(Requirement::from(
+                uv_pep508::Requirement::from_str(&format!("{}=={}", dist.name, dist.version))
+                    .into_diagnostic()?,
+            ));
+        } else {
+            warn_user!(
+                "Removed `{}`, which was installed from a URL and is incompatible with the upgraded interpreter; reinstall it to restore it",
+                dist.name().cyan()
+            );
+        }
+    }
 
-    // Install seed packages.
-    if seed {
+    // Install seed packages, along with any packages that need to be reinstalled.
+    if seed || !reinstalls.is_empty() {
         // Extract the interpreter.
         let interpreter = venv.interpreter();
 
@@ -300,7 +371,7 @@ async fn venv_impl(
             .index_urls(index_locations.index_urls())
             .index_strategy(index_strategy)
             .keyring(keyring_provider)
-            .allow_insecure_host(allow_insecure_host.to_vec())
+            .network_settings(network_settings)
             .markers(interpreter.markers())
             .platform(interpreter.platform())
             .build();
@@ -356,7 +427,9 @@ async fn venv_impl(
         );
 
         // Resolve the seed packages.
-        let requirements = if interpreter.python_tuple() >= (3, 12) {
+        let mut requirements = if !seed {
+            vec![]
+        } else if interpreter.python_tuple() >= (3, 12) {
             vec![Requirement::from(
                 uv_pep508::Requirement::from_str("pip").unwrap(),
             )]
@@ -368,13 +441,15 @@ async fn venv_impl(
                 Requirement::from(uv_pep508::Requirement::from_str("wheel").unwrap()),
             ]
         };
+        requirements.extend(reinstalls);
 
         let build_stack = BuildStack::default();
 
         // Resolve and install the requirements.
         //
-        // Since the virtual environment is empty, and the set of requirements is trivial (no
-        // constraints, no editables, etc.), we can use the build dispatch APIs directly.
+        // Since the set of requirements is trivial (no constraints, no editables, etc.), and any
+        // existing packages are compatible with the interpreter, we can use the build dispatch APIs
+        // directly.
         let resolution = build_dispatch
             .resolve(&requirements, &build_stack)
             .await
@@ -384,7 +459,7 @@ async fn venv_impl(
             .await
             .map_err(|err| VenvError::Seed(err.into()))?;
 
-        let changelog = Changelog::from_installed(installed);
+        let changelog = Changelog::new(installed, uninstalled);
         DefaultInstallLogger
             .on_complete(&changelog, printer)
             .into_diagnostic()?;
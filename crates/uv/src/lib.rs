@@ -19,13 +19,15 @@ use tracing::{debug, instrument};
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
 use uv_cli::{
-    compat::CompatArgs, BuildBackendCommand, CacheCommand, CacheNamespace, Cli, Commands,
-    PipCommand, PipNamespace, ProjectCommand,
+    compat::CompatArgs, AuthCommand, AuthNamespace, BuildBackendCommand, CacheCommand,
+    CacheNamespace, Cli, Commands, PipCommand, PipNamespace, ProjectCommand,
 };
 use uv_cli::{PythonCommand, PythonNamespace, ToolCommand, ToolNamespace, TopLevelArgs};
 #[cfg(feature = "self-update")]
 use uv_cli::{SelfCommand, SelfNamespace, SelfUpdateArgs};
+use uv_distribution_types::Index;
 use uv_fs::{Simplified, CWD};
+use uv_python::VersionFileDiscoveryOptions;
 use uv_requirements::RequirementsSource;
 use uv_scripts::{Pep723Error, Pep723Item, Pep723Metadata, Pep723Script};
 use uv_settings::{Combine, FilesystemOptions, Options};
@@ -36,9 +38,9 @@ use uv_workspace::{DiscoveryOptions, Workspace};
 use crate::commands::{ExitStatus, RunCommand, ToolRunCommand};
 use crate::printer::Printer;
 use crate::settings::{
-    CacheSettings, GlobalSettings, PipCheckSettings, PipCompileSettings, PipFreezeSettings,
-    PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings, PipUninstallSettings,
-    PublishSettings,
+    CacheSettings, GlobalSettings, NetworkSettings, PipCheckSettings, PipCompileSettings,
+    PipFreezeSettings, PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings,
+    PipUninstallSettings, PublishSettings,
 };
 
 pub(crate) mod commands;
@@ -147,17 +149,10 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         }) = &mut **command
         {
             let settings = GlobalSettings::resolve(&cli.top_level.global_args, filesystem.as_ref());
+            let network_settings = NetworkSettings::resolve(&settings)?;
             Some(
-                RunCommand::from_args(
-                    command,
-                    *module,
-                    *script,
-                    *gui_script,
-                    settings.connectivity,
-                    settings.native_tls,
-                    &settings.allow_insecure_host,
-                )
-                .await?,
+                RunCommand::from_args(command, *module, *script, *gui_script, &network_settings)
+                    .await?,
             )
         } else {
             None
@@ -251,6 +246,14 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     // Resolve the global settings.
     let globals = GlobalSettings::resolve(&cli.top_level.global_args, filesystem.as_ref());
 
+    // Resolve the network settings, reading any custom TLS certificates.
+    let network_settings = NetworkSettings::resolve(&globals)?;
+
+    // Determine which Python version files to respect.
+    let version_files = VersionFileDiscoveryOptions::default()
+        .with_no_config(cli.top_level.no_config)
+        .with_no_external(globals.no_external_version_files);
+
     // Resolve the cache settings.
     let cache_settings = CacheSettings::resolve(*cli.top_level.cache_args, filesystem.as_ref());
 
@@ -337,7 +340,12 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     show_settings!(cache_settings, false);
 
     // Configure the cache.
-    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?;
+    let cache = Cache::from_settings(
+        cache_settings.no_cache,
+        cache_settings.cache_dir,
+        cache_settings.cache_readonly_dirs,
+        cache_settings.remote_build_cache,
+    )?;
 
     let result = match *cli.command {
         Commands::Help(args) => commands::help(
@@ -414,9 +422,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.index_strategy,
                 args.settings.dependency_metadata,
                 args.settings.keyring_provider,
-                &globals.allow_insecure_host,
+                &network_settings,
                 args.settings.config_setting,
-                globals.connectivity,
                 args.settings.no_build_isolation,
                 args.settings.no_build_isolation_package,
                 args.settings.build_options,
@@ -430,8 +437,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.python,
                 args.settings.system,
                 globals.python_preference,
+                &globals.python_discovery_sources,
                 globals.concurrency,
-                globals.native_tls,
                 globals.quiet,
                 cache,
                 printer,
@@ -485,7 +492,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.keyring_provider,
                 args.settings.allow_empty_requirements,
                 globals.installer_metadata,
-                globals.connectivity,
+                &network_settings,
                 &args.settings.config_setting,
                 args.settings.no_build_isolation,
                 args.settings.no_build_isolation_package,
@@ -501,9 +508,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.prefix,
                 args.settings.sources,
                 globals.python_preference,
+                &globals.python_discovery_sources,
                 globals.concurrency,
-                globals.native_tls,
-                &globals.allow_insecure_host,
                 cache,
                 args.dry_run,
                 printer,
@@ -576,7 +582,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.compile_bytecode,
                 args.settings.hash_checking,
                 globals.installer_metadata,
-                globals.connectivity,
+                &network_settings,
                 &args.settings.config_setting,
                 args.settings.no_build_isolation,
                 args.settings.no_build_isolation_package,
@@ -593,9 +599,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.target,
                 args.settings.prefix,
                 globals.python_preference,
+                &globals.python_discovery_sources,
                 globals.concurrency,
-                globals.native_tls,
-                &globals.allow_insecure_host,
                 cache,
                 args.dry_run,
                 printer,
@@ -631,10 +636,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.target,
                 args.settings.prefix,
                 cache,
-                globals.connectivity,
-                globals.native_tls,
+                &network_settings,
                 args.settings.keyring_provider,
-                &globals.allow_insecure_host,
                 args.dry_run,
                 printer,
             )
@@ -681,14 +684,12 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.index_locations,
                 args.settings.index_strategy,
                 args.settings.keyring_provider,
-                globals.allow_insecure_host,
-                globals.connectivity,
+                &network_settings,
                 globals.concurrency,
                 args.settings.strict,
                 args.settings.exclude_newer,
                 args.settings.python.as_deref(),
                 args.settings.system,
-                globals.native_tls,
                 &cache,
                 printer,
             )
@@ -735,14 +736,12 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.index_locations,
                 args.settings.index_strategy,
                 args.settings.keyring_provider,
-                globals.allow_insecure_host,
-                globals.connectivity,
+                &network_settings,
                 globals.concurrency,
                 args.settings.strict,
                 args.settings.exclude_newer,
                 args.settings.python.as_deref(),
                 args.settings.system,
-                globals.native_tls,
                 &cache,
                 printer,
             )
@@ -765,6 +764,57 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 printer,
             )
         }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Login(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::AuthLoginSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::auth_login(
+                &args.service,
+                args.username,
+                args.password,
+                args.keyring_provider,
+                network_settings.credential_helper.as_deref(),
+                &args.indexes,
+                printer,
+            )
+            .await
+        }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Logout(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::AuthLogoutSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::auth_logout(
+                &args.service,
+                args.username.as_deref(),
+                &args.indexes,
+                printer,
+            )
+            .await
+        }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Token(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::AuthTokenSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::auth_token(
+                &args.service,
+                args.username.as_deref(),
+                &args.indexes,
+                printer,
+            )
+            .await
+        }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::List,
+        }) => commands::auth_list(printer),
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Clean(args),
         })
@@ -774,9 +824,75 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Prune(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::CachePruneSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::cache_prune(args.ci, args.max_size, args.older_than, &cache, printer)
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Size(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_size(args.output_format, &cache, printer)
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::List(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::CacheListSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::cache_list(
+                args.package.as_ref(),
+                args.output_format,
+                &args.indexes,
+                &cache,
+                printer,
+            )
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Verify(args),
         }) => {
             show_settings!(args);
-            commands::cache_prune(args.ci, &cache, printer)
+            commands::cache_verify(args.repair, &cache, printer)
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Export(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::CacheExportSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::cache_export(
+                &args.lock,
+                &args.output,
+                &args.python_platforms,
+                args.python,
+                args.resolver,
+                globals.python_preference,
+                &globals.python_discovery_sources,
+                &network_settings,
+                globals.concurrency,
+                &cache,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Import(args),
+        }) => {
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::cache_import(&args.bundle, &cache, printer).await
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
@@ -818,13 +934,12 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.python,
                 args.install_mirrors,
                 args.settings,
-                cli.top_level.no_config,
+                version_files,
                 globals.python_preference,
+                &globals.python_discovery_sources,
                 globals.python_downloads,
-                globals.connectivity,
+                &network_settings,
                 globals.concurrency,
-                globals.native_tls,
-                &globals.allow_insecure_host,
                 &cache,
                 printer,
                 globals.preview,
@@ -868,22 +983,22 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.python.as_deref(),
                 args.settings.install_mirrors,
                 globals.python_preference,
+                &globals.python_discovery_sources,
                 globals.python_downloads,
                 args.settings.link_mode,
                 &args.settings.index_locations,
                 args.settings.index_strategy,
                 args.settings.dependency_metadata,
                 args.settings.keyring_provider,
-                &globals.allow_insecure_host,
+                &network_settings,
                 uv_virtualenv::Prompt::from_args(prompt),
                 args.system_site_packages,
-                globals.connectivity,
                 args.seed,
                 args.allow_existing,
+                args.upgrade_python,
                 args.settings.exclude_newer,
                 globals.concurrency,
-                globals.native_tls,
-                cli.top_level.no_config,
+                version_files,
                 args.no_project,
                 &cache,
                 printer,
@@ -899,7 +1014,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 run_command,
                 script,
                 globals,
-                cli.top_level.no_config,
+                &network_settings,
+                version_files,
                 filesystem,
                 cache,
                 printer,
@@ -1001,12 +1117,11 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 invocation_source,
                 args.isolated,
                 globals.python_preference,
+                &globals.python_discovery_sources,
                 globals.python_downloads,
                 globals.installer_metadata,
-                globals.connectivity,
+                &network_settings,
                 globals.concurrency,
-                globals.native_tls,
-                &globals.allow_insecure_host,
                 cache,
                 printer,
                 globals.preview,
@@ -1066,12 +1181,11 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.options,
                 args.settings,
                 globals.python_preference,
+                &globals.python_discovery_sources,
                 globals.python_downloads,
                 globals.installer_metadata,
-                globals.connectivity,
+                &network_settings,
                 globals.concurrency,
-                globals.native_tls,
-                &globals.allow_insecure_host,
                 cache,
                 printer,
                 globals.preview,
@@ -1110,15 +1224,14 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.names,
                 args.python,
                 args.install_mirrors,
-                globals.connectivity,
+                &network_settings,
                 args.args,
                 args.filesystem,
                 globals.python_preference,
+                &globals.python_discovery_sources,
                 globals.python_downloads,
                 globals.installer_metadata,
                 globals.concurrency,
-                globals.native_tls,
-                &globals.allow_insecure_host,
                 &cache,
                 printer,
                 globals.preview,
@@ -1168,6 +1281,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.show_urls,
                 args.output_format,
                 globals.python_preference,
+                &globals.python_discovery_sources,
                 globals.python_downloads,
                 &cache,
                 printer,
@@ -1190,11 +1304,10 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.python_install_mirror,
                 args.pypy_install_mirror,
                 args.default,
+                args.keyring_provider,
                 globals.python_downloads,
-                globals.native_tls,
-                globals.connectivity,
-                &globals.allow_insecure_host,
-                cli.top_level.no_config,
+                &network_settings,
+                version_files,
                 globals.preview,
                 printer,
             )
@@ -1216,6 +1329,33 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             )
             .await
         }
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::Verify(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PythonVerifySettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::python_verify(
+                &project_dir,
+                args.install_dir,
+                args.targets,
+                args.repair,
+                args.python_install_mirror,
+                args.pypy_install_mirror,
+                args.keyring_provider,
+                globals.python_downloads,
+                &network_settings,
+                version_files,
+                globals.preview,
+                &cache,
+                printer,
+            )
+            .await
+        }
         Commands::Python(PythonNamespace {
             command: PythonCommand::Find(args),
         }) => {
@@ -1229,9 +1369,10 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &project_dir,
                 args.request,
                 args.no_project,
-                cli.top_level.no_config,
+                version_files,
                 args.system,
                 globals.python_preference,
+                &globals.python_discovery_sources,
                 &cache,
             )
             .await
@@ -1250,6 +1391,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.request,
                 args.resolved,
                 globals.python_preference,
+                &globals.python_discovery_sources,
+                version_files,
                 args.no_project,
                 &cache,
                 printer,
@@ -1293,10 +1436,12 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 keyring_provider,
                 check_url,
                 index,
+                check,
+                attestations,
                 index_locations,
             } = PublishSettings::resolve(args, filesystem);
 
-            let (publish_url, check_url) = if let Some(index_name) = index {
+            let (publish_url, check_index) = if let Some(index_name) = index {
                 debug!("Publishing with index {index_name}");
                 let index = index_locations
                     .simple_indexes()
@@ -1326,24 +1471,32 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                     .publish_url
                     .clone()
                     .with_context(|| format!("Index is missing a publish URL: `{index_name}`"))?;
-                let check_url = index.url.clone();
-                (publish_url, Some(check_url))
+                (publish_url, Some(index.clone()))
             } else {
-                (publish_url, check_url)
+                (publish_url, check_url.map(Index::from_index_url))
             };
 
+            // Respect the HTTP settings of the index being published to, including when it's
+            // selected by its publish URL rather than by name.
+            let publish_http = index_locations
+                .simple_indexes()
+                .find(|index| index.publish_url.as_ref() == Some(&publish_url))
+                .and_then(|index| index.http.clone());
+
             commands::publish(
                 files,
                 publish_url,
                 trusted_publishing,
                 keyring_provider,
-                &globals.allow_insecure_host,
+                &network_settings,
                 username,
                 password,
-                check_url,
+                check_index,
+                publish_http,
+                check,
+                attestations.as_deref(),
+                globals.concurrency,
                 &cache,
-                globals.connectivity,
-                globals.native_tls,
                 printer,
             )
             .await
@@ -1395,8 +1548,8 @@ async fn run_project(
     command: Option<RunCommand>,
     script: Option<Pep723Item>,
     globals: GlobalSettings,
-    // TODO(zanieb): Determine a better story for passing `no_config` in here
-    no_config: bool,
+    network_settings: &NetworkSettings,
+    version_files: VersionFileDiscoveryOptions<'_>,
     filesystem: Option<FilesystemOptions>,
     cache: Cache,
     printer: Printer,
@@ -1443,11 +1596,10 @@ async fn run_project(
                 args.install_mirrors,
                 args.no_workspace,
                 globals.python_preference,
+                &globals.python_discovery_sources,
                 globals.python_downloads,
-                globals.connectivity,
-                globals.native_tls,
-                &globals.allow_insecure_host,
-                no_config,
+                network_settings,
+                version_files,
                 &cache,
                 printer,
                 globals.preview,
@@ -1496,7 +1648,7 @@ async fn run_project(
                 args.all_packages,
                 args.package,
                 args.no_project,
-                no_config,
+                version_files,
                 args.extras,
                 args.dev,
                 args.editable,
@@ -1505,12 +1657,11 @@ async fn run_project(
                 args.install_mirrors,
                 args.settings,
                 globals.python_preference,
+                &globals.python_discovery_sources,
                 globals.python_downloads,
                 globals.installer_metadata,
-                globals.connectivity,
+                network_settings,
                 globals.concurrency,
-                globals.native_tls,
-                &globals.allow_insecure_host,
                 &cache,
                 printer,
                 args.env_file,
@@ -1555,15 +1706,14 @@ async fn run_project(
                 args.python,
                 args.install_mirrors,
                 globals.python_preference,
+                &globals.python_discovery_sources,
                 globals.python_downloads,
                 args.settings,
                 script,
                 globals.installer_metadata,
-                globals.connectivity,
+                network_settings,
                 globals.concurrency,
-                globals.native_tls,
-                &globals.allow_insecure_host,
-                no_config,
+                version_files,
                 &cache,
                 printer,
                 globals.preview,
@@ -1598,12 +1748,11 @@ async fn run_project(
                 args.settings,
                 script,
                 globals.python_preference,
+                &globals.python_discovery_sources,
                 globals.python_downloads,
-                globals.connectivity,
+                network_settings,
                 globals.concurrency,
-                globals.native_tls,
-                &globals.allow_insecure_host,
-                no_config,
+                version_files,
                 &cache,
                 printer,
                 globals.preview,
@@ -1654,13 +1803,12 @@ async fn run_project(
                 args.settings,
                 args.script,
                 globals.python_preference,
+                &globals.python_discovery_sources,
                 globals.python_downloads,
                 globals.installer_metadata,
-                globals.connectivity,
+                network_settings,
                 globals.concurrency,
-                globals.native_tls,
-                &globals.allow_insecure_host,
-                no_config,
+                version_files,
                 &cache,
                 printer,
                 globals.preview,
@@ -1700,13 +1848,12 @@ async fn run_project(
                 args.settings,
                 script,
                 globals.python_preference,
+                &globals.python_discovery_sources,
                 globals.python_downloads,
                 globals.installer_metadata,
-                globals.connectivity,
+                network_settings,
                 globals.concurrency,
-                globals.native_tls,
-                &globals.allow_insecure_host,
-                no_config,
+                version_files,
                 &cache,
                 printer,
                 globals.preview,
@@ -1747,12 +1894,11 @@ async fn run_project(
                 args.resolver,
                 script,
                 globals.python_preference,
+                &globals.python_discovery_sources,
                 globals.python_downloads,
-                globals.connectivity,
+                network_settings,
                 globals.concurrency,
-                globals.native_tls,
-                &globals.allow_insecure_host,
-                no_config,
+                version_files,
                 &cache,
                 printer,
                 globals.preview,
@@ -1794,12 +1940,11 @@ async fn run_project(
                 args.install_mirrors,
                 args.settings,
                 globals.python_preference,
+                &globals.python_discovery_sources,
                 globals.python_downloads,
-                globals.connectivity,
+                network_settings,
                 globals.concurrency,
-                globals.native_tls,
-                &globals.allow_insecure_host,
-                no_config,
+                version_files,
                 globals.quiet,
                 &cache,
                 printer,
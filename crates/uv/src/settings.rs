@@ -6,7 +6,7 @@ use std::str::FromStr;
 
 use url::Url;
 
-use uv_cache::{CacheArgs, Refresh};
+use uv_cache::{Age, ByteSize, CacheArgs, Refresh, RemoteCache};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
     options::{flag, resolver_installer_options, resolver_options},
@@ -14,26 +14,32 @@ use uv_cli::{
     ToolUpgradeArgs,
 };
 use uv_cli::{
-    AddArgs, ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe,
+    AddArgs, AuthLoginArgs, AuthLogoutArgs, AuthTokenArgs, CacheExportArgs, CacheListArgs,
+    ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe, OutputFormat,
     PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
-    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs,
-    PythonListFormat, PythonPinArgs, PythonUninstallArgs, RemoveArgs, RunArgs, SyncArgs,
-    ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs,
+    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PruneArgs, PythonFindArgs, PythonInstallArgs,
+    PythonListArgs, PythonPinArgs, PythonUninstallArgs, PythonVerifyArgs, RemoveArgs, RunArgs,
+    SyncArgs, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs,
+    VenvArgs,
+};
+use uv_client::{
+    BaseClientBuilder, Connectivity, HostTlsError, HostTlsMaterial, RegistryClientBuilder,
 };
-use uv_client::Connectivity;
 use uv_configuration::{
     BuildOptions, Concurrency, ConfigSettings, DevGroupsSpecification, DryRun, EditableMode,
-    ExportFormat, ExtrasSpecification, HashCheckingMode, IndexStrategy, InstallOptions,
-    KeyringProviderType, NoBinary, NoBuild, PreviewMode, ProjectBuildBackend, Reinstall,
-    RequiredVersion, SourceStrategy, TargetTriple, TrustedHost, TrustedPublishing, Upgrade,
-    VersionControlSystem,
+    ExportFormat, ExtrasSpecification, HashCheckingMode, HostTls, IndexStrategy, InstallOptions,
+    KeyringProviderType, NoBinary, NoBuild, PreviewMode, ProjectBuildBackend, ProxySettings,
+    Reinstall, RequiredVersion, SourceStrategy, TargetTriple, TrustedHost, TrustedPublishing,
+    Upgrade, VersionControlSystem,
 };
-use uv_distribution_types::{DependencyMetadata, Index, IndexLocations, IndexUrl};
+use uv_distribution_types::{DependencyMetadata, Index, IndexHttpPolicy, IndexLocations, IndexUrl};
 use uv_install_wheel::LinkMode;
 use uv_normalize::PackageName;
 use uv_pep508::{ExtraName, RequirementOrigin};
 use uv_pypi_types::{Requirement, SupportedEnvironments};
-use uv_python::{Prefix, PythonDownloads, PythonPreference, PythonVersion, Target};
+use uv_python::{
+    Prefix, PythonDiscoverySource, PythonDownloads, PythonPreference, PythonVersion, Target,
+};
 use uv_resolver::{
     AnnotationStyle, DependencyMode, ExcludeNewer, ForkStrategy, PrereleaseMode, ResolutionMode,
 };
@@ -63,10 +69,16 @@ pub(crate) struct GlobalSettings {
     pub(crate) concurrency: Concurrency,
     pub(crate) connectivity: Connectivity,
     pub(crate) allow_insecure_host: Vec<TrustedHost>,
+    pub(crate) host_tls: Vec<HostTls>,
+    pub(crate) credential_helper: Option<PathBuf>,
+    pub(crate) http: IndexHttpPolicy,
+    pub(crate) proxy: ProxySettings,
     pub(crate) show_settings: bool,
     pub(crate) preview: PreviewMode,
     pub(crate) python_preference: PythonPreference,
     pub(crate) python_downloads: PythonDownloads,
+    pub(crate) no_external_version_files: bool,
+    pub(crate) python_discovery_sources: Vec<PythonDiscoverySource>,
     pub(crate) no_progress: bool,
     pub(crate) installer_metadata: bool,
 }
@@ -116,6 +128,10 @@ impl GlobalSettings {
                     .combine(workspace.and_then(|workspace| workspace.globals.concurrent_installs))
                     .map(NonZeroUsize::get)
                     .unwrap_or_else(Concurrency::threads),
+                uploads: env(env::CONCURRENT_UPLOADS)
+                    .combine(workspace.and_then(|workspace| workspace.globals.concurrent_uploads))
+                    .map(NonZeroUsize::get)
+                    .unwrap_or(Concurrency::DEFAULT_UPLOADS),
             },
             connectivity: if flag(args.offline, args.no_offline)
                 .combine(workspace.and_then(|workspace| workspace.globals.offline))
@@ -142,6 +158,32 @@ impl GlobalSettings {
                         .flatten(),
                 )
                 .collect(),
+            host_tls: workspace
+                .and_then(|workspace| workspace.globals.host_tls.clone())
+                .unwrap_or_default(),
+            credential_helper: args.credential_helper.clone().combine(
+                workspace.and_then(|workspace| workspace.globals.credential_helper.clone()),
+            ),
+            // Settings from the environment (e.g., `UV_HTTP_RETRIES`) are applied on top of these
+            // settings by the client.
+            http: IndexHttpPolicy {
+                retries: workspace.and_then(|workspace| workspace.globals.http_retries),
+                retry_backoff_min: workspace
+                    .and_then(|workspace| workspace.globals.http_retry_backoff_min),
+                retry_backoff_max: workspace
+                    .and_then(|workspace| workspace.globals.http_retry_backoff_max),
+                connect_timeout: workspace
+                    .and_then(|workspace| workspace.globals.http_connect_timeout),
+                read_timeout: workspace.and_then(|workspace| workspace.globals.http_timeout),
+                retry_status_codes: workspace
+                    .and_then(|workspace| workspace.globals.http_retry_status_codes.clone()),
+            },
+            proxy: ProxySettings {
+                proxy: workspace.and_then(|workspace| workspace.globals.proxy.clone()),
+                no_proxy: workspace
+                    .and_then(|workspace| workspace.globals.no_proxy.clone())
+                    .unwrap_or_default(),
+            },
             show_settings: args.show_settings,
             preview: PreviewMode::from(
                 flag(args.preview, args.no_preview)
@@ -157,6 +199,10 @@ impl GlobalSettings {
                 .combine(env(env::UV_PYTHON_DOWNLOADS))
                 .combine(workspace.and_then(|workspace| workspace.globals.python_downloads))
                 .unwrap_or_default(),
+            no_external_version_files: args.no_external_version_files,
+            python_discovery_sources: workspace
+                .and_then(|workspace| workspace.globals.python_discovery_sources.clone())
+                .unwrap_or_default(),
             // Disable the progress bar with `RUST_LOG` to avoid progress fragments interleaving
             // with log messages.
             no_progress: args.no_progress || std::env::var_os(EnvVars::RUST_LOG).is_some(),
@@ -165,12 +211,78 @@ impl GlobalSettings {
     }
 }
 
+/// The resolved network settings to use for any invocation of the CLI.
+#[derive(Debug, Clone)]
+pub(crate) struct NetworkSettings {
+    pub(crate) connectivity: Connectivity,
+    pub(crate) native_tls: bool,
+    pub(crate) allow_insecure_host: Vec<TrustedHost>,
+    pub(crate) host_tls: Vec<HostTlsMaterial>,
+    pub(crate) credential_helper: Option<PathBuf>,
+    pub(crate) http: IndexHttpPolicy,
+    pub(crate) proxy: ProxySettings,
+}
+
+impl NetworkSettings {
+    /// Resolve the [`NetworkSettings`] from the [`GlobalSettings`], reading the TLS material for
+    /// any hosts with custom TLS settings.
+    pub(crate) fn resolve(globals: &GlobalSettings) -> Result<Self, HostTlsError> {
+        Ok(Self {
+            connectivity: globals.connectivity,
+            native_tls: globals.native_tls,
+            allow_insecure_host: globals.allow_insecure_host.clone(),
+            host_tls: globals
+                .host_tls
+                .iter()
+                .cloned()
+                .map(HostTlsMaterial::read)
+                .collect::<Result<_, _>>()?,
+            credential_helper: globals.credential_helper.clone(),
+            http: globals.http.clone(),
+            proxy: globals.proxy.clone(),
+        })
+    }
+}
+
+/// Apply the resolved [`NetworkSettings`] to an HTTP client builder.
+pub(crate) trait NetworkSettingsExt {
+    /// Configure the connectivity, TLS, credential helper, retry and timeout, and proxy settings.
+    #[must_use]
+    fn network_settings(self, network_settings: &NetworkSettings) -> Self;
+}
+
+impl NetworkSettingsExt for BaseClientBuilder<'_> {
+    fn network_settings(self, network_settings: &NetworkSettings) -> Self {
+        self.connectivity(network_settings.connectivity)
+            .native_tls(network_settings.native_tls)
+            .credential_helper(network_settings.credential_helper.clone())
+            .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .host_tls(network_settings.host_tls.clone())
+            .http_policy(network_settings.http.clone())
+            .proxy(network_settings.proxy.clone())
+    }
+}
+
+impl NetworkSettingsExt for RegistryClientBuilder<'_> {
+    fn network_settings(self, network_settings: &NetworkSettings) -> Self {
+        self.connectivity(network_settings.connectivity)
+            .native_tls(network_settings.native_tls)
+            .credential_helper(network_settings.credential_helper.clone())
+            .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .host_tls(network_settings.host_tls.clone())
+            .http_policy(network_settings.http.clone())
+            .proxy(network_settings.proxy.clone())
+    }
+}
+
 /// The resolved cache settings to use for any invocation of the CLI.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
 pub(crate) struct CacheSettings {
     pub(crate) no_cache: bool,
     pub(crate) cache_dir: Option<PathBuf>,
+    pub(crate) cache_readonly_dirs: Vec<PathBuf>,
+    pub(crate) remote_build_cache: Option<RemoteCache>,
 }
 
 impl CacheSettings {
@@ -184,6 +296,211 @@ impl CacheSettings {
             cache_dir: args
                 .cache_dir
                 .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_dir.clone())),
+            cache_readonly_dirs: args
+                .cache_readonly_dirs
+                .or_else(|| {
+                    workspace.and_then(|workspace| workspace.globals.cache_readonly_dirs.clone())
+                })
+                .unwrap_or_default(),
+            remote_build_cache: args.remote_build_cache.or_else(|| {
+                workspace.and_then(|workspace| workspace.globals.remote_build_cache.clone())
+            }),
+        }
+    }
+}
+
+/// The resolved settings to use for a `auth login` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct AuthLoginSettings {
+    pub(crate) service: String,
+    pub(crate) username: Option<String>,
+    pub(crate) password: Option<String>,
+    pub(crate) keyring_provider: KeyringProviderType,
+    pub(crate) indexes: Vec<Index>,
+}
+
+impl AuthLoginSettings {
+    /// Resolve the [`AuthLoginSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: AuthLoginArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let AuthLoginArgs {
+            service,
+            username,
+            password,
+            token,
+            keyring_provider,
+        } = args;
+
+        let ResolverInstallerOptions {
+            keyring_provider: keyring_provider_option,
+            index,
+            ..
+        } = filesystem
+            .map(FilesystemOptions::into_options)
+            .unwrap_or_default()
+            .top_level;
+
+        // Tokens are encoded in the same way as username/password
+        let (username, password) = if let Some(token) = token {
+            (Some("__token__".to_string()), Some(token))
+        } else {
+            (username, password)
+        };
+
+        Self {
+            service,
+            username,
+            password,
+            keyring_provider: keyring_provider
+                .combine(keyring_provider_option)
+                .unwrap_or_default(),
+            indexes: index.unwrap_or_default(),
+        }
+    }
+}
+
+/// The resolved settings to use for a `auth logout` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct AuthLogoutSettings {
+    pub(crate) service: String,
+    pub(crate) username: Option<String>,
+    pub(crate) indexes: Vec<Index>,
+}
+
+impl AuthLogoutSettings {
+    /// Resolve the [`AuthLogoutSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: AuthLogoutArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let AuthLogoutArgs { service, username } = args;
+
+        Self {
+            service,
+            username,
+            indexes: filesystem
+                .and_then(|filesystem| filesystem.into_options().top_level.index)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// The resolved settings to use for a `auth token` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct AuthTokenSettings {
+    pub(crate) service: String,
+    pub(crate) username: Option<String>,
+    pub(crate) indexes: Vec<Index>,
+}
+
+impl AuthTokenSettings {
+    /// Resolve the [`AuthTokenSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: AuthTokenArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let AuthTokenArgs { service, username } = args;
+
+        Self {
+            service,
+            username,
+            indexes: filesystem
+                .and_then(|filesystem| filesystem.into_options().top_level.index)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// The resolved settings to use for a `cache prune` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct CachePruneSettings {
+    pub(crate) ci: bool,
+    pub(crate) max_size: Option<ByteSize>,
+    pub(crate) older_than: Option<Age>,
+}
+
+impl CachePruneSettings {
+    /// Resolve the [`CachePruneSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: PruneArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let PruneArgs {
+            ci,
+            max_size,
+            older_than,
+        } = args;
+
+        Self {
+            ci,
+            max_size: max_size
+                .or_else(|| filesystem.and_then(|filesystem| filesystem.globals.cache_max_size)),
+            older_than,
+        }
+    }
+}
+
+/// The resolved settings to use for a `cache list` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct CacheListSettings {
+    pub(crate) package: Option<PackageName>,
+    pub(crate) output_format: OutputFormat,
+    pub(crate) indexes: Vec<IndexUrl>,
+}
+
+impl CacheListSettings {
+    /// Resolve the [`CacheListSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: CacheListArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let CacheListArgs {
+            package,
+            output_format,
+        } = args;
+
+        // Collect the configured indexes, to identify the index from which each entry originated.
+        let indexes = filesystem
+            .map(|filesystem| {
+                let top_level = filesystem.into_options().top_level;
+                top_level
+                    .index
+                    .into_iter()
+                    .flatten()
+                    .chain(top_level.index_url.map(Index::from))
+                    .chain(
+                        top_level
+                            .extra_index_url
+                            .into_iter()
+                            .flatten()
+                            .map(Index::from),
+                    )
+                    .map(|index| index.url)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            package,
+            output_format,
+            indexes,
+        }
+    }
+}
+
+/// The resolved settings to use for a `cache export` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct CacheExportSettings {
+    pub(crate) lock: PathBuf,
+    pub(crate) output: PathBuf,
+    pub(crate) python_platforms: Vec<TargetTriple>,
+    pub(crate) python: Option<String>,
+    pub(crate) resolver: ResolverSettings,
+}
+
+impl CacheExportSettings {
+    /// Resolve the [`CacheExportSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: CacheExportArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let CacheExportArgs {
+            lock,
+            output,
+            python_platform,
+            python,
+        } = args;
+
+        Self {
+            lock,
+            output,
+            python_platforms: python_platform,
+            python: python.and_then(Maybe::into_option),
+            resolver: ResolverSettings::combine(ResolverOptions::default(), filesystem),
         }
     }
 }
@@ -772,7 +1089,7 @@ pub(crate) struct PythonListSettings {
     pub(crate) all_arches: bool,
     pub(crate) all_versions: bool,
     pub(crate) show_urls: bool,
-    pub(crate) output_format: PythonListFormat,
+    pub(crate) output_format: OutputFormat,
 }
 
 impl PythonListSettings {
@@ -836,6 +1153,7 @@ pub(crate) struct PythonInstallSettings {
     pub(crate) python_install_mirror: Option<String>,
     pub(crate) pypy_install_mirror: Option<String>,
     pub(crate) default: bool,
+    pub(crate) keyring_provider: KeyringProviderType,
 }
 
 impl PythonInstallSettings {
@@ -843,15 +1161,17 @@ impl PythonInstallSettings {
     #[allow(clippy::needless_pass_by_value)]
     pub(crate) fn resolve(args: PythonInstallArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let options = filesystem.map(FilesystemOptions::into_options);
-        let (python_mirror, pypy_mirror) = match options {
+        let (python_mirror, pypy_mirror, keyring_provider) = match options {
             Some(options) => (
                 options.install_mirrors.python_install_mirror,
                 options.install_mirrors.pypy_install_mirror,
+                options.top_level.keyring_provider,
             ),
-            None => (None, None),
+            None => (None, None, None),
         };
         let python_mirror = args.mirror.or(python_mirror);
         let pypy_mirror = args.pypy_mirror.or(pypy_mirror);
+        let keyring_provider = args.keyring_provider.combine(keyring_provider);
 
         let PythonInstallArgs {
             install_dir,
@@ -860,6 +1180,7 @@ impl PythonInstallSettings {
             force,
             mirror: _,
             pypy_mirror: _,
+            keyring_provider: _,
             default,
         } = args;
 
@@ -871,6 +1192,7 @@ impl PythonInstallSettings {
             python_install_mirror: python_mirror,
             pypy_install_mirror: pypy_mirror,
             default,
+            keyring_provider: keyring_provider.unwrap_or_default(),
         }
     }
 }
@@ -905,6 +1227,54 @@ impl PythonUninstallSettings {
     }
 }
 
+/// The resolved settings to use for a `python verify` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PythonVerifySettings {
+    pub(crate) install_dir: Option<PathBuf>,
+    pub(crate) targets: Vec<String>,
+    pub(crate) repair: bool,
+    pub(crate) python_install_mirror: Option<String>,
+    pub(crate) pypy_install_mirror: Option<String>,
+    pub(crate) keyring_provider: KeyringProviderType,
+}
+
+impl PythonVerifySettings {
+    /// Resolve the [`PythonVerifySettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: PythonVerifyArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let options = filesystem.map(FilesystemOptions::into_options);
+        let (python_mirror, pypy_mirror, keyring_provider) = match options {
+            Some(options) => (
+                options.install_mirrors.python_install_mirror,
+                options.install_mirrors.pypy_install_mirror,
+                options.top_level.keyring_provider,
+            ),
+            None => (None, None, None),
+        };
+        let python_mirror = args.mirror.or(python_mirror);
+        let pypy_mirror = args.pypy_mirror.or(pypy_mirror);
+        let keyring_provider = args.keyring_provider.combine(keyring_provider);
+
+        let PythonVerifyArgs {
+            install_dir,
+            targets,
+            repair,
+            mirror: _,
+            pypy_mirror: _,
+            keyring_provider: _,
+        } = args;
+
+        Self {
+            install_dir,
+            targets,
+            repair,
+            python_install_mirror: python_mirror,
+            pypy_install_mirror: pypy_mirror,
+            keyring_provider: keyring_provider.unwrap_or_default(),
+        }
+    }
+}
+
 /// The resolved settings to use for a `python find` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
@@ -2245,6 +2615,7 @@ impl BuildSettings {
 pub(crate) struct VenvSettings {
     pub(crate) seed: bool,
     pub(crate) allow_existing: bool,
+    pub(crate) upgrade_python: bool,
     pub(crate) path: Option<PathBuf>,
     pub(crate) prompt: Option<String>,
     pub(crate) system_site_packages: bool,
@@ -2263,6 +2634,7 @@ impl VenvSettings {
             no_system,
             seed,
             allow_existing,
+            upgrade_python,
             path,
             prompt,
             system_site_packages,
@@ -2280,6 +2652,7 @@ impl VenvSettings {
         Self {
             seed,
             allow_existing,
+            upgrade_python,
             path,
             prompt,
             system_site_packages,
@@ -3003,6 +3376,8 @@ pub(crate) struct PublishSettings {
     pub(crate) username: Option<String>,
     pub(crate) password: Option<String>,
     pub(crate) index: Option<String>,
+    pub(crate) check: bool,
+    pub(crate) attestations: Option<PathBuf>,
 
     // Both CLI and configuration.
     pub(crate) publish_url: Url,
@@ -3060,6 +3435,8 @@ impl PublishSettings {
                 .unwrap_or_default(),
             check_url: args.check_url.combine(check_url),
             index: args.index,
+            check: args.check,
+            attestations: args.attestations,
             index_locations: IndexLocations::new(
                 index
                     .into_iter()
@@ -3087,6 +3464,9 @@ mod env {
     pub(super) const CONCURRENT_INSTALLS: (&str, &str) =
         (EnvVars::UV_CONCURRENT_INSTALLS, "a non-zero integer");
 
+    pub(super) const CONCURRENT_UPLOADS: (&str, &str) =
+        (EnvVars::UV_CONCURRENT_UPLOADS, "a non-zero integer");
+
     pub(super) const UV_PYTHON_DOWNLOADS: (&str, &str) = (
         EnvVars::UV_PYTHON_DOWNLOADS,
         "one of 'auto', 'true', 'manual', 'never', or 'false'",
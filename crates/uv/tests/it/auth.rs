@@ -0,0 +1,152 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+#[test]
+fn login_token_logout() {
+    let context = TestContext::new_with_versions(&[]);
+
+    uv_snapshot!(context.filters(), context.auth().arg("login")
+        .arg("example.com")
+        .arg("--token")
+        .arg("secret"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stored credentials for __token__ on https://example.com/
+    "###);
+
+    uv_snapshot!(context.filters(), context.auth().arg("list"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://example.com/ __token__
+
+    ----- stderr -----
+    "###);
+
+    // The credentials apply to any URL on the host.
+    uv_snapshot!(context.filters(), context.auth().arg("token")
+        .arg("https://example.com/simple"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    secret
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.auth().arg("logout")
+        .arg("example.com"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed credentials for __token__ on https://example.com/
+    "###);
+
+    uv_snapshot!(context.filters(), context.auth().arg("logout")
+        .arg("example.com"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    No credentials found for https://example.com/
+    "###);
+
+    uv_snapshot!(context.filters(), context.auth().arg("token")
+        .arg("example.com"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No credentials found for https://example.com/
+    "###);
+}
+
+#[test]
+fn login_index_name() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [[tool.uv.index]]
+        name = "internal"
+        url = "https://example.com/private/simple"
+        "#,
+    )?;
+
+    // The credentials apply to the root of the index.
+    uv_snapshot!(context.filters(), context.auth().arg("login")
+        .arg("internal")
+        .arg("--username")
+        .arg("user")
+        .arg("--password")
+        .arg("pass"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stored credentials for user on https://example.com/private
+    "###);
+
+    uv_snapshot!(context.filters(), context.auth().arg("token")
+        .arg("https://example.com/private/files/foo.whl"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pass
+
+    ----- stderr -----
+    "###);
+
+    // But not to other paths on the same host.
+    uv_snapshot!(context.filters(), context.auth().arg("token")
+        .arg("https://example.com/public/simple"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No credentials found for https://example.com/public/simple
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn login_keyring_helper_without_credential_helper() {
+    let context = TestContext::new_with_versions(&[]);
+
+    // Selecting the credential helper provider requires a credential helper.
+    uv_snapshot!(context.filters(), context.auth().arg("login")
+        .arg("example.com")
+        .arg("--token")
+        .arg("secret")
+        .arg("--keyring-provider")
+        .arg("helper")
+        .env_remove(EnvVars::UV_CREDENTIAL_HELPER), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--keyring-provider helper` requires a credential helper to be set via `--credential-helper` (or `credential-helper` in `uv.toml`)
+    "###);
+}
@@ -0,0 +1,137 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// A bundle created by `cache export` should allow an offline `sync` in a fresh cache, after
+/// `cache import`.
+#[test]
+fn cache_export_import_offline_sync() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let pyproject_toml = r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+    "#;
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(pyproject_toml)?;
+    context.lock().assert().success();
+
+    // Export the lockfile's distributions to a bundle.
+    let bundle = context.temp_dir.child("bundle.tar.zst");
+    uv_snapshot!(context.filters(), context.cache_export().arg("--lock").arg("uv.lock").arg("-o").arg(bundle.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Exported 1 distribution to: bundle.tar.zst ([SIZE])
+    "###);
+
+    bundle.assert(predicates::path::is_file());
+
+    // Import the bundle into a fresh cache, and sync without network access.
+    let target = TestContext::new("3.12");
+    target
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(pyproject_toml)?;
+    target
+        .temp_dir
+        .child("uv.lock")
+        .write_str(&fs_err::read_to_string(context.temp_dir.join("uv.lock"))?)?;
+
+    uv_snapshot!(target.filters(), target.cache_import().arg(bundle.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Imported bundle into: [CACHE_DIR]/
+    "###);
+
+    uv_snapshot!(target.filters(), target.sync().arg("--offline").arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    Ok(())
+}
+
+/// `cache import` should reject bundles with links that point outside of the bundle.
+#[tokio::test]
+#[cfg(unix)]
+async fn cache_import_reject_links() -> Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    /// Write a bundle that contains a single link, at `wheels-v3/pypi/evil`.
+    async fn write_bundle(path: &std::path::Path, link: &str) -> Result<()> {
+        let mut builder = tar::Builder::new(Vec::new());
+        let mut header = tar::Header::new_gnu();
+        header.set_entry_type(tar::EntryType::Symlink);
+        header.set_size(0);
+        header.set_mode(0o777);
+        builder.append_link(&mut header, "wheels-v3/pypi/evil", link)?;
+        let contents = builder.into_inner()?;
+
+        let mut encoder = async_compression::tokio::write::ZstdEncoder::new(Vec::new());
+        encoder.write_all(&contents).await?;
+        encoder.shutdown().await?;
+        fs_err::write(path, encoder.into_inner())?;
+        Ok(())
+    }
+
+    let context = TestContext::new("3.12");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"\[CACHE_DIR\]/\.tmp\w+", "[STAGING]")])
+        .collect::<Vec<_>>();
+
+    // A link with an absolute target.
+    let bundle = context.temp_dir.child("absolute.tar.zst");
+    write_bundle(bundle.path(), "/etc").await?;
+    uv_snapshot!(filters, context.cache_import().arg(bundle.path()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to import bundle: absolute.tar.zst
+      Caused by: Link `[STAGING]/wheels-v3/pypi/evil` has an absolute target: `/etc`
+    "###);
+
+    // A link that escapes the bundle.
+    let bundle = context.temp_dir.child("escape.tar.zst");
+    write_bundle(bundle.path(), "../../../../etc").await?;
+    uv_snapshot!(filters, context.cache_import().arg(bundle.path()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to import bundle: escape.tar.zst
+      Caused by: Link `[STAGING]/wheels-v3/pypi/evil` points outside of `[STAGING]`: `../../../../etc`
+    "###);
+
+    // Nothing should have been imported.
+    context
+        .cache_dir
+        .child("wheels-v3")
+        .child("pypi")
+        .child("evil")
+        .assert(predicates::path::missing());
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// `cache list` should include the wheels that were downloaded from PyPI.
+#[test]
+fn cache_list_package() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r#""path":"[^"]*""#, r#""path":"[PATH]""#),
+            (r#""size":\d+"#, r#""size":[SIZE]"#),
+            (r#""modified":"[^"]*""#, r#""modified":"[DATE]""#),
+        ])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.cache_list().arg("--package").arg("iniconfig").arg("--output-format").arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"kind":"wheel","name":"iniconfig","version":"2.0.0","identifier":"iniconfig-2.0.0-py3-none-any.whl","path":"[PATH]","size":[SIZE],"modified":"[DATE]","origin":"pypi"}]
+
+    ----- stderr -----
+    "###);
+
+    // Filtering by another package should omit the wheel.
+    uv_snapshot!(filters, context.cache_list().arg("--package").arg("anyio").arg("--output-format").arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    []
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(filters, context.cache_list().arg("--package").arg("anyio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No cache entries found
+    "###);
+
+    Ok(())
+}
@@ -381,3 +381,125 @@ fn prune_stale_revision() -> Result<()> {
 
     Ok(())
 }
+
+/// `cache prune --max-size` should evict unzipped archives until the cache fits within the limit.
+#[test]
+fn prune_max_size() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    // Install a requirement, to populate the cache. Copy the files, such that the archive isn't
+    // hard-linked into the environment.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--link-mode")
+        .arg("copy")
+        .assert()
+        .success();
+
+    let filters: Vec<_> = std::iter::once((r"Removed \d+ files", "Removed [N] files"))
+        .chain(context.filters())
+        .collect();
+
+    // A generous limit should leave the cache untouched.
+    uv_snapshot!(&filters, context.prune().arg("--max-size").arg("1GiB"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    No unused entries found
+    "###);
+
+    // An empty limit should evict the archive.
+    uv_snapshot!(&filters, context.prune().arg("--max-size").arg("0B"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Removed [N] files ([SIZE])
+    "###);
+
+    assert!(fs_err::read_dir(context.cache_dir.child("archive-v0"))?
+        .next()
+        .is_none());
+
+    // The pointers to the archive should be evicted along with it.
+    assert!(walkdir::WalkDir::new(context.cache_dir.child("wheels-v3"))
+        .into_iter()
+        .filter_map(Result::ok)
+        .all(|entry| entry.file_type().is_dir()));
+
+    Ok(())
+}
+
+/// `cache prune --max-size` should never evict archives that are symlinked into an environment.
+#[cfg(unix)]
+#[test]
+fn prune_max_size_symlink() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    // Install a requirement, linking the files from the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--link-mode")
+        .arg("symlink")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.prune().arg("--max-size").arg("0B"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    No unused entries found
+    "###);
+
+    // The environment should still be usable.
+    context.assert_command("import iniconfig").success();
+
+    Ok(())
+}
+
+/// `cache prune --older-than` should only evict archives that haven't been used recently.
+#[test]
+fn prune_older_than() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--link-mode")
+        .arg("copy")
+        .assert()
+        .success();
+
+    // The archive was just used, so it should be retained.
+    uv_snapshot!(context.filters(), context.prune().arg("--older-than").arg("30d"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    No unused entries found
+    "###);
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// `cache size` should report the size of each cache bucket.
+#[test]
+fn cache_size() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.cache_size(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    wheels-v3          [SIZE]
+    sdists-v7          [SIZE]
+    flat-index-v2      [SIZE]
+    git-v0             [SIZE]
+    interpreter-v5     [SIZE]
+    simple-v15         [SIZE]
+    archive-v0         [SIZE]
+    builds-v0          [SIZE]
+    environments-v1    [SIZE]
+    Total              [SIZE]
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+/// `cache size --output-format json` should report the size of each cache bucket.
+#[test]
+fn cache_size_json() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r#""path":"[^"]*""#, r#""path":"[PATH]""#),
+            (r#""size":\d+"#, r#""size":[SIZE]"#),
+        ])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.cache_size().arg("--output-format").arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"path":"[PATH]","size":[SIZE],"buckets":[{"bucket":"wheels-v3","path":"[PATH]","size":[SIZE]},{"bucket":"sdists-v7","path":"[PATH]","size":[SIZE]},{"bucket":"flat-index-v2","path":"[PATH]","size":[SIZE]},{"bucket":"git-v0","path":"[PATH]","size":[SIZE]},{"bucket":"interpreter-v5","path":"[PATH]","size":[SIZE]},{"bucket":"simple-v15","path":"[PATH]","size":[SIZE]},{"bucket":"archive-v0","path":"[PATH]","size":[SIZE]},{"bucket":"builds-v0","path":"[PATH]","size":[SIZE]},{"bucket":"environments-v1","path":"[PATH]","size":[SIZE]}]}
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
@@ -0,0 +1,232 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::uv_snapshot;
+use crate::common::TestContext;
+
+/// Filter the randomly-generated IDs of the unzipped wheels in the cache.
+const ARCHIVE_FILTER: (&str, &str) = (r"archive-v0/[A-Za-z0-9_-]+", "archive-v0/[ID]");
+
+/// `cache verify` should succeed on a freshly-populated cache.
+#[test]
+fn cache_verify_clean() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.cache_verify(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    No issues found
+    "###);
+
+    Ok(())
+}
+
+/// `cache verify` should detect a corrupt unzipped wheel, and `--repair` should remove it, such
+/// that the wheel is re-fetched when next needed.
+#[test]
+fn cache_verify_corrupt_archive() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Truncate a file in the unzipped wheel.
+    let archive = fs_err::read_dir(context.cache_dir.child("archive-v0"))?
+        .next()
+        .expect("unzipped wheel")?
+        .path();
+    fs_err::write(archive.join("iniconfig").join("__init__.py"), "")?;
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain(std::iter::once(ARCHIVE_FILTER))
+        .collect();
+
+    uv_snapshot!(&filters, context.cache_verify(), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    corrupt-archive: [CACHE_DIR]/archive-v0/[ID]
+      RECORD file doesn't match wheel contents: Size mismatch for: iniconfig/__init__.py
+    dangling-pointer: [CACHE_DIR]/wheels-v3/pypi/iniconfig/iniconfig-2.0.0-py3-none-any.http
+      Refers to a corrupt unzipped wheel: [CACHE_DIR]/archive-v0/[ID]
+    Found 2 issues (run `uv cache verify --repair` to remove the affected entries)
+    "###);
+
+    // Repair the cache.
+    uv_snapshot!(&filters, context.cache_verify().arg("--repair"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    corrupt-archive: [CACHE_DIR]/archive-v0/[ID]
+      RECORD file doesn't match wheel contents: Size mismatch for: iniconfig/__init__.py
+    dangling-pointer: [CACHE_DIR]/wheels-v3/pypi/iniconfig/iniconfig-2.0.0-py3-none-any.http
+      Refers to a corrupt unzipped wheel: [CACHE_DIR]/archive-v0/[ID]
+    Repaired 2 issues ([SIZE] removed)
+    "###);
+
+    assert!(!archive.exists());
+
+    // The cache should now be valid.
+    uv_snapshot!(&filters, context.cache_verify(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    No issues found
+    "###);
+
+    // Re-installing should re-fetch the wheel.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .assert()
+        .success();
+
+    uv_snapshot!(&filters, context.cache_verify(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    No issues found
+    "###);
+
+    Ok(())
+}
+
+/// `cache verify` should report, but not fail on, unzipped wheels that aren't referenced by any
+/// cache entry; `--repair` should leave them to `cache prune`.
+#[test]
+fn cache_verify_orphaned_archive() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Remove the pointer to the unzipped wheel.
+    fs_err::remove_file(
+        context
+            .cache_dir
+            .child("wheels-v3")
+            .child("pypi")
+            .child("iniconfig")
+            .child("iniconfig-2.0.0-py3-none-any.http"),
+    )?;
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain(std::iter::once(ARCHIVE_FILTER))
+        .collect();
+
+    uv_snapshot!(&filters, context.cache_verify().arg("--repair"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    orphaned-archive: [CACHE_DIR]/archive-v0/[ID]
+      Not referenced by any cache entry
+    Found 1 unreferenced unzipped wheel (run `uv cache prune` to remove them)
+    No issues found
+    "###);
+
+    // The unzipped wheel should be left in place.
+    assert_eq!(
+        fs_err::read_dir(context.cache_dir.child("archive-v0"))?.count(),
+        1
+    );
+
+    Ok(())
+}
+
+/// `cache verify` should detect entries left behind by interrupted writes.
+#[test]
+fn cache_verify_partial_entry() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Simulate an interrupted unzip.
+    context
+        .cache_dir
+        .child("archive-v0")
+        .child(".tmpAbC123")
+        .create_dir_all()?;
+
+    uv_snapshot!(context.filters(), context.cache_verify(), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    partial-entry: [CACHE_DIR]/archive-v0/.tmpAbC123
+      Incomplete entry
+    Found 1 issue (run `uv cache verify --repair` to remove the affected entries)
+    "###);
+
+    uv_snapshot!(context.filters(), context.cache_verify().arg("--repair"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    partial-entry: [CACHE_DIR]/archive-v0/.tmpAbC123
+      Incomplete entry
+    Repaired 1 issue
+    "###);
+
+    Ok(())
+}
@@ -532,6 +532,7 @@ impl TestContext {
     /// * Don't wrap text output based on the terminal we're in, the test output doesn't get printed
     ///   but snapshotted to a string.
     /// * Use a fake `HOME` to avoid accidentally changing the developer's machine.
+    /// * Use a temporary credential store, rather than the developer's stored credentials.
     /// * Hide other Pythons with `UV_PYTHON_INSTALL_DIR` and installed interpreters with
     ///   `UV_TEST_PYTHON_PATH` and an active venv (if applicable) by removing `VIRTUAL_ENV`.
     /// * Increase the stack size to avoid stack overflows on windows due to large async functions.
@@ -550,6 +551,10 @@ impl TestContext {
             .env(EnvVars::UV_NO_WRAP, "1")
             .env(EnvVars::PATH, path)
             .env(EnvVars::HOME, self.home_dir.as_os_str())
+            .env(
+                EnvVars::UV_CREDENTIALS_DIR,
+                self.temp_dir.child("credentials").as_os_str(),
+            )
             .env(EnvVars::UV_PYTHON_INSTALL_DIR, "")
             // Installations are not allowed by default; see `Self::with_managed_python_dirs`
             .env(EnvVars::UV_PYTHON_DOWNLOADS, "never")
@@ -746,6 +751,17 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv python verify` command with options shared across scenarios.
+    pub fn python_verify(&self) -> Command {
+        let mut command = self.new_command();
+        self.add_shared_args(&mut command, true);
+        command
+            .arg("python")
+            .arg("verify")
+            .current_dir(&self.temp_dir);
+        command
+    }
+
     /// Create a `uv python pin` command with options shared across scenarios.
     pub fn python_pin(&self) -> Command {
         let mut command = self.new_command();
@@ -861,6 +877,54 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv cache size` command.
+    pub fn cache_size(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("size");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache list` command.
+    pub fn cache_list(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("list");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache verify` command.
+    pub fn cache_verify(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("verify");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache export` command.
+    pub fn cache_export(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("export");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache import` command.
+    pub fn cache_import(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("import");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv auth` command.
+    pub fn auth(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("auth");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
     /// Create a `uv build_backend` command.
     ///
     /// Note that this command is hidden and only invoking it through a build frontend is supported.
@@ -1204,6 +1268,7 @@ pub fn python_installations_for_versions(
                 &PythonRequest::parse(python_version),
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::Managed,
+                &[],
                 &cache,
             ) {
                 python.into_interpreter().sys_executable().to_owned()
@@ -28,6 +28,7 @@ fn help() {
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      auth                       Manage credentials for package indexes and other hosts
       cache                      Manage uv's cache
       self                       Manage the uv executable
       version                    Display uv's version
@@ -45,6 +46,8 @@ fn help() {
               [possible values: only-managed, managed, system, only-system]
           --no-python-downloads
               Disable automatic downloads of Python. [env: "UV_PYTHON_DOWNLOADS=never"]
+          --no-external-version-files
+              Ignore Python version files owned by other tools [env: UV_NO_EXTERNAL_VERSION_FILES=]
 
     Global options:
       -q, --quiet
@@ -109,6 +112,7 @@ fn help_flag() {
       venv     Create a virtual environment
       build    Build Python packages into source distributions and wheels
       publish  Upload distributions to an index
+      auth     Manage credentials for package indexes and other hosts
       cache    Manage uv's cache
       self     Manage the uv executable
       version  Display uv's version
@@ -125,6 +129,8 @@ fn help_flag() {
               [possible values: only-managed, managed, system, only-system]
           --no-python-downloads
               Disable automatic downloads of Python. [env: "UV_PYTHON_DOWNLOADS=never"]
+          --no-external-version-files
+              Ignore Python version files owned by other tools [env: UV_NO_EXTERNAL_VERSION_FILES=]
 
     Global options:
       -q, --quiet
@@ -188,6 +194,7 @@ fn help_short_flag() {
       venv     Create a virtual environment
       build    Build Python packages into source distributions and wheels
       publish  Upload distributions to an index
+      auth     Manage credentials for package indexes and other hosts
       cache    Manage uv's cache
       self     Manage the uv executable
       version  Display uv's version
@@ -204,6 +211,8 @@ fn help_short_flag() {
               [possible values: only-managed, managed, system, only-system]
           --no-python-downloads
               Disable automatic downloads of Python. [env: "UV_PYTHON_DOWNLOADS=never"]
+          --no-external-version-files
+              Ignore Python version files owned by other tools [env: UV_NO_EXTERNAL_VERSION_FILES=]
 
     Global options:
       -q, --quiet
@@ -297,6 +306,7 @@ fn help_subcommand() {
       pin        Pin to a specific Python version
       dir        Show the uv Python installation directory
       uninstall  Uninstall Python versions
+      verify     Verify the integrity of managed Python installations
 
     Cache options:
       -n, --no-cache
@@ -336,6 +346,13 @@ fn help_subcommand() {
           --no-python-downloads
               Disable automatic downloads of Python. [env: "UV_PYTHON_DOWNLOADS=never"]
 
+          --no-external-version-files
+              Ignore Python version files owned by other tools.
+              
+              By default, `.tool-versions`, `mise.toml`, and `runtime.txt` files are also respected.
+              
+              [env: UV_NO_EXTERNAL_VERSION_FILES=]
+
     Global options:
       -q, --quiet
               Do not print any output
@@ -582,6 +599,13 @@ fn help_subsubcommand() {
           --no-python-downloads
               Disable automatic downloads of Python. [env: "UV_PYTHON_DOWNLOADS=never"]
 
+          --no-external-version-files
+              Ignore Python version files owned by other tools.
+              
+              By default, `.tool-versions`, `mise.toml`, and `runtime.txt` files are also respected.
+              
+              [env: UV_NO_EXTERNAL_VERSION_FILES=]
+
     Global options:
       -q, --quiet
               Do not print any output
@@ -711,6 +735,7 @@ fn help_flag_subcommand() {
       pin        Pin to a specific Python version
       dir        Show the uv Python installation directory
       uninstall  Uninstall Python versions
+      verify     Verify the integrity of managed Python installations
 
     Cache options:
       -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
@@ -723,6 +748,8 @@ fn help_flag_subcommand() {
               [possible values: only-managed, managed, system, only-system]
           --no-python-downloads
               Disable automatic downloads of Python. [env: "UV_PYTHON_DOWNLOADS=never"]
+          --no-external-version-files
+              Ignore Python version files owned by other tools [env: UV_NO_EXTERNAL_VERSION_FILES=]
 
     Global options:
       -q, --quiet
@@ -796,6 +823,8 @@ fn help_flag_subsubcommand() {
               [possible values: only-managed, managed, system, only-system]
           --no-python-downloads
               Disable automatic downloads of Python. [env: "UV_PYTHON_DOWNLOADS=never"]
+          --no-external-version-files
+              Ignore Python version files owned by other tools [env: UV_NO_EXTERNAL_VERSION_FILES=]
 
     Global options:
       -q, --quiet
@@ -906,6 +935,7 @@ fn help_unknown_subsubcommand() {
         pin
         dir
         uninstall
+        verify
     "###);
 }
 
@@ -936,6 +966,7 @@ fn help_with_global_option() {
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      auth                       Manage credentials for package indexes and other hosts
       cache                      Manage uv's cache
       self                       Manage the uv executable
       version                    Display uv's version
@@ -953,6 +984,8 @@ fn help_with_global_option() {
               [possible values: only-managed, managed, system, only-system]
           --no-python-downloads
               Disable automatic downloads of Python. [env: "UV_PYTHON_DOWNLOADS=never"]
+          --no-external-version-files
+              Ignore Python version files owned by other tools [env: UV_NO_EXTERNAL_VERSION_FILES=]
 
     Global options:
       -q, --quiet
@@ -1052,6 +1085,7 @@ fn help_with_no_pager() {
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      auth                       Manage credentials for package indexes and other hosts
       cache                      Manage uv's cache
       self                       Manage the uv executable
       version                    Display uv's version
@@ -1069,6 +1103,8 @@ fn help_with_no_pager() {
               [possible values: only-managed, managed, system, only-system]
           --no-python-downloads
               Disable automatic downloads of Python. [env: "UV_PYTHON_DOWNLOADS=never"]
+          --no-external-version-files
+              Ignore Python version files owned by other tools [env: UV_NO_EXTERNAL_VERSION_FILES=]
 
     Global options:
       -q, --quiet
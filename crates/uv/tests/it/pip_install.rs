@@ -267,6 +267,50 @@ fn invalid_uv_toml_option_disallowed() -> Result<()> {
     Ok(())
 }
 
+/// Invalid `host-tls` settings should error, rather than falling back to the default TLS settings.
+#[test]
+fn invalid_uv_toml_host_tls() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(indoc! {r#"
+        host-tls = [
+            { host = "pypi.internal.example.com", ca-cert = "missing.pem" },
+        ]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Invalid TLS settings for `pypi.internal.example.com`
+      Caused by: failed to open file `missing.pem`: No such file or directory (os error 2)
+    "###
+    );
+
+    uv_toml.write_str(indoc! {r#"
+        host-tls = [
+            { host = "pypi.internal.example.com", client-key = "client.key" },
+        ]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Invalid TLS settings for `pypi.internal.example.com`
+      Caused by: `client-key` was provided without a `client-cert`
+    "###
+    );
+
+    Ok(())
+}
+
 /// For indirect, non-user controlled pyproject.toml, we don't enforce correctness.
 ///
 /// If we fail to extract the PEP 621 metadata, we fall back to treating it as a source
@@ -5174,6 +5218,69 @@ fn install_index_with_relative_links() {
     context.assert_command("import anyio").success();
 }
 
+/// Install a package from an index that requires authentication from the credential store.
+#[test]
+fn install_package_basic_auth_from_credential_store() {
+    let context = TestContext::new("3.12");
+
+    context
+        .auth()
+        .arg("login")
+        .arg("pypi-proxy.fly.dev")
+        .arg("--username")
+        .arg("public")
+        .arg("--password")
+        .arg("heron")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.pip_install()
+        .arg("anyio")
+        .arg("--index-url")
+        .arg("https://pypi-proxy.fly.dev/basic-auth/simple")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    context.assert_command("import anyio").success();
+
+    // Without the stored credentials, the index is inaccessible.
+    context
+        .auth()
+        .arg("logout")
+        .arg("pypi-proxy.fly.dev")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.pip_install()
+        .arg("iniconfig")
+        .arg("--index-url")
+        .arg("https://pypi-proxy.fly.dev/basic-auth/simple")
+        .arg("--strict"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because iniconfig was not found in the package registry and you require iniconfig, we can conclude that your requirements are unsatisfiable.
+
+          hint: An index URL (https://pypi-proxy.fly.dev/basic-auth/simple) could not be queried due to a lack of valid authentication credentials (401 Unauthorized).
+    "###
+    );
+}
+
 /// Install a package from an index that requires authentication from the keyring.
 #[test]
 fn install_package_basic_auth_from_keyring() {
@@ -8776,3 +8883,240 @@ fn no_sources_workspace_discovery() -> Result<()> {
 
     Ok(())
 }
+
+/// Install a package from a read-only cache layer, without modifying the layer.
+#[test]
+fn install_readonly_cache_layer() -> Result<()> {
+    /// Collect the paths and modification times of the entries in a directory.
+    fn listing(root: &std::path::Path) -> Vec<(std::path::PathBuf, std::time::SystemTime)> {
+        let mut entries = walkdir::WalkDir::new(root)
+            .into_iter()
+            .map(|entry| {
+                let entry = entry.unwrap();
+                let modified = entry.metadata().unwrap().modified().unwrap();
+                (entry.into_path(), modified)
+            })
+            .collect::<Vec<_>>();
+        entries.sort();
+        entries
+    }
+
+    // Populate the read-only cache.
+    let seed = TestContext::new("3.12");
+    seed.pip_install().arg("iniconfig").assert().success();
+    let before = listing(seed.cache_dir.path());
+
+    // Install from the read-only cache, without network access.
+    let context = TestContext::new("3.12");
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .arg("--offline")
+        .env(EnvVars::UV_CACHE_READONLY_DIRS, seed.cache_dir.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // The read-only cache should be unmodified.
+    assert_eq!(before, listing(seed.cache_dir.path()));
+
+    // The entries should have been imported into the cache.
+    context.assert_installed("iniconfig", "2.0.0");
+    assert!(context.cache_dir.child("archive-v0").exists());
+
+    Ok(())
+}
+
+/// Install a package from a read-only cache layer that was moved after it was populated, as
+/// configured via `cache-readonly-dirs`.
+#[test]
+#[cfg(unix)]
+fn install_readonly_cache_layer_relocated() -> Result<()> {
+    // Populate the read-only cache, then move it, such that its links into the archive bucket
+    // point at its original location.
+    let seed = TestContext::new("3.12");
+    seed.pip_install().arg("iniconfig").assert().success();
+
+    let context = TestContext::new("3.12");
+    let relocated = context.temp_dir.child("relocated");
+    fs::rename(seed.cache_dir.path(), relocated.path())?;
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(&format!(
+        "cache-readonly-dirs = ['{}']",
+        relocated.path().display()
+    ))?;
+
+    // Install from the read-only cache, without network access.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .arg("--offline"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    context.assert_installed("iniconfig", "2.0.0");
+
+    // Any links in the cache should point into the cache itself, rather than into the read-only
+    // cache (or its original location).
+    for entry in walkdir::WalkDir::new(context.cache_dir.path()) {
+        let entry = entry?;
+        if entry.path_is_symlink() {
+            let target = fs::read_link(entry.path())?;
+            assert!(
+                target.starts_with(context.cache_dir.path()),
+                "`{}` points outside of the cache: `{}`",
+                entry.path().display(),
+                target.display()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Share wheels built from source distributions via a remote build cache.
+#[test]
+fn install_remote_build_cache() -> Result<()> {
+    let remote = assert_fs::TempDir::new()?;
+
+    // Only show the logs for the remote cache.
+    let remote_log = "uv_distribution::source::remote=debug";
+
+    // Build `iniconfig` from source, which should upload the wheel to the remote cache.
+    let seed = TestContext::new("3.12").with_filtered_path(remote.path(), "REMOTE");
+    let filters = seed
+        .filters()
+        .into_iter()
+        .chain([(
+            r"built-wheels-v0/iniconfig/\w+",
+            "built-wheels-v0/iniconfig/[KEY]",
+        )])
+        .collect::<Vec<_>>();
+    uv_snapshot!(filters, seed.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--no-binary")
+        .arg("iniconfig")
+        .env(EnvVars::UV_REMOTE_BUILD_CACHE, remote.path())
+        .env(EnvVars::RUST_LOG, remote_log), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    DEBUG No built wheels in remote cache for: built-wheels-v0/iniconfig/[KEY]
+    DEBUG Uploading built wheel to remote cache: iniconfig-2.0.0-py3-none-any.whl
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    let index = walkdir::WalkDir::new(remote.path())
+        .into_iter()
+        .filter_map(Result::ok)
+        .find(|entry| entry.file_name() == "index.json")
+        .expect("remote cache to contain an index");
+    insta::with_settings!({
+        filters => vec![(r"[0-9a-f]{64}", "[SHA256]")],
+    }, {
+        insta::assert_snapshot!(fs::read_to_string(index.path())?, @r###"
+        {
+          "wheels": [
+            {
+              "filename": "iniconfig-2.0.0-py3-none-any.whl",
+              "sha256": "[SHA256]"
+            }
+          ]
+        }
+        "###);
+    });
+    let wheel = index
+        .path()
+        .with_file_name("iniconfig-2.0.0-py3-none-any.whl");
+    assert!(wheel.is_file());
+
+    // With a fresh cache, the wheel should be fetched from the remote cache, rather than built.
+    let context = TestContext::new("3.12").with_filtered_path(remote.path(), "REMOTE");
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--no-binary")
+        .arg("iniconfig")
+        .env(EnvVars::UV_REMOTE_BUILD_CACHE, remote.path())
+        .env(EnvVars::RUST_LOG, remote_log), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    DEBUG Fetched built wheel from remote cache: iniconfig-2.0.0-py3-none-any.whl
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // If the wheel in the remote cache is corrupted, it should be rejected, and built locally.
+    fs::write(&wheel, "corrupted")?;
+    let context = TestContext::new("3.12").with_filtered_path(remote.path(), "REMOTE");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"[0-9a-f]{64}", "[SHA256]")])
+        .collect::<Vec<_>>();
+    uv_snapshot!(filters, context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--no-binary")
+        .arg("iniconfig")
+        .env(EnvVars::UV_REMOTE_BUILD_CACHE, remote.path())
+        .env(EnvVars::RUST_LOG, remote_log), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    WARN Failed to fetch built wheel from remote cache `[REMOTE]/`: Hash mismatch for `iniconfig-2.0.0-py3-none-any.whl` (expected `[SHA256]`, computed `[SHA256]`)
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Plain HTTP remote build caches are only supported for loopback addresses.
+#[test]
+fn install_remote_build_cache_insecure() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .env(EnvVars::UV_REMOTE_BUILD_CACHE, "http://cache.example.com/uv"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'http://cache.example.com/uv' for '--remote-build-cache <REMOTE_BUILD_CACHE>': `http://cache.example.com/uv` must use `https` (`http` is only supported for loopback addresses)
+
+    For more information, try '--help'.
+    "###
+    );
+}
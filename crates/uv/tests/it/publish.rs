@@ -1,9 +1,15 @@
 use crate::common::{uv_snapshot, venv_bin_path, TestContext};
 use assert_cmd::assert::OutputAssertExt;
 use assert_fs::fixture::{FileTouch, FileWriteStr, PathChild};
-use indoc::indoc;
+use indoc::{formatdoc, indoc};
 use std::env;
 use std::env::current_dir;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use url::Url;
 use uv_static::EnvVars;
 
 #[test]
@@ -117,6 +123,34 @@ fn missing_trusted_publishing_permission() {
     );
 }
 
+/// Check the error when the OIDC token file for trusted publishing doesn't exist.
+#[test]
+#[cfg(unix)]
+fn missing_oidc_token_file() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--publish-url")
+        .arg("https://test.pypi.org/legacy/")
+        .arg("--trusted-publishing")
+        .arg("always")
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl")
+        .env(EnvVars::UV_PUBLISH_OIDC_TOKEN_FILE, context.temp_dir.join("token"))
+        .env_remove(EnvVars::UV_PUBLISH_OIDC_TOKEN), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv publish` is experimental and may change without warning
+    Publishing 1 file to https://test.pypi.org/legacy/
+    error: Failed to obtain token for trusted publishing
+      Caused by: Failed to read the OIDC token
+      Caused by: failed to open file `[TEMP_DIR]/token`: No such file or directory (os error 2)
+    "###
+    );
+}
+
 /// Check the error when there are no credentials provided on GitHub Actions. Is it an incorrect
 /// trusted publishing configuration?
 #[test]
@@ -168,6 +202,86 @@ fn skip_existing_redirect() {
     );
 }
 
+/// Validate the files with `--check` and abort before uploading anything.
+#[test]
+fn check_invalid_wheel() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("-u")
+        .arg("dummy")
+        .arg("-p")
+        .arg("dummy")
+        .arg("--publish-url")
+        .arg("https://test.pypi.org/legacy/")
+        .arg("--check")
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl")
+        .arg("../../scripts/links/maturin-1.4.0-py3-none-any.whl"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv publish` is experimental and may change without warning
+    Checking ok-1.0.0-py3-none-any.whl: PASSED
+      warning: The metadata doesn't include a description, so the project page on the index will be empty
+    Checking maturin-1.4.0-py3-none-any.whl: FAILED
+      error: The hash of `maturin-1.4.0.dist-info/METADATA` doesn't match the hash in the `RECORD` file
+      error: `maturin-1.4.0.data/scripts/maturin` is listed in the `RECORD` file, but is missing from the wheel
+      warning: The metadata doesn't include a description, so the project page on the index will be empty
+    error: 1 file failed validation, no files were uploaded
+    "###
+    );
+}
+
+/// Publish to a local directory, laid out as a simple index.
+#[test]
+fn publish_to_directory() {
+    let context = TestContext::new("3.12");
+    let index = context.temp_dir.child("index");
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--publish-url")
+        .arg(Url::from_file_path(index.path()).unwrap().as_str())
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl")
+        .arg("../../scripts/links/tqdm-999.0.0.tar.gz"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv publish` is experimental and may change without warning
+    Publishing 2 files file://[TEMP_DIR]/index
+    Copying ok-1.0.0-py3-none-any.whl to [TEMP_DIR]/index
+    Copying tqdm-999.0.0.tar.gz to [TEMP_DIR]/index
+    "###
+    );
+
+    assert!(index
+        .child("ok")
+        .child("ok-1.0.0-py3-none-any.whl")
+        .exists());
+    assert!(index.child("tqdm").child("index.html").exists());
+    assert!(index.child("index.html").exists());
+
+    // Publishing the same files again is a no-op.
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--publish-url")
+        .arg(Url::from_file_path(index.path()).unwrap().as_str())
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv publish` is experimental and may change without warning
+    Publishing 1 file to file://[TEMP_DIR]/index
+    Copying ok-1.0.0-py3-none-any.whl to [TEMP_DIR]/index
+    File already exists, skipping
+    "###
+    );
+}
+
 #[test]
 fn dubious_filenames() {
     let context = TestContext::new("3.12");
@@ -399,3 +513,176 @@ fn invalid_index() {
     "###
     );
 }
+
+/// Start a registry on a local port that accepts wheels and rejects source distributions.
+///
+/// Each connection is served on its own thread, such that concurrent uploads are handled
+/// concurrently. Returns the address of the registry.
+fn start_upload_server() -> String {
+    start_flaky_upload_server(0).0
+}
+
+/// Start a registry like [`start_upload_server`], which responds to the first `failures` uploads
+/// with `418 I'm a teapot`. Returns the address of the registry and the number of uploads it
+/// received.
+fn start_flaky_upload_server(failures: usize) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let uploads = Arc::new(AtomicUsize::new(0));
+    let counter = uploads.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let counter = counter.clone();
+            thread::spawn(move || {
+                let fail = counter.fetch_add(1, Ordering::SeqCst) < failures;
+                handle_upload(stream, fail);
+            });
+        }
+    });
+    (address, uploads)
+}
+
+/// Read an upload request, with either a sized or a chunked body, and respond to it.
+fn handle_upload(mut stream: TcpStream, fail: bool) {
+    let mut request = Vec::new();
+    let mut buffer = [0; 8192];
+    loop {
+        let read = stream.read(&mut buffer).unwrap();
+        if read == 0 {
+            break;
+        }
+        request.extend_from_slice(&buffer[..read]);
+        let Some(header_end) = request.windows(4).position(|window| window == b"\r\n\r\n") else {
+            continue;
+        };
+        let headers = String::from_utf8_lossy(&request[..header_end]).to_ascii_lowercase();
+        let body = &request[header_end + 4..];
+        let complete = if let Some(length) = headers
+            .lines()
+            .find_map(|line| line.strip_prefix("content-length:"))
+        {
+            body.len() >= length.trim().parse::<usize>().unwrap()
+        } else {
+            body.ends_with(b"0\r\n\r\n")
+        };
+        if complete {
+            break;
+        }
+    }
+
+    let sdist = b"filename=\"tqdm-999.0.0.tar.gz\"";
+    let response = if fail {
+        "HTTP/1.1 418 I'm a teapot\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+    } else if request.windows(sdist.len()).any(|window| window == sdist) {
+        "HTTP/1.1 400 Bad Request\r\ncontent-type: text/plain\r\ncontent-length: 20\r\nconnection: close\r\n\r\nInvalid distribution"
+    } else {
+        "HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+    };
+    stream.write_all(response.as_bytes()).unwrap();
+}
+
+/// Upload files concurrently, where one of the uploads fails.
+#[test]
+fn concurrent_uploads_partial_failure() {
+    let context = TestContext::new("3.12");
+    let address = start_upload_server();
+    let address_filter = regex::escape(&address);
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(address_filter.as_str(), "[SERVER]")])
+        .collect::<Vec<_>>();
+
+    // All files are attempted, the results are summarized, and the failure leads to a non-zero
+    // exit code.
+    uv_snapshot!(filters, context.publish()
+        .arg("-u")
+        .arg("dummy")
+        .arg("-p")
+        .arg("dummy")
+        .arg("--trusted-publishing")
+        .arg("never")
+        .arg("--publish-url")
+        .arg(format!("http://{address}/legacy/"))
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl")
+        .arg("../../scripts/links/tqdm-999.0.0.tar.gz")
+        .env(EnvVars::UV_CONCURRENT_UPLOADS, "2"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv publish` is experimental and may change without warning
+    Publishing 2 files http://[SERVER]/legacy/
+    Uploading ok-1.0.0-py3-none-any.whl ([SIZE])
+    Uploading tqdm-999.0.0.tar.gz ([SIZE])
+
+    Uploaded ok-1.0.0-py3-none-any.whl
+      Failed tqdm-999.0.0.tar.gz
+    1 uploaded, 0 skipped, 1 failed
+    error: Failed to publish `../../scripts/links/tqdm-999.0.0.tar.gz` to http://[SERVER]/legacy/
+      Caused by: Upload failed with status code 400 Bad Request. Server says: Invalid distribution
+    "###
+    );
+}
+
+/// Uploads are retried with the HTTP settings of the index matching the publish URL.
+#[test]
+fn publish_url_index_retry_status_codes() {
+    let context = TestContext::new("3.12");
+    let (address, uploads) = start_flaky_upload_server(1);
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&formatdoc! {r#"
+            [project]
+            name = "foo"
+            version = "0.1.0"
+
+            [[tool.uv.index]]
+            name = "local"
+            url = "http://{address}/simple/"
+            publish-url = "http://{address}/legacy/"
+            http = {{ retries = 1, retry-backoff-min = 0, retry-backoff-max = 0, retry-status-codes = [418] }}
+        "#})
+        .unwrap();
+
+    let ok_wheel = current_dir()
+        .unwrap()
+        .join("../../scripts/links/ok-1.0.0-py3-none-any.whl");
+
+    context
+        .publish()
+        .arg("-u")
+        .arg("dummy")
+        .arg("-p")
+        .arg("dummy")
+        .arg("--trusted-publishing")
+        .arg("never")
+        .arg("--publish-url")
+        .arg(format!("http://{address}/legacy/"))
+        .arg(&ok_wheel)
+        .current_dir(context.temp_dir.path())
+        .assert()
+        .success();
+    assert_eq!(uploads.load(Ordering::SeqCst), 2);
+
+    // Without a matching index, the status code isn't retried.
+    let (address, uploads) = start_flaky_upload_server(1);
+    context
+        .publish()
+        .arg("-u")
+        .arg("dummy")
+        .arg("-p")
+        .arg("dummy")
+        .arg("--trusted-publishing")
+        .arg("never")
+        .arg("--publish-url")
+        .arg(format!("http://{address}/legacy/"))
+        .arg(&ok_wheel)
+        .current_dir(context.temp_dir.path())
+        .assert()
+        .failure();
+    assert_eq!(uploads.load(Ordering::SeqCst), 1);
+}
@@ -1,4 +1,4 @@
-use assert_fs::prelude::PathChild;
+use assert_fs::prelude::{FileTouch, PathChild, SymlinkToFile};
 use assert_fs::{fixture::FileWriteStr, prelude::PathCreateDir};
 use indoc::indoc;
 
@@ -663,3 +663,120 @@ fn python_find_venv_invalid() {
     ----- stderr -----
     "###);
 }
+
+#[cfg(unix)]
+#[test]
+fn python_find_discovery_sources() {
+    let context: TestContext = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    // Move Python 3.12 into a pyenv-style installation directory, outside the search path
+    let (_, executable) = context
+        .python_versions
+        .iter()
+        .find(|(version, _)| version.to_string() == "3.12")
+        .unwrap();
+    let pyenv_root = context.temp_dir.child("pyenv");
+    let bin = pyenv_root.child("versions").child("3.12").child("bin");
+    bin.create_dir_all().unwrap();
+    bin.child("python3").symlink_to_file(executable).unwrap();
+
+    let python_path = context.python_dir.join("3.11");
+
+    // Other tools' installations are not searched by default
+    uv_snapshot!(context.filters(), context.python_find()
+        .arg("3.12")
+        .env(EnvVars::UV_TEST_PYTHON_PATH, &python_path)
+        .env(EnvVars::PYENV_ROOT, pyenv_root.path()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No interpreter found for Python 3.12 in virtual environments, managed installations, or search path
+    "###);
+
+    // Once enabled, pyenv installations are found
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str(r#"python-discovery-sources = ["pyenv"]"#)
+        .unwrap();
+
+    uv_snapshot!(context.filters(), context.python_find()
+        .arg("3.12")
+        .env(EnvVars::UV_TEST_PYTHON_PATH, &python_path)
+        .env(EnvVars::PYENV_ROOT, pyenv_root.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/pyenv/versions/3.12/bin/python3
+
+    ----- stderr -----
+    "###);
+
+    // Interpreters on the search path are still preferred
+    uv_snapshot!(context.filters(), context.python_find()
+        .env(EnvVars::UV_TEST_PYTHON_PATH, &python_path)
+        .env(EnvVars::PYENV_ROOT, pyenv_root.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.11]
+
+    ----- stderr -----
+    "###);
+}
+
+#[cfg(unix)]
+#[test]
+fn python_find_discovery_sources_conda() {
+    let context: TestContext = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    // Create a conda environment with Python 3.12, outside the search path
+    let (_, executable) = context
+        .python_versions
+        .iter()
+        .find(|(version, _)| version.to_string() == "3.12")
+        .unwrap();
+    let conda_root = context.temp_dir.child("conda");
+    conda_root.child("bin").create_dir_all().unwrap();
+    conda_root.child("bin").child("conda").touch().unwrap();
+    let bin = conda_root.child("envs").child("science").child("bin");
+    bin.create_dir_all().unwrap();
+    bin.child("python3").symlink_to_file(executable).unwrap();
+
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str(r#"python-discovery-sources = ["conda"]"#)
+        .unwrap();
+
+    let python_path = context.python_dir.join("3.11");
+
+    // Conda environments are found when any interpreter is allowed
+    uv_snapshot!(context.filters(), context.python_find()
+        .arg("3.12")
+        .env(EnvVars::UV_TEST_PYTHON_PATH, &python_path)
+        .env(EnvVars::CONDA_EXE, conda_root.child("bin").child("conda").path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/conda/envs/science/bin/python3
+
+    ----- stderr -----
+    "###);
+
+    // But they are not system interpreters
+    uv_snapshot!(context.filters(), context.python_find()
+        .arg("3.12")
+        .arg("--system")
+        .env(EnvVars::UV_TEST_PYTHON_PATH, &python_path)
+        .env(EnvVars::CONDA_EXE, conda_root.child("bin").child("conda").path()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No interpreter found for Python 3.12 in managed installations or search path
+    "###);
+}
@@ -167,6 +167,96 @@ fn python_reinstall_patch() {
     "###);
 }
 
+#[cfg(unix)]
+#[test]
+fn python_verify() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_managed_python_dirs();
+
+    // Nothing to verify
+    uv_snapshot!(context.filters(), context.python_verify(), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    No Python installations found
+    "###);
+
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.8 in [TIME]
+     + cpython-3.12.8-[PLATFORM]
+    "###);
+
+    // A fresh installation should pass verification
+    uv_snapshot!(context.filters(), context.python_verify(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 1 installation in [TIME]
+    "###);
+
+    // Modify a file in the standard library
+    let installation = fs_err::read_dir(context.temp_dir.join("managed"))
+        .unwrap()
+        .filter_map(Result::ok)
+        .find(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .starts_with("cpython-3.12")
+        })
+        .unwrap()
+        .path();
+    fs_err::write(
+        installation.join("lib").join("python3.12").join("this.py"),
+        "",
+    )
+    .unwrap();
+
+    uv_snapshot!(context.filters(), context.python_verify(), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Python installation cpython-3.12.8-[PLATFORM] failed verification
+      - `lib/python3.12/this.py` was modified
+
+    hint: Use `uv python verify --repair` to reinstall the broken installations
+    "###);
+
+    // Repair the installation
+    uv_snapshot!(context.filters(), context.python_verify().arg("--repair"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Python installation cpython-3.12.8-[PLATFORM] failed verification
+      - `lib/python3.12/this.py` was modified
+    Installed Python 3.12.8 in [TIME]
+     ~ cpython-3.12.8-[PLATFORM]
+    "###);
+
+    uv_snapshot!(context.filters(), context.python_verify(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 1 installation in [TIME]
+    "###);
+}
+
 #[test]
 fn python_install_automatic() {
     let context: TestContext = TestContext::new_with_versions(&[])
@@ -6,6 +6,7 @@ use uv_python::{
     platform::{Arch, Os},
     PYTHON_VERSIONS_FILENAME, PYTHON_VERSION_FILENAME,
 };
+use uv_static::EnvVars;
 
 #[test]
 fn python_pin() {
@@ -659,3 +660,135 @@ fn python_pin_with_comments() -> Result<()> {
 
     Ok(())
 }
+
+/// Version files owned by other tools should be read, and updated in place.
+#[test]
+fn python_pin_tool_versions() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    let tool_versions = context.temp_dir.child(".tool-versions");
+    tool_versions.write_str(indoc::indoc! {r"
+        nodejs 20.11.0
+        python 3.11
+    "})?;
+
+    uv_snapshot!(context.filters(), context.python_pin(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.11
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.python_pin().arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Updated `.tool-versions` from `3.11` -> `3.12.[X]`
+
+    ----- stderr -----
+    "###);
+
+    // The file is updated with the resolved version, in asdf's syntax.
+    let tool_versions = context.read(".tool-versions");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(tool_versions, @r###"
+        nodejs 20.11.0
+        python 3.12.[X]
+        "###);
+    });
+
+    // A `.python-version` file takes precedence.
+    context
+        .temp_dir
+        .child(PYTHON_VERSION_FILENAME)
+        .write_str("3.11")?;
+    uv_snapshot!(context.filters(), context.python_pin(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.11
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+/// Version files owned by other tools can be ignored.
+#[test]
+fn python_pin_no_external_version_files() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    context
+        .temp_dir
+        .child("runtime.txt")
+        .write_str("python-3.12")?;
+
+    uv_snapshot!(context.filters(), context.python_pin(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.12
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.python_pin().env(EnvVars::UV_NO_EXTERNAL_VERSION_FILES, "1"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No pinned Python version found
+    "###);
+
+    Ok(())
+}
+
+/// `runtime.txt` files are updated in Heroku's syntax.
+#[test]
+fn python_pin_runtime_txt() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    context
+        .temp_dir
+        .child("runtime.txt")
+        .write_str("python-3.11\n")?;
+
+    uv_snapshot!(context.filters(), context.python_pin().arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Updated `runtime.txt` from `3.11` -> `3.12.[X]`
+
+    ----- stderr -----
+    "###);
+
+    let runtime_txt = context.read("runtime.txt");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(runtime_txt, @"python-3.12.[X]");
+    });
+
+    // Requests without a matching interpreter can't be written.
+    // (skip on Windows because the snapshot is different and the behavior is not platform dependent)
+    #[cfg(unix)]
+    {
+        uv_snapshot!(context.filters(), context.python_pin().arg("3.8"), @r###"
+        success: false
+        exit_code: 2
+        ----- stdout -----
+
+        ----- stderr -----
+        warning: No interpreter found for Python 3.8 in managed installations or search path
+        error: Failed to update `runtime.txt`: no Python installation found for `3.8`
+        "###);
+    }
+
+    Ok(())
+}
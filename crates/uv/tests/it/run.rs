@@ -105,15 +105,13 @@ fn run_with_python_version() -> Result<()> {
 
     ----- stderr -----
     Using CPython 3.11.[X] interpreter at: [PYTHON-3.11]
-    Removed virtual environment at: .venv
-    Creating virtual environment at: .venv
+    Updated Python interpreter for virtual environment at: .venv
     Resolved 5 packages in [TIME]
     Prepared 1 package in [TIME]
-    Installed 4 packages in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - anyio==3.7.0
      + anyio==3.6.0
-     + foo==1.0.0 (from file://[TEMP_DIR]/)
-     + idna==3.6
-     + sniffio==1.3.1
     "###);
 
     // This time, we target Python 3.8 instead.
@@ -3610,11 +3608,9 @@ fn run_active_environment() -> Result<()> {
 
     ----- stderr -----
     Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
-    Removed virtual environment at: foo
-    Creating virtual environment at: foo
+    Updated Python interpreter for virtual environment at: foo
     Resolved 2 packages in [TIME]
-    Installed 1 package in [TIME]
-     + iniconfig==2.0.0
+    Audited 1 package in [TIME]
     "###);
 
     Ok(())
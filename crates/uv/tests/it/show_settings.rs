@@ -65,13 +65,30 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -80,6 +97,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -131,6 +150,14 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         explicit: false,
                         default: true,
                         origin: None,
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                 ],
@@ -236,13 +263,30 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -251,6 +295,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -302,6 +348,14 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         explicit: false,
                         default: true,
                         origin: None,
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                 ],
@@ -408,13 +462,30 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -423,6 +494,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -474,6 +547,14 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         explicit: false,
                         default: true,
                         origin: None,
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                 ],
@@ -612,13 +693,30 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -627,6 +725,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -678,6 +778,14 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         explicit: false,
                         default: true,
                         origin: None,
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                 ],
@@ -785,13 +893,30 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -800,6 +925,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -937,13 +1064,30 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -952,6 +1096,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -1003,6 +1149,14 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         explicit: false,
                         default: true,
                         origin: None,
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                 ],
@@ -1133,13 +1287,30 @@ fn resolve_index_url() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -1148,6 +1319,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -1199,6 +1372,14 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         explicit: false,
                         default: false,
                         origin: None,
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                     Index {
@@ -1228,6 +1409,14 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         explicit: false,
                         default: true,
                         origin: None,
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                 ],
@@ -1335,13 +1524,30 @@ fn resolve_index_url() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -1350,6 +1556,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -1403,6 +1611,14 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         origin: Some(
                             Cli,
                         ),
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                     Index {
@@ -1432,6 +1648,14 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         explicit: false,
                         default: false,
                         origin: None,
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                     Index {
@@ -1461,6 +1685,14 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         explicit: false,
                         default: true,
                         origin: None,
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                 ],
@@ -1591,13 +1823,30 @@ fn resolve_find_links() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -1606,6 +1855,8 @@ fn resolve_find_links() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -1658,6 +1909,14 @@ fn resolve_find_links() -> anyhow::Result<()> {
                         explicit: false,
                         default: false,
                         origin: None,
+                        format: Flat,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                 ],
@@ -1786,13 +2045,30 @@ fn resolve_top_level() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -1801,6 +2077,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -1944,13 +2222,30 @@ fn resolve_top_level() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -1959,6 +2254,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -2010,6 +2307,14 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         explicit: false,
                         default: false,
                         origin: None,
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                     Index {
@@ -2039,6 +2344,14 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         explicit: false,
                         default: false,
                         origin: None,
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                 ],
@@ -2144,13 +2457,30 @@ fn resolve_top_level() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -2159,6 +2489,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -2210,6 +2542,14 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         explicit: false,
                         default: false,
                         origin: None,
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                     Index {
@@ -2239,6 +2579,14 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         explicit: false,
                         default: false,
                         origin: None,
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                 ],
@@ -2368,13 +2716,30 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -2383,6 +2748,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -2516,13 +2883,30 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -2531,6 +2915,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -2664,13 +3050,30 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -2679,6 +3082,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -2814,13 +3219,30 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -2829,6 +3251,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -2983,13 +3407,30 @@ fn resolve_tool() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -2998,6 +3439,8 @@ fn resolve_tool() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     ToolInstallSettings {
         package: "requirements.in",
@@ -3142,13 +3585,30 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -3157,6 +3617,8 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -3318,13 +3780,30 @@ fn resolve_both() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -3333,6 +3812,8 @@ fn resolve_both() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -3384,6 +3865,14 @@ fn resolve_both() -> anyhow::Result<()> {
                         explicit: false,
                         default: true,
                         origin: None,
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                 ],
@@ -3612,13 +4101,30 @@ fn resolve_config_file() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -3627,6 +4133,8 @@ fn resolve_config_file() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -3678,6 +4186,14 @@ fn resolve_config_file() -> anyhow::Result<()> {
                         explicit: false,
                         default: true,
                         origin: None,
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                 ],
@@ -3884,13 +4400,30 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -3899,6 +4432,8 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -4035,13 +4570,30 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -4050,6 +4602,8 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -4194,6 +4748,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [
@@ -4208,10 +4763,26 @@ fn allow_insecure_host() -> anyhow::Result<()> {
                 port: None,
             },
         ],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -4220,6 +4791,8 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -4367,13 +4940,30 @@ fn index_priority() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -4382,6 +4972,8 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -4435,6 +5027,14 @@ fn index_priority() -> anyhow::Result<()> {
                         origin: Some(
                             Cli,
                         ),
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                     Index {
@@ -4464,6 +5064,14 @@ fn index_priority() -> anyhow::Result<()> {
                         explicit: false,
                         default: false,
                         origin: None,
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                 ],
@@ -4569,13 +5177,30 @@ fn index_priority() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -4584,6 +5209,8 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -4637,6 +5264,14 @@ fn index_priority() -> anyhow::Result<()> {
                         origin: Some(
                             Cli,
                         ),
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                     Index {
@@ -4666,6 +5301,14 @@ fn index_priority() -> anyhow::Result<()> {
                         explicit: false,
                         default: false,
                         origin: None,
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                 ],
@@ -4777,13 +5420,30 @@ fn index_priority() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -4792,6 +5452,8 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -4845,6 +5507,14 @@ fn index_priority() -> anyhow::Result<()> {
                         origin: Some(
                             Cli,
                         ),
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                     Index {
@@ -4874,6 +5544,14 @@ fn index_priority() -> anyhow::Result<()> {
                         explicit: false,
                         default: true,
                         origin: None,
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                 ],
@@ -4980,13 +5658,30 @@ fn index_priority() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -4995,6 +5690,8 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -5048,6 +5745,14 @@ fn index_priority() -> anyhow::Result<()> {
                         origin: Some(
                             Cli,
                         ),
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                     Index {
@@ -5077,6 +5782,14 @@ fn index_priority() -> anyhow::Result<()> {
                         explicit: false,
                         default: true,
                         origin: None,
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                 ],
@@ -5190,13 +5903,30 @@ fn index_priority() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -5205,6 +5935,8 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -5258,6 +5990,14 @@ fn index_priority() -> anyhow::Result<()> {
                         origin: Some(
                             Cli,
                         ),
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                     Index {
@@ -5287,6 +6027,14 @@ fn index_priority() -> anyhow::Result<()> {
                         explicit: false,
                         default: true,
                         origin: None,
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                 ],
@@ -5393,13 +6141,30 @@ fn index_priority() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -5408,6 +6173,8 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipCompileSettings {
         src_file: [
@@ -5461,6 +6228,14 @@ fn index_priority() -> anyhow::Result<()> {
                         origin: Some(
                             Cli,
                         ),
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                     Index {
@@ -5490,6 +6265,14 @@ fn index_priority() -> anyhow::Result<()> {
                         explicit: false,
                         default: true,
                         origin: None,
+                        format: Simple,
+                        authenticate: Auto,
+                        auth_scheme: Basic,
+                        auth_header: None,
+                        cache_control: None,
+                        http: None,
+                        proxy: None,
+                        no_proxy: false,
                         publish_url: None,
                     },
                 ],
@@ -5609,13 +6392,30 @@ fn verify_hashes() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -5624,6 +6424,8 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipInstallSettings {
         package: [],
@@ -5751,13 +6553,30 @@ fn verify_hashes() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -5766,6 +6585,8 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipInstallSettings {
         package: [],
@@ -5891,13 +6712,30 @@ fn verify_hashes() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -5906,6 +6744,8 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipInstallSettings {
         package: [],
@@ -6033,13 +6873,30 @@ fn verify_hashes() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -6048,6 +6905,8 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipInstallSettings {
         package: [],
@@ -6173,13 +7032,30 @@ fn verify_hashes() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -6188,6 +7064,8 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipInstallSettings {
         package: [],
@@ -6314,13 +7192,30 @@ fn verify_hashes() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            uploads: 1,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        host_tls: [],
+        credential_helper: None,
+        http: IndexHttpPolicy {
+            retries: None,
+            retry_backoff_min: None,
+            retry_backoff_max: None,
+            connect_timeout: None,
+            read_timeout: None,
+            retry_status_codes: None,
+        },
+        proxy: ProxySettings {
+            proxy: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
+        no_external_version_files: false,
+        python_discovery_sources: [],
         no_progress: false,
         installer_metadata: true,
     }
@@ -6329,6 +7224,8 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        cache_readonly_dirs: [],
+        remote_build_cache: None,
     }
     PipInstallSettings {
         package: [],
@@ -3381,7 +3381,7 @@ fn sync_custom_environment_path() -> Result<()> {
     // Even with some extraneous content...
     fs_err::write(context.temp_dir.join("foo").join("file"), b"")?;
 
-    // We can delete and use it
+    // We can upgrade and use it
     uv_snapshot!(context.filters(), context.sync().env(EnvVars::UV_PROJECT_ENVIRONMENT, "foo"), @r###"
     success: true
     exit_code: 0
@@ -3389,8 +3389,7 @@ fn sync_custom_environment_path() -> Result<()> {
 
     ----- stderr -----
     Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
-    Removed virtual environment at: foo
-    Creating virtual environment at: foo
+    Updated Python interpreter for virtual environment at: foo
     Resolved 2 packages in [TIME]
     Installed 1 package in [TIME]
      + iniconfig==2.0.0
@@ -3514,11 +3513,9 @@ fn sync_active_environment() -> Result<()> {
 
     ----- stderr -----
     Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
-    Removed virtual environment at: foo
-    Creating virtual environment at: foo
+    Updated Python interpreter for virtual environment at: foo
     Resolved 2 packages in [TIME]
-    Installed 1 package in [TIME]
-     + iniconfig==2.0.0
+    Audited 1 package in [TIME]
     "###);
 
     Ok(())
@@ -4777,7 +4774,7 @@ fn sync_invalid_environment() -> Result<()> {
     // Even with some extraneous content...
     fs_err::write(context.temp_dir.join(".venv").join("file"), b"")?;
 
-    // We can delete and use it
+    // We can upgrade and use it
     uv_snapshot!(context.filters(), context.sync(), @r###"
     success: true
     exit_code: 0
@@ -4785,8 +4782,7 @@ fn sync_invalid_environment() -> Result<()> {
 
     ----- stderr -----
     Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
-    Removed virtual environment at: .venv
-    Creating virtual environment at: .venv
+    Updated Python interpreter for virtual environment at: .venv
     Resolved 2 packages in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
@@ -4976,13 +4972,9 @@ fn sync_python_version() -> Result<()> {
 
     ----- stderr -----
     Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
-    Removed virtual environment at: .venv
-    Creating virtual environment at: .venv
+    Updated Python interpreter for virtual environment at: .venv
     Resolved 4 packages in [TIME]
-    Installed 3 packages in [TIME]
-     + anyio==3.7.0
-     + idna==3.6
-     + sniffio==1.3.1
+    Audited 3 packages in [TIME]
     "###);
 
     // Create a pin that's incompatible with the project
@@ -6662,11 +6654,9 @@ fn sync_dry_run() -> Result<()> {
 
     ----- stderr -----
     Using CPython 3.8.[X] interpreter at: [PYTHON-3.8]
-    Removed virtual environment at: .venv
-    Creating virtual environment at: .venv
+    Updated Python interpreter for virtual environment at: .venv
     Resolved 2 packages in [TIME]
-    Installed 1 package in [TIME]
-     + iniconfig==2.0.0
+    Audited 1 package in [TIME]
     "###);
 
     uv_snapshot!(context.filters(), context.sync().arg("--dry-run"), @r###"
@@ -872,6 +872,141 @@ fn non_empty_dir_exists_allow_existing() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn upgrade_python() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--prompt")
+        .arg("foo")
+        .arg("--system-site-packages")
+        .arg("--python")
+        .arg("3.11"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.11.[X] interpreter at: [PYTHON-3.11]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate
+    "###
+    );
+
+    // Install a pure-Python package, a package with interpreter-specific wheels, and an editable.
+    context
+        .pip_install()
+        .arg("iniconfig")
+        .arg("markupsafe==2.1.3")
+        .arg("-e")
+        .arg(
+            context
+                .workspace_root
+                .join("scripts/packages/poetry_editable"),
+        )
+        .assert()
+        .success();
+
+    // Upgrading the interpreter should retain the compatible packages, reinstall the
+    // incompatible ones, and respect the provided options.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--upgrade-python")
+        .arg("--prompt")
+        .arg("bar")
+        .arg("--relocatable")
+        .arg("--seed")
+        .arg("--python")
+        .arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Updating virtual environment with seed packages at: .venv
+     ~ markupsafe==2.1.3
+     + pip==24.0
+    Activate with: source .venv/[BIN]/activate
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_freeze(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyio==4.3.0
+    idna==3.6
+    iniconfig==2.0.0
+    markupsafe==2.1.3
+    pip==24.0
+    -e file://[WORKSPACE]/scripts/packages/poetry_editable
+    sniffio==1.3.1
+
+    ----- stderr -----
+    "###
+    );
+
+    context.assert_command("import iniconfig").success();
+    context.assert_command("import markupsafe").success();
+    context.assert_command("import poetry_editable").success();
+
+    #[cfg(unix)]
+    {
+        context
+            .venv
+            .child("lib")
+            .child("python3.11")
+            .assert(predicates::path::missing());
+        context
+            .venv
+            .child("bin")
+            .child("python3.11")
+            .assert(predicates::path::missing());
+
+        let site_packages = context
+            .venv
+            .child("lib")
+            .child("python3.12")
+            .child("site-packages");
+        site_packages
+            .child("iniconfig")
+            .assert(predicates::path::is_dir());
+        site_packages
+            .child("MarkupSafe-2.1.3.dist-info")
+            .child("WHEEL")
+            .assert(predicates::str::contains("cp312-cp312"));
+    }
+
+    let pyvenv_cfg = fs_err::read_to_string(context.venv.child("pyvenv.cfg"))?;
+    assert!(pyvenv_cfg.contains("version_info = 3.12."));
+    assert!(pyvenv_cfg.contains("include-system-site-packages = false"));
+    assert!(pyvenv_cfg.contains("relocatable = true"));
+    assert!(pyvenv_cfg.contains("seed = true"));
+    assert!(pyvenv_cfg.contains("prompt = bar"));
+
+    // If there's no existing environment, one is created.
+    fs_err::remove_dir_all(&context.venv)?;
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--upgrade-python")
+        .arg("--python")
+        .arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate
+    "###
+    );
+
+    Ok(())
+}
+
 #[test]
 #[cfg(windows)]
 fn windows_shims() -> Result<()> {